@@ -13,8 +13,16 @@ pub fn generate_base(characteristics: DecimalCharacteristics) -> proc_macro::Tok
 
     let denominator = 10u128.pow(parsed_scale as u32);
     let almost_denominator = denominator.checked_sub(1).unwrap();
+    let half = denominator / 2;
 
     proc_macro::TokenStream::from(quote!(
+        impl #struct_name {
+            // constants evaluated at compile time, usable in const contexts
+            pub const DENOMINATOR: u128 = #denominator;
+            pub const ONE: u128 = #denominator;
+            pub const HALF: u128 = #half;
+        }
+
         impl Decimal for #struct_name {
             type U = #underlying_type;
 
@@ -4,7 +4,12 @@ use crate::utils::string_to_ident;
 use crate::DecimalCharacteristics;
 
 pub fn generate_checked_ops(characteristics: DecimalCharacteristics) -> proc_macro::TokenStream {
-    let DecimalCharacteristics { struct_name, .. } = characteristics;
+    let DecimalCharacteristics {
+        struct_name,
+        underlying_type,
+        big_type,
+        ..
+    } = characteristics;
 
     let name_str = &struct_name.to_string();
     let module_name = string_to_ident("tests_checked_ops_", &name_str);
@@ -26,6 +31,85 @@ pub fn generate_checked_ops(characteristics: DecimalCharacteristics) -> proc_mac
             }
         }
 
+        impl<T: Decimal> CheckedMulDiv<T> for #struct_name
+        where
+            T::U: TryInto<#underlying_type>,
+        {
+            fn checked_mul(self, rhs: T) -> std::result::Result<Self, String> {
+                Ok(Self::new(
+                    self.get()
+                    .checked_mul(rhs.get().try_into().map_err(|_| "checked_mul: can't convert rhs to underlying type")?)
+                    .ok_or_else(|| "checked_mul: (self * rhs) multiplication overflow")?
+                    .checked_div(T::checked_one()?)
+                    .ok_or_else(|| "checked_mul: ((self * rhs) / rhs::one()) division overflow")?
+                ))
+            }
+
+            fn checked_div(self, rhs: T) -> std::result::Result<Self, String> {
+                Ok(Self::new(
+                    self.get()
+                    .checked_mul(T::checked_one()?)
+                    .ok_or_else(|| "checked_div: (self * rhs::one()) multiplication overflow")?
+                    .checked_div(rhs.get().try_into().map_err(|_| "checked_div: can't convert rhs to underlying type")?)
+                    .ok_or_else(|| "checked_div: ((self * rhs::one()) / rhs) division overflow or division by zero")?
+                ))
+            }
+        }
+
+        impl<T: Decimal> CheckedBigOps<T> for #struct_name
+        where
+            T::U: TryInto<#big_type>,
+        {
+            fn checked_big_mul(self, rhs: T) -> std::result::Result<Self, String> {
+                Ok(Self::new(
+                    #big_type::try_from(self.get()).map_err(|_| "checked_big_mul: can't convert self to big_type")?
+                    .checked_mul(rhs.get().try_into().map_err(|_| "checked_big_mul: can't convert rhs to big_type")?)
+                    .ok_or_else(|| "checked_big_mul: (self * rhs) multiplication overflow")?
+                    .checked_div(T::checked_one()?)
+                    .ok_or_else(|| "checked_big_mul: ((self * rhs) / rhs::one()) division overflow")?
+                    .try_into().map_err(|_| "checked_big_mul: can't convert to result")?
+                ))
+            }
+
+            fn checked_big_mul_up(self, rhs: T) -> std::result::Result<Self, String> {
+                Ok(Self::new(
+                    #big_type::try_from(self.get()).map_err(|_| "checked_big_mul_up: can't convert self to big_type")?
+                    .checked_mul(rhs.get().try_into().map_err(|_| "checked_big_mul_up: can't convert rhs to big_type")?)
+                    .ok_or_else(|| "checked_big_mul_up: (self * rhs) multiplication overflow")?
+                    .checked_add(T::checked_one::<#big_type>()? - #big_type::from(1u8))
+                    .ok_or_else(|| "checked_big_mul_up: ((self * rhs) + rhs::almost_one()) addition overflow")?
+                    .checked_div(T::checked_one()?)
+                    .ok_or_else(|| "checked_big_mul_up: (((self * rhs) + rhs::almost_one()) / rhs::one()) division overflow")?
+                    .try_into().map_err(|_| "checked_big_mul_up: can't convert to result")?
+                ))
+            }
+
+            fn checked_big_div(self, rhs: T) -> std::result::Result<Self, String> {
+                Ok(Self::new(
+                    #big_type::try_from(self.get()).map_err(|_| "checked_big_div: can't convert self to big_type")?
+                    .checked_mul(T::checked_one()?)
+                    .ok_or_else(|| "checked_big_div: (self * rhs::one()) multiplication overflow")?
+                    .checked_div(rhs.get().try_into().map_err(|_| "checked_big_div: can't convert rhs to big_type")?)
+                    .ok_or_else(|| "checked_big_div: ((self * rhs::one()) / rhs) division overflow or division by zero")?
+                    .try_into().map_err(|_| "checked_big_div: can't convert to result")?
+                ))
+            }
+
+            fn checked_big_div_up(self, rhs: T) -> std::result::Result<Self, String> {
+                let rhs: #big_type = rhs.get().try_into().map_err(|_| "checked_big_div_up: can't convert rhs to big_type")?;
+                Ok(Self::new(
+                    #big_type::try_from(self.get()).map_err(|_| "checked_big_div_up: can't convert self to big_type")?
+                    .checked_mul(T::checked_one()?)
+                    .ok_or_else(|| "checked_big_div_up: (self * rhs::one()) multiplication overflow")?
+                    .checked_add(rhs.checked_sub(#big_type::from(1u8)).ok_or_else(|| "checked_big_div_up: division by zero")?)
+                    .ok_or_else(|| "checked_big_div_up: ((self * rhs::one()) + (rhs - 1)) addition overflow")?
+                    .checked_div(rhs)
+                    .ok_or_else(|| "checked_big_div_up: (((self * rhs::one()) + (rhs - 1)) / rhs) division overflow")?
+                    .try_into().map_err(|_| "checked_big_div_up: can't convert to result")?
+                ))
+            }
+        }
+
         #[cfg(test)]
         pub mod #module_name {
             use super::*;
@@ -61,6 +145,32 @@ pub fn generate_checked_ops(characteristics: DecimalCharacteristics) -> proc_mac
 
                 assert_eq!(result, Err("checked_sub: (self - rhs) subtraction underflow".to_string()));
             }
+
+            #[test]
+            fn test_checked_mul_div() {
+                let a = #struct_name::new(6);
+                let b = #struct_name::from_integer(3);
+
+                assert_eq!(a.checked_mul(b), Ok(#struct_name::new(18)));
+                assert_eq!(a.checked_div(b), Ok(#struct_name::new(2)));
+                assert!(#struct_name::max_instance().checked_mul(b).is_err());
+                assert!(a.checked_div(#struct_name::new(0)).is_err());
+            }
+
+            #[test]
+            fn test_checked_big_ops() {
+                let a = #struct_name::from_integer(6);
+                let b = #struct_name::from_integer(3);
+
+                assert_eq!(a.checked_big_mul(b), Ok(a.big_mul(b)));
+                assert_eq!(a.checked_big_mul_up(b), Ok(a.big_mul_up(b)));
+                assert_eq!(a.checked_big_div(b), Ok(a.big_div(b)));
+                assert_eq!(a.checked_big_div_up(b), Ok(a.big_div_up(b)));
+                assert_eq!(#struct_name::new(1).checked_big_div_up(#struct_name::new(3)), Ok(#struct_name::new(1).big_div_up(#struct_name::new(3))));
+                assert!(#struct_name::max_instance().checked_big_mul(b).is_err());
+                assert!(a.checked_big_div(#struct_name::new(0)).is_err());
+                assert!(a.checked_big_div_up(#struct_name::new(0)).is_err());
+            }
         }
     ))
 }
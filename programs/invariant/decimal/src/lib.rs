@@ -1,7 +1,7 @@
 mod traits;
 mod uint;
 
-pub use crate::uint::U256;
+pub use crate::uint::{to_u256, U256};
 
 pub use decimal_core::decimal;
 pub use num_traits;
@@ -28,6 +28,17 @@ struct N(u8);
 pub mod tests {
     use super::*;
 
+    #[test]
+    fn test_constants() {
+        assert_eq!(R::DENOMINATOR, 1000);
+        assert_eq!(R::ONE, R::one::<u128>());
+        assert_eq!(R::HALF, 500);
+        assert_eq!(Q::ONE, 10);
+        assert_eq!(Q::HALF, 5);
+        assert_eq!(N::ONE, 1);
+        assert_eq!(N::HALF, 0);
+    }
+
     #[test]
     fn test_from_decimal() {
         let r = R(42);
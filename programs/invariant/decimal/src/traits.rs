@@ -61,3 +61,15 @@ pub trait CheckedOps: Sized {
     fn checked_add(self, rhs: Self) -> std::result::Result<Self, String>;
     fn checked_sub(self, rhs: Self) -> std::result::Result<Self, String>;
 }
+
+pub trait CheckedMulDiv<T>: Sized {
+    fn checked_mul(self, rhs: T) -> std::result::Result<Self, String>;
+    fn checked_div(self, rhs: T) -> std::result::Result<Self, String>;
+}
+
+pub trait CheckedBigOps<T>: Sized {
+    fn checked_big_mul(self, rhs: T) -> std::result::Result<Self, String>;
+    fn checked_big_mul_up(self, rhs: T) -> std::result::Result<Self, String>;
+    fn checked_big_div(self, rhs: T) -> std::result::Result<Self, String>;
+    fn checked_big_div_up(self, rhs: T) -> std::result::Result<Self, String>;
+}
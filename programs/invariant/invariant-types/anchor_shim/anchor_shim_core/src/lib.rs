@@ -0,0 +1,152 @@
+// Attributes of anchor-lang 0.26 the account layouts are written with, expanded the same way
+// minus the parts needing a program around them (owners, account (de)serialization, idl)
+use quote::quote;
+use sha2::{Digest, Sha256};
+use syn::{parse_macro_input, ItemEnum, ItemStruct, LitStr};
+
+#[proc_macro_attribute]
+pub fn zero_copy(
+    _attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let account_struct = parse_macro_input!(item as ItemStruct);
+
+    // #[repr(C)] unless the struct picks its own
+    let repr = match account_struct
+        .attrs
+        .iter()
+        .any(|attr| attr.path.is_ident("repr"))
+    {
+        true => quote! {},
+        false => quote! { #[repr(C)] },
+    };
+
+    proc_macro::TokenStream::from(quote! {
+        #[derive(Copy, Clone)]
+        #repr
+        #account_struct
+    })
+}
+
+#[proc_macro_attribute]
+pub fn account(
+    attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    assert_eq!(
+        attr.to_string(),
+        "zero_copy",
+        "account: only zero copy accounts are supported"
+    );
+    let account_struct = parse_macro_input!(item as ItemStruct);
+    let name = &account_struct.ident;
+    let discriminator = &Sha256::digest(format!("account:{}", name).as_bytes())[..8];
+
+    proc_macro::TokenStream::from(quote! {
+        #[anchor_lang::zero_copy]
+        #account_struct
+
+        unsafe impl anchor_lang::__private::bytemuck::Pod for #name {}
+        unsafe impl anchor_lang::__private::bytemuck::Zeroable for #name {}
+
+        impl anchor_lang::Discriminator for #name {
+            const DISCRIMINATOR: [u8; 8] = [#(#discriminator),*];
+        }
+    })
+}
+
+#[proc_macro_attribute]
+pub fn error_code(
+    _attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let mut error_enum = parse_macro_input!(item as ItemEnum);
+    let name = error_enum.ident.clone();
+
+    let mut names = vec![];
+    let mut messages = vec![];
+    for variant in error_enum.variants.iter_mut() {
+        let ident = variant.ident.clone();
+        let ident_name = ident.to_string();
+        names.push(quote! { #name::#ident => #ident_name.to_string() });
+
+        let msg = match variant
+            .attrs
+            .iter()
+            .position(|attr| attr.path.is_ident("msg"))
+        {
+            Some(index) => Some(
+                variant
+                    .attrs
+                    .remove(index)
+                    .parse_args::<LitStr>()
+                    .expect("error_code: msg has to be a string literal"),
+            ),
+            None => None,
+        };
+        messages.push(match msg {
+            Some(msg) => quote! { #name::#ident => write!(fmt, #msg) },
+            None => quote! { #name::#ident => <Self as std::fmt::Debug>::fmt(self, fmt) },
+        });
+    }
+
+    proc_macro::TokenStream::from(quote! {
+        #[derive(std::fmt::Debug, Clone, Copy)]
+        #[repr(u32)]
+        #error_enum
+
+        impl #name {
+            pub fn name(&self) -> String {
+                match self {
+                    #(#names),*
+                }
+            }
+        }
+
+        impl From<#name> for u32 {
+            fn from(e: #name) -> u32 {
+                e as u32 + anchor_lang::error::ERROR_CODE_OFFSET
+            }
+        }
+
+        impl From<#name> for anchor_lang::error::Error {
+            fn from(error_code: #name) -> anchor_lang::error::Error {
+                anchor_lang::error::Error {
+                    error_name: error_code.name(),
+                    error_code_number: error_code.into(),
+                    error_msg: error_code.to_string(),
+                }
+            }
+        }
+
+        impl std::fmt::Display for #name {
+            fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    #(#messages),*
+                }
+            }
+        }
+    })
+}
+
+#[proc_macro]
+pub fn declare_id(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let id = parse_macro_input!(item as LitStr);
+    let bytes = bs58::decode(id.value())
+        .into_vec()
+        .expect("declare_id: id is not base58");
+    assert_eq!(bytes.len(), 32, "declare_id: id is not 32 bytes long");
+
+    proc_macro::TokenStream::from(quote! {
+        pub const ID: anchor_lang::solana_program::pubkey::Pubkey =
+            anchor_lang::solana_program::pubkey::Pubkey::new_from_array([#(#bytes),*]);
+
+        pub fn check_id(id: &anchor_lang::solana_program::pubkey::Pubkey) -> bool {
+            id == &ID
+        }
+
+        pub fn id() -> anchor_lang::solana_program::pubkey::Pubkey {
+            ID
+        }
+    })
+}
@@ -0,0 +1,72 @@
+// The part of anchor-lang invariant-types is written against, standing in for it when the anchor
+// feature is off. Layouts and discriminators of the accounts stay those of anchor.
+pub use anchor_shim_core::{account, declare_id, error_code, zero_copy};
+pub use borsh::{BorshDeserialize as AnchorDeserialize, BorshSerialize as AnchorSerialize};
+pub use solana_program;
+
+pub type Result<T> = std::result::Result<T, error::Error>;
+
+pub trait Discriminator {
+    const DISCRIMINATOR: [u8; 8];
+    fn discriminator() -> [u8; 8] {
+        Self::DISCRIMINATOR
+    }
+}
+
+pub mod error {
+    pub const ERROR_CODE_OFFSET: u32 = 6000;
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Error {
+        pub error_name: String,
+        pub error_code_number: u32,
+        pub error_msg: String,
+    }
+
+    impl std::fmt::Display for Error {
+        fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(
+                fmt,
+                "Error Code: {}. Error Number: {}. Error Message: {}.",
+                self.error_name, self.error_code_number, self.error_msg
+            )
+        }
+    }
+
+    impl std::error::Error for Error {}
+}
+
+#[macro_export]
+macro_rules! error {
+    ($error:expr) => {
+        $crate::error::Error::from($error)
+    };
+}
+
+#[macro_export]
+macro_rules! require {
+    ($invariant:expr, $error:expr $(,)?) => {
+        if !($invariant) {
+            return Err($crate::error!($error));
+        }
+    };
+}
+
+pub mod prelude {
+    pub use super::{
+        account, declare_id, error, error_code, require, zero_copy, AnchorDeserialize,
+        AnchorSerialize, Result,
+    };
+    pub use borsh;
+    pub use solana_program::instruction::AccountMeta;
+    pub use solana_program::msg;
+    pub use solana_program::program_error::ProgramError;
+    pub use solana_program::pubkey::Pubkey;
+    pub use solana_program::sysvar::clock::Clock;
+    pub use solana_program::sysvar::rent::Rent;
+}
+
+#[doc(hidden)]
+pub mod __private {
+    pub use bytemuck;
+}
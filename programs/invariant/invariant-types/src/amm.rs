@@ -0,0 +1,421 @@
+use std::collections::HashMap;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::AccountMeta;
+
+use crate::{
+    decimals::*,
+    err, function, location,
+    math::calculate_price_sqrt,
+    ok_or_mark_trace,
+    simulation::swap_over_ticks,
+    structs::{get_search_limit, Pool, Tick, Tickmap, TICK_CROSSES_PER_IX},
+    trace,
+    utils::{get_launch_config_address, get_tick_address, TrackableError, TrackableResult},
+    ANCHOR_DISCRIMINATOR_SIZE, ID, SEED, STATE_SEED,
+};
+
+// TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA
+pub const TOKEN_PROGRAM_ID: Pubkey = Pubkey::new_from_array([
+    6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172, 28, 180, 133, 237,
+    95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169,
+]);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuoteParams {
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub in_amount: u64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Quote {
+    pub in_amount: u64,
+    pub out_amount: u64,
+    pub fee_amount: u64,
+    pub fee_mint: Pubkey,
+    // input could not be swapped entirely with currently loaded ticks
+    pub not_enough_liquidity: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SwapParams {
+    pub source_mint: Pubkey,
+    pub destination_mint: Pubkey,
+    pub user_source_token_account: Pubkey,
+    pub user_destination_token_account: Pubkey,
+    pub user_transfer_authority: Pubkey,
+}
+
+// Interface expected by aggregators: accounts are fetched by the caller,
+// fed back through `update_from_accounts` and then quoted off-chain
+pub trait Amm {
+    fn key(&self) -> Pubkey;
+    fn reserve_mints(&self) -> Vec<Pubkey>;
+    fn get_accounts_to_update(&self) -> Vec<Pubkey>;
+    fn update_from_accounts(&mut self, accounts: &HashMap<Pubkey, Vec<u8>>) -> TrackableResult<()>;
+    fn quote(&self, params: &QuoteParams) -> TrackableResult<Quote>;
+    fn get_accounts_for_swap(&self, params: &SwapParams) -> TrackableResult<Vec<AccountMeta>>;
+}
+
+pub struct InvariantAmm {
+    pub address: Pubkey,
+    pub pool: Pool,
+    pub tickmap: Option<Box<Tickmap>>,
+    pub ticks: HashMap<Pubkey, Tick>,
+}
+
+impl InvariantAmm {
+    pub fn new(address: Pubkey, pool: Pool) -> Self {
+        Self {
+            address,
+            pool,
+            tickmap: None,
+            ticks: HashMap::new(),
+        }
+    }
+
+    pub fn from_account_data(address: Pubkey, data: &[u8]) -> TrackableResult<Self> {
+        Ok(Self::new(address, ok_or_mark_trace!(deserialize(data))?))
+    }
+
+    // Initialized ticks a single swap instruction can reach in given direction
+    fn initialized_ticks(&self, x_to_y: bool) -> Vec<i32> {
+        let tickmap = match &self.tickmap {
+            Some(tickmap) => tickmap,
+            None => return vec![],
+        };
+        let tick_spacing = self.pool.tick_spacing;
+        let mut ticks = vec![];
+        let mut current = self.pool.current_tick_index;
+
+        while ticks.len() < TICK_CROSSES_PER_IX {
+            let next = match x_to_y {
+                true => tickmap.prev_initialized(current, tick_spacing),
+                false => tickmap.next_initialized(current, tick_spacing),
+            };
+            match next {
+                Some(index) => {
+                    ticks.push(index);
+                    current = match x_to_y {
+                        true => index - tick_spacing as i32,
+                        false => index,
+                    };
+                }
+                None => break,
+            }
+        }
+        ticks
+    }
+
+    // Quotes are bounded by the furthest tick loaded or by the search range of the tickmap
+    fn sqrt_price_limit(&self, x_to_y: bool, ticks: &[i32]) -> Price {
+        let limit = match ticks.last() {
+            Some(index) => *index,
+            None => get_search_limit(
+                self.pool.current_tick_index,
+                self.pool.tick_spacing,
+                !x_to_y,
+            ),
+        };
+        calculate_price_sqrt(limit)
+    }
+
+    fn x_to_y(&self, input_mint: &Pubkey, output_mint: &Pubkey) -> TrackableResult<bool> {
+        let pool = &self.pool;
+        if *input_mint == pool.token_x && *output_mint == pool.token_y {
+            Ok(true)
+        } else if *input_mint == pool.token_y && *output_mint == pool.token_x {
+            Ok(false)
+        } else {
+            Err(err!("mints do not match the pool"))
+        }
+    }
+}
+
+fn deserialize<T: AnchorDeserialize>(data: &[u8]) -> TrackableResult<T> {
+    if data.len() < ANCHOR_DISCRIMINATOR_SIZE {
+        return Err(err!("account data too short"));
+    }
+    T::deserialize(&mut &data[ANCHOR_DISCRIMINATOR_SIZE..]).map_err(|e| err!(&e.to_string()))
+}
+
+impl Amm for InvariantAmm {
+    fn key(&self) -> Pubkey {
+        self.address
+    }
+
+    fn reserve_mints(&self) -> Vec<Pubkey> {
+        vec![self.pool.token_x, self.pool.token_y]
+    }
+
+    fn get_accounts_to_update(&self) -> Vec<Pubkey> {
+        let mut accounts = vec![self.address, self.pool.tickmap];
+        accounts.extend(
+            self.initialized_ticks(true)
+                .into_iter()
+                .chain(self.initialized_ticks(false))
+                .map(|index| get_tick_address(&self.address, index)),
+        );
+        accounts
+    }
+
+    fn update_from_accounts(&mut self, accounts: &HashMap<Pubkey, Vec<u8>>) -> TrackableResult<()> {
+        if let Some(data) = accounts.get(&self.address) {
+            self.pool = ok_or_mark_trace!(deserialize(data))?;
+        }
+        if let Some(data) = accounts.get(&self.pool.tickmap) {
+            self.tickmap = Some(Box::new(ok_or_mark_trace!(deserialize(data))?));
+        }
+
+        self.ticks.clear();
+        for index in self
+            .initialized_ticks(true)
+            .into_iter()
+            .chain(self.initialized_ticks(false))
+        {
+            let address = get_tick_address(&self.address, index);
+            if let Some(data) = accounts.get(&address) {
+                self.ticks
+                    .insert(address, ok_or_mark_trace!(deserialize(data))?);
+            }
+        }
+        Ok(())
+    }
+
+    fn quote(&self, params: &QuoteParams) -> TrackableResult<Quote> {
+        if self.pool.withdrawal_only {
+            return Err(err!("pool accepts only withdrawals"));
+        }
+        let x_to_y = ok_or_mark_trace!(self.x_to_y(&params.input_mint, &params.output_mint))?;
+        let tick_indexes = self.initialized_ticks(x_to_y);
+        let ticks: Vec<Tick> = tick_indexes
+            .iter()
+            .filter_map(|index| {
+                self.ticks
+                    .get(&get_tick_address(&self.address, *index))
+                    .copied()
+            })
+            .collect();
+        if ticks.len() != tick_indexes.len() {
+            return Err(err!("ticks are not loaded, update accounts first"));
+        }
+
+        let result = ok_or_mark_trace!(swap_over_ticks(
+            &self.pool,
+            &ticks,
+            x_to_y,
+            TokenAmount(params.in_amount),
+            true,
+            self.sqrt_price_limit(x_to_y, &tick_indexes),
+        ))?;
+
+        Ok(Quote {
+            in_amount: result.amount_in.0,
+            out_amount: result.amount_out.0,
+            fee_amount: result.fee.0,
+            fee_mint: params.input_mint,
+            not_enough_liquidity: !result.remaining.is_zero(),
+        })
+    }
+
+    fn get_accounts_for_swap(&self, params: &SwapParams) -> TrackableResult<Vec<AccountMeta>> {
+        let x_to_y = ok_or_mark_trace!(self.x_to_y(&params.source_mint, &params.destination_mint))?;
+        let (account_x, account_y) = match x_to_y {
+            true => (
+                params.user_source_token_account,
+                params.user_destination_token_account,
+            ),
+            false => (
+                params.user_destination_token_account,
+                params.user_source_token_account,
+            ),
+        };
+        let (state, _) = Pubkey::find_program_address(&[STATE_SEED.as_bytes()], &ID);
+        let (program_authority, _) = Pubkey::find_program_address(&[SEED.as_bytes()], &ID);
+
+        // same order as in the Swap instruction
+        let mut accounts = vec![
+            AccountMeta::new_readonly(state, false),
+            AccountMeta::new(self.address, false),
+            AccountMeta::new(self.pool.tickmap, false),
+            AccountMeta::new(account_x, false),
+            AccountMeta::new(account_y, false),
+            AccountMeta::new(self.pool.token_x_reserve, false),
+            AccountMeta::new(self.pool.token_y_reserve, false),
+            AccountMeta::new_readonly(params.user_transfer_authority, true),
+            AccountMeta::new_readonly(program_authority, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ];
+        // swaps fail without the accounts configured on the pool, global stats are left out as
+        // write locking a single account would serialize swaps across all pools
+        if self.pool.oracle_initialized {
+            accounts.push(AccountMeta::new(self.pool.oracle_address, false));
+        }
+        if self.pool.hook_program != Pubkey::default() {
+            accounts.push(AccountMeta::new_readonly(self.pool.hook_program, false));
+        }
+        if self.pool.launch_end_slot != 0 {
+            accounts.push(AccountMeta::new_readonly(
+                get_launch_config_address(&self.address),
+                false,
+            ));
+        }
+        accounts.extend(
+            self.initialized_ticks(x_to_y)
+                .into_iter()
+                .map(|index| AccountMeta::new(get_tick_address(&self.address, index), false)),
+        );
+        Ok(accounts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // fields in declaration order, as stored on chain
+    fn tick_data(tick: &Tick) -> Vec<u8> {
+        let mut data = vec![0u8; ANCHOR_DISCRIMINATOR_SIZE];
+        { tick.pool }.serialize(&mut data).unwrap();
+        { tick.index }.serialize(&mut data).unwrap();
+        tick.sign.serialize(&mut data).unwrap();
+        { tick.liquidity_change }.serialize(&mut data).unwrap();
+        { tick.liquidity_gross }.serialize(&mut data).unwrap();
+        { tick.sqrt_price }.serialize(&mut data).unwrap();
+        { tick.fee_growth_outside_x }.serialize(&mut data).unwrap();
+        { tick.fee_growth_outside_y }.serialize(&mut data).unwrap();
+        { tick.seconds_per_liquidity_outside }
+            .serialize(&mut data)
+            .unwrap();
+        { tick.seconds_outside }.serialize(&mut data).unwrap();
+        tick.bump.serialize(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn test_token_program_id() {
+        assert_eq!(
+            TOKEN_PROGRAM_ID.to_string(),
+            "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        );
+    }
+
+    #[test]
+    fn test_quote() {
+        let address = Pubkey::new_unique();
+        let token_x = Pubkey::new_unique();
+        let token_y = Pubkey::new_unique();
+        let tick_spacing = 10;
+        let pool = Pool {
+            token_x,
+            token_y,
+            tickmap: Pubkey::new_unique(),
+            tick_spacing,
+            current_tick_index: 0,
+            sqrt_price: Price::from_integer(1),
+            liquidity: Liquidity::from_integer(1_000_000),
+            fee: FixedPoint::from_scale(3, 3),
+            ..Default::default()
+        };
+        let mut tickmap = Tickmap::default();
+        tickmap.flip(true, -10, tick_spacing);
+        tickmap.flip(true, 10, tick_spacing);
+
+        let mut amm = InvariantAmm::new(address, pool);
+        let mut accounts = HashMap::new();
+        accounts.insert(pool.tickmap, {
+            let mut data = vec![0u8; ANCHOR_DISCRIMINATOR_SIZE];
+            data.extend_from_slice(&tickmap.bitmap);
+            data
+        });
+        amm.update_from_accounts(&accounts).unwrap();
+
+        // ticks are requested once the tickmap is known
+        let to_update = amm.get_accounts_to_update();
+        let lower = get_tick_address(&address, -10);
+        let upper = get_tick_address(&address, 10);
+        assert_eq!(to_update, vec![address, pool.tickmap, lower, upper]);
+
+        // quoting without ticks fails
+        let params = QuoteParams {
+            input_mint: token_x,
+            output_mint: token_y,
+            in_amount: 100,
+        };
+        assert!(amm.quote(&params).is_err());
+
+        for (address, index, sign) in [(lower, -10, true), (upper, 10, false)] {
+            let tick = Tick {
+                index,
+                sign,
+                liquidity_change: Liquidity::from_integer(1_000_000),
+                ..Default::default()
+            };
+            accounts.insert(address, tick_data(&tick));
+        }
+        amm.update_from_accounts(&accounts).unwrap();
+
+        let quote = amm.quote(&params).unwrap();
+        assert_eq!(quote.in_amount, 100);
+        assert_eq!(quote.out_amount, 98);
+        assert_eq!(quote.fee_mint, token_x);
+        assert!(!quote.not_enough_liquidity);
+
+        // swap larger than liquidity in range
+        let quote = amm
+            .quote(&QuoteParams {
+                input_mint: token_y,
+                output_mint: token_x,
+                in_amount: 1_000_000,
+            })
+            .unwrap();
+        assert!(quote.not_enough_liquidity);
+
+        // sunset pools are not quoted
+        amm.pool.withdrawal_only = true;
+        assert!(amm.quote(&params).is_err());
+        amm.pool.withdrawal_only = false;
+
+        // swap accounts
+        let owner = Pubkey::new_unique();
+        let source = Pubkey::new_unique();
+        let destination = Pubkey::new_unique();
+        let metas = amm
+            .get_accounts_for_swap(&SwapParams {
+                source_mint: token_y,
+                destination_mint: token_x,
+                user_source_token_account: source,
+                user_destination_token_account: destination,
+                user_transfer_authority: owner,
+            })
+            .unwrap();
+        assert_eq!(metas.len(), 11);
+        assert_eq!(metas[3].pubkey, destination);
+        assert_eq!(metas[4].pubkey, source);
+        assert!(metas[7].is_signer);
+        assert_eq!(metas[10].pubkey, upper);
+
+        // accounts configured on the pool precede the ticks
+        amm.pool.oracle_initialized = true;
+        amm.pool.oracle_address = Pubkey::new_unique();
+        amm.pool.hook_program = Pubkey::new_unique();
+        amm.pool.launch_end_slot = 100;
+        let metas = amm
+            .get_accounts_for_swap(&SwapParams {
+                source_mint: token_x,
+                destination_mint: token_y,
+                user_source_token_account: source,
+                user_destination_token_account: destination,
+                user_transfer_authority: owner,
+            })
+            .unwrap();
+        assert_eq!(metas.len(), 14);
+        assert_eq!(metas[10].pubkey, { amm.pool.oracle_address });
+        assert!(metas[10].is_writable);
+        assert_eq!(metas[11].pubkey, { amm.pool.hook_program });
+        assert!(!metas[11].is_writable);
+        assert_eq!(metas[12].pubkey, get_launch_config_address(&address));
+        assert_eq!(metas[13].pubkey, lower);
+    }
+}
@@ -0,0 +1,342 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+// Arguments of the program instructions. Borsh layout is the same as passing the fields
+// one by one, so `data` can be used as instruction data directly.
+
+// Side of a swap. Variants are ordered so borsh encodes them as the `x_to_y: bool` they
+// replaced, `XtoY` being `true`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    YtoX,
+    XtoY,
+}
+
+impl Direction {
+    pub fn is_x_to_y(self) -> bool {
+        self == Direction::XtoY
+    }
+}
+
+impl From<bool> for Direction {
+    fn from(x_to_y: bool) -> Self {
+        match x_to_y {
+            true => Direction::XtoY,
+            false => Direction::YtoX,
+        }
+    }
+}
+
+// Whether the swapped amount is the input or the output. Encoded as the `by_amount_in: bool`
+// it replaced, `In` being `true`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AmountSpec {
+    Out,
+    In,
+}
+
+impl AmountSpec {
+    pub fn is_in(self) -> bool {
+        self == AmountSpec::In
+    }
+}
+
+impl From<bool> for AmountSpec {
+    fn from(by_amount_in: bool) -> Self {
+        match by_amount_in {
+            true => AmountSpec::In,
+            false => AmountSpec::Out,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct CreateFeeTierArgs {
+    pub fee: u128,
+    pub tick_spacing: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct CreatePoolArgs {
+    pub init_tick: i32,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct InitPoolAndPositionArgs {
+    pub init_tick: i32,
+    pub lower_tick_index: i32,
+    pub upper_tick_index: i32,
+    pub liquidity_delta: u128, // raw Liquidity of the first position
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct SwapArgs {
+    pub direction: Direction,
+    pub amount: u64,
+    pub amount_spec: AmountSpec,
+    pub sqrt_price_limit: u128,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct SwapBoundedArgs {
+    pub direction: Direction,
+    pub amount: u64,
+    pub amount_spec: AmountSpec,
+    pub sqrt_price_limit: u128,
+    pub tick_limit: u16, // most ticks crossed before ending with a partial fill
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct RouteHop {
+    pub direction: Direction,
+    pub sqrt_price_limit: u128,
+    // crossed ticks, then the hook program and the oracle of the pool if it has them
+    pub tick_accounts: u8,
+}
+
+// Exact input swap through a sequence of pools, output of every hop is the input of the next
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct SwapRouteArgs {
+    pub amount_in: u64,
+    pub min_amount_out: u64,
+    pub hops: Vec<RouteHop>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct RebateTierArgs {
+    pub min_maker_fees: u64,
+    pub rebate: u128, // raw FixedPoint, share of the claimed fee paid on top of it
+}
+
+// Replaces the whole rebate schedule, tiers sorted by ascending `min_maker_fees`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct SetRebateScheduleArgs {
+    pub tiers: Vec<RebateTierArgs>,
+}
+
+// Set as return data of swap, prices are sqrt prices
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct SwapReturnData {
+    pub lp_fee: u64,
+    pub protocol_fee: u64,
+    pub referral_fee: u64,
+    pub price_before: u128,
+    pub price_after: u128,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct FeeTierReturnData {
+    pub fee: u128,
+    pub tick_spacing: u16,
+}
+
+// Set as return data of list_fee_tiers, in the order the fee tiers were created
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct ListFeeTiersReturnData {
+    pub fee_tiers: Vec<FeeTierReturnData>,
+}
+
+impl CreateFeeTierArgs {
+    pub fn data(&self) -> Vec<u8> {
+        instruction_data("create_fee_tier", self)
+    }
+}
+
+impl CreatePoolArgs {
+    pub fn data(&self) -> Vec<u8> {
+        instruction_data("create_pool", self)
+    }
+}
+
+impl InitPoolAndPositionArgs {
+    pub fn data(&self) -> Vec<u8> {
+        instruction_data("init_pool_and_position", self)
+    }
+}
+
+impl SwapArgs {
+    pub fn data(&self) -> Vec<u8> {
+        instruction_data("swap", self)
+    }
+
+    // same accounts as swap, the instruction always fails after emitting its events
+    pub fn simulate_data(&self) -> Vec<u8> {
+        instruction_data("simulate_swap", self)
+    }
+}
+
+impl SwapBoundedArgs {
+    pub fn data(&self) -> Vec<u8> {
+        instruction_data("swap_bounded", self)
+    }
+}
+
+impl SwapRouteArgs {
+    pub fn data(&self) -> Vec<u8> {
+        instruction_data("swap_route", self)
+    }
+}
+
+impl SetRebateScheduleArgs {
+    pub fn data(&self) -> Vec<u8> {
+        instruction_data("set_rebate_schedule", self)
+    }
+}
+
+pub fn instruction_discriminator(name: &str) -> [u8; 8] {
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash(format!("global:{}", name).as_bytes()).to_bytes()[..8]);
+    discriminator
+}
+
+fn instruction_data<T: AnchorSerialize>(name: &str, args: &T) -> Vec<u8> {
+    let mut data = instruction_discriminator(name).to_vec();
+    args.serialize(&mut data).unwrap();
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_direction_encoded_as_bool() {
+        for flag in [true, false] {
+            let direction = Direction::from(flag);
+            assert_eq!(direction.is_x_to_y(), flag);
+            assert_eq!(direction.try_to_vec().unwrap(), flag.try_to_vec().unwrap());
+            assert_eq!(Direction::try_from_slice(&[flag as u8]).unwrap(), direction);
+
+            let amount_spec = AmountSpec::from(flag);
+            assert_eq!(amount_spec.is_in(), flag);
+            assert_eq!(
+                amount_spec.try_to_vec().unwrap(),
+                flag.try_to_vec().unwrap()
+            );
+            assert_eq!(
+                AmountSpec::try_from_slice(&[flag as u8]).unwrap(),
+                amount_spec
+            );
+        }
+    }
+
+    #[test]
+    fn test_swap_args_data() {
+        let args = SwapArgs {
+            direction: Direction::XtoY,
+            amount: 1_000,
+            amount_spec: AmountSpec::Out,
+            sqrt_price_limit: 42,
+        };
+        let data = args.data();
+
+        assert_eq!(data[..8], [248, 198, 158, 145, 225, 117, 135, 200]);
+        // fields serialized in order, same as positional arguments
+        assert_eq!(data[8], 1);
+        assert_eq!(data[9..17], 1_000u64.to_le_bytes());
+        assert_eq!(data[17], 0);
+        assert_eq!(data[18..34], 42u128.to_le_bytes());
+        assert_eq!(data.len(), 34);
+        assert_eq!(SwapArgs::try_from_slice(&data[8..]).unwrap(), args);
+
+        let simulate_data = args.simulate_data();
+        assert_eq!(
+            simulate_data[..8],
+            instruction_discriminator("simulate_swap")
+        );
+        assert_ne!(simulate_data[..8], data[..8]);
+        assert_eq!(simulate_data[8..], data[8..]);
+    }
+
+    #[test]
+    fn test_swap_bounded_args_data() {
+        let args = SwapBoundedArgs {
+            direction: Direction::YtoX,
+            amount: 1_000,
+            amount_spec: AmountSpec::In,
+            sqrt_price_limit: 42,
+            tick_limit: 5,
+        };
+        let data = args.data();
+
+        assert_eq!(data[..8], instruction_discriminator("swap_bounded"));
+        // layout of SwapArgs followed by the limit
+        assert_eq!(data[8], 0);
+        assert_eq!(data[9..17], 1_000u64.to_le_bytes());
+        assert_eq!(data[17], 1);
+        assert_eq!(data[18..34], 42u128.to_le_bytes());
+        assert_eq!(data[34..36], 5u16.to_le_bytes());
+        assert_eq!(data.len(), 36);
+        assert_eq!(SwapBoundedArgs::try_from_slice(&data[8..]).unwrap(), args);
+    }
+
+    #[test]
+    fn test_swap_route_args_data() {
+        let hop = RouteHop {
+            direction: Direction::YtoX,
+            sqrt_price_limit: 7,
+            tick_accounts: 3,
+        };
+        let args = SwapRouteArgs {
+            amount_in: 500,
+            min_amount_out: 490,
+            hops: vec![hop, hop],
+        };
+        let data = args.data();
+
+        assert_eq!(data[..8], instruction_discriminator("swap_route"));
+        assert_eq!(data[8..16], 500u64.to_le_bytes());
+        assert_eq!(data[16..24], 490u64.to_le_bytes());
+        // vec is prefixed with its length
+        assert_eq!(data[24..28], 2u32.to_le_bytes());
+        assert_eq!(data.len(), 28 + 2 * (1 + 16 + 1));
+        assert_eq!(SwapRouteArgs::try_from_slice(&data[8..]).unwrap(), args);
+    }
+
+    #[test]
+    fn test_set_rebate_schedule_args_data() {
+        let args = SetRebateScheduleArgs {
+            tiers: vec![RebateTierArgs {
+                min_maker_fees: 100,
+                rebate: 5,
+            }],
+        };
+        let data = args.data();
+
+        assert_eq!(data[..8], instruction_discriminator("set_rebate_schedule"));
+        assert_eq!(data[8..12], 1u32.to_le_bytes());
+        assert_eq!(data[12..20], 100u64.to_le_bytes());
+        assert_eq!(data[20..36], 5u128.to_le_bytes());
+        assert_eq!(data.len(), 36);
+    }
+
+    #[test]
+    fn test_create_args_data() {
+        let fee_tier = CreateFeeTierArgs {
+            fee: 10,
+            tick_spacing: 4,
+        };
+        let data = fee_tier.data();
+        assert_eq!(data[..8], [150, 158, 85, 114, 219, 75, 212, 91]);
+        assert_eq!(data.len(), 8 + 16 + 2);
+
+        let pool = CreatePoolArgs { init_tick: -3 };
+        let data = pool.data();
+        assert_eq!(data[..8], [233, 146, 209, 142, 207, 104, 64, 188]);
+        assert_eq!(data[8..], (-3i32).to_le_bytes());
+
+        let pool_and_position = InitPoolAndPositionArgs {
+            init_tick: -3,
+            lower_tick_index: -10,
+            upper_tick_index: 10,
+            liquidity_delta: 7,
+        };
+        let data = pool_and_position.data();
+        assert_eq!(
+            data[..8],
+            instruction_discriminator("init_pool_and_position")
+        );
+        assert_eq!(data[8..12], (-3i32).to_le_bytes());
+        assert_eq!(data[20..], 7u128.to_le_bytes());
+    }
+}
@@ -0,0 +1,47 @@
+use std::{env, fs, process};
+
+use invariant_types::replay::{replay, PoolHistory};
+
+// Replays a pool history exported as JSON and prints where it diverges from the recorded states
+// cargo run -p invariant-types --features replay --bin replay -- history.json
+fn main() {
+    let path = match env::args().nth(1) {
+        Some(path) => path,
+        None => {
+            eprintln!("usage: replay <history.json>");
+            process::exit(2);
+        }
+    };
+    let history: PoolHistory = match fs::read_to_string(&path)
+        .map_err(|e| e.to_string())
+        .and_then(|file| serde_json::from_str(&file).map_err(|e| e.to_string()))
+    {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("can't read {}: {}", path, e);
+            process::exit(2);
+        }
+    };
+
+    let discrepancies = match replay(&history) {
+        Ok(discrepancies) => discrepancies,
+        Err(e) => {
+            eprintln!("{}", e.to_string());
+            process::exit(2);
+        }
+    };
+    for d in &discrepancies {
+        println!(
+            "#{} {} {}: expected {}, replayed {}",
+            d.transaction, d.signature, d.field, d.expected, d.actual
+        );
+    }
+    println!(
+        "{} transactions replayed, {} discrepancies",
+        history.transactions.len(),
+        discrepancies.len()
+    );
+    if !discrepancies.is_empty() {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,367 @@
+#[cfg(feature = "pool-cache")]
+pub mod pool_cache;
+#[cfg(feature = "pool-cache")]
+pub mod swap_executor;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+
+use crate::{
+    decimals::*,
+    err, function, location,
+    math::{get_max_sqrt_price, get_min_sqrt_price},
+    ok_or_mark_trace,
+    simulation::swap_over_ticks,
+    structs::{Pool, Tick},
+    trace,
+    utils::{TrackableError, TrackableResult},
+    MAX_SQRT_PRICE, MIN_SQRT_PRICE,
+};
+
+// Rough compute cost of a swap, measured on mainnet transactions
+pub const SWAP_BASE_COMPUTE_UNITS: u32 = 50_000;
+pub const TICK_CROSS_COMPUTE_UNITS: u32 = 25_000;
+
+// Compute used by the remaining instructions in tests/cu.spec.ts, rounded up with ~20% margin.
+// Rerun the suite and update them whenever an instruction changes.
+pub const CREATE_POOL_COMPUTE_UNITS: u32 = 40_000;
+pub const CREATE_TICK_COMPUTE_UNITS: u32 = 20_000;
+pub const CREATE_POSITION_COMPUTE_UNITS: u32 = 70_000;
+pub const REMOVE_POSITION_COMPUTE_UNITS: u32 = 70_000;
+pub const CLAIM_FEE_COMPUTE_UNITS: u32 = 45_000;
+pub const WITHDRAW_PROTOCOL_FEE_COMPUTE_UNITS: u32 = 30_000;
+
+pub const MAX_COMPUTE_UNITS: u32 = 1_400_000;
+// ComputeBudget111111111111111111111111111111
+pub const COMPUTE_BUDGET_PROGRAM_ID: Pubkey = Pubkey::new_from_array([
+    3, 6, 70, 111, 229, 33, 23, 50, 255, 236, 173, 186, 114, 195, 155, 231, 188, 140, 229, 187,
+    197, 247, 18, 107, 44, 67, 155, 58, 64, 0, 0, 0,
+]);
+// tags of ComputeBudgetInstruction variants
+const SET_COMPUTE_UNIT_LIMIT: u8 = 2;
+const SET_COMPUTE_UNIT_PRICE: u8 = 3;
+
+pub fn estimate_swap_compute_units(ticks_crossed: u32) -> u32 {
+    ticks_crossed
+        .saturating_mul(TICK_CROSS_COMPUTE_UNITS)
+        .saturating_add(SWAP_BASE_COMPUTE_UNITS)
+        .min(MAX_COMPUTE_UNITS)
+}
+
+// Compute units of a swap with given parameters, from tick crossings it is predicted to make
+pub fn predict_swap_compute_units(
+    pool: &Pool,
+    ticks: &[Tick],
+    x_to_y: bool,
+    amount: TokenAmount,
+    by_amount_in: bool,
+    sqrt_price_limit: Price,
+) -> TrackableResult<u32> {
+    let result = ok_or_mark_trace!(swap_over_ticks(
+        pool,
+        ticks,
+        x_to_y,
+        amount,
+        by_amount_in,
+        sqrt_price_limit
+    ))?;
+    Ok(estimate_swap_compute_units(result.ticks_crossed))
+}
+
+// SetComputeUnitLimit followed by SetComputeUnitPrice when a priority fee is given
+pub fn compute_budget_instructions(
+    compute_units: u32,
+    micro_lamports_per_cu: Option<u64>,
+) -> Vec<Instruction> {
+    let mut limit = vec![SET_COMPUTE_UNIT_LIMIT];
+    limit.extend_from_slice(&compute_units.min(MAX_COMPUTE_UNITS).to_le_bytes());
+    let mut instructions = vec![Instruction {
+        program_id: COMPUTE_BUDGET_PROGRAM_ID,
+        accounts: vec![],
+        data: limit,
+    }];
+    if let Some(price) = micro_lamports_per_cu {
+        let mut data = vec![SET_COMPUTE_UNIT_PRICE];
+        data.extend_from_slice(&price.to_le_bytes());
+        instructions.push(Instruction {
+            program_id: COMPUTE_BUDGET_PROGRAM_ID,
+            accounts: vec![],
+            data,
+        });
+    }
+    instructions
+}
+
+// Prepends compute budget instructions sized for the whole transaction
+pub fn with_compute_budget(
+    instructions: Vec<Instruction>,
+    compute_units: u32,
+    micro_lamports_per_cu: Option<u64>,
+) -> Vec<Instruction> {
+    let mut result = compute_budget_instructions(compute_units, micro_lamports_per_cu);
+    result.extend(instructions);
+    result
+}
+
+// Splits a swap of `amount` in into consecutive swaps, each crossing few enough ticks to fit
+// into `max_cu` compute units. Part of the amount which the liquidity can't fill is dropped.
+pub fn split_swap_for_compute(
+    pool: &Pool,
+    ticks: &[Tick],
+    amount: TokenAmount,
+    x_to_y: bool,
+    max_cu: u32,
+) -> TrackableResult<Vec<TokenAmount>> {
+    let max_crossed = match max_cu.checked_sub(SWAP_BASE_COMPUTE_UNITS) {
+        Some(budget) => budget / TICK_CROSS_COMPUTE_UNITS,
+        None => return Err(err!("compute budget can't fit a single swap")),
+    };
+    let sqrt_price_limit = match x_to_y {
+        true => get_min_sqrt_price(pool.tick_spacing),
+        false => get_max_sqrt_price(pool.tick_spacing),
+    };
+
+    let mut pool = *pool;
+    let mut remaining = amount;
+    let mut chunks = vec![];
+    while !remaining.is_zero() {
+        let simulate = |pool: &Pool, amount: TokenAmount| {
+            swap_over_ticks(pool, ticks, x_to_y, amount, true, sqrt_price_limit)
+        };
+
+        let mut result = ok_or_mark_trace!(simulate(&pool, remaining))?;
+        let mut chunk = remaining;
+        if result.ticks_crossed > max_crossed {
+            // largest amount still within the budget
+            let (mut low, mut high) = (0u64, remaining.0);
+            while low < high {
+                let middle = high - (high - low) / 2;
+                match ok_or_mark_trace!(simulate(&pool, TokenAmount(middle)))?.ticks_crossed
+                    > max_crossed
+                {
+                    true => high = middle - 1,
+                    false => low = middle,
+                }
+            }
+            if low == 0 {
+                return Err(err!("compute budget can't fit a single tick crossing"));
+            }
+            chunk = TokenAmount(low);
+            result = ok_or_mark_trace!(simulate(&pool, chunk))?;
+        }
+
+        let filled = chunk - result.remaining;
+        if filled.is_zero() {
+            break;
+        }
+        chunks.push(filled);
+        remaining -= filled;
+
+        pool.sqrt_price = result.sqrt_price;
+        pool.liquidity = result.liquidity;
+        pool.current_tick_index = result.current_tick_index;
+
+        // liquidity ran out
+        if !result.remaining.is_zero() {
+            break;
+        }
+    }
+
+    Ok(chunks)
+}
+
+const BPS_DENOMINATOR: u64 = 10_000;
+
+// Limit that lets the price move at most `slippage_bps` of the spot price (not the sqrt)
+// away from `pool_sqrt_price` in the direction of the swap. Rounded towards the pool price,
+// so the resulting limit never allows more slippage than requested.
+pub fn sqrt_price_limit_from_slippage(
+    pool_sqrt_price: Price,
+    slippage_bps: u64,
+    x_to_y: bool,
+) -> Price {
+    let denominator = U256::from(BPS_DENOMINATOR);
+    let multiplier = match x_to_y {
+        true => denominator - U256::from(slippage_bps.min(BPS_DENOMINATOR)),
+        false => denominator + U256::from(slippage_bps),
+    };
+    let squared = U256::from(pool_sqrt_price.get()) * U256::from(pool_sqrt_price.get());
+    let limit = match squared.checked_mul(multiplier) {
+        Some(value) => {
+            let (value, rest) = value.div_mod(denominator);
+            let root = value.integer_sqrt();
+            // price falling, round the limit up to stay within the slippage
+            match x_to_y && (!rest.is_zero() || root * root < value) {
+                true => root + U256::from(1),
+                false => root,
+            }
+        }
+        None => U256::from(MAX_SQRT_PRICE),
+    };
+
+    let limit = limit
+        .max(U256::from(MIN_SQRT_PRICE))
+        .min(U256::from(MAX_SQRT_PRICE));
+    Price::new(limit.as_u128())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::calculate_price_sqrt;
+
+    fn tick(index: i32, sign: bool, liquidity_change: u64) -> Tick {
+        Tick {
+            index,
+            sign,
+            liquidity_change: Liquidity::from_integer(liquidity_change),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_split_swap_for_compute() {
+        // nested positions crossed one after another when going up
+        let ticks = [
+            tick(-30, true, 1_000),
+            tick(-10, true, 1_000_000),
+            tick(10, false, 1_000_000),
+            tick(20, true, 1_000_000),
+            tick(30, false, 1_000_000),
+            tick(40, false, 1_000),
+        ];
+        let pool = Pool {
+            current_tick_index: 0,
+            tick_spacing: 10,
+            sqrt_price: Price::from_integer(1),
+            liquidity: Liquidity::from_integer(1_001_000),
+            fee: FixedPoint::from_scale(3, 3),
+            ..Default::default()
+        };
+        let amount = TokenAmount(800);
+
+        // enough compute for everything
+        let chunks = split_swap_for_compute(&pool, &ticks, amount, false, 1_400_000).unwrap();
+        assert_eq!(chunks, vec![amount]);
+
+        // one crossing per swap
+        let max_cu = SWAP_BASE_COMPUTE_UNITS + TICK_CROSS_COMPUTE_UNITS;
+        let chunks = split_swap_for_compute(&pool, &ticks, amount, false, max_cu).unwrap();
+        assert!(chunks.len() > 1);
+        assert_eq!(chunks.iter().map(|c| c.0).sum::<u64>(), amount.0);
+
+        // replaying chunks never goes over the budget
+        let limit = get_max_sqrt_price(pool.tick_spacing);
+        let mut state = pool;
+        for chunk in chunks {
+            let result = swap_over_ticks(&state, &ticks, false, chunk, true, limit).unwrap();
+            assert!(result.ticks_crossed <= 1);
+            assert!(result.remaining.is_zero());
+            state.sqrt_price = result.sqrt_price;
+            state.liquidity = result.liquidity;
+            state.current_tick_index = result.current_tick_index;
+        }
+
+        // budget too small for a swap
+        assert!(split_swap_for_compute(&pool, &ticks, amount, false, 1_000).is_err());
+
+        // compute budget of the whole swap
+        let units = predict_swap_compute_units(
+            &pool,
+            &ticks,
+            false,
+            amount,
+            true,
+            get_max_sqrt_price(pool.tick_spacing),
+        )
+        .unwrap();
+        assert_eq!(
+            units,
+            SWAP_BASE_COMPUTE_UNITS + 2 * TICK_CROSS_COMPUTE_UNITS
+        );
+    }
+
+    #[test]
+    fn test_compute_budget_instructions() {
+        assert_eq!(
+            COMPUTE_BUDGET_PROGRAM_ID.to_string(),
+            "ComputeBudget111111111111111111111111111111"
+        );
+        assert_eq!(estimate_swap_compute_units(0), SWAP_BASE_COMPUTE_UNITS);
+        assert_eq!(estimate_swap_compute_units(u32::MAX), MAX_COMPUTE_UNITS);
+
+        let swap = Instruction {
+            program_id: Pubkey::new_unique(),
+            accounts: vec![],
+            data: vec![],
+        };
+        let instructions = with_compute_budget(vec![swap.clone()], 200_000, None);
+        assert_eq!(instructions.len(), 2);
+        assert_eq!(instructions[0].program_id, COMPUTE_BUDGET_PROGRAM_ID);
+        assert_eq!(instructions[0].data, vec![2, 0x40, 0x0d, 0x03, 0x00]);
+        assert_eq!(instructions[1], swap);
+
+        let instructions = with_compute_budget(vec![swap], 2_000_000, Some(1_000));
+        assert_eq!(instructions.len(), 3);
+        assert_eq!(instructions[0].data[1..], MAX_COMPUTE_UNITS.to_le_bytes());
+        assert_eq!(instructions[1].data, vec![3, 0xe8, 0x03, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_split_swap_for_compute_partial_fill() {
+        let ticks = [tick(-10, true, 1_000), tick(10, false, 1_000)];
+        let pool = Pool {
+            current_tick_index: 0,
+            tick_spacing: 10,
+            sqrt_price: Price::from_integer(1),
+            liquidity: Liquidity::from_integer(1_000),
+            fee: FixedPoint::from_scale(3, 3),
+            ..Default::default()
+        };
+        let chunks =
+            split_swap_for_compute(&pool, &ticks, TokenAmount(1_000_000), true, 1_400_000).unwrap();
+        // only the liquidity of the range can be used
+        assert_eq!(chunks.len(), 1);
+        assert!(chunks[0] < TokenAmount(1_000_000));
+    }
+
+    #[test]
+    fn test_sqrt_price_limit_from_slippage() {
+        let one = Price::from_integer(1);
+
+        // 1% down, sqrt(0.99) = 0.994987437106619954734479...
+        let limit = sqrt_price_limit_from_slippage(one, 100, true);
+        assert_eq!(limit, Price::new(994987437106619954734480));
+        // 1% up, sqrt(1.01) = 1.004987562112089027021926...
+        let limit = sqrt_price_limit_from_slippage(one, 100, false);
+        assert_eq!(limit, Price::new(1004987562112089027021926));
+
+        // never more than requested
+        let sqrt_price = calculate_price_sqrt(1234);
+        let limit = sqrt_price_limit_from_slippage(sqrt_price, 37, true);
+        let squared = |price: Price| U256::from(price.get()) * U256::from(price.get());
+        assert!(squared(limit) * U256::from(10_000) >= squared(sqrt_price) * U256::from(9_963));
+        let limit = sqrt_price_limit_from_slippage(sqrt_price, 37, false);
+        assert!(squared(limit) * U256::from(10_000) <= squared(sqrt_price) * U256::from(10_037));
+
+        // zero slippage keeps the price
+        assert_eq!(
+            sqrt_price_limit_from_slippage(sqrt_price, 0, true),
+            sqrt_price
+        );
+        assert_eq!(
+            sqrt_price_limit_from_slippage(sqrt_price, 0, false),
+            sqrt_price
+        );
+
+        // clamped to the price range
+        assert_eq!(
+            sqrt_price_limit_from_slippage(one, 10_000, true),
+            Price::new(MIN_SQRT_PRICE)
+        );
+        assert_eq!(
+            sqrt_price_limit_from_slippage(Price::new(MAX_SQRT_PRICE), u64::MAX, false),
+            Price::new(MAX_SQRT_PRICE)
+        );
+    }
+}
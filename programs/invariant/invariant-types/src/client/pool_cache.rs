@@ -0,0 +1,387 @@
+// Live copy of a single pool for quoting off-chain. The pool, its tickmap and the ticks a swap
+// can reach are fetched over RPC once and then kept up to date from websocket notifications.
+// Every update publishes a new immutable snapshot, so quotes never wait for the subscriptions.
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use anchor_lang::prelude::*;
+use arc_swap::ArcSwapOption;
+use futures_util::StreamExt;
+use solana_account_decoder::UiAccountEncoding;
+use solana_pubsub_client::nonblocking::pubsub_client::PubsubClient;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client_api::config::RpcAccountInfoConfig;
+use solana_sdk::commitment_config::CommitmentConfig;
+use tokio::sync::mpsc::{self, UnboundedSender};
+use tokio::task::JoinHandle;
+
+use crate::{
+    amm::{Amm, InvariantAmm, Quote, QuoteParams},
+    err, function, location, ok_or_mark_trace, trace,
+    utils::{TrackableError, TrackableResult},
+};
+
+pub struct PoolSnapshot {
+    // highest slot among the accounts the snapshot was built from
+    pub slot: u64,
+    pub amm: InvariantAmm,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct AccountUpdate {
+    address: Pubkey,
+    slot: u64,
+    data: Vec<u8>,
+}
+
+enum StoreState {
+    Ready(Box<PoolSnapshot>),
+    // accounts which have to be fetched before a snapshot can be built
+    Missing(Vec<Pubkey>),
+}
+
+// Raw data of the accounts a pool snapshot is built from, owned by the update task
+struct AccountStore {
+    address: Pubkey,
+    data: HashMap<Pubkey, Vec<u8>>,
+    slots: HashMap<Pubkey, u64>,
+}
+
+impl AccountStore {
+    fn new(address: Pubkey) -> Self {
+        Self {
+            address,
+            data: HashMap::new(),
+            slots: HashMap::new(),
+        }
+    }
+
+    // Notifications of different accounts may come out of order, older data is dropped.
+    // Closed accounts come with empty data and are forgotten.
+    fn apply(&mut self, update: AccountUpdate) {
+        if self.slots.get(&update.address).copied().unwrap_or(0) > update.slot {
+            return;
+        }
+        self.slots.insert(update.address, update.slot);
+        match update.data.is_empty() {
+            true => self.data.remove(&update.address),
+            false => self.data.insert(update.address, update.data),
+        };
+    }
+
+    // Data of an account which is no longer followed can't be trusted later on
+    fn forget(&mut self, address: &Pubkey) {
+        self.data.remove(address);
+        self.slots.remove(address);
+    }
+
+    fn state(&self) -> TrackableResult<StoreState> {
+        let pool_data = match self.data.get(&self.address) {
+            Some(data) => data,
+            None => return Ok(StoreState::Missing(vec![self.address])),
+        };
+        let mut amm = ok_or_mark_trace!(InvariantAmm::from_account_data(self.address, pool_data))?;
+        ok_or_mark_trace!(amm.update_from_accounts(&self.data))?;
+
+        let required = amm.get_accounts_to_update();
+        let missing: Vec<Pubkey> = required
+            .iter()
+            .filter(|address| !self.data.contains_key(address))
+            .copied()
+            .collect();
+        if !missing.is_empty() {
+            return Ok(StoreState::Missing(missing));
+        }
+
+        let slot = required
+            .iter()
+            .filter_map(|address| self.slots.get(address))
+            .max()
+            .copied()
+            .unwrap_or_default();
+        Ok(StoreState::Ready(Box::new(PoolSnapshot { slot, amm })))
+    }
+}
+
+#[derive(Clone)]
+pub struct PoolCache {
+    address: Pubkey,
+    snapshot: Arc<ArcSwapOption<PoolSnapshot>>,
+}
+
+impl PoolCache {
+    pub fn address(&self) -> Pubkey {
+        self.address
+    }
+
+    // Latest consistent state of the pool, none until all accounts were loaded
+    pub fn snapshot(&self) -> Option<Arc<PoolSnapshot>> {
+        self.snapshot.load_full()
+    }
+
+    pub fn quote(&self, params: &QuoteParams) -> TrackableResult<Quote> {
+        match self.snapshot.load().as_ref() {
+            Some(snapshot) => snapshot.amm.quote(params),
+            None => Err(err!("pool cache is not loaded yet")),
+        }
+    }
+
+    // Starts following the pool. The task ends with an error when the connection fails, the
+    // cache then keeps serving the last snapshot, whose slot tells how stale it is.
+    pub async fn subscribe(
+        rpc_url: &str,
+        ws_url: &str,
+        address: Pubkey,
+        commitment: CommitmentConfig,
+    ) -> TrackableResult<(Self, JoinHandle<TrackableResult<()>>)> {
+        let rpc = RpcClient::new_with_commitment(rpc_url.to_string(), commitment);
+        let pubsub = PubsubClient::new(ws_url)
+            .await
+            .map_err(|e| err!(&e.to_string()))?;
+
+        let cache = Self {
+            address,
+            snapshot: Arc::new(ArcSwapOption::empty()),
+        };
+        let handle = tokio::spawn(run(
+            cache.clone(),
+            rpc,
+            Arc::new(pubsub),
+            account_config(commitment),
+        ));
+        Ok((cache, handle))
+    }
+}
+
+fn account_config(commitment: CommitmentConfig) -> RpcAccountInfoConfig {
+    RpcAccountInfoConfig {
+        encoding: Some(UiAccountEncoding::Base64),
+        commitment: Some(commitment),
+        ..Default::default()
+    }
+}
+
+async fn run(
+    cache: PoolCache,
+    rpc: RpcClient,
+    pubsub: Arc<PubsubClient>,
+    config: RpcAccountInfoConfig,
+) -> TrackableResult<()> {
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    let mut store = AccountStore::new(cache.address);
+    let mut subscriptions: HashMap<Pubkey, JoinHandle<()>> = HashMap::new();
+
+    loop {
+        match ok_or_mark_trace!(store.state())? {
+            StoreState::Ready(snapshot) => {
+                // ticks out of reach of a swap are no longer followed
+                let required = snapshot.amm.get_accounts_to_update();
+                let unused: Vec<Pubkey> = subscriptions
+                    .keys()
+                    .filter(|address| !required.contains(address))
+                    .copied()
+                    .collect();
+                for address in unused {
+                    if let Some(subscription) = subscriptions.remove(&address) {
+                        subscription.abort();
+                    }
+                    store.forget(&address);
+                }
+                cache.snapshot.store(Some(Arc::new(*snapshot)));
+            }
+            StoreState::Missing(missing) => {
+                // subscriptions start before the fetch, whichever data is newer wins
+                for address in &missing {
+                    subscriptions.entry(*address).or_insert_with(|| {
+                        subscribe_account(pubsub.clone(), *address, config.clone(), sender.clone())
+                    });
+                }
+                let response = rpc
+                    .get_multiple_accounts_with_config(&missing, config.clone())
+                    .await
+                    .map_err(|e| err!(&e.to_string()))?;
+                let mut complete = true;
+                for (address, account) in missing.iter().zip(response.value) {
+                    match account {
+                        Some(account) => store.apply(AccountUpdate {
+                            address: *address,
+                            slot: response.context.slot,
+                            data: account.data,
+                        }),
+                        None if *address == cache.address => {
+                            return Err(err!(&format!("pool {} does not exist", address)))
+                        }
+                        // tick closed before its tickmap update arrived
+                        None => complete = false,
+                    }
+                }
+                if complete {
+                    continue;
+                }
+            }
+        }
+
+        let update: TrackableResult<AccountUpdate> = match receiver.recv().await {
+            Some(update) => update,
+            None => return Ok(()),
+        };
+        store.apply(ok_or_mark_trace!(update)?);
+        // rebuild once per batch of queued notifications
+        while let Ok(update) = receiver.try_recv() {
+            store.apply(ok_or_mark_trace!(update)?);
+        }
+    }
+}
+
+fn subscribe_account(
+    pubsub: Arc<PubsubClient>,
+    address: Pubkey,
+    config: RpcAccountInfoConfig,
+    sender: UnboundedSender<TrackableResult<AccountUpdate>>,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let (mut stream, _unsubscribe) =
+            match pubsub.account_subscribe(&address, Some(config)).await {
+                Ok(subscription) => subscription,
+                Err(e) => {
+                    let _ = sender.send(Err(err!(&e.to_string())));
+                    return;
+                }
+            };
+        while let Some(response) = stream.next().await {
+            let update = match response.value.data.decode() {
+                Some(data) => Ok(AccountUpdate {
+                    address,
+                    slot: response.context.slot,
+                    data,
+                }),
+                None => Err(err!(&format!("can't decode data of account {}", address))),
+            };
+            if sender.send(update).is_err() {
+                return;
+            }
+        }
+        let _ = sender.send(Err(err!(&format!("subscription of {} closed", address))));
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decimals::*;
+    use crate::snapshots::tests::{pool_data, tick_data};
+    use crate::structs::{Pool, Tick, Tickmap};
+    use crate::utils::get_tick_address;
+    use crate::ANCHOR_DISCRIMINATOR_SIZE;
+
+    fn update(address: Pubkey, slot: u64, data: Vec<u8>) -> AccountUpdate {
+        AccountUpdate {
+            address,
+            slot,
+            data,
+        }
+    }
+
+    fn missing(store: &AccountStore) -> Vec<Pubkey> {
+        match store.state().unwrap() {
+            StoreState::Missing(missing) => missing,
+            StoreState::Ready(_) => vec![],
+        }
+    }
+
+    #[test]
+    fn test_account_store() {
+        let address = Pubkey::new_unique();
+        let token_x = Pubkey::new_unique();
+        let token_y = Pubkey::new_unique();
+        let tick_spacing = 10;
+        let pool = Pool {
+            token_x,
+            token_y,
+            tickmap: Pubkey::new_unique(),
+            tick_spacing,
+            current_tick_index: 0,
+            sqrt_price: Price::from_integer(1),
+            liquidity: Liquidity::from_integer(1_000_000),
+            fee: FixedPoint::from_scale(3, 3),
+            ..Default::default()
+        };
+        let mut tickmap = Tickmap::default();
+        tickmap.flip(true, -10, tick_spacing);
+        tickmap.flip(true, 10, tick_spacing);
+        let mut tickmap_data = vec![0u8; ANCHOR_DISCRIMINATOR_SIZE];
+        tickmap_data.extend_from_slice(&tickmap.bitmap);
+
+        let lower = get_tick_address(&address, -10);
+        let upper = get_tick_address(&address, 10);
+        let tick = |index: i32, sign: bool| {
+            tick_data(&Tick {
+                index,
+                sign,
+                liquidity_change: Liquidity::from_integer(1_000_000),
+                ..Default::default()
+            })
+        };
+
+        // accounts are requested as they become known
+        let mut store = AccountStore::new(address);
+        assert_eq!(missing(&store), vec![address]);
+        store.apply(update(address, 5, pool_data(&pool)));
+        assert_eq!(missing(&store), vec![pool.tickmap]);
+        store.apply(update(pool.tickmap, 5, tickmap_data));
+        assert_eq!(missing(&store), vec![lower, upper]);
+        store.apply(update(lower, 5, tick(-10, true)));
+        store.apply(update(upper, 6, tick(10, false)));
+
+        let params = QuoteParams {
+            input_mint: token_x,
+            output_mint: token_y,
+            in_amount: 100,
+        };
+        let snapshot = match store.state().unwrap() {
+            StoreState::Ready(snapshot) => snapshot,
+            StoreState::Missing(missing) => panic!("missing {:?}", missing),
+        };
+        assert_eq!(snapshot.slot, 6);
+        assert_eq!(snapshot.amm.quote(&params).unwrap().out_amount, 98);
+
+        // stale notification is dropped
+        let moved = Pool {
+            liquidity: Liquidity::from_integer(1),
+            ..pool
+        };
+        store.apply(update(address, 4, pool_data(&moved)));
+        match store.state().unwrap() {
+            StoreState::Ready(snapshot) => {
+                assert_eq!({ snapshot.amm.pool.liquidity }, { pool.liquidity })
+            }
+            StoreState::Missing(missing) => panic!("missing {:?}", missing),
+        }
+
+        // closed tick has to be fetched again before quoting
+        store.apply(update(upper, 7, vec![]));
+        assert_eq!(missing(&store), vec![upper]);
+
+        // forgotten account is fetched again even with an older slot
+        store.forget(&lower);
+        assert_eq!(missing(&store), vec![lower, upper]);
+        store.apply(update(lower, 1, tick(-10, true)));
+        assert_eq!(missing(&store), vec![upper]);
+    }
+
+    #[test]
+    fn test_quote_before_load() {
+        let cache = PoolCache {
+            address: Pubkey::new_unique(),
+            snapshot: Arc::new(ArcSwapOption::empty()),
+        };
+        assert!(cache.snapshot().is_none());
+        assert!(cache
+            .quote(&QuoteParams {
+                input_mint: Pubkey::new_unique(),
+                output_mint: Pubkey::new_unique(),
+                in_amount: 1,
+            })
+            .is_err());
+    }
+}
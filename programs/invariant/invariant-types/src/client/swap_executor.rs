@@ -0,0 +1,231 @@
+// Swap submission for bots. Every attempt starts from freshly fetched pool state, so a retry
+// gets a new price limit and tick accounts instead of resending a transaction bound to fail
+// the same way.
+use std::collections::HashMap;
+use std::time::Duration;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{Instruction, InstructionError};
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client_api::client_error::Error as ClientError;
+use solana_sdk::signature::{Keypair, Signature, Signer};
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+use crate::{
+    amm::{Amm, InvariantAmm, Quote, QuoteParams, SwapParams},
+    args::{AmountSpec, Direction, SwapArgs},
+    client::{predict_swap_compute_units, sqrt_price_limit_from_slippage, with_compute_budget},
+    decimals::*,
+    err,
+    errors::InvariantErrorCode,
+    function, location, ok_or_mark_trace,
+    structs::Tick,
+    trace,
+    utils::{TrackableError, TrackableResult},
+    ID,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SwapRequest {
+    pub pool: Pubkey,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub amount_in: u64,
+    pub slippage_bps: u64, // of the spot price at the time of each attempt
+    pub user_source_token_account: Pubkey,
+    pub user_destination_token_account: Pubkey,
+    pub micro_lamports_per_cu: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryConfig {
+    pub max_attempts: u32,
+    pub delay: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            delay: Duration::from_millis(400),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SwapExecution {
+    pub signature: Signature,
+    pub attempts: u32,
+    pub quote: Quote, // of the attempt which landed
+}
+
+// Price moved past the limit, or ticks changed between the fetch and the swap
+const RETRYABLE_ERRORS: [InvariantErrorCode; 3] = [
+    InvariantErrorCode::PriceLimitReached,
+    InvariantErrorCode::TickNotFound,
+    InvariantErrorCode::WrongTick,
+];
+
+fn is_retryable(error: &ClientError) -> bool {
+    match error.get_transaction_error() {
+        Some(TransactionError::InstructionError(_, InstructionError::Custom(code))) => {
+            RETRYABLE_ERRORS.iter().any(|e| u32::from(*e) == code)
+        }
+        Some(TransactionError::BlockhashNotFound) => true,
+        _ => false,
+    }
+}
+
+// Swaps `amount_in` of the input mint, resubmitting on the errors a refresh of the pool state
+// can fix. Other errors end the execution right away.
+pub async fn execute_swap_with_retry(
+    rpc: &RpcClient,
+    owner: &Keypair,
+    request: &SwapRequest,
+    config: &RetryConfig,
+) -> TrackableResult<SwapExecution> {
+    let mut attempts = 0;
+    loop {
+        attempts += 1;
+        let amm = ok_or_mark_trace!(load_pool(rpc, request.pool).await)?;
+        let (instructions, quote) = ok_or_mark_trace!(swap_instructions(&amm, owner, request))?;
+        let blockhash = rpc
+            .get_latest_blockhash()
+            .await
+            .map_err(|e| err!(&e.to_string()))?;
+        let transaction = Transaction::new_signed_with_payer(
+            &instructions,
+            Some(&owner.pubkey()),
+            &[owner],
+            blockhash,
+        );
+
+        match rpc.send_and_confirm_transaction(&transaction).await {
+            Ok(signature) => {
+                return Ok(SwapExecution {
+                    signature,
+                    attempts,
+                    quote,
+                })
+            }
+            Err(e) if is_retryable(&e) && attempts < config.max_attempts => {
+                tokio::time::sleep(config.delay).await;
+            }
+            Err(e) => {
+                return Err(err!(&format!(
+                    "swap failed after {} attempts: {}",
+                    attempts, e
+                )))
+            }
+        }
+    }
+}
+
+// The tickmap tells which ticks are in reach, so accounts are fetched again until the list
+// of accounts to update settles
+async fn load_pool(rpc: &RpcClient, address: Pubkey) -> TrackableResult<InvariantAmm> {
+    let data = rpc
+        .get_account_data(&address)
+        .await
+        .map_err(|e| err!(&e.to_string()))?;
+    let mut amm = ok_or_mark_trace!(InvariantAmm::from_account_data(address, &data))?;
+
+    let mut addresses = vec![];
+    while addresses != amm.get_accounts_to_update() {
+        addresses = amm.get_accounts_to_update();
+        let accounts = rpc
+            .get_multiple_accounts(&addresses)
+            .await
+            .map_err(|e| err!(&e.to_string()))?;
+        let accounts: HashMap<Pubkey, Vec<u8>> = addresses
+            .iter()
+            .zip(accounts)
+            .filter_map(|(address, account)| account.map(|account| (*address, account.data)))
+            .collect();
+        ok_or_mark_trace!(amm.update_from_accounts(&accounts))?;
+    }
+    Ok(amm)
+}
+
+fn swap_instructions(
+    amm: &InvariantAmm,
+    owner: &Keypair,
+    request: &SwapRequest,
+) -> TrackableResult<(Vec<Instruction>, Quote)> {
+    let quote = ok_or_mark_trace!(amm.quote(&QuoteParams {
+        input_mint: request.input_mint,
+        output_mint: request.output_mint,
+        in_amount: request.amount_in,
+    }))?;
+    if quote.not_enough_liquidity {
+        return Err(err!("not enough liquidity for the swap"));
+    }
+
+    let x_to_y = request.input_mint == amm.pool.token_x;
+    let sqrt_price_limit =
+        sqrt_price_limit_from_slippage(amm.pool.sqrt_price, request.slippage_bps, x_to_y);
+    let accounts = ok_or_mark_trace!(amm.get_accounts_for_swap(&SwapParams {
+        source_mint: request.input_mint,
+        destination_mint: request.output_mint,
+        user_source_token_account: request.user_source_token_account,
+        user_destination_token_account: request.user_destination_token_account,
+        user_transfer_authority: owner.pubkey(),
+    }))?;
+    let ticks: Vec<Tick> = amm.ticks.values().copied().collect();
+    let compute_units = ok_or_mark_trace!(predict_swap_compute_units(
+        &amm.pool,
+        &ticks,
+        x_to_y,
+        TokenAmount(request.amount_in),
+        true,
+        sqrt_price_limit
+    ))?;
+
+    let swap = Instruction {
+        program_id: ID,
+        accounts,
+        data: SwapArgs {
+            direction: Direction::from(x_to_y),
+            amount: request.amount_in,
+            amount_spec: AmountSpec::In,
+            sqrt_price_limit: sqrt_price_limit.v,
+        }
+        .data(),
+    };
+    Ok((
+        with_compute_budget(vec![swap], compute_units, request.micro_lamports_per_cu),
+        quote,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_retryable() {
+        let custom = |code: u32| {
+            ClientError::from(TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(code),
+            ))
+        };
+
+        // 0x1778 and 0x1777 as returned by the program
+        assert!(is_retryable(&custom(6008)));
+        assert!(is_retryable(&custom(6007)));
+        assert!(is_retryable(&custom(u32::from(
+            InvariantErrorCode::WrongTick
+        ))));
+        assert!(is_retryable(&ClientError::from(
+            TransactionError::BlockhashNotFound
+        )));
+
+        assert!(!is_retryable(&custom(u32::from(
+            InvariantErrorCode::ZeroAmount
+        ))));
+        assert!(!is_retryable(&ClientError::from(
+            TransactionError::InsufficientFundsForFee
+        )));
+    }
+}
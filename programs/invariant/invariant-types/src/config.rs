@@ -0,0 +1,69 @@
+// Program ids, seeds and other deployment constants, so no other module hardcodes them.
+// The network is picked with the mainnet (default), devnet and localnet features. When more
+// than one is enabled the most local network wins, so devnet can be enabled on top of the
+// default features.
+use anchor_lang::prelude::*;
+
+#[cfg(feature = "localnet")]
+mod network {
+    use super::*;
+
+    // keys of Anchor.toml
+    declare_id!("HyaB3W9q6XdA5xwpU4XnSZV94htfmbmqJXZcEbRaJutt");
+    pub mod staker_program {
+        use super::*;
+        declare_id!("MJ6WF1tpEJ7Gk8ULqejDJapRfqBwBEp1dH5QvAgYxu9");
+    }
+    pub const NETWORK: &str = "localnet";
+}
+
+#[cfg(all(feature = "devnet", not(feature = "localnet")))]
+mod network {
+    use super::*;
+
+    // devnet builds of the programs are deployed under the mainnet keys
+    declare_id!("HyaB3W9q6XdA5xwpU4XnSZV94htfmbmqJXZcEbRaJutt");
+    pub mod staker_program {
+        use super::*;
+        declare_id!("MJ6WF1tpEJ7Gk8ULqejDJapRfqBwBEp1dH5QvAgYxu9");
+    }
+    pub const NETWORK: &str = "devnet";
+}
+
+#[cfg(not(any(feature = "devnet", feature = "localnet")))]
+mod network {
+    use super::*;
+
+    declare_id!("HyaB3W9q6XdA5xwpU4XnSZV94htfmbmqJXZcEbRaJutt");
+    pub mod staker_program {
+        use super::*;
+        declare_id!("MJ6WF1tpEJ7Gk8ULqejDJapRfqBwBEp1dH5QvAgYxu9");
+    }
+    pub const NETWORK: &str = "mainnet";
+}
+
+pub use network::*;
+pub const STAKER_ID: Pubkey = staker_program::ID;
+
+pub const SEED: &str = "Invariant";
+pub const STATE_SEED: &str = "statev1";
+pub const ADMIN_LOG_SEED: &str = "adminlogv1";
+pub const FEE_TIER_SEED: &str = "feetierv1";
+pub const FEE_TIER_REGISTRY_SEED: &str = "feetierregistryv1";
+pub const POOL_SEED: &str = "poolv1";
+pub const TICK_SEED: &str = "tickv1";
+pub const POSITION_SEED: &str = "positionv1";
+pub const POSITION_LIST_SEED: &str = "positionlistv1";
+pub const LAUNCH_CONFIG_SEED: &str = "launchv1";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_network() {
+        assert!(["mainnet", "devnet", "localnet"].contains(&NETWORK));
+        assert!(check_id(&ID));
+        assert_ne!(ID, STAKER_ID);
+    }
+}
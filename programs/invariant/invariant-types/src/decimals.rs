@@ -7,7 +7,10 @@ use anchor_lang::prelude::*;
 use crate::utils::{TrackableError, TrackableResult};
 use crate::{err, function, location};
 
-pub const PRICE_LIQUIDITY_DENOMINATOR: u128 = 1__0000_0000__0000_0000__00u128;
+pub const PRICE_LIQUIDITY_DENOMINATOR: u128 = Price::ONE / Liquidity::ONE;
+// 10 ^ (FeeGrowth::scale() + Liquidity::scale() - FixedPoint::scale())
+const FEE_GROWTH_TO_FEE_DENOMINATOR: U256 =
+    to_u256(FeeGrowth::ONE * Liquidity::ONE / FixedPoint::ONE);
 
 #[decimal(24)]
 #[zero_copy]
@@ -59,18 +62,19 @@ impl FeeGrowth {
         FeeGrowth::new(self.get() - other.get())
     }
 
-    pub fn from_fee(liquidity: Liquidity, fee: TokenAmount) -> Self {
-        FeeGrowth::new(
+    // fails for zero liquidity or when dust liquidity makes fee growth overflow
+    pub fn from_fee(liquidity: Liquidity, fee: TokenAmount) -> TrackableResult<Self> {
+        Ok(FeeGrowth::new(
             U256::from(fee.get())
                 .checked_mul(FeeGrowth::one())
-                .unwrap()
+                .ok_or_else(|| err!(TrackableError::MUL))?
                 .checked_mul(Liquidity::one())
-                .unwrap()
+                .ok_or_else(|| err!(TrackableError::MUL))?
                 .checked_div(liquidity.here())
-                .unwrap()
+                .ok_or_else(|| err!(TrackableError::DIV))?
                 .try_into()
-                .unwrap(),
-        )
+                .map_err(|_| err!(TrackableError::cast::<Self>().as_str()))?,
+        ))
     }
 
     pub fn to_fee(self, liquidity: Liquidity) -> FixedPoint {
@@ -79,9 +83,7 @@ impl FeeGrowth {
                 .unwrap()
                 .checked_mul(liquidity.here())
                 .unwrap()
-                .checked_div(U256::from(10).pow(U256::from(
-                    FeeGrowth::scale() + Liquidity::scale() - FixedPoint::scale(),
-                )))
+                .checked_div(FEE_GROWTH_TO_FEE_DENOMINATOR)
                 .unwrap()
                 .try_into()
                 .unwrap_or_else(|_| panic!("value too big to parse in `FeeGrowth::to_fee`")),
@@ -156,6 +158,87 @@ impl Price {
     }
 }
 
+// Lossy conversions meant for off-chain analytics only, never use them in the swap math.
+// f64 keeps 53 bits of mantissa, so the raw value is exact only up to 2^53 (~9.007e15),
+// above that the relative error of a single conversion is bounded by 2^-53 (~1.1e-16).
+// `from_f64_lossy` truncates towards zero and saturates: NaN and negative values map to 0,
+// values too large for the underlying integer map to its max value.
+#[cfg(feature = "analytics")]
+macro_rules! impl_f64_lossy {
+    ($($decimal:ident),*) => {
+        $(
+            impl $decimal {
+                pub fn to_f64_lossy(self) -> f64 {
+                    self.get() as f64 / 10f64.powi(Self::scale() as i32)
+                }
+
+                pub fn from_f64_lossy(value: f64) -> Self {
+                    Self::new((value * 10f64.powi(Self::scale() as i32)) as _)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "analytics")]
+impl_f64_lossy!(Price, Liquidity, FeeGrowth, FixedPoint, TokenAmount);
+
+// Canonical binary encoding of big ints and accumulators for off-chain systems. Big-endian
+// keeps the byte order equal to the numeric order, so encoded values sort correctly as keys.
+pub trait BigEndian: Sized {
+    type Bytes: AsRef<[u8]>;
+
+    fn to_be_bytes(&self) -> Self::Bytes;
+    fn from_be_bytes(bytes: Self::Bytes) -> Self;
+}
+
+impl BigEndian for U256 {
+    type Bytes = [u8; 32];
+
+    fn to_be_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        self.to_big_endian(&mut bytes);
+        bytes
+    }
+
+    fn from_be_bytes(bytes: [u8; 32]) -> Self {
+        U256::from_big_endian(&bytes)
+    }
+}
+
+macro_rules! impl_big_endian {
+    ($($decimal:ident),*) => {
+        $(
+            impl BigEndian for $decimal {
+                type Bytes = [u8; 16];
+
+                fn to_be_bytes(&self) -> [u8; 16] {
+                    self.get().to_be_bytes()
+                }
+
+                fn from_be_bytes(bytes: [u8; 16]) -> Self {
+                    Self::new(u128::from_be_bytes(bytes))
+                }
+            }
+        )*
+    };
+}
+
+impl_big_endian!(Price, Liquidity, FeeGrowth, FixedPoint);
+
+// Displays the big-endian encoding as fixed width lowercase hex prefixed with `0x`
+pub struct Hex<T: BigEndian>(pub T);
+
+impl<T: BigEndian> std::fmt::Display for Hex<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("0x")?;
+        for byte in self.0.to_be_bytes().as_ref() {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 pub mod tests {
     use crate::{math::calculate_price_sqrt, structs::MAX_TICK};
@@ -171,6 +254,13 @@ pub mod tests {
             1_000000_000000_000000_000000
         );
         assert_eq!(TokenAmount::from_integer(1).get(), 1);
+        assert_eq!(PRICE_LIQUIDITY_DENOMINATOR, 1__0000_0000__0000_0000__00u128);
+        assert_eq!(
+            FEE_GROWTH_TO_FEE_DENOMINATOR,
+            U256::from(10).pow(U256::from(
+                FeeGrowth::scale() + Liquidity::scale() - FixedPoint::scale()
+            ))
+        );
     }
 
     #[test]
@@ -183,17 +273,20 @@ pub mod tests {
     fn test_from_fee() {
         // One
         {
-            let fee_growth = FeeGrowth::from_fee(Liquidity::from_integer(1), TokenAmount(1));
+            let fee_growth =
+                FeeGrowth::from_fee(Liquidity::from_integer(1), TokenAmount(1)).unwrap();
             assert_eq!(fee_growth, FeeGrowth::from_integer(1));
         }
         // Half
         {
-            let fee_growth = FeeGrowth::from_fee(Liquidity::from_integer(2), TokenAmount(1));
+            let fee_growth =
+                FeeGrowth::from_fee(Liquidity::from_integer(2), TokenAmount(1)).unwrap();
             assert_eq!(fee_growth, FeeGrowth::from_scale(5, 1))
         }
         // Little
         {
-            let fee_growth = FeeGrowth::from_fee(Liquidity::from_integer(u64::MAX), TokenAmount(1));
+            let fee_growth =
+                FeeGrowth::from_fee(Liquidity::from_integer(u64::MAX), TokenAmount(1)).unwrap();
             // real    5.42101086242752217003726400434970855712890625 × 10^-20
             // expected 54210
             assert_eq!(fee_growth, FeeGrowth::new(54210))
@@ -201,11 +294,37 @@ pub mod tests {
         // Fairly big
         {
             let fee_growth =
-                FeeGrowth::from_fee(Liquidity::from_integer(100), TokenAmount(1_000_000));
+                FeeGrowth::from_fee(Liquidity::from_integer(100), TokenAmount(1_000_000)).unwrap();
             assert_eq!(fee_growth, FeeGrowth::from_integer(10000))
         }
     }
 
+    #[test]
+    fn test_from_fee_dust_liquidity() {
+        // zero liquidity
+        {
+            let result = FeeGrowth::from_fee(Liquidity::new(0), TokenAmount(1));
+            assert!(result.is_err());
+        }
+        // single unit of liquidity
+        {
+            let fee_growth = FeeGrowth::from_fee(Liquidity::new(1), TokenAmount(1)).unwrap();
+            assert_eq!(fee_growth, FeeGrowth::from_integer(1_000_000));
+        }
+        // single unit of liquidity, biggest fee that fits
+        {
+            let fee = TokenAmount(340282366);
+            let fee_growth = FeeGrowth::from_fee(Liquidity::new(1), fee).unwrap();
+            assert_eq!(fee_growth, FeeGrowth::from_integer(340_282_366_000_000u128));
+        }
+        // single unit of liquidity, overflow
+        {
+            let fee = TokenAmount(340282367);
+            let result = FeeGrowth::from_fee(Liquidity::new(1), fee);
+            assert!(result.is_err());
+        }
+    }
+
     #[test]
     fn test_to_fee() {
         // equal
@@ -213,7 +332,7 @@ pub mod tests {
             let amount = TokenAmount(100);
             let liquidity = Liquidity::from_integer(1_000_000);
 
-            let fee_growth = FeeGrowth::from_fee(liquidity, amount);
+            let fee_growth = FeeGrowth::from_fee(liquidity, amount).unwrap();
             let out = fee_growth.to_fee(liquidity);
             assert_eq!(out, FixedPoint::from_decimal(amount));
         }
@@ -223,7 +342,7 @@ pub mod tests {
             let liquidity_before = Liquidity::from_integer(1_000_000);
             let liquidity_after = Liquidity::from_integer(10_000_000);
 
-            let fee_growth = FeeGrowth::from_fee(liquidity_before, amount);
+            let fee_growth = FeeGrowth::from_fee(liquidity_before, amount).unwrap();
             let out = fee_growth.to_fee(liquidity_after);
             assert_eq!(out, FixedPoint::from_integer(1000))
         }
@@ -232,7 +351,7 @@ pub mod tests {
             let amount = TokenAmount(100_000_000__000000);
             let liquidity = Liquidity::from_integer(2u128.pow(77));
 
-            let fee_growth = FeeGrowth::from_fee(liquidity, amount);
+            let fee_growth = FeeGrowth::from_fee(liquidity, amount).unwrap();
             // real    6.61744490042422139897126953655970282852649688720703125 × 10^-22
             // expected 661744490042422
             assert_eq!(fee_growth, FeeGrowth::new(661744490042422));
@@ -247,7 +366,7 @@ pub mod tests {
             let amount = TokenAmount(600000000000000000);
             let liquidity = Liquidity::from_integer(10000000000000000000u128);
 
-            let fee_growth = FeeGrowth::from_fee(liquidity, amount);
+            let fee_growth = FeeGrowth::from_fee(liquidity, amount).unwrap();
             // real     0.06
             // expected 0.06
             assert_eq!(fee_growth, FeeGrowth::new(60000000000000000000000));
@@ -277,6 +396,57 @@ pub mod tests {
         assert_eq!(price.big_mul_up(liquidity), expected_price + Price::new(1));
     }
 
+    #[test]
+    fn test_checked_decimal_ops() {
+        let liquidity = Liquidity::new(4_902_430_892_340_393);
+        let price: Price = Price::new(9_833_489_034_289_032_430_082_130_832);
+
+        assert_eq!(
+            liquidity.checked_big_mul(price),
+            Ok(liquidity.big_mul(price))
+        );
+        assert_eq!(
+            liquidity.checked_big_mul_up(price),
+            Ok(liquidity.big_mul_up(price))
+        );
+        assert_eq!(
+            price.checked_big_mul(liquidity),
+            Ok(price.big_mul(liquidity))
+        );
+        assert_eq!(
+            price.checked_big_div(liquidity),
+            Ok(price.big_div(liquidity))
+        );
+        assert_eq!(
+            price.checked_big_div_up(liquidity),
+            Ok(price.big_div_up(liquidity))
+        );
+        assert_eq!(
+            FixedPoint::from_integer(2).checked_mul(FeeGrowth::from_integer(3)),
+            Ok(FixedPoint::from_integer(6))
+        );
+        assert_eq!(
+            TokenAmount(10).checked_div(FixedPoint::from_integer(4)),
+            Ok(TokenAmount(2))
+        );
+
+        // overflow and division by zero are reported instead of panicking
+        assert!(Price::max_instance()
+            .checked_big_mul(Price::from_integer(2))
+            .is_err());
+        assert!(Liquidity::max_instance()
+            .checked_mul(Liquidity::from_integer(2))
+            .is_err());
+        assert!(TokenAmount::max_instance()
+            .checked_mul(FixedPoint::from_integer(2))
+            .is_err());
+        assert!(price.checked_div(Liquidity::new(0)).is_err());
+        assert!(price.checked_big_div(Liquidity::new(0)).is_err());
+        assert!(FeeGrowth::from_integer(1)
+            .checked_big_div_up(Price::new(0))
+            .is_err());
+    }
+
     #[test]
     fn test_big_div_values_to_token() {
         // base examples tested in up-level functions
@@ -397,4 +567,80 @@ pub mod tests {
             assert_eq!(result_up, expected_result);
         }
     }
+
+    #[cfg(feature = "analytics")]
+    #[test]
+    fn test_f64_lossy() {
+        // exact within mantissa
+        {
+            assert_eq!(Price::from_integer(1).to_f64_lossy(), 1f64);
+            assert_eq!(Liquidity::from_scale(5, 1).to_f64_lossy(), 0.5f64);
+            assert_eq!(FixedPoint::from_scale(1, 2).to_f64_lossy(), 0.01f64);
+            assert_eq!(TokenAmount(42).to_f64_lossy(), 42f64);
+            assert_eq!(TokenAmount::from_f64_lossy(42f64), TokenAmount(42));
+            assert_eq!(Liquidity::from_f64_lossy(0.5), Liquidity::from_scale(5, 1));
+        }
+        // relative error bounded for large values
+        {
+            let price = calculate_price_sqrt(MAX_TICK);
+            let back = Price::from_f64_lossy(price.to_f64_lossy());
+            let diff = price.get().max(back.get()) - price.get().min(back.get());
+            assert!((diff as f64) / (price.get() as f64) < 1e-15);
+        }
+        // saturation
+        {
+            assert_eq!(FixedPoint::from_f64_lossy(-1f64), FixedPoint::new(0));
+            assert_eq!(FixedPoint::from_f64_lossy(f64::NAN), FixedPoint::new(0));
+            assert_eq!(TokenAmount::from_f64_lossy(f64::MAX), TokenAmount(u64::MAX));
+        }
+    }
+
+    #[test]
+    fn test_big_endian() {
+        // U256
+        {
+            // 3 * 2^128 + 0xff
+            let value = U256::from(u128::MAX) * U256::from(3u8) + U256::from(0x0102u16);
+            let bytes = value.to_be_bytes();
+            assert_eq!(U256::from_be_bytes(bytes), value);
+            assert_eq!(bytes[..15], [0u8; 15]);
+            assert_eq!(bytes[15], 0x03);
+            assert_eq!(bytes[16..31], [0u8; 15]);
+            assert_eq!(bytes[31], 0xff);
+            assert_eq!(U256::from_be_bytes([0xff; 32]), U256::MAX);
+            assert_eq!(U256::zero().to_be_bytes(), [0u8; 32]);
+        }
+        // accumulators
+        {
+            let fee_growth = FeeGrowth::new(0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10);
+            let bytes = fee_growth.to_be_bytes();
+            assert_eq!(
+                bytes,
+                [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]
+            );
+            assert_eq!(FeeGrowth::from_be_bytes(bytes), fee_growth);
+
+            let price = Price::new(u128::MAX);
+            assert_eq!(Price::from_be_bytes(price.to_be_bytes()), price);
+        }
+        // byte order matches numeric order
+        {
+            let smaller = FixedPoint::new(u64::MAX as u128).to_be_bytes();
+            let greater = FixedPoint::new(u64::MAX as u128 + 1).to_be_bytes();
+            assert!(smaller < greater);
+        }
+    }
+
+    #[test]
+    fn test_hex() {
+        assert_eq!(
+            Hex(FeeGrowth::new(0xabc)).to_string(),
+            "0x00000000000000000000000000000abc"
+        );
+        assert_eq!(
+            Hex(U256::from(1u8) << 255).to_string(),
+            format!("0x80{}", "0".repeat(62))
+        );
+        assert_eq!(Hex(Liquidity::new(0)).to_string().len(), 2 + 32);
+    }
 }
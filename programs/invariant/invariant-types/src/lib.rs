@@ -1,17 +1,27 @@
+// plain Rust consumers build without anchor, the shim takes its place under the same name
+#[cfg(not(feature = "anchor"))]
+extern crate anchor_shim as anchor_lang;
+
+#[cfg(feature = "amm")]
+pub mod amm;
+pub mod args;
+pub mod client;
+pub mod config;
 pub mod decimals;
 pub mod errors;
+pub mod liquidity;
 pub mod log;
 pub mod macros;
 pub mod math;
+pub mod migration;
+pub mod replay;
+pub mod simulation;
+#[cfg(test)]
+mod snapshots;
 pub mod structs;
 pub mod utils;
 
-use anchor_lang::prelude::*;
-
-declare_id!("HyaB3W9q6XdA5xwpU4XnSZV94htfmbmqJXZcEbRaJutt");
-pub const SEED: &str = "Invariant";
-pub const STATE_SEED: &str = "statev1";
-pub const TICK_SEED: &str = "tickv1";
+pub use config::*;
 pub const ANCHOR_DISCRIMINATOR_SIZE: usize = 8;
 pub const MAX_VIRTUAL_CROSS: u16 = 10;
 pub const MAX_SQRT_PRICE: u128 = 65535383934512647000000000000;
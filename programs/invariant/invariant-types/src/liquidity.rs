@@ -0,0 +1,56 @@
+// Included by the program as well, so it only depends on the decimals module both crates have
+use core::convert::TryInto;
+
+use crate::decimals::*;
+
+// L = x * sqrt_price_a * sqrt_price_b / (sqrt_price_b - sqrt_price_a)
+fn get_liquidity_by_x(
+    amount_x: TokenAmount,
+    sqrt_price_a: Price,
+    sqrt_price_b: Price,
+) -> Option<Liquidity> {
+    let sqrt_prices_product = sqrt_price_a.big_mul_to_value(sqrt_price_b);
+    Some(Liquidity::new(
+        U256::from(amount_x.get())
+            .checked_mul(sqrt_prices_product)?
+            .checked_mul(Liquidity::one())?
+            .checked_div(U256::from((sqrt_price_b - sqrt_price_a).get()))?
+            .try_into()
+            .ok()?,
+    ))
+}
+
+// L = y / (sqrt_price_b - sqrt_price_a)
+fn get_liquidity_by_y(
+    amount_y: TokenAmount,
+    sqrt_price_a: Price,
+    sqrt_price_b: Price,
+) -> Option<Liquidity> {
+    Some(Liquidity::new(
+        U256::from(amount_y.get())
+            .checked_mul(Liquidity::one())?
+            .checked_mul(Price::one())?
+            .checked_div(U256::from((sqrt_price_b - sqrt_price_a).get()))?
+            .try_into()
+            .ok()?,
+    ))
+}
+
+// biggest liquidity that can be deposited in the range with given amounts, rounded down
+pub fn get_max_liquidity(
+    amount_x: TokenAmount,
+    amount_y: TokenAmount,
+    lower_sqrt_price: Price,
+    upper_sqrt_price: Price,
+    current_sqrt_price: Price,
+) -> Option<Liquidity> {
+    if current_sqrt_price <= lower_sqrt_price {
+        return get_liquidity_by_x(amount_x, lower_sqrt_price, upper_sqrt_price);
+    }
+    if current_sqrt_price >= upper_sqrt_price {
+        return get_liquidity_by_y(amount_y, lower_sqrt_price, upper_sqrt_price);
+    }
+    let by_x = get_liquidity_by_x(amount_x, current_sqrt_price, upper_sqrt_price)?;
+    let by_y = get_liquidity_by_y(amount_y, lower_sqrt_price, current_sqrt_price)?;
+    Some(by_x.min(by_y))
+}
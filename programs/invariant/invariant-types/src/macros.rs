@@ -3,6 +3,11 @@ macro_rules! size {
     ($name: ident) => {
         impl $name {
             pub const LEN: usize = std::mem::size_of::<$name>() + 8;
+
+            // lamports an account of the type needs to be allocated with
+            pub fn rent_exempt_minimum(rent: &anchor_lang::prelude::Rent) -> u64 {
+                rent.minimum_balance(Self::LEN)
+            }
         }
     };
 }
@@ -4,6 +4,7 @@ use std::{cell::RefMut, convert::TryInto};
 use anchor_lang::*;
 
 use crate::{
+    args::{AmountSpec, Direction},
     decimals::*,
     errors::InvariantErrorCode,
     structs::{get_search_limit, Pool, Tick, Tickmap, MAX_TICK, TICK_LIMIT},
@@ -93,11 +94,12 @@ pub fn calculate_price_sqrt(tick_index: i32) -> Price {
 // and compares its price to the price limit of the trade
 pub fn get_closer_limit(
     sqrt_price_limit: Price,
-    x_to_y: bool,
+    direction: Direction,
     current_tick: i32, // tick already scaled by tick_spacing
     tick_spacing: u16,
     tickmap: &Tickmap,
 ) -> Result<(Price, Option<(i32, bool)>)> {
+    let x_to_y = direction.is_x_to_y();
     // find initalized tick (None also for virtual tick limiated by search scope)
     let closes_tick_index = if x_to_y {
         tickmap.prev_initialized(current_tick, tick_spacing)
@@ -139,8 +141,8 @@ pub fn compute_swap_step(
     current_price_sqrt: Price,
     target_price_sqrt: Price,
     liquidity: Liquidity, // pool.liquidity
-    amount: TokenAmount,  // reaming_amount (input or output depending on by_amount_in)
-    by_amount_in: bool,
+    amount: TokenAmount,  // reaming_amount (input or output depending on amount_spec)
+    amount_spec: AmountSpec,
     fee: FixedPoint, // pool.fee
 ) -> TrackableResult<SwapResult> {
     if liquidity.is_zero() {
@@ -153,6 +155,7 @@ pub fn compute_swap_step(
     }
 
     let x_to_y = current_price_sqrt >= target_price_sqrt;
+    let by_amount_in = amount_spec.is_in();
 
     let next_price_sqrt;
     let mut amount_in = TokenAmount(0);
@@ -410,6 +413,32 @@ fn get_next_sqrt_price_x_up(
     ))
 }
 
+// amount / L scaled to price, computed on U256 as amount * 10^24 alone overflows u128
+// for amounts above ~3.4 * 10^14, reachable by mints with many decimals
+// max_nominator = 2^64 * 10^48 ~ 2^224 so no possible to overflow
+fn get_quotient(amount: TokenAmount, liquidity: Liquidity, up: bool) -> TrackableResult<Price> {
+    let nominator = U256::from(amount.get())
+        .checked_mul(U256::from(Price::ONE))
+        .and_then(|value| value.checked_mul(U256::from(Price::ONE)))
+        .ok_or_else(|| err!(TrackableError::MUL))?;
+    let denominator = U256::from(liquidity.get())
+        .checked_mul(U256::from(PRICE_LIQUIDITY_DENOMINATOR))
+        .ok_or_else(|| err!(TrackableError::MUL))?;
+    let nominator = match up {
+        true => nominator
+            .checked_add(denominator - U256::from(1))
+            .ok_or_else(|| err!(TrackableError::ADD))?,
+        false => nominator,
+    };
+    Ok(Price::new(
+        nominator
+            .checked_div(denominator)
+            .ok_or_else(|| err!(TrackableError::DIV))?
+            .try_into()
+            .map_err(|_| err!(TrackableError::cast::<Price>().as_str()))?,
+    ))
+}
+
 // price +- (amount / L)
 fn get_next_sqrt_price_y_down(
     price_sqrt: Price,
@@ -426,39 +455,19 @@ fn get_next_sqrt_price_y_down(
     // PRICE_LIQUIDITY_DENOMINATOR = 10 ^ (24 - 6)
 
     if add {
-        // Price::from_scale(amount, TokenAmount::scale())
-        // max_nominator = max_amount * 10^24 => 2^144 so possible to overflow here
-
         // max_denominator = max_liquidity
         // max_denominator = U256(u128::MAX) * U256(10^18)
         // max_denominator = U256(2^128 * 10^18) ~ 2^188 so no possible to overflow
 
-        // quotient - max quotient nominator
-        // quotient_max_nominator = U256(max_nominator) * U256(10^24)
-        // quotient_max_nominator = 2^128 * 10^24 ~ 2^208 so no possible to overflow in intermediate operations
-
         // max_quotient = max_nominator / min_denominator
-        // max_quotient = 2^128 * 10^24 / 10^18 ~ 2^148 so possible to overflow in max_quote
-        let quotient = from_result!(Price::checked_from_decimal(amount)
-            .map_err(|err| err!(&err))? // TODO: add util macro to map str -> TrackableError
-            .checked_big_div_by_number(
-                U256::from(liquidity.get())
-                    .checked_mul(U256::from(PRICE_LIQUIDITY_DENOMINATOR))
-                    .unwrap(),
-            ))?;
+        // max_quotient = 2^64 * 10^48 / 10^18 ~ 2^164 so possible to overflow in max_quote
+        let quotient = ok_or_mark_trace!(get_quotient(amount, liquidity, false))?;
         // max_quotient = 2^128
         // price_sqrt = 2^96
         // possible to overflow in result
         from_result!(price_sqrt.checked_add(quotient))
     } else {
-        // Price::from_scale - same as case above
-        let quotient = from_result!(Price::checked_from_decimal(amount)
-            .map_err(|err| err!(&err))? // TODO: add util macro to map str -> TrackableError
-            .checked_big_div_by_number_up(
-                U256::from(liquidity.get())
-                    .checked_mul(U256::from(PRICE_LIQUIDITY_DENOMINATOR))
-                    .unwrap(),
-            ))?;
+        let quotient = ok_or_mark_trace!(get_quotient(amount, liquidity, true))?;
         from_result!(price_sqrt.checked_sub(quotient))
     }
 }
@@ -468,14 +477,15 @@ pub fn is_enough_amount_to_push_price(
     current_price_sqrt: Price,
     liquidity: Liquidity,
     fee: FixedPoint,
-    by_amount_in: bool,
-    x_to_y: bool,
+    amount_spec: AmountSpec,
+    direction: Direction,
 ) -> TrackableResult<bool> {
     if liquidity.is_zero() {
         return Ok(true);
     }
 
-    let next_price_sqrt = ok_or_mark_trace!(if by_amount_in {
+    let x_to_y = direction.is_x_to_y();
+    let next_price_sqrt = ok_or_mark_trace!(if amount_spec.is_in() {
         let amount_after_fee = amount.big_mul(FixedPoint::from_integer(1) - fee);
         get_next_sqrt_price_from_input(current_price_sqrt, liquidity, amount_after_fee, x_to_y)
     } else {
@@ -504,6 +514,24 @@ pub fn cross_tick(tick: &mut RefMut<Tick>, pool: &mut Pool) -> Result<()> {
     Ok(())
 }
 
+// biggest liquidity that can be deposited in the range with given amounts, rounded down
+pub fn get_max_liquidity(
+    amount_x: TokenAmount,
+    amount_y: TokenAmount,
+    lower_sqrt_price: Price,
+    upper_sqrt_price: Price,
+    current_sqrt_price: Price,
+) -> TrackableResult<Liquidity> {
+    crate::liquidity::get_max_liquidity(
+        amount_x,
+        amount_y,
+        lower_sqrt_price,
+        upper_sqrt_price,
+        current_sqrt_price,
+    )
+    .ok_or_else(|| err!(TrackableError::MUL))
+}
+
 pub fn get_max_tick(tick_spacing: u16) -> i32 {
     let limit_by_space = TICK_LIMIT
         .checked_sub(1)
@@ -541,9 +569,9 @@ mod tests {
     use crate::{
         decimals::{FixedPoint, Liquidity, Price, TokenAmount},
         math::{
-            compute_swap_step, cross_tick, get_delta_x, get_delta_y, get_max_sqrt_price,
-            get_max_tick, get_min_sqrt_price, get_min_tick, get_next_sqrt_price_x_up,
-            get_next_sqrt_price_y_down, SwapResult,
+            compute_swap_step, cross_tick, get_delta_x, get_delta_y, get_max_liquidity,
+            get_max_sqrt_price, get_max_tick, get_min_sqrt_price, get_min_tick,
+            get_next_sqrt_price_x_up, get_next_sqrt_price_y_down, SwapResult,
         },
         structs::{Pool, Tick, MAX_TICK},
         utils::TrackableError,
@@ -551,6 +579,7 @@ mod tests {
     };
 
     use super::{calculate_price_sqrt, is_enough_amount_to_push_price, FeeGrowth};
+    use crate::args::{AmountSpec, Direction};
 
     #[test]
     fn test_compute_swap_step() {
@@ -563,7 +592,8 @@ mod tests {
             let amount = TokenAmount(1);
             let fee = FixedPoint::from_scale(6, 4);
 
-            let result = compute_swap_step(price, target, liquidity, amount, true, fee).unwrap();
+            let result =
+                compute_swap_step(price, target, liquidity, amount, AmountSpec::In, fee).unwrap();
 
             let expected_result = SwapResult {
                 next_price_sqrt: price,
@@ -581,9 +611,10 @@ mod tests {
             let amount = TokenAmount(20);
             let fee = FixedPoint::from_scale(6, 4);
 
-            let result_in = compute_swap_step(price, target, liquidity, amount, true, fee).unwrap();
+            let result_in =
+                compute_swap_step(price, target, liquidity, amount, AmountSpec::In, fee).unwrap();
             let result_out =
-                compute_swap_step(price, target, liquidity, amount, false, fee).unwrap();
+                compute_swap_step(price, target, liquidity, amount, AmountSpec::Out, fee).unwrap();
 
             let expected_result = SwapResult {
                 next_price_sqrt: target,
@@ -602,7 +633,8 @@ mod tests {
             let amount = TokenAmount(1000000);
             let fee = FixedPoint::from_scale(6, 4);
 
-            let result = compute_swap_step(price, target, liquidity, amount, true, fee).unwrap();
+            let result =
+                compute_swap_step(price, target, liquidity, amount, AmountSpec::In, fee).unwrap();
             let expected_result = SwapResult {
                 next_price_sqrt: Price::new(1013331333333_333333333333),
                 amount_in: TokenAmount(999400),
@@ -619,7 +651,8 @@ mod tests {
             let amount = TokenAmount(2000000);
             let fee = FixedPoint::from_scale(6, 4);
 
-            let result = compute_swap_step(price, target, liquidity, amount, false, fee).unwrap();
+            let result =
+                compute_swap_step(price, target, liquidity, amount, AmountSpec::Out, fee).unwrap();
             let expected_result = SwapResult {
                 next_price_sqrt: Price::new(100999999600000_000000000000),
                 amount_in: TokenAmount(197), // (5000000000000 * (101 - 100.9999996)) /  (101 * 100.9999996)
@@ -635,7 +668,7 @@ mod tests {
 
             let liquidity = Liquidity::new(20006000_000000);
             let amount = TokenAmount(1_000_000);
-            let by_amount_in = true;
+            let by_amount_in = AmountSpec::In;
             let fee = FixedPoint::from_scale(6, 4); // 0.0006 -> 0.06%
 
             let result = compute_swap_step(
@@ -661,7 +694,7 @@ mod tests {
             let target_price_sqrt = Price::from_integer(1);
             let liquidity = Liquidity::new(u128::MAX / 1_000000);
             let amount = TokenAmount(1);
-            let by_amount_in = false;
+            let by_amount_in = AmountSpec::Out;
             let fee = FixedPoint::from_scale(6, 4); // 0.0006 -> 0.06%
 
             let result = compute_swap_step(
@@ -687,7 +720,7 @@ mod tests {
             let target_price_sqrt = Price::from_scale(1999500149965u128, 12);
             let liquidity = Liquidity::from_integer(100_000000000000_000000000000u128);
             let amount = TokenAmount(10);
-            let by_amount_in = true;
+            let by_amount_in = AmountSpec::In;
             let fee = FixedPoint::from_scale(6, 4); // 0.0006 -> 0.06%
 
             let result = compute_swap_step(
@@ -713,7 +746,7 @@ mod tests {
             let target_price_sqrt = Price::from_scale(100005, 5); // 1.00005
             let liquidity = Liquidity::from_integer(368944000000_000000000000u128);
             let amount = TokenAmount(1);
-            let by_amount_in = true;
+            let by_amount_in = AmountSpec::In;
             let fee = FixedPoint::from_scale(6, 4); // 0.0006 -> 0.06%
 
             let result = compute_swap_step(
@@ -739,7 +772,7 @@ mod tests {
             let target_price_sqrt = Price::from_scale(100005, 5); // 1.00005
             let liquidity = Liquidity::from_integer(368944000000_000000000000u128);
             let amount = TokenAmount(1);
-            let by_amount_in = false;
+            let by_amount_in = AmountSpec::Out;
             let fee = FixedPoint::from_scale(6, 4); // 0.0006 -> 0.06%
 
             let result = compute_swap_step(
@@ -765,7 +798,7 @@ mod tests {
             let target_price_sqrt = Price::from_scale(100005, 5); // 1.00005
             let liquidity = Liquidity::new(0);
             let amount = TokenAmount(100000);
-            let by_amount_in = true;
+            let by_amount_in = AmountSpec::In;
             let fee = FixedPoint::from_scale(6, 4); // 0.0006 -> 0.06%
 
             let result = compute_swap_step(
@@ -791,7 +824,7 @@ mod tests {
             let target_price_sqrt = Price::from_scale(100005, 5); // 1.00005
             let liquidity = Liquidity::new(0);
             let amount = TokenAmount(100000);
-            let by_amount_in = false;
+            let by_amount_in = AmountSpec::Out;
             let fee = FixedPoint::from_scale(6, 4); // 0.0006 -> 0.06%
 
             let result = compute_swap_step(
@@ -817,7 +850,7 @@ mod tests {
             let target_price_sqrt = Price::from_integer(1);
             let liquidity = Liquidity::from_integer(50000000);
             let amount = TokenAmount(1000);
-            let by_amount_in = true;
+            let by_amount_in = AmountSpec::In;
             let fee = FixedPoint::new(0);
 
             let result = compute_swap_step(
@@ -845,7 +878,7 @@ mod tests {
             let one_token = TokenAmount(1);
             let tokens_with_same_output = TokenAmount(85);
             let zero_token = TokenAmount(0);
-            let by_amount_in = false;
+            let by_amount_in = AmountSpec::Out;
             let max_fee = FixedPoint::from_scale(9, 1);
             let min_fee = FixedPoint::from_integer(0);
 
@@ -926,7 +959,7 @@ mod tests {
                 two_price_sqrt,
                 one_liquidity,
                 max_amount,
-                true,
+                AmountSpec::In,
                 max_fee,
             )
             .unwrap();
@@ -947,7 +980,7 @@ mod tests {
                 two_price_sqrt,
                 max_liquidity,
                 max_amount,
-                true,
+                AmountSpec::In,
                 min_fee,
             )
             .unwrap_err()
@@ -966,7 +999,7 @@ mod tests {
                 two_price_sqrt,
                 big_liquidity,
                 amount_pushing_price_to_target - TokenAmount(1),
-                true,
+                AmountSpec::In,
                 min_fee,
             )
             .unwrap();
@@ -988,7 +1021,7 @@ mod tests {
                 two_price_sqrt,
                 max_liquidity,
                 TokenAmount::max_instance(),
-                true,
+                AmountSpec::In,
                 max_fee - FixedPoint::new(19),
             )
             .unwrap();
@@ -1013,7 +1046,7 @@ mod tests {
                 min_price_sqrt,
                 max_liquidity,
                 max_amount_not_reached_target_price,
-                true,
+                AmountSpec::In,
                 min_fee,
             )
             .unwrap_err()
@@ -1034,14 +1067,14 @@ mod tests {
                     max_price_sqrt,
                     max_liquidity,
                     max_amount_not_reached_target_price,
-                    true,
+                    AmountSpec::In,
                     min_fee,
                 )
                 .unwrap_err()
                 .get();
 
-                assert_eq!(cause, "checked_from_scale: (multiplier * base) overflow");
-                assert_eq!(stack.len(), 3);
+                assert_eq!(cause, "get_delta_x overflow");
+                assert_eq!(stack.len(), 1);
             }
             // 2. checked_big_div - no possible to trigger from compute_swap_step
             {
@@ -1051,7 +1084,7 @@ mod tests {
                     max_price_sqrt,
                     one_liquidity - Liquidity::new(1),
                     min_overflow_token_amount - TokenAmount(1),
-                    true,
+                    AmountSpec::In,
                     min_fee,
                 )
                 .unwrap();
@@ -1080,7 +1113,7 @@ mod tests {
                     max_price_sqrt,
                     max_liquidity,
                     TokenAmount(TokenAmount::max_value() - 1),
-                    false,
+                    AmountSpec::Out,
                     min_fee,
                 )
                 .unwrap_err()
@@ -1095,7 +1128,7 @@ mod tests {
                     max_price_sqrt,
                     Liquidity::from_integer(281_477_613_507_675u128),
                     TokenAmount(TokenAmount::max_value() - 1),
-                    false,
+                    AmountSpec::Out,
                     min_fee,
                 )
                 .unwrap();
@@ -1117,7 +1150,7 @@ mod tests {
                     max_price_sqrt,
                     Liquidity::from_integer(100_000_000_00u128),
                     TokenAmount(1),
-                    false,
+                    AmountSpec::Out,
                     min_fee,
                 )
                 .unwrap();
@@ -1217,23 +1250,29 @@ mod tests {
         let min_overflow_token_amount = TokenAmount::new(340282366920939);
         let max_price = calculate_price_sqrt(MAX_TICK);
         let one_liquidity: Liquidity = Liquidity::from_integer(1);
-        // extension TokenAmount to Price decimal overflow
+        // quotient overflow at max amount
         {
             {
                 let result =
                     get_next_sqrt_price_y_down(min_price, sample_liquidity, max_amount, true)
                         .unwrap_err();
                 let (_, cause, stack) = result.get();
-                assert_eq!(cause, "checked_from_scale: (multiplier * base) overflow");
-                assert_eq!(stack.len(), 1);
+                assert_eq!(
+                    cause,
+                    "conversion to invariant_types::decimals::Price type failed"
+                );
+                assert_eq!(stack.len(), 2);
             }
             {
                 let result =
                     get_next_sqrt_price_y_down(min_price, sample_liquidity, max_amount, false)
                         .unwrap_err();
                 let (_, cause, stack) = result.get();
-                assert_eq!(cause, "checked_from_scale: (multiplier * base) overflow");
-                assert_eq!(stack.len(), 1);
+                assert_eq!(
+                    cause,
+                    "conversion to invariant_types::decimals::Price type failed"
+                );
+                assert_eq!(stack.len(), 2);
             }
         }
         // quotient overflow
@@ -1248,8 +1287,11 @@ mod tests {
                     )
                     .unwrap_err();
                     let (_, cause, stack) = result.get();
-                    assert_eq!(cause, "checked_big_div_by_number: can't convert to result");
-                    assert_eq!(stack.len(), 1);
+                    assert_eq!(
+                        cause,
+                        "conversion to invariant_types::decimals::Price type failed"
+                    );
+                    assert_eq!(stack.len(), 2);
                 }
                 {
                     let result = get_next_sqrt_price_y_down(
@@ -1262,9 +1304,9 @@ mod tests {
                     let (_, cause, stack) = result.get();
                     assert_eq!(
                         cause,
-                        "checked_big_div_by_number_up: can't convert to result"
+                        "conversion to invariant_types::decimals::Price type failed"
                     );
-                    assert_eq!(stack.len(), 1);
+                    assert_eq!(stack.len(), 2);
                 }
             }
             {
@@ -1679,8 +1721,8 @@ mod tests {
                 max_price_sqrt,
                 min_liquidity,
                 min_fee,
-                false,
-                false,
+                AmountSpec::Out,
+                Direction::YtoX,
             )
             .unwrap_err()
             .get();
@@ -1960,4 +2002,161 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_get_max_liquidity() {
+        let lower_sqrt_price = calculate_price_sqrt(-1000);
+        let upper_sqrt_price = calculate_price_sqrt(1000);
+        let amount = TokenAmount(1_000_000);
+        // below range, only x
+        {
+            let current_sqrt_price = calculate_price_sqrt(-2000);
+            let liquidity = get_max_liquidity(
+                amount,
+                TokenAmount(0),
+                lower_sqrt_price,
+                upper_sqrt_price,
+                current_sqrt_price,
+            )
+            .unwrap();
+            let x = get_delta_x(lower_sqrt_price, upper_sqrt_price, liquidity, true).unwrap();
+            assert!(x <= amount);
+            let x = get_delta_x(
+                lower_sqrt_price,
+                upper_sqrt_price,
+                liquidity + Liquidity::from_integer(1),
+                true,
+            )
+            .unwrap();
+            assert!(x > amount);
+        }
+        // above range, only y
+        {
+            let current_sqrt_price = calculate_price_sqrt(2000);
+            let liquidity = get_max_liquidity(
+                TokenAmount(0),
+                amount,
+                lower_sqrt_price,
+                upper_sqrt_price,
+                current_sqrt_price,
+            )
+            .unwrap();
+            let y = get_delta_y(lower_sqrt_price, upper_sqrt_price, liquidity, true).unwrap();
+            assert!(y <= amount);
+            let y = get_delta_y(
+                lower_sqrt_price,
+                upper_sqrt_price,
+                liquidity + Liquidity::from_integer(1),
+                true,
+            )
+            .unwrap();
+            assert!(y > amount);
+        }
+        // in range, limited by the smaller side
+        {
+            let current_sqrt_price = Price::from_integer(1);
+            let liquidity = get_max_liquidity(
+                amount,
+                TokenAmount(10),
+                lower_sqrt_price,
+                upper_sqrt_price,
+                current_sqrt_price,
+            )
+            .unwrap();
+            let y = get_delta_y(lower_sqrt_price, current_sqrt_price, liquidity, true).unwrap();
+            let x = get_delta_x(current_sqrt_price, upper_sqrt_price, liquidity, true).unwrap();
+            assert!(y <= TokenAmount(10));
+            assert!(x <= amount);
+        }
+        // price on the lower bound
+        {
+            let liquidity = get_max_liquidity(
+                amount,
+                TokenAmount(0),
+                lower_sqrt_price,
+                upper_sqrt_price,
+                lower_sqrt_price,
+            )
+            .unwrap();
+            assert!(!liquidity.is_zero());
+        }
+        // max amounts on the widest range
+        {
+            let result = get_max_liquidity(
+                TokenAmount(u64::MAX),
+                TokenAmount(u64::MAX),
+                Price::new(MIN_SQRT_PRICE),
+                Price::new(MAX_SQRT_PRICE),
+                Price::from_integer(1),
+            );
+            assert!(result.is_ok());
+        }
+    }
+
+    #[test]
+    fn test_token_decimals_extremes() {
+        let lower_sqrt_price = calculate_price_sqrt(-1000);
+        let upper_sqrt_price = calculate_price_sqrt(1000);
+        let current_sqrt_price = Price::from_integer(1);
+        // 0 decimals, a single whole token
+        {
+            let amount = TokenAmount(1);
+            let liquidity = get_max_liquidity(
+                amount,
+                TokenAmount(0),
+                lower_sqrt_price,
+                upper_sqrt_price,
+                calculate_price_sqrt(-2000),
+            )
+            .unwrap();
+            assert!(!liquidity.is_zero());
+            let x = get_delta_x(lower_sqrt_price, upper_sqrt_price, liquidity, true).unwrap();
+            assert!(x <= amount);
+
+            // swapping a single unit never pays out more than it is worth
+            let result = compute_swap_step(
+                current_sqrt_price,
+                lower_sqrt_price,
+                Liquidity::from_integer(1_000_000),
+                amount,
+                AmountSpec::In,
+                FixedPoint::from_scale(3, 3),
+            )
+            .unwrap();
+            assert!(result.amount_in + result.fee_amount <= amount);
+            assert!(result.amount_out <= amount);
+        }
+        // 18 decimals, amounts close to u64::MAX (~18.4 tokens)
+        {
+            let amount = TokenAmount(u64::MAX - 1);
+            let liquidity = get_max_liquidity(
+                amount,
+                amount,
+                lower_sqrt_price,
+                upper_sqrt_price,
+                current_sqrt_price,
+            )
+            .unwrap();
+            let x = get_delta_x(current_sqrt_price, upper_sqrt_price, liquidity, true).unwrap();
+            let y = get_delta_y(lower_sqrt_price, current_sqrt_price, liquidity, true).unwrap();
+            assert!(x <= amount);
+            assert!(y <= amount);
+
+            let result = compute_swap_step(
+                current_sqrt_price,
+                upper_sqrt_price,
+                liquidity,
+                amount,
+                AmountSpec::In,
+                FixedPoint::from_scale(3, 3),
+            )
+            .unwrap();
+            assert!(result.amount_in + result.fee_amount <= amount);
+        }
+        // 0 against 18 decimals at parity needs a price of 10^18 per unit,
+        // outside of the tick range, such pools can't be initialized at that price
+        {
+            assert!(Price::from_integer(1_000_000_000) > Price::new(MAX_SQRT_PRICE));
+        }
+    }
 }
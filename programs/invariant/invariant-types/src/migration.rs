@@ -0,0 +1,108 @@
+use core::convert::TryInto;
+
+use crate::decimals::*;
+use crate::log::get_tick_at_sqrt_price;
+use crate::math::{get_max_tick, get_min_tick};
+use crate::utils::{TrackableError, TrackableResult};
+use crate::{err, function, location, MAX_SQRT_PRICE, MIN_SQRT_PRICE};
+
+// Helpers for moving positions from other concentrated liquidity AMMs.
+// Orca Whirlpools and Raydium CLMM share the 1.0001 tick base with Invariant,
+// only tick spacing and sqrt price encoding (Q64.64) differ.
+
+// converts Q64.64 sqrt price into decimal one, rounded down
+pub fn sqrt_price_from_x64(sqrt_price_x64: u128) -> TrackableResult<Price> {
+    let sqrt_price = U256::from(sqrt_price_x64)
+        .checked_mul(Price::one())
+        .ok_or_else(|| err!(TrackableError::MUL))?
+        >> 64;
+    let sqrt_price = Price::new(
+        sqrt_price
+            .try_into()
+            .map_err(|_| err!(TrackableError::cast::<Price>().as_str()))?,
+    );
+
+    if sqrt_price < Price::new(MIN_SQRT_PRICE) || sqrt_price > Price::new(MAX_SQRT_PRICE) {
+        return Err(err!("sqrt price out of range"));
+    }
+    Ok(sqrt_price)
+}
+
+// nearest initializable tick below Q64.64 sqrt price
+pub fn tick_from_sqrt_price_x64(sqrt_price_x64: u128, tick_spacing: u16) -> TrackableResult<i32> {
+    let sqrt_price = sqrt_price_from_x64(sqrt_price_x64)?;
+    Ok(get_tick_at_sqrt_price(sqrt_price, tick_spacing))
+}
+
+// Maps external range onto ticks initializable with `tick_spacing`.
+// The range is widened, so it always covers the original one.
+pub fn map_external_range(
+    lower_tick: i32,
+    upper_tick: i32,
+    tick_spacing: u16,
+) -> TrackableResult<(i32, i32)> {
+    if lower_tick >= upper_tick {
+        return Err(err!("lower tick has to be below upper tick"));
+    }
+    let spacing = tick_spacing as i32;
+    let lower = lower_tick.div_euclid(spacing) * spacing;
+    let upper = -(-upper_tick).div_euclid(spacing) * spacing;
+
+    Ok((
+        lower.max(get_min_tick(tick_spacing)),
+        upper.min(get_max_tick(tick_spacing)),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::calculate_price_sqrt;
+
+    #[test]
+    fn test_sqrt_price_from_x64() {
+        // 1
+        {
+            let sqrt_price = sqrt_price_from_x64(1u128 << 64).unwrap();
+            assert_eq!(sqrt_price, Price::from_integer(1));
+        }
+        // 1.5
+        {
+            let sqrt_price = sqrt_price_from_x64(3u128 << 63).unwrap();
+            assert_eq!(sqrt_price, Price::from_scale(15, 1));
+        }
+        // out of range
+        {
+            assert!(sqrt_price_from_x64(0).is_err());
+            assert!(sqrt_price_from_x64(u128::MAX).is_err());
+        }
+    }
+
+    #[test]
+    fn test_tick_from_sqrt_price_x64() {
+        assert_eq!(tick_from_sqrt_price_x64(1u128 << 64, 1).unwrap(), 0);
+        // sqrt(1.0001 ^ 100) * 2^64 rounded down
+        let sqrt_price_x64 =
+            (U256::from(calculate_price_sqrt(100).get()) << 64) / U256::from(Price::one::<u128>());
+        let sqrt_price_x64 = sqrt_price_x64.as_u128() + 1;
+        assert_eq!(tick_from_sqrt_price_x64(sqrt_price_x64, 1).unwrap(), 100);
+        assert_eq!(tick_from_sqrt_price_x64(sqrt_price_x64, 64).unwrap(), 64);
+    }
+
+    #[test]
+    fn test_map_external_range() {
+        // already aligned
+        assert_eq!(map_external_range(-128, 64, 64).unwrap(), (-128, 64));
+        // widened outwards
+        assert_eq!(map_external_range(-100, 100, 64).unwrap(), (-128, 128));
+        assert_eq!(map_external_range(1, 2, 10).unwrap(), (0, 10));
+        assert_eq!(map_external_range(-2, -1, 10).unwrap(), (-10, 0));
+        // clamped to price space
+        assert_eq!(
+            map_external_range(-443636, 443636, 1).unwrap(),
+            (get_min_tick(1), get_max_tick(1))
+        );
+        // empty range
+        assert!(map_external_range(10, 10, 1).is_err());
+    }
+}
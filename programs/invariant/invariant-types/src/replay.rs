@@ -0,0 +1,386 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    decimals::*,
+    err, function, location, ok_or_mark_trace,
+    simulation::swap_over_ticks,
+    structs::{Pool, Tick},
+    trace,
+    utils::{TrackableError, TrackableResult},
+};
+
+// Ordered history of a pool as exported from its transactions, starting from a recorded state.
+// Decimals are given by their raw `v` values.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PoolHistory {
+    pub pool: PoolRecord,
+    #[serde(default)]
+    pub ticks: Vec<TickRecord>,
+    pub transactions: Vec<TransactionRecord>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PoolRecord {
+    pub tick_spacing: u16,
+    pub fee: u128,
+    pub sqrt_price: u128,
+    pub liquidity: u128,
+    pub current_tick_index: i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TickRecord {
+    pub index: i32,
+    pub sign: bool,
+    pub liquidity_change: u128,
+    pub liquidity_gross: u128,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransactionRecord {
+    pub signature: String,
+    pub instruction: InstructionRecord,
+    #[serde(default)]
+    pub expected: ExpectedState,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InstructionRecord {
+    Swap {
+        x_to_y: bool,
+        amount: u64,
+        by_amount_in: bool,
+        sqrt_price_limit: u128,
+    },
+    // opening, closing or resizing a position
+    ModifyPosition {
+        lower_tick_index: i32,
+        upper_tick_index: i32,
+        liquidity_delta: u128,
+        add: bool,
+    },
+}
+
+// State recorded after the transaction, fields left out are not checked
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExpectedState {
+    #[serde(default)]
+    pub sqrt_price: Option<u128>,
+    #[serde(default)]
+    pub liquidity: Option<u128>,
+    #[serde(default)]
+    pub current_tick_index: Option<i32>,
+    #[serde(default)]
+    pub amount_in: Option<u64>,
+    #[serde(default)]
+    pub amount_out: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Discrepancy {
+    pub transaction: usize,
+    pub signature: String,
+    pub field: String,
+    pub expected: String,
+    pub actual: String,
+}
+
+#[derive(Default)]
+struct Outcome {
+    amount_in: Option<u64>,
+    amount_out: Option<u64>,
+}
+
+// Replays the history through the simulation and lists every recorded value it doesn't
+// reproduce. After a transaction the state is set to the recorded one, so a single discrepancy
+// doesn't show up again in all the transactions following it.
+pub fn replay(history: &PoolHistory) -> TrackableResult<Vec<Discrepancy>> {
+    let mut pool = Pool {
+        tick_spacing: history.pool.tick_spacing,
+        fee: FixedPoint::new(history.pool.fee),
+        sqrt_price: Price::new(history.pool.sqrt_price),
+        liquidity: Liquidity::new(history.pool.liquidity),
+        current_tick_index: history.pool.current_tick_index,
+        ..Default::default()
+    };
+    let mut ticks: Vec<Tick> = history
+        .ticks
+        .iter()
+        .map(|tick| Tick {
+            index: tick.index,
+            sign: tick.sign,
+            liquidity_change: Liquidity::new(tick.liquidity_change),
+            liquidity_gross: Liquidity::new(tick.liquidity_gross),
+            ..Default::default()
+        })
+        .collect();
+
+    let mut discrepancies = vec![];
+    for (index, transaction) in history.transactions.iter().enumerate() {
+        let outcome = ok_or_mark_trace!(apply(&mut pool, &mut ticks, &transaction.instruction))
+            .map_err(|mut e| {
+                e.cause = format!("{} in {}", e.cause, transaction.signature);
+                e
+            })?;
+
+        let expected = transaction.expected;
+        let mut check = |field: &str, expected: Option<String>, actual: Option<String>| {
+            if let Some(expected) = expected {
+                let actual = actual.unwrap_or_else(|| "none".to_string());
+                if expected != actual {
+                    discrepancies.push(Discrepancy {
+                        transaction: index,
+                        signature: transaction.signature.clone(),
+                        field: field.to_string(),
+                        expected,
+                        actual,
+                    });
+                }
+            }
+        };
+        check(
+            "sqrt_price",
+            expected.sqrt_price.map(|v| v.to_string()),
+            Some({ pool.sqrt_price }.v.to_string()),
+        );
+        check(
+            "liquidity",
+            expected.liquidity.map(|v| v.to_string()),
+            Some({ pool.liquidity }.v.to_string()),
+        );
+        check(
+            "current_tick_index",
+            expected.current_tick_index.map(|v| v.to_string()),
+            Some({ pool.current_tick_index }.to_string()),
+        );
+        check(
+            "amount_in",
+            expected.amount_in.map(|v| v.to_string()),
+            outcome.amount_in.map(|v| v.to_string()),
+        );
+        check(
+            "amount_out",
+            expected.amount_out.map(|v| v.to_string()),
+            outcome.amount_out.map(|v| v.to_string()),
+        );
+
+        if let Some(sqrt_price) = expected.sqrt_price {
+            pool.sqrt_price = Price::new(sqrt_price);
+        }
+        if let Some(liquidity) = expected.liquidity {
+            pool.liquidity = Liquidity::new(liquidity);
+        }
+        if let Some(current_tick_index) = expected.current_tick_index {
+            pool.current_tick_index = current_tick_index;
+        }
+    }
+
+    Ok(discrepancies)
+}
+
+fn apply(
+    pool: &mut Pool,
+    ticks: &mut Vec<Tick>,
+    instruction: &InstructionRecord,
+) -> TrackableResult<Outcome> {
+    match *instruction {
+        InstructionRecord::Swap {
+            x_to_y,
+            amount,
+            by_amount_in,
+            sqrt_price_limit,
+        } => {
+            let result = ok_or_mark_trace!(swap_over_ticks(
+                pool,
+                ticks,
+                x_to_y,
+                TokenAmount(amount),
+                by_amount_in,
+                Price::new(sqrt_price_limit)
+            ))?;
+            pool.sqrt_price = result.sqrt_price;
+            pool.liquidity = result.liquidity;
+            pool.current_tick_index = result.current_tick_index;
+            Ok(Outcome {
+                amount_in: Some(result.amount_in.0),
+                amount_out: Some(result.amount_out.0),
+            })
+        }
+        InstructionRecord::ModifyPosition {
+            lower_tick_index,
+            upper_tick_index,
+            liquidity_delta,
+            add,
+        } => {
+            if lower_tick_index >= upper_tick_index {
+                return Err(err!("wrong position range"));
+            }
+            let delta = Liquidity::new(liquidity_delta);
+            // liquidity is added when crossing the lower tick upwards and removed at the upper
+            ok_or_mark_trace!(update_tick(ticks, lower_tick_index, delta, add, add))?;
+            ok_or_mark_trace!(update_tick(ticks, upper_tick_index, delta, add, !add))?;
+
+            let current = pool.current_tick_index;
+            if lower_tick_index <= current && current < upper_tick_index {
+                pool.liquidity = match add {
+                    true => pool.liquidity.checked_add(delta),
+                    false => pool.liquidity.checked_sub(delta),
+                }
+                .map_err(|e| err!(&e))?;
+            }
+            Ok(Outcome::default())
+        }
+    }
+}
+
+// Moves the net liquidity change of the tick by `delta` in the direction of `positive`,
+// creating the tick when missing and dropping it once no position uses it
+fn update_tick(
+    ticks: &mut Vec<Tick>,
+    index: i32,
+    delta: Liquidity,
+    add: bool,
+    positive: bool,
+) -> TrackableResult<()> {
+    let position = match ticks.iter().position(|tick| tick.index == index) {
+        Some(position) => position,
+        None if add => {
+            ticks.push(Tick {
+                index,
+                sign: true,
+                ..Default::default()
+            });
+            ticks.len() - 1
+        }
+        None => return Err(err!(&format!("tick {} is missing", index))),
+    };
+    let tick = &mut ticks[position];
+
+    let (sign, change) = (tick.sign, tick.liquidity_change);
+    let (sign, change) = match sign == positive {
+        true => (sign, change.checked_add(delta).map_err(|e| err!(&e))?),
+        false if change >= delta => (sign, change - delta),
+        false => (positive, delta - change),
+    };
+    let gross = match add {
+        true => tick.liquidity_gross.checked_add(delta),
+        false => tick.liquidity_gross.checked_sub(delta),
+    }
+    .map_err(|e| err!(&e))?;
+
+    tick.sign = sign;
+    tick.liquidity_change = change;
+    tick.liquidity_gross = gross;
+    if gross.is_zero() {
+        ticks.remove(position);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::calculate_price_sqrt;
+
+    fn history(transactions: Vec<TransactionRecord>) -> PoolHistory {
+        PoolHistory {
+            pool: PoolRecord {
+                tick_spacing: 10,
+                fee: FixedPoint::from_scale(3, 3).v,
+                sqrt_price: Price::from_integer(1).v,
+                liquidity: 0,
+                current_tick_index: 0,
+            },
+            ticks: vec![],
+            transactions,
+        }
+    }
+
+    fn transaction(instruction: InstructionRecord, expected: ExpectedState) -> TransactionRecord {
+        TransactionRecord {
+            signature: "sig".to_string(),
+            instruction,
+            expected,
+        }
+    }
+
+    #[test]
+    fn test_replay() {
+        let open = InstructionRecord::ModifyPosition {
+            lower_tick_index: -10,
+            upper_tick_index: 10,
+            liquidity_delta: Liquidity::from_integer(1_000_000).v,
+            add: true,
+        };
+        let swap = InstructionRecord::Swap {
+            x_to_y: true,
+            amount: 100,
+            by_amount_in: true,
+            sqrt_price_limit: calculate_price_sqrt(-20).v,
+        };
+        let close = InstructionRecord::ModifyPosition {
+            lower_tick_index: -10,
+            upper_tick_index: 10,
+            liquidity_delta: Liquidity::from_integer(1_000_000).v,
+            add: false,
+        };
+
+        // learn the outcome of the swap first
+        let mut pool = Pool {
+            tick_spacing: 10,
+            fee: FixedPoint::from_scale(3, 3),
+            sqrt_price: Price::from_integer(1),
+            ..Default::default()
+        };
+        let mut ticks = vec![];
+        apply(&mut pool, &mut ticks, &open).unwrap();
+        assert_eq!(ticks.len(), 2);
+        assert_eq!({ pool.liquidity }, Liquidity::from_integer(1_000_000));
+        let outcome = apply(&mut pool, &mut ticks, &swap).unwrap();
+        let swapped = ExpectedState {
+            sqrt_price: Some({ pool.sqrt_price }.v),
+            liquidity: Some({ pool.liquidity }.v),
+            current_tick_index: Some(pool.current_tick_index),
+            amount_in: Some(100),
+            amount_out: outcome.amount_out,
+        };
+        apply(&mut pool, &mut ticks, &close).unwrap();
+        assert!(ticks.is_empty());
+        assert!({ pool.liquidity }.is_zero());
+
+        // matching history
+        let opened = ExpectedState {
+            liquidity: Some(Liquidity::from_integer(1_000_000).v),
+            ..Default::default()
+        };
+        let closed = ExpectedState {
+            liquidity: Some(0),
+            ..Default::default()
+        };
+        let matching = history(vec![
+            transaction(open, opened),
+            transaction(swap, swapped),
+            transaction(close, closed),
+        ]);
+        assert_eq!(replay(&matching).unwrap(), vec![]);
+
+        // wrong recorded output is reported once, the price of the next swap isn't affected
+        let wrong = ExpectedState {
+            amount_out: Some(swapped.amount_out.unwrap() + 1),
+            ..swapped
+        };
+        let diverging = history(vec![
+            transaction(open, opened),
+            transaction(swap, wrong),
+            transaction(close, closed),
+        ]);
+        let discrepancies = replay(&diverging).unwrap();
+        assert_eq!(discrepancies.len(), 1);
+        assert_eq!(discrepancies[0].transaction, 1);
+        assert_eq!(discrepancies[0].field, "amount_out");
+
+        // closing a position that was never opened
+        assert!(replay(&history(vec![transaction(close, closed)])).is_err());
+    }
+}
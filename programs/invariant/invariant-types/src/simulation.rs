@@ -0,0 +1,1161 @@
+use std::convert::TryInto;
+
+use anchor_lang::prelude::Pubkey;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    args::{AmountSpec, Direction},
+    decimals::*,
+    err, function, location,
+    math::{
+        calculate_price_sqrt, compute_swap_step, get_delta_x, get_delta_y, get_max_sqrt_price,
+        get_max_tick, get_min_sqrt_price, get_min_tick, is_enough_amount_to_push_price,
+    },
+    ok_or_mark_trace,
+    structs::{Pool, Tick, Tickmap, MAX_TICK},
+    trace,
+    utils::{TrackableError, TrackableResult},
+};
+
+const BPS_DENOMINATOR: u64 = 10_000;
+const SECONDS_PER_YEAR: u64 = 365 * 24 * 60 * 60;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TicksSwapResult {
+    pub amount_in: TokenAmount,
+    pub amount_out: TokenAmount,
+    pub fee: TokenAmount,
+    pub sqrt_price: Price,
+    pub ticks_crossed: u32,
+    pub remaining: TokenAmount,
+    // state to continue the simulation from
+    pub liquidity: Liquidity,
+    pub current_tick_index: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LiquidityBucket {
+    pub lower_tick: i32,
+    pub upper_tick: i32,
+    pub lower_sqrt_price: u128,
+    pub upper_sqrt_price: u128,
+    // active liquidity averaged over ticks of the bucket
+    pub liquidity: u128,
+}
+
+// Active liquidity between consecutive initialized ticks, as (from_tick, liquidity)
+// sorted by tick. Liquidity below the lowest and above the highest tick is zero.
+fn liquidity_segments(pool: &Pool, ticks: &[Tick]) -> Vec<(i32, Liquidity)> {
+    let mut ticks: Vec<(i32, bool, Liquidity)> = ticks
+        .iter()
+        .map(|tick| ({ tick.index }, tick.sign, { tick.liquidity_change }))
+        .collect();
+    ticks.sort_by_key(|(index, _, _)| *index);
+    ticks.dedup_by_key(|(index, _, _)| *index);
+
+    // segment containing current tick starts at the last tick not above it
+    let current = ticks
+        .iter()
+        .rposition(|(index, _, _)| *index <= pool.current_tick_index);
+
+    let mut segments = vec![Liquidity::new(0); ticks.len()];
+    let mut liquidity = pool.liquidity;
+    if let Some(current) = current {
+        segments[current] = liquidity;
+        // going down subtracts what was added when crossing upwards
+        for i in (0..current).rev() {
+            let (_, sign, change) = ticks[i + 1];
+            liquidity = match sign {
+                true => liquidity.checked_sub(change),
+                false => liquidity.checked_add(change),
+            }
+            .unwrap_or_else(|_| Liquidity::new(0));
+            segments[i] = liquidity;
+        }
+    }
+    let mut liquidity = pool.liquidity;
+    let start = current.map_or(0, |current| current + 1);
+    for i in start..ticks.len() {
+        let (_, sign, change) = ticks[i];
+        liquidity = match sign {
+            true => liquidity.checked_add(change),
+            false => liquidity.checked_sub(change),
+        }
+        .unwrap_or_else(|_| Liquidity::new(0));
+        segments[i] = liquidity;
+    }
+
+    ticks
+        .iter()
+        .zip(segments)
+        .map(|((index, _, _), liquidity)| (*index, liquidity))
+        .collect()
+}
+
+// Aggregates liquidity of the pool into buckets of `bucket_size` ticks aligned to multiples
+// of the bucket size, covering range between the lowest and highest initialized tick
+pub fn liquidity_profile(pool: &Pool, ticks: &[Tick], bucket_size: u32) -> Vec<LiquidityBucket> {
+    assert!(bucket_size > 0, "bucket size cannot be zero");
+    let segments = liquidity_segments(pool, ticks);
+    let (lowest, highest) = match (segments.first(), segments.last()) {
+        (Some((lowest, _)), Some((highest, _))) if lowest < highest => (*lowest, *highest),
+        _ => return vec![],
+    };
+
+    let bucket_size = bucket_size as i64;
+    let first_bucket = (lowest as i64).div_euclid(bucket_size) * bucket_size;
+
+    let mut buckets = vec![];
+    let mut segment = 0;
+    let mut lower = first_bucket;
+    while lower < highest as i64 {
+        let upper = lower + bucket_size;
+        let mut weighted = U256::from(0);
+
+        while segment < segments.len() {
+            let from = segments[segment].0 as i64;
+            let to = match segments.get(segment + 1) {
+                Some((next, _)) => *next as i64,
+                None => break,
+            };
+            let overlap = to.min(upper) - from.max(lower);
+            if overlap > 0 {
+                weighted += U256::from(segments[segment].1.v) * U256::from(overlap);
+            }
+            if to > upper {
+                break;
+            }
+            segment += 1;
+        }
+
+        let lower_tick = lower.max(-MAX_TICK as i64) as i32;
+        let upper_tick = upper.min(MAX_TICK as i64) as i32;
+        buckets.push(LiquidityBucket {
+            lower_tick,
+            upper_tick,
+            lower_sqrt_price: calculate_price_sqrt(lower_tick).v,
+            upper_sqrt_price: calculate_price_sqrt(upper_tick).v,
+            liquidity: (weighted / U256::from(bucket_size))
+                .try_into()
+                .unwrap_or(u128::MAX),
+        });
+        lower = upper;
+    }
+
+    buckets
+}
+
+// Replays the on-chain swap loop over a snapshot of initialized ticks,
+// stopping at `sqrt_price_limit` with the rest of the amount left in `remaining`
+pub(crate) fn swap_over_ticks(
+    pool: &Pool,
+    ticks: &[Tick],
+    x_to_y: bool,
+    amount: TokenAmount,
+    by_amount_in: bool,
+    sqrt_price_limit: Price,
+) -> TrackableResult<TicksSwapResult> {
+    let mut ticks: Vec<&Tick> = ticks.iter().collect();
+    ticks.sort_by_key(|tick| tick.index);
+
+    let tick_spacing = pool.tick_spacing;
+    let fee = pool.fee;
+    let mut sqrt_price = pool.sqrt_price;
+    let mut liquidity = pool.liquidity;
+    let mut current_tick_index = pool.current_tick_index;
+    let mut remaining = amount;
+    let mut result = TicksSwapResult {
+        amount_in: TokenAmount(0),
+        amount_out: TokenAmount(0),
+        fee: TokenAmount(0),
+        sqrt_price,
+        ticks_crossed: 0,
+        remaining: TokenAmount(0),
+        liquidity,
+        current_tick_index,
+    };
+
+    while !remaining.is_zero() {
+        // current tick is below the price, so it is crossed first when going down
+        let next_tick = match x_to_y {
+            true => ticks
+                .iter()
+                .rev()
+                .find(|tick| tick.index <= current_tick_index),
+            false => ticks.iter().find(|tick| tick.index > current_tick_index),
+        };
+        let (target, next_tick) = match next_tick {
+            Some(tick) => {
+                let price = calculate_price_sqrt(tick.index);
+                match (x_to_y && price > sqrt_price_limit) || (!x_to_y && price < sqrt_price_limit)
+                {
+                    true => (price, Some(tick)),
+                    false => (sqrt_price_limit, None),
+                }
+            }
+            None => (sqrt_price_limit, None),
+        };
+        if target == sqrt_price {
+            // end of the price range, partial fill
+            break;
+        }
+
+        let step = ok_or_mark_trace!(compute_swap_step(
+            sqrt_price,
+            target,
+            liquidity,
+            remaining,
+            AmountSpec::from(by_amount_in),
+            fee
+        ))?;
+        remaining = match by_amount_in {
+            true => remaining
+                .checked_sub(step.amount_in + step.fee_amount)
+                .map_err(|e| err!(&e))?,
+            false => remaining
+                .checked_sub(step.amount_out)
+                .map_err(|e| err!(&e))?,
+        };
+        result.amount_in += step.amount_in + step.fee_amount;
+        result.amount_out += step.amount_out;
+        result.fee += step.fee_amount;
+        sqrt_price = step.next_price_sqrt;
+
+        match next_tick {
+            Some(tick) if sqrt_price == target => {
+                let is_enough_amount_to_cross = ok_or_mark_trace!(is_enough_amount_to_push_price(
+                    remaining,
+                    sqrt_price,
+                    liquidity,
+                    fee,
+                    AmountSpec::from(by_amount_in),
+                    Direction::from(x_to_y)
+                ))?;
+                if !x_to_y || is_enough_amount_to_cross {
+                    liquidity = match (x_to_y, tick.sign) {
+                        (true, true) | (false, false) => {
+                            liquidity.checked_sub(tick.liquidity_change)
+                        }
+                        (true, false) | (false, true) => {
+                            liquidity.checked_add(tick.liquidity_change)
+                        }
+                    }
+                    .map_err(|e| err!(&e))?;
+                    result.ticks_crossed += 1;
+                } else if !remaining.is_zero() {
+                    // remainder too small to move the price is taken as a fee
+                    if by_amount_in {
+                        result.amount_in += remaining;
+                        result.fee += remaining;
+                    }
+                    remaining = TokenAmount(0);
+                }
+                current_tick_index = match x_to_y && is_enough_amount_to_cross {
+                    true => tick.index - tick_spacing as i32,
+                    false => tick.index,
+                };
+            }
+            None if sqrt_price == target => break,
+            _ => {}
+        }
+    }
+
+    result.sqrt_price = sqrt_price;
+    result.remaining = remaining;
+    result.liquidity = liquidity;
+    result.current_tick_index = current_tick_index;
+    Ok(result)
+}
+
+// Change of the spot price (not the sqrt) in basis points, rounded up
+fn price_change_bps(sqrt_price_before: Price, sqrt_price_after: Price) -> u64 {
+    let before = U256::from(sqrt_price_before.v) * U256::from(sqrt_price_before.v);
+    let after = U256::from(sqrt_price_after.v) * U256::from(sqrt_price_after.v);
+    if before.is_zero() {
+        return 0;
+    }
+    let denominator = U256::from(BPS_DENOMINATOR);
+    let bps = if after <= before {
+        // 10000 - floor(after * 10000 / before)
+        denominator - after * denominator / before
+    } else {
+        // ceil(after * 10000 / before) - 10000
+        (after * denominator + before - U256::from(1)) / before - denominator
+    };
+    bps.try_into().unwrap_or(u64::MAX)
+}
+
+// Returns (expected_out, price_impact_bps, ticks_crossed) for swapping `amount_in`
+// in given direction, where price impact is the move of the pool spot price.
+// Trades exceeding available liquidity are filled partially.
+pub fn estimate_price_impact(
+    pool: &Pool,
+    ticks: &[Tick],
+    amount_in: TokenAmount,
+    x_to_y: bool,
+) -> TrackableResult<(TokenAmount, u64, u32)> {
+    let sqrt_price_limit = match x_to_y {
+        true => get_min_sqrt_price(pool.tick_spacing),
+        false => get_max_sqrt_price(pool.tick_spacing),
+    };
+    let result = ok_or_mark_trace!(swap_over_ticks(
+        pool,
+        ticks,
+        x_to_y,
+        amount_in,
+        true,
+        sqrt_price_limit
+    ))?;
+
+    Ok((
+        result.amount_out,
+        price_change_bps(pool.sqrt_price, result.sqrt_price),
+        result.ticks_crossed,
+    ))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SwapSimulation {
+    pub amount_in: TokenAmount, // fee included
+    pub amount_out: TokenAmount,
+    pub fee: TokenAmount,
+    pub price_impact_bps: u64,
+    pub ticks_crossed: u32,
+    pub sqrt_price_after: Price,
+    pub remaining: TokenAmount, // part of the amount left when the price limit was reached
+}
+
+// Initialized ticks between the current price and `sqrt_price_limit`, in the order a swap
+// crosses them. The swap searches the tickmap again from every tick it reaches, so the path
+// is not bounded by the search range.
+pub fn ticks_on_path(
+    pool: &Pool,
+    tickmap: &Tickmap,
+    x_to_y: bool,
+    sqrt_price_limit: Price,
+) -> Vec<i32> {
+    let tick_spacing = pool.tick_spacing;
+    let mut ticks = vec![];
+    let mut current = pool.current_tick_index;
+
+    loop {
+        let next = match x_to_y {
+            true => tickmap.prev_initialized_tick(current, tick_spacing),
+            false => tickmap.next_initialized_tick(current, tick_spacing),
+        };
+        let index = match next {
+            Some(index) => index,
+            None => break,
+        };
+        let price = calculate_price_sqrt(index);
+        let beyond_limit = match x_to_y {
+            true => price <= sqrt_price_limit,
+            false => price >= sqrt_price_limit,
+        };
+        if beyond_limit {
+            break;
+        }
+        ticks.push(index);
+
+        current = match x_to_y {
+            true => index - tick_spacing as i32,
+            false => index,
+        };
+    }
+    ticks
+}
+
+// Expected result of the swap instruction called with given arguments. `ticks` have to
+// contain every initialized tick on the path to the price limit, others are ignored.
+pub fn simulate_swap(
+    pool: &Pool,
+    tickmap: &Tickmap,
+    ticks: &[Tick],
+    direction: Direction,
+    amount: TokenAmount,
+    amount_spec: AmountSpec,
+    sqrt_price_limit: Price,
+) -> TrackableResult<SwapSimulation> {
+    let (x_to_y, by_amount_in) = (direction.is_x_to_y(), amount_spec.is_in());
+    if amount.is_zero() {
+        return Err(err!("zero amount"));
+    }
+    let valid_limit = match x_to_y {
+        true => {
+            sqrt_price_limit < { pool.sqrt_price }
+                && sqrt_price_limit >= get_min_sqrt_price(pool.tick_spacing)
+        }
+        false => {
+            sqrt_price_limit > { pool.sqrt_price }
+                && sqrt_price_limit <= get_max_sqrt_price(pool.tick_spacing)
+        }
+    };
+    if !valid_limit {
+        return Err(err!("wrong price limit"));
+    }
+
+    let indexes = ticks_on_path(pool, tickmap, x_to_y, sqrt_price_limit);
+    let path = indexes
+        .iter()
+        .map(|index| {
+            ticks
+                .iter()
+                .find(|tick| tick.index == *index)
+                .copied()
+                .ok_or_else(|| err!(&format!("tick {} is missing", index)))
+        })
+        .collect::<TrackableResult<Vec<Tick>>>()?;
+
+    let result = ok_or_mark_trace!(swap_over_ticks(
+        pool,
+        &path,
+        x_to_y,
+        amount,
+        by_amount_in,
+        sqrt_price_limit
+    ))?;
+
+    Ok(SwapSimulation {
+        amount_in: result.amount_in,
+        amount_out: result.amount_out,
+        fee: result.fee,
+        price_impact_bps: price_change_bps(pool.sqrt_price, result.sqrt_price),
+        ticks_crossed: result.ticks_crossed,
+        sqrt_price_after: result.sqrt_price,
+        remaining: result.remaining,
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PositionRange {
+    pub lower_tick_index: i32,
+    pub upper_tick_index: i32,
+    pub liquidity: Liquidity,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeChange {
+    // positive amounts have to be deposited, negative are returned to the owner
+    pub delta_x: i128,
+    pub delta_y: i128,
+    // fees of the pool accrued inside the new range over its lifetime, annualized
+    // and divided by the value of the position in token y, zero when out of range
+    pub fee_apr_bps: u64,
+}
+
+// x * price + y, scaled by Price::one()
+fn value_in_y(sqrt_price: Price, x: U256, y: U256) -> Option<U256> {
+    let price = U256::from(sqrt_price.v) * U256::from(sqrt_price.v) / U256::from(Price::ONE);
+    x.checked_mul(price)?
+        .checked_add(y.checked_mul(U256::from(Price::ONE))?)
+}
+
+// Tokens backing the liquidity in the range, rounded the same way as the program does
+// when depositing (`up`) or withdrawing
+fn amounts_in_range(
+    pool: &Pool,
+    lower_tick_index: i32,
+    upper_tick_index: i32,
+    liquidity: Liquidity,
+    up: bool,
+) -> TrackableResult<(TokenAmount, TokenAmount)> {
+    let lower_sqrt_price = calculate_price_sqrt(lower_tick_index);
+    let upper_sqrt_price = calculate_price_sqrt(upper_tick_index);
+    let (x, y) = if pool.current_tick_index < lower_tick_index {
+        (
+            get_delta_x(lower_sqrt_price, upper_sqrt_price, liquidity, up),
+            Some(TokenAmount(0)),
+        )
+    } else if pool.current_tick_index < upper_tick_index {
+        (
+            get_delta_x(pool.sqrt_price, upper_sqrt_price, liquidity, up),
+            get_delta_y(lower_sqrt_price, pool.sqrt_price, liquidity, up),
+        )
+    } else {
+        (
+            Some(TokenAmount(0)),
+            get_delta_y(lower_sqrt_price, upper_sqrt_price, liquidity, up),
+        )
+    };
+    match (x, y) {
+        (Some(x), Some(y)) => Ok((x, y)),
+        _ => Err(err!("token amount overflow")),
+    }
+}
+
+// Fee growth inside the range as the program computes it. Bounds without an initialized
+// tick have no history, so all fees of the pool on their side are counted as inside.
+fn fee_growth_inside(
+    pool: &Pool,
+    ticks: &[Tick],
+    lower_tick_index: i32,
+    upper_tick_index: i32,
+) -> (FeeGrowth, FeeGrowth) {
+    let outside = |index: i32| {
+        ticks
+            .iter()
+            .find(|tick| tick.index == index)
+            .map(|tick| ({ tick.fee_growth_outside_x }, { tick.fee_growth_outside_y }))
+            .unwrap_or((FeeGrowth::new(0), FeeGrowth::new(0)))
+    };
+    let global = (pool.fee_growth_global_x, pool.fee_growth_global_y);
+    let (lower_x, lower_y) = outside(lower_tick_index);
+    let (upper_x, upper_y) = outside(upper_tick_index);
+
+    let (below_x, below_y) = match pool.current_tick_index >= lower_tick_index {
+        true => (lower_x, lower_y),
+        false => (
+            global.0.unchecked_sub(lower_x),
+            global.1.unchecked_sub(lower_y),
+        ),
+    };
+    let (above_x, above_y) = match pool.current_tick_index < upper_tick_index {
+        true => (upper_x, upper_y),
+        false => (
+            global.0.unchecked_sub(upper_x),
+            global.1.unchecked_sub(upper_y),
+        ),
+    };
+    (
+        global.0.unchecked_sub(below_x).unchecked_sub(above_x),
+        global.1.unchecked_sub(below_y).unchecked_sub(above_y),
+    )
+}
+
+// Moves the position to [new_lower, new_upper) keeping its liquidity. Returns tokens
+// needed on top of the withdrawn ones and the fee APR of the new range, assuming the
+// pool keeps generating fees at its historical rate.
+pub fn simulate_range_change(
+    position: &PositionRange,
+    new_lower_tick_index: i32,
+    new_upper_tick_index: i32,
+    pool: &Pool,
+    ticks: &[Tick],
+) -> TrackableResult<RangeChange> {
+    let tick_spacing = pool.tick_spacing as i32;
+    if new_lower_tick_index >= new_upper_tick_index
+        || new_lower_tick_index < get_min_tick(pool.tick_spacing)
+        || new_upper_tick_index > get_max_tick(pool.tick_spacing)
+        || new_lower_tick_index % tick_spacing != 0
+        || new_upper_tick_index % tick_spacing != 0
+    {
+        return Err(err!("invalid tick range"));
+    }
+
+    let liquidity = position.liquidity;
+    let (withdrawn_x, withdrawn_y) = ok_or_mark_trace!(amounts_in_range(
+        pool,
+        position.lower_tick_index,
+        position.upper_tick_index,
+        liquidity,
+        false
+    ))?;
+    let (deposited_x, deposited_y) = ok_or_mark_trace!(amounts_in_range(
+        pool,
+        new_lower_tick_index,
+        new_upper_tick_index,
+        liquidity,
+        true
+    ))?;
+
+    let in_range = pool.current_tick_index >= new_lower_tick_index
+        && pool.current_tick_index < new_upper_tick_index;
+    let elapsed = { pool.last_timestamp }.saturating_sub(pool.start_timestamp);
+    let fee_apr_bps = if in_range && elapsed > 0 {
+        let (growth_x, growth_y) =
+            fee_growth_inside(pool, ticks, new_lower_tick_index, new_upper_tick_index);
+        let (fee_x, fee_y) = (growth_x.to_fee(liquidity), growth_y.to_fee(liquidity));
+
+        // fees are additionally scaled by FixedPoint::one()
+        let value = |x: u128, y: u128| value_in_y(pool.sqrt_price, U256::from(x), U256::from(y));
+        let position_value = value(deposited_x.get() as u128, deposited_y.get() as u128)
+            .ok_or_else(|| err!(TrackableError::MUL))?;
+        let fees_value = value(fee_x.get(), fee_y.get())
+            .and_then(|fees| fees.checked_mul(U256::from(SECONDS_PER_YEAR * BPS_DENOMINATOR)))
+            .ok_or_else(|| err!(TrackableError::MUL))?;
+        let denominator = position_value
+            .checked_mul(U256::from(FixedPoint::ONE))
+            .and_then(|value| value.checked_mul(U256::from(elapsed)))
+            .ok_or_else(|| err!(TrackableError::MUL))?;
+
+        match denominator.is_zero() {
+            true => 0,
+            false => (fees_value / denominator).try_into().unwrap_or(u64::MAX),
+        }
+    } else {
+        0
+    };
+
+    Ok(RangeChange {
+        delta_x: deposited_x.get() as i128 - withdrawn_x.get() as i128,
+        delta_y: deposited_y.get() as i128 - withdrawn_y.get() as i128,
+        fee_apr_bps,
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoolScore {
+    pub index: usize,
+    // fees in token y an LP depositing `amount` would earn over the horizon
+    pub expected_fees: TokenAmount,
+}
+
+// Fees a deposit worth `amount` of token y would earn in the pool over `horizon` seconds,
+// assuming fees keep accruing at the historical rate and are shared pro rata with the
+// current active liquidity. Liquidity is valued as 2 * L * sqrt_price, the worth of
+// a position at its center.
+fn expected_pool_fees(pool: &Pool, amount: TokenAmount, horizon: u64) -> TrackableResult<U256> {
+    let elapsed = { pool.last_timestamp }.saturating_sub(pool.start_timestamp);
+    if elapsed == 0 {
+        return Ok(U256::from(0));
+    }
+    let liquidity = pool.liquidity;
+    let (fee_x, fee_y) = (
+        pool.fee_growth_global_x.to_fee(liquidity),
+        pool.fee_growth_global_y.to_fee(liquidity),
+    );
+
+    // scaled by Price::one() and FixedPoint::one()
+    let fees = value_in_y(
+        pool.sqrt_price,
+        U256::from(fee_x.get()),
+        U256::from(fee_y.get()),
+    )
+    .ok_or_else(|| err!(TrackableError::MUL))?;
+    let fees = fees
+        .checked_mul(U256::from(horizon))
+        .ok_or_else(|| err!(TrackableError::MUL))?
+        / U256::from(elapsed)
+        / U256::from(FixedPoint::ONE);
+
+    // scaled by Price::one()
+    let depth = U256::from(liquidity.v) * U256::from(pool.sqrt_price.v) * U256::from(2)
+        / U256::from(Liquidity::ONE);
+    let deposit = U256::from(amount.get()) * U256::from(Price::ONE);
+    let total = depth + deposit;
+    if total.is_zero() {
+        return Ok(U256::from(0));
+    }
+    Ok(fees
+        .checked_mul(deposit)
+        .ok_or_else(|| err!(TrackableError::MUL))?
+        / total
+        / U256::from(Price::ONE))
+}
+
+// Recommends which of the pools of a single pair an LP depositing `amount` (valued in
+// token y) should enter, scoring fee tiers by their historical fee accumulators and depth
+pub fn best_pool_for_pair(
+    pools: &[Pool],
+    amount: TokenAmount,
+    horizon: u64,
+) -> TrackableResult<Option<PoolScore>> {
+    if let Some(first) = pools.first() {
+        if pools
+            .iter()
+            .any(|pool| pool.token_x != first.token_x || pool.token_y != first.token_y)
+        {
+            return Err(err!("pools of different pairs"));
+        }
+    }
+
+    let mut best: Option<PoolScore> = None;
+    for (index, pool) in pools.iter().enumerate() {
+        let fees = ok_or_mark_trace!(expected_pool_fees(pool, amount, horizon))?;
+        let expected_fees = TokenAmount(fees.try_into().unwrap_or(u64::MAX));
+        let better = match &best {
+            Some(best) => expected_fees > best.expected_fees,
+            None => true,
+        };
+        if better {
+            best = Some(PoolScore {
+                index,
+                expected_fees,
+            });
+        }
+    }
+    Ok(best)
+}
+
+// Staker incentive as stored in its account, `reward_mint` being the mint of its token account
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IncentiveState {
+    pub reward_mint: Pubkey,
+    pub total_reward_unclaimed: TokenAmount,
+    pub total_seconds_claimed: u64,
+    pub start_time: u64,
+    pub end_time: u64,
+    // part of the unclaimed reward earned before the last reward rate change
+    pub accrued_reward: TokenAmount,
+    pub accrued_seconds: u64,
+}
+
+// Projected yearly reward of the staked liquidity over its value in token y, in bps. The staker
+// pays `total_reward_unclaimed` over the unclaimed seconds, a stake earning its share of the pool
+// liquidity of every second, and the stakes are valued as the same share of the accounted
+// reserves. Only rewards in one of the tokens of the pool can be valued.
+pub fn incentive_apr(
+    incentive: &IncentiveState,
+    pool: &Pool,
+    total_staked_liquidity: Liquidity,
+) -> TrackableResult<u64> {
+    let reward_in_x = match incentive.reward_mint {
+        mint if mint == pool.token_x => true,
+        mint if mint == pool.token_y => false,
+        _ => return Err(err!("reward mint is not a token of the pool")),
+    };
+    // the reward accrued before the last rate change is not paid for the seconds to come
+    let reward_unclaimed = incentive.total_reward_unclaimed - incentive.accrued_reward;
+    let seconds_unclaimed = incentive
+        .end_time
+        .saturating_sub(incentive.start_time)
+        .saturating_sub(incentive.total_seconds_claimed)
+        .saturating_sub(incentive.accrued_seconds);
+    // stakes out of range earn nothing, so at most the pool liquidity is rewarded
+    let staked = std::cmp::min(total_staked_liquidity, pool.liquidity);
+    if seconds_unclaimed == 0 || staked.is_zero() {
+        return Ok(0);
+    }
+
+    let yearly_reward =
+        U256::from(reward_unclaimed.get()) * U256::from(SECONDS_PER_YEAR) * U256::from(staked.v)
+            / U256::from(seconds_unclaimed)
+            / U256::from(pool.liquidity.v);
+    let reward_value = match reward_in_x {
+        true => value_in_y(pool.sqrt_price, yearly_reward, U256::from(0)),
+        false => value_in_y(pool.sqrt_price, U256::from(0), yearly_reward),
+    }
+    .and_then(|value| value.checked_mul(U256::from(BPS_DENOMINATOR)))
+    .ok_or_else(|| err!(TrackableError::MUL))?;
+    let staked_value = value_in_y(
+        pool.sqrt_price,
+        U256::from(pool.reserve_x),
+        U256::from(pool.reserve_y),
+    )
+    .and_then(|value| value.checked_mul(U256::from(staked.v)))
+    .ok_or_else(|| err!(TrackableError::MUL))?
+        / U256::from(pool.liquidity.v);
+
+    Ok(match staked_value.is_zero() {
+        true => 0,
+        false => (reward_value / staked_value).try_into().unwrap_or(u64::MAX),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tick(index: i32, sign: bool, liquidity_change: u64) -> Tick {
+        Tick {
+            index,
+            sign,
+            liquidity_change: Liquidity::from_integer(liquidity_change),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_liquidity_profile() {
+        // two overlapping positions: [-20, 20) with 10 and [0, 40) with 5
+        let ticks = [
+            tick(40, false, 5),
+            tick(-20, true, 10),
+            tick(0, true, 5),
+            tick(20, false, 10),
+        ];
+        // price inside both positions
+        {
+            let pool = Pool {
+                current_tick_index: 10,
+                liquidity: Liquidity::from_integer(15),
+                ..Default::default()
+            };
+            let profile = liquidity_profile(&pool, &ticks, 20);
+            let liquidity: Vec<u128> = profile.iter().map(|b| b.liquidity).collect();
+            assert_eq!(
+                liquidity,
+                vec![
+                    Liquidity::from_integer(10).v,
+                    Liquidity::from_integer(15).v,
+                    Liquidity::from_integer(5).v
+                ]
+            );
+            assert_eq!(profile[0].lower_tick, -20);
+            assert_eq!(profile[2].upper_tick, 40);
+            assert_eq!(profile[1].lower_sqrt_price, calculate_price_sqrt(0).v);
+        }
+        // price below all positions, buckets not aligned to ticks are averaged
+        {
+            let pool = Pool {
+                current_tick_index: -100,
+                liquidity: Liquidity::new(0),
+                ..Default::default()
+            };
+            let profile = liquidity_profile(&pool, &ticks, 40);
+            let liquidity: Vec<u128> = profile.iter().map(|b| b.liquidity).collect();
+            // [-40, 0) -> 10 over half of the bucket, [0, 40) -> 15 and 5 over halves
+            assert_eq!(
+                liquidity,
+                vec![Liquidity::from_integer(5).v, Liquidity::from_integer(10).v]
+            );
+        }
+        // no ticks
+        {
+            let pool = Pool::default();
+            assert!(liquidity_profile(&pool, &[], 10).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_price_change_bps() {
+        let one = Price::from_integer(1);
+        assert_eq!(price_change_bps(one, one), 0);
+        // sqrt(0.99) = 0.994987...
+        assert_eq!(price_change_bps(one, Price::from_scale(994987, 6)), 101);
+        assert_eq!(price_change_bps(one, Price::from_scale(994988, 6)), 100);
+        // sqrt(1.01) = 1.004987...
+        assert_eq!(price_change_bps(one, Price::from_scale(1004988, 6)), 101);
+        assert_eq!(price_change_bps(one, Price::from_scale(1004987, 6)), 100);
+    }
+
+    #[test]
+    fn test_estimate_price_impact() {
+        let ticks = [tick(-10, true, 1_000_000), tick(10, false, 1_000_000)];
+        let pool = Pool {
+            current_tick_index: 0,
+            tick_spacing: 10,
+            sqrt_price: Price::from_integer(1),
+            liquidity: Liquidity::from_integer(1_000_000),
+            fee: FixedPoint::from_scale(3, 3),
+            ..Default::default()
+        };
+        // small trade stays inside the range
+        {
+            let (out, impact, crossed) =
+                estimate_price_impact(&pool, &ticks, TokenAmount(100), true).unwrap();
+            // ~100 in with 0.3% fee at price 1, price moves by ~2 * 100 / 1_000_000
+            assert_eq!(out, TokenAmount(98));
+            assert_eq!(impact, 2);
+            assert_eq!(crossed, 0);
+        }
+        // both directions are symmetric around price 1
+        {
+            let (out_x, impact_x, _) =
+                estimate_price_impact(&pool, &ticks, TokenAmount(100), true).unwrap();
+            let (out_y, impact_y, _) =
+                estimate_price_impact(&pool, &ticks, TokenAmount(100), false).unwrap();
+            assert_eq!(out_x, out_y);
+            assert_eq!(impact_x, impact_y);
+        }
+        // trade larger than the range crosses the tick and is filled partially
+        {
+            let (out, impact, crossed) =
+                estimate_price_impact(&pool, &ticks, TokenAmount(10_000), false).unwrap();
+            let range_out = crate::math::get_delta_x(
+                Price::from_integer(1),
+                calculate_price_sqrt(10),
+                Liquidity::from_integer(1_000_000),
+                false,
+            )
+            .unwrap();
+            assert_eq!(out, range_out);
+            assert_eq!(crossed, 1);
+            // whole price range was traversed after leaving liquidity
+            assert!(impact > 1_000_000);
+        }
+    }
+
+    #[test]
+    fn test_simulate_swap() {
+        let tick_spacing = 10;
+        let ticks = [
+            tick(-20, true, 1_000_000),
+            tick(-10, true, 1_000_000),
+            tick(10, false, 1_000_000),
+            tick(20, false, 1_000_000),
+        ];
+        let pool = Pool {
+            current_tick_index: 0,
+            tick_spacing,
+            sqrt_price: Price::from_integer(1),
+            liquidity: Liquidity::from_integer(2_000_000),
+            fee: FixedPoint::from_scale(3, 3),
+            ..Default::default()
+        };
+        let mut tickmap = Tickmap::default();
+        for tick in ticks.iter() {
+            tickmap.flip(true, tick.index, tick_spacing);
+        }
+        let min_limit = get_min_sqrt_price(tick_spacing);
+
+        // same as the swap loop over the ticks
+        {
+            let simulation = simulate_swap(
+                &pool,
+                &tickmap,
+                &ticks,
+                Direction::XtoY,
+                TokenAmount(100),
+                AmountSpec::In,
+                min_limit,
+            )
+            .unwrap();
+            let (out, impact, crossed) =
+                estimate_price_impact(&pool, &ticks, TokenAmount(100), true).unwrap();
+            assert_eq!(simulation.amount_out, out);
+            assert_eq!(simulation.price_impact_bps, impact);
+            assert_eq!(simulation.ticks_crossed, crossed);
+            assert_eq!(simulation.amount_in, TokenAmount(100));
+            assert!(!simulation.fee.is_zero());
+            assert!(simulation.remaining.is_zero());
+        }
+        // crossing both ticks below, stopped at the end of the liquidity
+        {
+            let simulation = simulate_swap(
+                &pool,
+                &tickmap,
+                &ticks,
+                Direction::XtoY,
+                TokenAmount(100_000),
+                AmountSpec::In,
+                min_limit,
+            )
+            .unwrap();
+            assert_eq!(simulation.ticks_crossed, 2);
+            assert!(!simulation.remaining.is_zero());
+        }
+        // exact output
+        {
+            let simulation = simulate_swap(
+                &pool,
+                &tickmap,
+                &ticks,
+                Direction::YtoX,
+                TokenAmount(100),
+                AmountSpec::Out,
+                get_max_sqrt_price(tick_spacing),
+            )
+            .unwrap();
+            assert_eq!(simulation.amount_out, TokenAmount(100));
+            assert!(simulation.amount_in > TokenAmount(100));
+        }
+        // ticks further than the search range of the tickmap are found too
+        {
+            let mut tickmap = tickmap;
+            tickmap.flip(true, -3000, tick_spacing);
+            assert_eq!(
+                ticks_on_path(&pool, &tickmap, true, min_limit),
+                vec![-10, -20, -3000]
+            );
+            assert_eq!(
+                ticks_on_path(&pool, &tickmap, true, calculate_price_sqrt(-20)),
+                vec![-10]
+            );
+        }
+        // tick on the path not passed
+        assert!(simulate_swap(
+            &pool,
+            &tickmap,
+            &ticks[1..],
+            Direction::XtoY,
+            TokenAmount(100),
+            AmountSpec::In,
+            min_limit
+        )
+        .is_err());
+        // limit on the wrong side of the price
+        assert!(simulate_swap(
+            &pool,
+            &tickmap,
+            &ticks,
+            Direction::XtoY,
+            TokenAmount(100),
+            AmountSpec::In,
+            Price::from_integer(2)
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_simulate_range_change() {
+        let pool = Pool {
+            current_tick_index: 0,
+            tick_spacing: 10,
+            sqrt_price: Price::from_integer(1),
+            liquidity: Liquidity::from_integer(1_000_000),
+            fee_growth_global_y: FeeGrowth::from_fee(
+                Liquidity::from_integer(1_000_000),
+                TokenAmount(100),
+            )
+            .unwrap(),
+            start_timestamp: 0,
+            last_timestamp: SECONDS_PER_YEAR,
+            ..Default::default()
+        };
+        let position = PositionRange {
+            lower_tick_index: -10,
+            upper_tick_index: 10,
+            liquidity: Liquidity::from_integer(1_000_000),
+        };
+
+        // same range only pays for rounding
+        let same = simulate_range_change(&position, -10, 10, &pool, &[]).unwrap();
+        assert!((0..=1).contains(&same.delta_x));
+        assert!((0..=1).contains(&same.delta_y));
+        // ~1000 tokens of value earned 100 in a year
+        assert!((990..=1010).contains(&same.fee_apr_bps));
+
+        // wider range needs more of both tokens and earns less per token
+        let wider = simulate_range_change(&position, -20, 20, &pool, &[]).unwrap();
+        assert!(wider.delta_x > 400 && wider.delta_y > 400);
+        assert!(wider.fee_apr_bps < same.fee_apr_bps);
+
+        // range above the price holds only token x
+        let above = simulate_range_change(&position, 10, 30, &pool, &[]).unwrap();
+        assert!(above.delta_x > 0);
+        assert!(above.delta_y < -400);
+        assert_eq!(above.fee_apr_bps, 0);
+
+        // fees accrued below the lower tick are not counted
+        let ticks = [Tick {
+            index: -10,
+            fee_growth_outside_y: pool.fee_growth_global_y,
+            ..Default::default()
+        }];
+        let result = simulate_range_change(&position, -10, 10, &pool, &ticks).unwrap();
+        assert_eq!(result.fee_apr_bps, 0);
+
+        // invalid ranges
+        assert!(simulate_range_change(&position, 10, -10, &pool, &[]).is_err());
+        assert!(simulate_range_change(&position, -15, 10, &pool, &[]).is_err());
+        assert!(simulate_range_change(&position, -10, 10 * MAX_TICK, &pool, &[]).is_err());
+    }
+
+    #[test]
+    fn test_incentive_apr() {
+        let pool = Pool {
+            token_x: Pubkey::new_unique(),
+            token_y: Pubkey::new_unique(),
+            sqrt_price: Price::from_integer(1),
+            liquidity: Liquidity::from_integer(1_000_000),
+            reserve_x: 500,
+            reserve_y: 500,
+            ..Default::default()
+        };
+        let incentive = IncentiveState {
+            reward_mint: pool.token_y,
+            total_reward_unclaimed: TokenAmount(100),
+            total_seconds_claimed: 0,
+            start_time: 0,
+            end_time: SECONDS_PER_YEAR,
+            accrued_reward: TokenAmount(0),
+            accrued_seconds: 0,
+        };
+
+        // 100 tokens a year over 1000 tokens of value, whatever part of the pool is staked
+        let full = incentive_apr(&incentive, &pool, pool.liquidity).unwrap();
+        assert_eq!(full, 1000);
+        let half = incentive_apr(&incentive, &pool, Liquidity::from_integer(500_000)).unwrap();
+        assert!((999..=1000).contains(&half));
+        // same price, so a reward in token x is worth the same
+        let reward_in_x = IncentiveState {
+            reward_mint: pool.token_x,
+            ..incentive
+        };
+        assert_eq!(
+            incentive_apr(&reward_in_x, &pool, pool.liquidity).unwrap(),
+            full
+        );
+        // half of the time already claimed doubles the rate of the rest
+        let claimed = IncentiveState {
+            total_seconds_claimed: SECONDS_PER_YEAR / 2,
+            ..incentive
+        };
+        assert_eq!(
+            incentive_apr(&claimed, &pool, pool.liquidity).unwrap(),
+            2 * full
+        );
+
+        // reward accrued before a rate change is left out
+        let rate_changed = IncentiveState {
+            total_reward_unclaimed: TokenAmount(150),
+            total_seconds_claimed: SECONDS_PER_YEAR / 4,
+            accrued_reward: TokenAmount(100),
+            accrued_seconds: SECONDS_PER_YEAR / 4,
+            ..incentive
+        };
+        assert_eq!(
+            incentive_apr(&rate_changed, &pool, pool.liquidity).unwrap(),
+            full
+        );
+
+        // nothing staked or nothing left to pay
+        assert_eq!(
+            incentive_apr(&incentive, &pool, Liquidity::new(0)).unwrap(),
+            0
+        );
+        let ended = IncentiveState {
+            total_seconds_claimed: SECONDS_PER_YEAR,
+            ..incentive
+        };
+        assert_eq!(incentive_apr(&ended, &pool, pool.liquidity).unwrap(), 0);
+        // reward the pool can't value
+        let foreign = IncentiveState {
+            reward_mint: Pubkey::new_unique(),
+            ..incentive
+        };
+        assert!(incentive_apr(&foreign, &pool, pool.liquidity).is_err());
+    }
+
+    #[test]
+    fn test_best_pool_for_pair() {
+        let pool = |fee_growth_y: u64, liquidity: u64| Pool {
+            sqrt_price: Price::from_integer(1),
+            liquidity: Liquidity::from_integer(liquidity),
+            fee_growth_global_y: FeeGrowth::from_fee(
+                Liquidity::from_integer(liquidity),
+                TokenAmount(fee_growth_y),
+            )
+            .unwrap(),
+            start_timestamp: 0,
+            last_timestamp: SECONDS_PER_YEAR,
+            ..Default::default()
+        };
+        // 1000 of fees a year with 2000 of depth, 1500 with 10000 of depth
+        let shallow = pool(1_000, 1_000);
+        let deep = pool(1_500, 5_000);
+
+        // small deposits earn more next to less liquidity
+        let best = best_pool_for_pair(&[deep, shallow], TokenAmount(100), SECONDS_PER_YEAR)
+            .unwrap()
+            .unwrap();
+        assert_eq!(best.index, 1);
+        // 1000 * 100 / 2100
+        assert_eq!(best.expected_fees, TokenAmount(47));
+
+        // large deposits dilute the shallow pool
+        let best = best_pool_for_pair(&[deep, shallow], TokenAmount(100_000), SECONDS_PER_YEAR)
+            .unwrap()
+            .unwrap();
+        assert_eq!(best.index, 0);
+
+        // pool without history earns nothing
+        let new = Pool {
+            start_timestamp: 10,
+            last_timestamp: 10,
+            ..shallow
+        };
+        let best = best_pool_for_pair(&[new], TokenAmount(100), SECONDS_PER_YEAR)
+            .unwrap()
+            .unwrap();
+        assert_eq!(best.expected_fees, TokenAmount(0));
+
+        assert_eq!(best_pool_for_pair(&[], TokenAmount(100), 1).unwrap(), None);
+        let other = Pool {
+            token_x: Pubkey::new_unique(),
+            ..deep
+        };
+        assert!(best_pool_for_pair(&[deep, other], TokenAmount(100), 1).is_err());
+    }
+}
@@ -0,0 +1,342 @@
+// Regression tests pinned to accounts downloaded from mainnet. Every directory in `fixtures`
+// is a single snapshot holding raw account data, as written by
+// `solana account <address> --output-file <file>`:
+//   pool    - the pool account
+//   ticks/  - every initialized tick account of the pool, file names don't matter
+//   swaps   - swaps executed on chain against exactly this state, one per line:
+//             `<x_to_y> <by_amount_in> <amount> <sqrt_price_limit> <amount_in> <amount_out>`
+//             with the limit as the raw value of `Price`, lines starting with `#` are skipped
+// Simulated swaps have to match the recorded results within 1 unit, so a refactor of the math
+// which moves any result fails here.
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anchor_lang::AnchorDeserialize;
+
+use crate::decimals::*;
+use crate::simulation::swap_over_ticks;
+use crate::structs::{Pool, Tick};
+use crate::utils::{TrackableError, TrackableResult};
+use crate::{err, function, location, ok_or_mark_trace, trace, ANCHOR_DISCRIMINATOR_SIZE};
+
+pub const FIXTURES_DIR: &str = "fixtures";
+// difference allowed between simulated and recorded amounts
+pub const SNAPSHOT_TOLERANCE: u64 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecordedSwap {
+    pub x_to_y: bool,
+    pub by_amount_in: bool,
+    pub amount: TokenAmount,
+    pub sqrt_price_limit: Price,
+    pub amount_in: TokenAmount,
+    pub amount_out: TokenAmount,
+}
+
+pub struct Snapshot {
+    pub name: String,
+    pub pool: Pool,
+    pub ticks: Vec<Tick>,
+    pub swaps: Vec<RecordedSwap>,
+}
+
+pub fn fixtures_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(FIXTURES_DIR)
+}
+
+fn read(path: &Path) -> TrackableResult<Vec<u8>> {
+    fs::read(path).map_err(|e| err!(&format!("{}: {}", path.display(), e)))
+}
+
+fn deserialize<T: AnchorDeserialize>(path: &Path) -> TrackableResult<T> {
+    let data = ok_or_mark_trace!(read(path))?;
+    if data.len() < ANCHOR_DISCRIMINATOR_SIZE {
+        return Err(err!(&format!("{}: account data too short", path.display())));
+    }
+    T::deserialize(&mut &data[ANCHOR_DISCRIMINATOR_SIZE..])
+        .map_err(|e| err!(&format!("{}: {}", path.display(), e)))
+}
+
+fn parse_swap(line: &str) -> TrackableResult<RecordedSwap> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    if fields.len() != 6 {
+        return Err(err!(&format!("expected 6 fields in `{}`", line)));
+    }
+    let flag = |field: &str| {
+        field
+            .parse::<bool>()
+            .map_err(|_| err!(&format!("invalid flag `{}`", field)))
+    };
+    let number = |field: &str| {
+        field
+            .parse::<u128>()
+            .map_err(|_| err!(&format!("invalid number `{}`", field)))
+    };
+    let amount = |field: &str| {
+        field
+            .parse::<u64>()
+            .map(TokenAmount)
+            .map_err(|_| err!(&format!("invalid amount `{}`", field)))
+    };
+
+    Ok(RecordedSwap {
+        x_to_y: flag(fields[0])?,
+        by_amount_in: flag(fields[1])?,
+        amount: amount(fields[2])?,
+        sqrt_price_limit: Price::new(number(fields[3])?),
+        amount_in: amount(fields[4])?,
+        amount_out: amount(fields[5])?,
+    })
+}
+
+pub fn load_snapshot(dir: &Path) -> TrackableResult<Snapshot> {
+    let pool = ok_or_mark_trace!(deserialize::<Pool>(&dir.join("pool")))?;
+
+    let mut ticks = vec![];
+    let ticks_dir = dir.join("ticks");
+    if ticks_dir.exists() {
+        let entries =
+            fs::read_dir(&ticks_dir).map_err(|e| err!(&format!("{}: {}", dir.display(), e)))?;
+        for entry in entries {
+            let path = entry.map_err(|e| err!(&e.to_string()))?.path();
+            ticks.push(ok_or_mark_trace!(deserialize::<Tick>(&path))?);
+        }
+    }
+
+    let swaps_data = ok_or_mark_trace!(read(&dir.join("swaps")))?;
+    let swaps = String::from_utf8_lossy(&swaps_data)
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(parse_swap)
+        .collect::<TrackableResult<Vec<_>>>();
+
+    Ok(Snapshot {
+        name: dir
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+        pool,
+        ticks,
+        swaps: ok_or_mark_trace!(swaps)?,
+    })
+}
+
+// Snapshots sorted by name, none when the fixtures were not downloaded
+pub fn load_snapshots(path: &Path) -> TrackableResult<Vec<Snapshot>> {
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    let mut dirs = vec![];
+    for entry in fs::read_dir(path).map_err(|e| err!(&e.to_string()))? {
+        let path = entry.map_err(|e| err!(&e.to_string()))?.path();
+        if path.is_dir() {
+            dirs.push(path);
+        }
+    }
+    dirs.sort();
+    dirs.iter()
+        .map(|dir| ok_or_mark_trace!(load_snapshot(dir)))
+        .collect()
+}
+
+// Replays recorded swaps, returning a description of every one off by more than the tolerance
+pub fn diff_snapshot(snapshot: &Snapshot) -> TrackableResult<Vec<String>> {
+    let mut mismatches = vec![];
+    for (i, swap) in snapshot.swaps.iter().enumerate() {
+        let result = ok_or_mark_trace!(swap_over_ticks(
+            &snapshot.pool,
+            &snapshot.ticks,
+            swap.x_to_y,
+            swap.amount,
+            swap.by_amount_in,
+            swap.sqrt_price_limit,
+        ))?;
+        let amount_in_diff =
+            result.amount_in.0.max(swap.amount_in.0) - result.amount_in.0.min(swap.amount_in.0);
+        let amount_out_diff =
+            result.amount_out.0.max(swap.amount_out.0) - result.amount_out.0.min(swap.amount_out.0);
+
+        if amount_in_diff > SNAPSHOT_TOLERANCE || amount_out_diff > SNAPSHOT_TOLERANCE {
+            mismatches.push(format!(
+                "{} swap {}: simulated in {} out {}, recorded in {} out {}",
+                snapshot.name,
+                i,
+                result.amount_in.0,
+                result.amount_out.0,
+                swap.amount_in.0,
+                swap.amount_out.0
+            ));
+        }
+    }
+    Ok(mismatches)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use anchor_lang::AnchorSerialize;
+
+    // fields in declaration order, as stored on chain
+    macro_rules! account_data {
+        ($account:expr, $($field:ident),*) => {{
+            let mut data = vec![0u8; ANCHOR_DISCRIMINATOR_SIZE];
+            $( { $account.$field }.serialize(&mut data).unwrap(); )*
+            data
+        }};
+    }
+
+    pub(crate) fn pool_data(pool: &Pool) -> Vec<u8> {
+        account_data!(
+            pool,
+            token_x,
+            token_y,
+            token_x_reserve,
+            token_y_reserve,
+            position_iterator,
+            tick_spacing,
+            fee,
+            protocol_fee,
+            liquidity,
+            sqrt_price,
+            current_tick_index,
+            tickmap,
+            fee_growth_global_x,
+            fee_growth_global_y,
+            fee_protocol_token_x,
+            fee_protocol_token_y,
+            seconds_per_liquidity_global,
+            start_timestamp,
+            last_timestamp,
+            fee_receiver,
+            oracle_address,
+            oracle_initialized,
+            bump,
+            fee_tier_fee,
+            pending_fee,
+            pending_fee_timestamp,
+            tick_ema,
+            tick_ema_half_life,
+            tick_ema_last_timestamp,
+            hook_program,
+            max_price_change_bps_per_tx,
+            withdrawal_only,
+            launch_end_slot,
+            referral_fee_bps,
+            reserve_x,
+            reserve_y,
+            flash_loan_fee_bps,
+            flash_loans_enabled,
+            large_swap_threshold_x,
+            large_swap_threshold_y
+        )
+    }
+
+    pub(crate) fn tick_data(tick: &Tick) -> Vec<u8> {
+        account_data!(
+            tick,
+            pool,
+            index,
+            sign,
+            liquidity_change,
+            liquidity_gross,
+            sqrt_price,
+            fee_growth_outside_x,
+            fee_growth_outside_y,
+            seconds_per_liquidity_outside,
+            seconds_outside,
+            bump,
+            limit_order_liquidity_below,
+            limit_order_liquidity_above,
+            limit_order_fills_below,
+            limit_order_fills_above,
+            limit_orders
+        )
+    }
+
+    #[test]
+    fn test_mainnet_snapshots() {
+        let snapshots = load_snapshots(&fixtures_path()).unwrap();
+        let mismatches: Vec<String> = snapshots
+            .iter()
+            .flat_map(|snapshot| diff_snapshot(snapshot).unwrap())
+            .collect();
+        assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+    }
+
+    #[test]
+    fn test_diff_snapshot() {
+        let dir = std::env::temp_dir().join(format!("invariant-snapshot-{}", std::process::id()));
+        fs::create_dir_all(dir.join("ticks")).unwrap();
+
+        let pool = Pool {
+            tick_spacing: 10,
+            current_tick_index: 0,
+            sqrt_price: Price::from_integer(1),
+            liquidity: Liquidity::from_integer(1_000_000),
+            fee: FixedPoint::from_scale(3, 3),
+            ..Default::default()
+        };
+        let mut ticks = vec![];
+        for &(index, sign) in [(-10, true), (10, false)].iter() {
+            let tick = Tick {
+                index,
+                sign,
+                liquidity_change: Liquidity::from_integer(1_000_000),
+                liquidity_gross: Liquidity::from_integer(1_000_000),
+                sqrt_price: crate::math::calculate_price_sqrt(index),
+                ..Default::default()
+            };
+            fs::write(dir.join("ticks").join(index.to_string()), tick_data(&tick)).unwrap();
+            ticks.push(tick);
+        }
+        fs::write(dir.join("pool"), pool_data(&pool)).unwrap();
+
+        // recorded amounts taken from the simulation, the second one is 2 units off
+        let limit = crate::math::calculate_price_sqrt(-10);
+        let simulated =
+            swap_over_ticks(&pool, &ticks, true, TokenAmount(100), true, limit).unwrap();
+        fs::write(
+            dir.join("swaps"),
+            format!(
+                "# x_to_y by_amount_in amount limit amount_in amount_out\n\
+                 true true 100 {limit} {amount_in} {amount_out}\n\
+                 true true 100 {limit} {amount_in} {off}\n",
+                limit = limit.v,
+                amount_in = simulated.amount_in.0,
+                amount_out = simulated.amount_out.0,
+                off = simulated.amount_out.0 + 2,
+            ),
+        )
+        .unwrap();
+
+        let snapshot = load_snapshot(&dir).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(snapshot.pool, pool);
+        assert_eq!(snapshot.ticks.len(), 2);
+        assert_eq!(snapshot.swaps.len(), 2);
+        assert_eq!(snapshot.swaps[0].sqrt_price_limit, limit);
+
+        let mismatches = diff_snapshot(&snapshot).unwrap();
+        assert_eq!(mismatches.len(), 1);
+        assert!(mismatches[0].contains("swap 1"));
+    }
+
+    #[test]
+    fn test_parse_swap() {
+        assert_eq!(
+            parse_swap("false true 10 1000000000000000000000000 10 9").unwrap(),
+            RecordedSwap {
+                x_to_y: false,
+                by_amount_in: true,
+                amount: TokenAmount(10),
+                sqrt_price_limit: Price::from_integer(1),
+                amount_in: TokenAmount(10),
+                amount_out: TokenAmount(9),
+            }
+        );
+        assert!(parse_swap("false true 10").is_err());
+        assert!(parse_swap("no true 10 1 10 9").is_err());
+    }
+}
@@ -1,9 +1,66 @@
 pub mod fee_tier;
+// The program is built against another anchor version, so instead of depending on it, its
+// account layouts are included from its source. They stay in the program, as its IDL is
+// generated from the structs found there.
+mod layout {
+    include!("../../../src/structs/layout.rs");
+}
+pub mod oracle;
 pub mod pool;
+pub mod position;
+pub mod position_list;
+pub mod price_feed;
+pub mod staker;
+pub mod state;
 pub mod tick;
 pub mod tickmap;
+pub mod versioned;
 
 pub use fee_tier::*;
+pub use oracle::*;
 pub use pool::*;
+pub use position::*;
+pub use position_list::*;
+pub use price_feed::*;
+pub use staker::*;
+pub use state::*;
 pub use tick::*;
 pub use tickmap::*;
+pub use versioned::*;
+
+// Deployed accounts keep their size, so a layout change has to come with a migration. The
+// staker accounts are still mirrored by hand in `staker`.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::prelude::Rent;
+    use std::mem::size_of;
+
+    #[test]
+    fn test_account_sizes() {
+        assert_eq!(size_of::<Pool>(), 532);
+        assert_eq!(size_of::<Position>(), 241);
+        assert_eq!(size_of::<Tick>(), 194);
+        assert_eq!(size_of::<FeeTier>(), 19);
+        assert_eq!(size_of::<Tickmap>(), 11091);
+        assert_eq!(size_of::<Oracle>(), 10246);
+        assert_eq!(size_of::<PriceFeed>(), 169);
+        assert_eq!(size_of::<PositionList>(), 5);
+        assert_eq!(size_of::<State>(), 99);
+        assert_eq!(size_of::<Incentive>(), 242);
+        assert_eq!(size_of::<UserStake>(), 107);
+    }
+
+    #[test]
+    fn test_rent_exempt_minimum() {
+        let rent = Rent::default();
+        assert_eq!(Pool::LEN, 8 + 532);
+        assert_eq!(Tickmap::LEN, 8 + 11091);
+        assert_eq!(
+            Pool::rent_exempt_minimum(&rent),
+            rent.minimum_balance(Pool::LEN)
+        );
+        // (128 bytes of account overhead + 107) * 3480 lamports per byte-year * 2 years
+        assert_eq!(State::rent_exempt_minimum(&rent), 1_635_600);
+    }
+}
@@ -0,0 +1,159 @@
+use core::convert::TryInto;
+
+pub use super::layout::{Oracle, Record};
+use crate::utils::{TrackableError, TrackableResult};
+use crate::{decimals::*, size};
+use crate::{err, function, location};
+
+pub const ORACLE_SIZE: usize = 256;
+
+impl Default for Oracle {
+    fn default() -> Self {
+        Oracle {
+            data: [Record::default(); ORACLE_SIZE],
+            head: 0,
+            amount: 0,
+            size: 0,
+        }
+    }
+}
+
+size!(Oracle);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Twap {
+    pub sqrt_price: Price,
+    pub liquidity: Liquidity,
+}
+
+impl Oracle {
+    // Records from the oldest to the newest
+    pub fn records(&self) -> Vec<Record> {
+        let size = self.size as usize;
+        let amount = self.amount as usize;
+        if size == 0 {
+            return vec![];
+        }
+        let oldest = (self.head as usize + size + 1 - amount) % size;
+        (0..amount)
+            .map(|i| self.data[(oldest + i) % size])
+            .collect()
+    }
+
+    // Time weighted averages over [start, end). Every record holds until the next one, the
+    // newest one until `end`. Fails when the window starts before the oldest record, as the
+    // state from before it is not known.
+    pub fn twap(&self, start: u64, end: u64) -> TrackableResult<Twap> {
+        if start >= end {
+            return Err(err!("empty time window"));
+        }
+        let records = self.records();
+        match records.first() {
+            Some(oldest) if { oldest.timestamp } <= start => {}
+            _ => return Err(err!("window starts before the oldest record")),
+        }
+
+        let mut price_sum = U256::from(0);
+        let mut liquidity_sum = U256::from(0);
+        for (i, record) in records.iter().enumerate() {
+            let from = { record.timestamp }.max(start);
+            let to = match records.get(i + 1) {
+                Some(next) => { next.timestamp }.min(end),
+                None => end,
+            };
+            if to <= from {
+                continue;
+            }
+            let duration = U256::from(to - from);
+            price_sum = price_sum
+                .checked_add(U256::from(record.sqrt_price.v) * duration)
+                .ok_or_else(|| err!(TrackableError::ADD))?;
+            liquidity_sum = liquidity_sum
+                .checked_add(U256::from(record.liquidity.v) * duration)
+                .ok_or_else(|| err!(TrackableError::ADD))?;
+        }
+
+        let window = U256::from(end - start);
+        Ok(Twap {
+            sqrt_price: Price::new(
+                (price_sum / window)
+                    .try_into()
+                    .map_err(|_| err!(TrackableError::cast::<Price>().as_str()))?,
+            ),
+            liquidity: Liquidity::new(
+                (liquidity_sum / window)
+                    .try_into()
+                    .map_err(|_| err!(TrackableError::cast::<Liquidity>().as_str()))?,
+            ),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn oracle(records: &[(u64, u128, u128)]) -> Oracle {
+        // same as the program: starts at the last slot, wraps after ORACLE_SIZE records
+        let mut oracle = Oracle {
+            head: ORACLE_SIZE as u16 - 1,
+            size: ORACLE_SIZE as u16,
+            ..Default::default()
+        };
+        for &(timestamp, sqrt_price, liquidity) in records {
+            oracle.head = (oracle.head + 1) % oracle.size;
+            oracle.data[oracle.head as usize] = Record {
+                timestamp,
+                sqrt_price: Price::new(sqrt_price),
+                liquidity: Liquidity::new(liquidity),
+            };
+            oracle.amount = (oracle.amount + 1).min(oracle.size);
+        }
+        oracle
+    }
+
+    #[test]
+    fn test_twap() {
+        let oracle = oracle(&[(10, 100, 1), (20, 200, 3), (40, 400, 5)]);
+
+        // within a single record
+        let twap = oracle.twap(10, 20).unwrap();
+        assert_eq!(twap.sqrt_price, Price::new(100));
+        assert_eq!(twap.liquidity, Liquidity::new(1));
+
+        // (100 * 10 + 200 * 20 + 400 * 10) / 40
+        let twap = oracle.twap(10, 50).unwrap();
+        assert_eq!(twap.sqrt_price, Price::new(225));
+        // (1 * 10 + 3 * 20 + 5 * 10) / 40
+        assert_eq!(twap.liquidity, Liquidity::new(3));
+
+        // window cut in the middle of records
+        let twap = oracle.twap(15, 25).unwrap();
+        assert_eq!(twap.sqrt_price, Price::new(150));
+
+        assert!(oracle.twap(5, 20).is_err());
+        assert!(oracle.twap(20, 20).is_err());
+        assert!(Oracle::default().twap(0, 1).is_err());
+    }
+
+    #[test]
+    fn test_twap_wrapped() {
+        let records: Vec<(u64, u128, u128)> = (0..ORACLE_SIZE as u64 + 10)
+            .map(|i| (i * 10, i as u128, 1))
+            .collect();
+        let oracle = oracle(&records);
+
+        let ordered = oracle.records();
+        assert_eq!(ordered.len(), ORACLE_SIZE);
+        assert_eq!({ ordered[0].timestamp }, 100);
+        assert_eq!(
+            { ordered[ORACLE_SIZE - 1].timestamp },
+            (ORACLE_SIZE as u64 + 9) * 10
+        );
+
+        // overwritten records are gone
+        assert!(oracle.twap(90, 200).is_err());
+        // (10 * 10 + 11 * 10) / 20 rounded down
+        assert_eq!(oracle.twap(100, 120).unwrap().sqrt_price, Price::new(10));
+    }
+}
@@ -1,33 +1,146 @@
-use anchor_lang::prelude::*;
+use core::convert::TryInto;
 
+pub use super::layout::Pool;
+use crate::utils::{TrackableError, TrackableResult};
 use crate::{decimals::*, size};
+use crate::{err, function, location};
+
+pub const TICK_EMA_DENOMINATOR: i64 = 1_000_000;
 
-#[account(zero_copy)]
-#[repr(packed)]
-#[derive(PartialEq, Default, Debug, AnchorDeserialize)]
-pub struct Pool {
-    pub token_x: Pubkey,
-    pub token_y: Pubkey,
-    pub token_x_reserve: Pubkey,
-    pub token_y_reserve: Pubkey,
-    pub position_iterator: u128,
-    pub tick_spacing: u16,
-    pub fee: FixedPoint,
-    pub protocol_fee: FixedPoint,
-    pub liquidity: Liquidity,
-    pub sqrt_price: Price,
-    pub current_tick_index: i32, // nearest tick below the current price
-    pub tickmap: Pubkey,
-    pub fee_growth_global_x: FeeGrowth,
-    pub fee_growth_global_y: FeeGrowth,
-    pub fee_protocol_token_x: u64, // should be changed to TokenAmount when Armani implements tuple structs
-    pub fee_protocol_token_y: u64,
-    pub seconds_per_liquidity_global: FixedPoint,
-    pub start_timestamp: u64,
-    pub last_timestamp: u64,
-    pub fee_receiver: Pubkey,
-    pub oracle_address: Pubkey,
-    pub oracle_initialized: bool,
-    pub bump: u8,
-}
 size!(Pool);
+
+impl Pool {
+    // average as of the last swap, rounded down same as current_tick_index
+    pub fn get_tick_ema(&self) -> i32 {
+        { self.tick_ema }.div_euclid(TICK_EMA_DENOMINATOR) as i32
+    }
+
+    // amount of token y for a single token x, rounded down
+    pub fn price_y_per_x(&self) -> TrackableResult<Price> {
+        let sqrt_price = U256::from({ self.sqrt_price }.get());
+        Ok(Price::new(
+            sqrt_price
+                .checked_mul(sqrt_price)
+                .ok_or_else(|| err!(TrackableError::MUL))?
+                .checked_div(Price::one())
+                .ok_or_else(|| err!(TrackableError::DIV))?
+                .try_into()
+                .map_err(|_| err!(TrackableError::cast::<Price>().as_str()))?,
+        ))
+    }
+
+    // amount of token x for a single token y, rounded down
+    pub fn price_x_per_y(&self) -> TrackableResult<Price> {
+        let sqrt_price = U256::from({ self.sqrt_price }.get());
+        let one = Price::one::<U256>();
+        Ok(Price::new(
+            one.checked_mul(one)
+                .ok_or_else(|| err!(TrackableError::MUL))?
+                .checked_mul(one)
+                .ok_or_else(|| err!(TrackableError::MUL))?
+                .checked_div(
+                    sqrt_price
+                        .checked_mul(sqrt_price)
+                        .ok_or_else(|| err!(TrackableError::MUL))?,
+                )
+                .ok_or_else(|| err!(TrackableError::DIV))?
+                .try_into()
+                .map_err(|_| err!(TrackableError::cast::<Price>().as_str()))?,
+        ))
+    }
+
+    // amounts `withdraw_protocol_fee` transfers to the fee receiver (or a withdrawer of the
+    // pool) when called now
+    pub fn claimable_protocol_fee(&self) -> (TokenAmount, TokenAmount) {
+        (
+            TokenAmount(self.fee_protocol_token_x),
+            TokenAmount(self.fee_protocol_token_y),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::calculate_price_sqrt;
+    use crate::{MAX_SQRT_PRICE, MIN_SQRT_PRICE};
+
+    #[test]
+    fn test_get_tick_ema() {
+        let pool = Pool {
+            tick_ema: 50 * TICK_EMA_DENOMINATOR + 1,
+            ..Default::default()
+        };
+        assert_eq!(pool.get_tick_ema(), 50);
+        let pool = Pool {
+            tick_ema: -3 * TICK_EMA_DENOMINATOR / 2,
+            ..Default::default()
+        };
+        assert_eq!(pool.get_tick_ema(), -2);
+    }
+
+    #[test]
+    fn test_claimable_protocol_fee() {
+        let pool = Pool {
+            fee_protocol_token_x: 10,
+            fee_protocol_token_y: 0,
+            ..Default::default()
+        };
+        assert_eq!(
+            pool.claimable_protocol_fee(),
+            (TokenAmount(10), TokenAmount(0))
+        );
+    }
+
+    #[test]
+    fn test_price_directions() {
+        // 1:1
+        {
+            let pool = Pool {
+                sqrt_price: Price::from_integer(1),
+                ..Default::default()
+            };
+            assert_eq!(pool.price_y_per_x().unwrap(), Price::from_integer(1));
+            assert_eq!(pool.price_x_per_y().unwrap(), Price::from_integer(1));
+        }
+        // 1 x = 4 y
+        {
+            let pool = Pool {
+                sqrt_price: Price::from_integer(2),
+                ..Default::default()
+            };
+            assert_eq!(pool.price_y_per_x().unwrap(), Price::from_integer(4));
+            assert_eq!(pool.price_x_per_y().unwrap(), Price::from_scale(25, 2));
+        }
+        // inverse of tick is the negated tick
+        {
+            let pool = Pool {
+                sqrt_price: calculate_price_sqrt(1000),
+                ..Default::default()
+            };
+            let inverse = Pool {
+                sqrt_price: calculate_price_sqrt(-1000),
+                ..Default::default()
+            };
+            // calculate_price_sqrt is precise up to 12 decimal places
+            let diff = |a: Price, b: Price| Price::new(a.get().abs_diff(b.get()));
+            let x_per_y = pool.price_x_per_y().unwrap();
+            let y_per_x = pool.price_y_per_x().unwrap();
+            assert!(diff(x_per_y, inverse.price_y_per_x().unwrap()) < Price::from_scale(1, 11));
+            assert!(diff(y_per_x, inverse.price_x_per_y().unwrap()) < Price::from_scale(1, 11));
+            // 1.0001 ^ 1000
+            assert!(diff(y_per_x, Price::from_scale(1_105165, 6)) < Price::from_scale(1, 6));
+        }
+        // bounds of the price space
+        {
+            for sqrt_price in [MIN_SQRT_PRICE, MAX_SQRT_PRICE] {
+                let pool = Pool {
+                    sqrt_price: Price::new(sqrt_price),
+                    ..Default::default()
+                };
+                assert!(pool.price_y_per_x().is_ok());
+                assert!(pool.price_x_per_y().is_ok());
+            }
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub use super::layout::Position;
+use crate::size;
+
+size!(Position);
@@ -0,0 +1,4 @@
+pub use super::layout::PositionList;
+use crate::size;
+
+size!(PositionList);
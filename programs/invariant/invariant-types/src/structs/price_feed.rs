@@ -0,0 +1,29 @@
+pub use super::layout::PriceFeed;
+use crate::size;
+
+size!(PriceFeed);
+
+impl PriceFeed {
+    // Consumers pick their own tolerance, a feed is only as fresh as its last crank
+    pub fn is_stale(&self, current_timestamp: u64, max_age: u64) -> bool {
+        current_timestamp.saturating_sub(self.publish_time) > max_age
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_stale() {
+        let feed = PriceFeed {
+            publish_time: 100,
+            ..Default::default()
+        };
+        assert!(!feed.is_stale(100, 0));
+        assert!(!feed.is_stale(160, 60));
+        assert!(feed.is_stale(161, 60));
+        // clock behind the feed
+        assert!(!feed.is_stale(50, 0));
+    }
+}
@@ -0,0 +1,49 @@
+use crate::size;
+use anchor_lang::prelude::*;
+
+// Accounts of the staker program. Its decimals are stored as their raw values.
+
+pub const MAX_EXTRA_REWARDS: usize = 2;
+
+#[zero_copy]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug, AnchorDeserialize)]
+pub struct ExtraReward {
+    pub token_account: Pubkey,
+    pub total_reward_unclaimed: u64,
+}
+
+#[account(zero_copy)]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug, AnchorDeserialize)]
+pub struct Incentive {
+    pub founder: Pubkey,
+    pub token_account: Pubkey,
+    pub total_reward_unclaimed: u64,
+    pub total_seconds_claimed: u64,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub end_claim_time: u64,
+    pub num_of_stakes: u64,
+    pub pool: Pubkey,
+    pub nonce: u8,
+    pub extra_rewards: [ExtraReward; MAX_EXTRA_REWARDS],
+    pub extra_rewards_count: u8,
+    pub accrued_reward: u64, // earned before the last reward rate change, part of the unclaimed one
+    pub accrued_seconds: u64,
+}
+size!(Incentive);
+
+#[account(zero_copy)]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug, AnchorDeserialize)]
+pub struct UserStake {
+    pub incentive: Pubkey,
+    pub position: Pubkey,
+    pub seconds_per_liquidity_initial: u128, // 12 decimals
+    pub liquidity: u128,                     // 6 decimals
+    pub bump: u8,
+    pub lock_end: u64,
+    pub boost: u16,
+}
+size!(UserStake);
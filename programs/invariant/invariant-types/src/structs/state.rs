@@ -0,0 +1,4 @@
+pub use super::layout::State;
+use crate::size;
+
+size!(State);
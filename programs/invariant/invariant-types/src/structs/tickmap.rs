@@ -1,15 +1,10 @@
 use std::convert::TryInto;
 
+pub use super::layout::Tickmap;
+use crate::errors::InvariantErrorCode;
 use crate::size;
 use anchor_lang::prelude::*;
 
-#[account(zero_copy)]
-#[repr(packed)]
-#[derive(AnchorDeserialize)]
-pub struct Tickmap {
-    pub bitmap: [u8; 11091], // Tick limit / 4
-}
-
 impl Default for Tickmap {
     fn default() -> Self {
         Tickmap { bitmap: [0; 11091] }
@@ -43,6 +38,24 @@ fn tick_to_position(tick: i32, tick_spacing: u16) -> (usize, u8) {
     (byte, bit)
 }
 
+// Same as tick_to_position for indexes coming from outside, which may not fit in the bitmap
+#[allow(clippy::result_large_err)]
+fn checked_tick_to_position(tick: i32, tick_spacing: u16) -> Result<(usize, u8)> {
+    require!(tick_spacing > 0, InvariantErrorCode::InvalidTickSpacing);
+    let tick_spacing = tick_spacing as i32;
+    require!(
+        tick % tick_spacing == 0
+            && (-MAX_TICK..=MAX_TICK).contains(&tick)
+            && (-TICK_LIMIT + 1..TICK_LIMIT).contains(&(tick / tick_spacing)),
+        InvariantErrorCode::InvalidTickIndex
+    );
+    Ok(tick_to_position(tick, tick_spacing as u16))
+}
+
+fn position_to_tick(bitmap_index: usize, tick_spacing: u16) -> i32 {
+    (bitmap_index as i32 - TICK_LIMIT) * tick_spacing as i32
+}
+
 // tick_spacing - spacing already scaled by tick_spacing
 pub fn get_search_limit(tick: i32, tick_spacing: u16, up: bool) -> i32 {
     let index = tick / tick_spacing as i32;
@@ -176,6 +189,48 @@ impl Tickmap {
         None
     }
 
+    // Unlike `next_initialized` these search the whole bitmap rather than TICK_SEARCH_RANGE,
+    // for off-chain use. Ticks don't have to be divisible by the spacing.
+    pub fn next_initialized_tick(&self, tick: i32, tick_spacing: u16) -> Option<i32> {
+        // first index above the tick
+        let index = tick.div_euclid(tick_spacing as i32) + 1 + TICK_LIMIT;
+        (index.max(0) as usize..self.bitmap.len() * 8)
+            .find(|index| self.is_set(*index))
+            .map(|index| position_to_tick(index, tick_spacing))
+    }
+
+    // Includes the tick itself, same as `prev_initialized`
+    pub fn prev_initialized_tick(&self, tick: i32, tick_spacing: u16) -> Option<i32> {
+        let index = tick.div_euclid(tick_spacing as i32) + TICK_LIMIT;
+        if index < 0 {
+            return None;
+        }
+        (0..=(index as usize).min(self.bitmap.len() * 8 - 1))
+            .rev()
+            .find(|index| self.is_set(*index))
+            .map(|index| position_to_tick(index, tick_spacing))
+    }
+
+    // All initialized ticks in ascending order
+    pub fn initialized_ticks(
+        &self,
+        tick_spacing: u16,
+    ) -> impl DoubleEndedIterator<Item = i32> + '_ {
+        self.bitmap
+            .iter()
+            .enumerate()
+            .filter(|(_, value)| **value != 0)
+            .flat_map(move |(byte, value)| {
+                (0..8)
+                    .filter(move |bit| (value >> bit) % 2 == 1)
+                    .map(move |bit| position_to_tick(byte * 8 + bit, tick_spacing))
+            })
+    }
+
+    fn is_set(&self, bitmap_index: usize) -> bool {
+        (self.bitmap[bitmap_index / 8] >> (bitmap_index % 8)) % 2 == 1
+    }
+
     pub fn get(&self, tick: i32, tick_spacing: u16) -> bool {
         let (byte, bit) = tick_to_position(tick, tick_spacing);
         let value = (self.bitmap[byte] >> bit) % 2;
@@ -193,6 +248,23 @@ impl Tickmap {
 
         self.bitmap[byte] ^= 1 << bit;
     }
+
+    #[allow(clippy::result_large_err)]
+    pub fn try_get(&self, tick: i32, tick_spacing: u16) -> Result<bool> {
+        let (byte, bit) = checked_tick_to_position(tick, tick_spacing)?;
+        Ok((self.bitmap[byte] >> bit) % 2 == 1)
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn try_flip(&mut self, value: bool, tick: i32, tick_spacing: u16) -> Result<()> {
+        let (byte, bit) = checked_tick_to_position(tick, tick_spacing)?;
+        require!(
+            ((self.bitmap[byte] >> bit) % 2 == 1) != value,
+            InvariantErrorCode::InvalidTickIndex
+        );
+        self.bitmap[byte] ^= 1 << bit;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -252,4 +324,89 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_initialized_ticks() {
+        let spacing = 10u16;
+        let max_tick = MAX_TICK / 10 * 10;
+        let ticks = [-max_tick, -20, 0, 30, max_tick];
+        let mut map = Tickmap::default();
+        assert_eq!(map.initialized_ticks(spacing).next(), None);
+        assert_eq!(map.next_initialized_tick(0, spacing), None);
+        assert_eq!(map.prev_initialized_tick(0, spacing), None);
+        for tick in ticks {
+            map.flip(true, tick, spacing);
+        }
+
+        assert_eq!(map.initialized_ticks(spacing).collect::<Vec<_>>(), ticks);
+        assert_eq!(
+            map.initialized_ticks(spacing).rev().collect::<Vec<_>>(),
+            ticks.iter().rev().copied().collect::<Vec<_>>()
+        );
+
+        // next is strictly above, prev includes the tick
+        assert_eq!(map.next_initialized_tick(-30, spacing), Some(-20));
+        assert_eq!(map.next_initialized_tick(-20, spacing), Some(0));
+        assert_eq!(map.prev_initialized_tick(0, spacing), Some(0));
+        assert_eq!(map.prev_initialized_tick(-1, spacing), Some(-20));
+        // not divisible by spacing
+        assert_eq!(map.next_initialized_tick(-5, spacing), Some(0));
+        assert_eq!(map.prev_initialized_tick(29, spacing), Some(0));
+        // beyond the search range of a single on-chain lookup
+        assert_eq!(map.next_initialized(30, spacing), None);
+        assert_eq!(map.next_initialized_tick(30, spacing), Some(max_tick));
+        assert_eq!(map.prev_initialized_tick(-30, spacing), Some(-max_tick));
+        // edges
+        assert_eq!(map.next_initialized_tick(max_tick, spacing), None);
+        assert_eq!(map.prev_initialized_tick(-max_tick - 10, spacing), None);
+        assert_eq!(
+            map.next_initialized_tick(i32::MIN / 2, spacing),
+            Some(-max_tick)
+        );
+        assert_eq!(
+            map.prev_initialized_tick(i32::MAX / 2, spacing),
+            Some(max_tick)
+        );
+    }
+
+    #[test]
+    fn test_checked_access_at_extremes() {
+        for tick_spacing in [1u16, 2, 4, 5, 8, 10, 16, 20, 50, 64, 100, 1000, u16::MAX] {
+            let spacing = tick_spacing as i32;
+            let max_tick = (MAX_TICK / spacing).min(TICK_LIMIT - 1) * spacing;
+            let mut map = Tickmap::default();
+
+            for tick in [max_tick, -max_tick, 0] {
+                assert!(!map.try_get(tick, tick_spacing).unwrap());
+                map.try_flip(true, tick, tick_spacing).unwrap();
+                assert!(map.try_get(tick, tick_spacing).unwrap());
+                // flipping to the same value
+                assert!(map.try_flip(true, tick, tick_spacing).is_err());
+                assert_eq!(
+                    map.try_get(tick, tick_spacing).unwrap(),
+                    map.get(tick, tick_spacing)
+                );
+                map.try_flip(false, tick, tick_spacing).unwrap();
+            }
+
+            // one step past the edges
+            assert!(map.try_get(max_tick + spacing, tick_spacing).is_err());
+            assert!(map.try_get(-max_tick - spacing, tick_spacing).is_err());
+            assert!(map
+                .try_flip(true, max_tick + spacing, tick_spacing)
+                .is_err());
+            // far outside and not divisible
+            assert!(map
+                .try_get(i32::MAX - i32::MAX % spacing, tick_spacing)
+                .is_err());
+            assert!(map
+                .try_get(i32::MIN - i32::MIN % spacing, tick_spacing)
+                .is_err());
+            if tick_spacing > 1 {
+                assert!(map.try_get(1, tick_spacing).is_err());
+            }
+            assert!(map.bitmap.iter().all(|byte| *byte == 0));
+        }
+        assert!(Tickmap::default().try_get(0, 0).is_err());
+    }
 }
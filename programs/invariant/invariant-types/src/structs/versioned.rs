@@ -0,0 +1,325 @@
+use std::mem::size_of;
+
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::{
+    decimals::*,
+    err, function, location,
+    structs::{Pool, Position, Tick, TICK_EMA_DENOMINATOR},
+    utils::{TrackableError, TrackableResult},
+    ANCHOR_DISCRIMINATOR_SIZE,
+};
+
+// Accounts keep the layout they were created with under the same discriminator, so historical
+// snapshots hold a mix of them. Layouts are told apart by the size of the account.
+pub const LEGACY_VERSION: u8 = 0; // layouts of the amm era
+pub const CURRENT_VERSION: u8 = 1;
+
+// Account decodable from its legacy layout, which is converted to the current one
+pub trait Versioned: Discriminator + AnchorDeserialize {
+    type Legacy: AnchorDeserialize + Into<Self>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VersionedAccount<T> {
+    pub version: u8,
+    pub account: T, // in the current layout whatever the version
+}
+
+impl<T: Versioned> VersionedAccount<T> {
+    pub fn from_account_data(data: &[u8]) -> TrackableResult<Self> {
+        if data.len() < ANCHOR_DISCRIMINATOR_SIZE
+            || data[..ANCHOR_DISCRIMINATOR_SIZE] != T::discriminator()
+        {
+            return Err(err!("discriminator does not match the account type"));
+        }
+        let mut body = &data[ANCHOR_DISCRIMINATOR_SIZE..];
+
+        // a layout which didn't change decodes as current
+        let (version, account) = if body.len() == size_of::<T>() {
+            (CURRENT_VERSION, T::deserialize(&mut body))
+        } else if body.len() == size_of::<T::Legacy>() {
+            (
+                LEGACY_VERSION,
+                T::Legacy::deserialize(&mut body).map(Into::into),
+            )
+        } else {
+            return Err(err!(&format!("unknown layout of {} bytes", data.len())));
+        };
+
+        Ok(Self {
+            version,
+            account: account.map_err(|e| err!(&e.to_string()))?,
+        })
+    }
+
+    pub fn is_legacy(&self) -> bool {
+        self.version == LEGACY_VERSION
+    }
+}
+
+#[zero_copy]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug, AnchorDeserialize)]
+pub struct LegacyPool {
+    pub token_x: Pubkey,
+    pub token_y: Pubkey,
+    pub token_x_reserve: Pubkey,
+    pub token_y_reserve: Pubkey,
+    pub position_iterator: u128,
+    pub tick_spacing: u16,
+    pub fee: FixedPoint,
+    pub protocol_fee: FixedPoint,
+    pub liquidity: Liquidity,
+    pub sqrt_price: Price,
+    pub current_tick_index: i32,
+    pub tickmap: Pubkey,
+    pub fee_growth_global_x: FeeGrowth,
+    pub fee_growth_global_y: FeeGrowth,
+    pub fee_protocol_token_x: u64,
+    pub fee_protocol_token_y: u64,
+    pub seconds_per_liquidity_global: FixedPoint,
+    pub start_timestamp: u64,
+    pub last_timestamp: u64,
+    pub fee_receiver: Pubkey,
+    pub oracle_address: Pubkey,
+    pub oracle_initialized: bool,
+    pub bump: u8,
+}
+
+// Fields added since are left unset, except those the legacy state already determines
+impl From<LegacyPool> for Pool {
+    fn from(legacy: LegacyPool) -> Self {
+        Pool {
+            token_x: legacy.token_x,
+            token_y: legacy.token_y,
+            token_x_reserve: legacy.token_x_reserve,
+            token_y_reserve: legacy.token_y_reserve,
+            position_iterator: legacy.position_iterator,
+            tick_spacing: legacy.tick_spacing,
+            fee: legacy.fee,
+            protocol_fee: legacy.protocol_fee,
+            liquidity: legacy.liquidity,
+            sqrt_price: legacy.sqrt_price,
+            current_tick_index: legacy.current_tick_index,
+            tickmap: legacy.tickmap,
+            fee_growth_global_x: legacy.fee_growth_global_x,
+            fee_growth_global_y: legacy.fee_growth_global_y,
+            fee_protocol_token_x: legacy.fee_protocol_token_x,
+            fee_protocol_token_y: legacy.fee_protocol_token_y,
+            seconds_per_liquidity_global: legacy.seconds_per_liquidity_global,
+            start_timestamp: legacy.start_timestamp,
+            last_timestamp: legacy.last_timestamp,
+            fee_receiver: legacy.fee_receiver,
+            oracle_address: legacy.oracle_address,
+            oracle_initialized: legacy.oracle_initialized,
+            bump: legacy.bump,
+            // legacy pools were derived from their fee, fees couldn't be updated back then
+            fee_tier_fee: legacy.fee,
+            tick_ema: legacy.current_tick_index as i64 * TICK_EMA_DENOMINATOR,
+            tick_ema_last_timestamp: legacy.last_timestamp,
+            ..Default::default()
+        }
+    }
+}
+
+#[zero_copy]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug, AnchorDeserialize)]
+pub struct LegacyPosition {
+    pub owner: Pubkey,
+    pub pool: Pubkey,
+    pub id: u128,
+    pub liquidity: Liquidity,
+    pub lower_tick_index: i32,
+    pub upper_tick_index: i32,
+    pub fee_growth_inside_x: FeeGrowth,
+    pub fee_growth_inside_y: FeeGrowth,
+    pub seconds_per_liquidity_inside: FixedPoint,
+    pub last_slot: u64,
+    pub tokens_owed_x: FixedPoint,
+    pub tokens_owed_y: FixedPoint,
+    pub bump: u8,
+}
+
+// Fee totals were not tracked, they start from zero
+impl From<LegacyPosition> for Position {
+    fn from(legacy: LegacyPosition) -> Self {
+        Position {
+            owner: legacy.owner,
+            pool: legacy.pool,
+            id: legacy.id,
+            liquidity: legacy.liquidity,
+            lower_tick_index: legacy.lower_tick_index,
+            upper_tick_index: legacy.upper_tick_index,
+            fee_growth_inside_x: legacy.fee_growth_inside_x,
+            fee_growth_inside_y: legacy.fee_growth_inside_y,
+            seconds_per_liquidity_inside: legacy.seconds_per_liquidity_inside,
+            last_slot: legacy.last_slot,
+            tokens_owed_x: legacy.tokens_owed_x,
+            tokens_owed_y: legacy.tokens_owed_y,
+            bump: legacy.bump,
+            ..Default::default()
+        }
+    }
+}
+
+#[zero_copy]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug, AnchorDeserialize)]
+pub struct LegacyTick {
+    pub pool: Pubkey,
+    pub index: i32,
+    pub sign: bool,
+    pub liquidity_change: Liquidity,
+    pub liquidity_gross: Liquidity,
+    pub sqrt_price: Price,
+    pub fee_growth_outside_x: FeeGrowth,
+    pub fee_growth_outside_y: FeeGrowth,
+    pub seconds_per_liquidity_outside: FixedPoint,
+    pub seconds_outside: u64,
+    pub bump: u8,
+}
+
+// Ticks had no limit orders
+impl From<LegacyTick> for Tick {
+    fn from(legacy: LegacyTick) -> Self {
+        Tick {
+            pool: legacy.pool,
+            index: legacy.index,
+            sign: legacy.sign,
+            liquidity_change: legacy.liquidity_change,
+            liquidity_gross: legacy.liquidity_gross,
+            sqrt_price: legacy.sqrt_price,
+            fee_growth_outside_x: legacy.fee_growth_outside_x,
+            fee_growth_outside_y: legacy.fee_growth_outside_y,
+            seconds_per_liquidity_outside: legacy.seconds_per_liquidity_outside,
+            seconds_outside: legacy.seconds_outside,
+            bump: legacy.bump,
+            ..Default::default()
+        }
+    }
+}
+
+impl Versioned for Pool {
+    type Legacy = LegacyPool;
+}
+
+impl Versioned for Position {
+    type Legacy = LegacyPosition;
+}
+
+impl Versioned for Tick {
+    type Legacy = LegacyTick;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account_data<T: Discriminator>(body: Vec<u8>) -> Vec<u8> {
+        let mut data = T::discriminator().to_vec();
+        data.extend(body);
+        data
+    }
+
+    #[test]
+    fn test_versioned_pool() {
+        assert_eq!(size_of::<LegacyPool>(), 392);
+        assert_eq!(size_of::<LegacyPosition>(), 193);
+
+        // legacy layout is a prefix of the current one
+        let mut body = vec![0u8; size_of::<LegacyPool>()];
+        let tick_spacing_offset = 4 * 32 + 16;
+        body[tick_spacing_offset..tick_spacing_offset + 2].copy_from_slice(&10u16.to_le_bytes());
+        let fee_offset = tick_spacing_offset + 2;
+        body[fee_offset..fee_offset + 16].copy_from_slice(&3000u128.to_le_bytes());
+        let current_tick_offset = fee_offset + 4 * 16;
+        body[current_tick_offset..current_tick_offset + 4].copy_from_slice(&(-7i32).to_le_bytes());
+
+        let legacy =
+            VersionedAccount::<Pool>::from_account_data(&account_data::<Pool>(body.clone()))
+                .unwrap();
+        assert!(legacy.is_legacy());
+        assert_eq!({ legacy.account.tick_spacing }, 10);
+        assert_eq!({ legacy.account.fee_tier_fee }, FixedPoint::new(3000));
+        assert_eq!(legacy.account.get_tick_ema(), -7);
+        assert_eq!({ legacy.account.reserve_x }, 0);
+
+        // same fields with the current layout
+        body.resize(size_of::<Pool>(), 0);
+        let current =
+            VersionedAccount::<Pool>::from_account_data(&account_data::<Pool>(body.clone()))
+                .unwrap();
+        assert_eq!(current.version, CURRENT_VERSION);
+        assert_eq!({ current.account.current_tick_index }, -7);
+        assert_eq!({ current.account.fee_tier_fee }, FixedPoint::new(0));
+
+        // size of neither layout, other account type
+        body.pop();
+        assert!(VersionedAccount::<Pool>::from_account_data(&account_data::<Pool>(body)).is_err());
+        let tick = account_data::<Tick>(vec![0u8; size_of::<Tick>()]);
+        assert!(VersionedAccount::<Pool>::from_account_data(&tick).is_err());
+        assert_eq!(
+            VersionedAccount::<Tick>::from_account_data(&tick)
+                .unwrap()
+                .version,
+            CURRENT_VERSION
+        );
+    }
+
+    #[test]
+    fn test_versioned_tick() {
+        assert_eq!(size_of::<LegacyTick>(), 142);
+
+        let mut body = vec![0u8; size_of::<LegacyTick>()];
+        body[32..36].copy_from_slice(&(-20i32).to_le_bytes());
+        body[36] = 1;
+        body[53..69].copy_from_slice(&7u128.to_le_bytes());
+        body[141] = 254;
+
+        let legacy =
+            VersionedAccount::<Tick>::from_account_data(&account_data::<Tick>(body.clone()))
+                .unwrap();
+        assert!(legacy.is_legacy());
+        assert_eq!({ legacy.account.index }, -20);
+        assert!(legacy.account.sign);
+        assert_eq!({ legacy.account.liquidity_gross }, Liquidity::new(7));
+        assert_eq!(legacy.account.bump, 254);
+        assert_eq!({ legacy.account.limit_orders }, 0);
+
+        body.resize(size_of::<Tick>(), 0);
+        let current =
+            VersionedAccount::<Tick>::from_account_data(&account_data::<Tick>(body)).unwrap();
+        assert_eq!(current.version, CURRENT_VERSION);
+        assert_eq!(current.account, legacy.account);
+    }
+
+    #[test]
+    fn test_versioned_position() {
+        let mut body = vec![0u8; size_of::<LegacyPosition>()];
+        body[64..80].copy_from_slice(&3u128.to_le_bytes());
+        body[96..100].copy_from_slice(&(-10i32).to_le_bytes());
+        body[100..104].copy_from_slice(&10i32.to_le_bytes());
+        body[192] = 255;
+
+        let legacy = VersionedAccount::<Position>::from_account_data(&account_data::<Position>(
+            body.clone(),
+        ))
+        .unwrap();
+        assert!(legacy.is_legacy());
+        assert_eq!({ legacy.account.id }, 3);
+        assert_eq!({ legacy.account.lower_tick_index }, -10);
+        assert_eq!({ legacy.account.upper_tick_index }, 10);
+        assert_eq!(legacy.account.bump, 255);
+        assert_eq!({ legacy.account.maker_fees_x }, FixedPoint::new(0));
+
+        body.resize(size_of::<Position>(), 0);
+        let current =
+            VersionedAccount::<Position>::from_account_data(&account_data::<Position>(body))
+                .unwrap();
+        assert_eq!(current.version, CURRENT_VERSION);
+        assert_eq!(current.account, legacy.account);
+    }
+}
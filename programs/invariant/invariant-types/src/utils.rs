@@ -1,8 +1,10 @@
 use std::cmp::Ordering;
+use std::ops::RangeInclusive;
 
 use anchor_lang::prelude::Pubkey;
 
-use crate::ID;
+use crate::structs::{Pool, Tickmap};
+use crate::{ID, LAUNCH_CONFIG_SEED, POOL_SEED, TICK_SEED};
 
 pub type TrackableResult<T> = Result<T, TrackableError>;
 
@@ -53,6 +55,8 @@ impl TrackableError {
     }
 }
 
+// `fee` is the fee of the tier the pool was created with (`Pool::fee_tier_fee`),
+// it does not follow later pool fee updates
 pub fn get_pool_address(
     first_token: Pubkey,
     second_token: Pubkey,
@@ -67,7 +71,7 @@ pub fn get_pool_address(
 
     let (pool_address, _) = Pubkey::find_program_address(
         &[
-            b"poolv1",
+            POOL_SEED.as_bytes(),
             token_x.as_ref(),
             token_y.as_ref(),
             &fee.to_le_bytes(),
@@ -78,6 +82,49 @@ pub fn get_pool_address(
     pool_address
 }
 
+pub fn get_tick_address(pool: &Pubkey, index: i32) -> Pubkey {
+    let (tick_address, _) = Pubkey::find_program_address(
+        &[TICK_SEED.as_bytes(), pool.as_ref(), &index.to_le_bytes()],
+        &ID,
+    );
+    tick_address
+}
+
+pub fn get_launch_config_address(pool: &Pubkey) -> Pubkey {
+    let (launch_config_address, _) =
+        Pubkey::find_program_address(&[LAUNCH_CONFIG_SEED.as_bytes(), pool.as_ref()], &ID);
+    launch_config_address
+}
+
+// Accounts an off-chain quoter has to fetch to simulate swaps within `tick_range`:
+// the pool, its tickmap and every initialized tick in the range (tickmap is a single account)
+pub fn required_accounts_for_quote(
+    pool_address: Pubkey,
+    pool: &Pool,
+    tickmap: &Tickmap,
+    tick_range: RangeInclusive<i32>,
+) -> Vec<Pubkey> {
+    let tick_spacing = pool.tick_spacing as i32;
+    let lower = crate::math::get_min_tick(pool.tick_spacing).max(*tick_range.start());
+    let upper = crate::math::get_max_tick(pool.tick_spacing).min(*tick_range.end());
+    // first index divisible by spacing
+    let first = lower.div_euclid(tick_spacing) * tick_spacing
+        + if lower.rem_euclid(tick_spacing) == 0 {
+            0
+        } else {
+            tick_spacing
+        };
+
+    let mut accounts = vec![pool_address, pool.tickmap];
+    accounts.extend(
+        (first..=upper)
+            .step_by(tick_spacing as usize)
+            .filter(|index| tickmap.get(*index, pool.tick_spacing))
+            .map(|index| get_tick_address(&pool_address, index)),
+    );
+    accounts
+}
+
 #[macro_use]
 pub mod trackable_result {
     #[macro_export]
@@ -223,4 +270,44 @@ mod tests {
         assert_eq!(pool_address_1, expected);
         assert_eq!(pool_address_2, expected);
     }
+
+    #[test]
+    fn test_required_accounts_for_quote() {
+        use super::*;
+        let pool_address = Pubkey::new_unique();
+        let pool = Pool {
+            tickmap: Pubkey::new_unique(),
+            tick_spacing: 10,
+            ..Default::default()
+        };
+        let mut tickmap = Tickmap::default();
+        for index in [-30, 0, 20, 50] {
+            tickmap.flip(true, index, pool.tick_spacing);
+        }
+        // range not aligned to spacing
+        {
+            let accounts = required_accounts_for_quote(pool_address, &pool, &tickmap, -35..=25);
+            assert_eq!(
+                accounts,
+                vec![
+                    pool_address,
+                    pool.tickmap,
+                    get_tick_address(&pool_address, -30),
+                    get_tick_address(&pool_address, 0),
+                    get_tick_address(&pool_address, 20),
+                ]
+            );
+        }
+        // range outside of the price space is clamped
+        {
+            let accounts =
+                required_accounts_for_quote(pool_address, &pool, &tickmap, i32::MIN..=i32::MAX);
+            assert_eq!(accounts.len(), 6);
+        }
+        // empty range
+        {
+            let accounts = required_accounts_for_quote(pool_address, &pool, &tickmap, 1..=9);
+            assert_eq!(accounts, vec![pool_address, pool.tickmap]);
+        }
+    }
 }
@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+
+// Instruction arguments, mirrored in invariant-types so clients and CPI callers
+// serialize them from the same definition
+
+// Side of a swap. Variants are ordered so borsh encodes them as the `x_to_y: bool` they
+// replaced, `XtoY` being `true`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    YtoX,
+    XtoY,
+}
+
+impl Direction {
+    pub fn is_x_to_y(self) -> bool {
+        self == Direction::XtoY
+    }
+}
+
+impl From<bool> for Direction {
+    fn from(x_to_y: bool) -> Self {
+        match x_to_y {
+            true => Direction::XtoY,
+            false => Direction::YtoX,
+        }
+    }
+}
+
+// Whether the swapped amount is the input or the output. Encoded as the `by_amount_in: bool`
+// it replaced, `In` being `true`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AmountSpec {
+    Out,
+    In,
+}
+
+impl AmountSpec {
+    pub fn is_in(self) -> bool {
+        self == AmountSpec::In
+    }
+}
+
+impl From<bool> for AmountSpec {
+    fn from(by_amount_in: bool) -> Self {
+        match by_amount_in {
+            true => AmountSpec::In,
+            false => AmountSpec::Out,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct CreateFeeTierArgs {
+    pub fee: u128,
+    pub tick_spacing: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct CreatePoolArgs {
+    pub init_tick: i32,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct InitPoolAndPositionArgs {
+    pub init_tick: i32,
+    pub lower_tick_index: i32,
+    pub upper_tick_index: i32,
+    pub liquidity_delta: u128, // raw Liquidity of the first position
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct SwapArgs {
+    pub direction: Direction,
+    pub amount: u64,
+    pub amount_spec: AmountSpec,
+    pub sqrt_price_limit: u128,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct SwapBoundedArgs {
+    pub direction: Direction,
+    pub amount: u64,
+    pub amount_spec: AmountSpec,
+    pub sqrt_price_limit: u128,
+    pub tick_limit: u16, // most ticks crossed before ending with a partial fill
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct RouteHop {
+    pub direction: Direction,
+    pub sqrt_price_limit: u128,
+    // crossed ticks, then the hook program and the oracle of the pool if it has them
+    pub tick_accounts: u8,
+}
+
+// Exact input swap through a sequence of pools, output of every hop is the input of the next
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct SwapRouteArgs {
+    pub amount_in: u64,
+    pub min_amount_out: u64,
+    pub hops: Vec<RouteHop>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct RebateTierArgs {
+    pub min_maker_fees: u64,
+    pub rebate: u128, // raw FixedPoint, share of the claimed fee paid on top of it
+}
+
+// Replaces the whole rebate schedule, tiers sorted by ascending `min_maker_fees`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct SetRebateScheduleArgs {
+    pub tiers: Vec<RebateTierArgs>,
+}
+
+// Set as return data of swap, prices are sqrt prices
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct SwapReturnData {
+    pub lp_fee: u64,
+    pub protocol_fee: u64,
+    pub referral_fee: u64,
+    pub price_before: u128,
+    pub price_after: u128,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct FeeTierReturnData {
+    pub fee: u128,
+    pub tick_spacing: u16,
+}
+
+// Set as return data of list_fee_tiers, in the order the fee tiers were created
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct ListFeeTiersReturnData {
+    pub fee_tiers: Vec<FeeTierReturnData>,
+}
@@ -4,7 +4,10 @@ pub use decimal::*;
 
 use anchor_lang::prelude::*;
 
-pub const PRICE_LIQUIDITY_DENOMINATOR: u128 = 1__0000_0000__0000_0000__00u128;
+pub const PRICE_LIQUIDITY_DENOMINATOR: u128 = Price::ONE / Liquidity::ONE;
+// 10 ^ (FeeGrowth::scale() + Liquidity::scale() - FixedPoint::scale())
+const FEE_GROWTH_TO_FEE_DENOMINATOR: U256 =
+    to_u256(FeeGrowth::ONE * Liquidity::ONE / FixedPoint::ONE);
 
 #[decimal(24)]
 #[zero_copy]
@@ -54,18 +57,16 @@ impl FeeGrowth {
         FeeGrowth::new(self.get() - other.get())
     }
 
-    pub fn from_fee(liquidity: Liquidity, fee: TokenAmount) -> Self {
-        FeeGrowth::new(
+    // returns None for zero liquidity or when dust liquidity makes fee growth overflow
+    pub fn from_fee(liquidity: Liquidity, fee: TokenAmount) -> Option<Self> {
+        Some(FeeGrowth::new(
             U256::from(fee.get())
-                .checked_mul(FeeGrowth::one())
-                .unwrap()
-                .checked_mul(Liquidity::one())
-                .unwrap()
-                .checked_div(liquidity.here())
-                .unwrap()
+                .checked_mul(FeeGrowth::one())?
+                .checked_mul(Liquidity::one())?
+                .checked_div(liquidity.here())?
                 .try_into()
-                .unwrap(),
-        )
+                .ok()?,
+        ))
     }
 
     pub fn to_fee(self, liquidity: Liquidity) -> FixedPoint {
@@ -74,14 +75,21 @@ impl FeeGrowth {
                 .unwrap()
                 .checked_mul(liquidity.here())
                 .unwrap()
-                .checked_div(U256::from(10).pow(U256::from(
-                    FeeGrowth::scale() + Liquidity::scale() - FixedPoint::scale(),
-                )))
+                .checked_div(FEE_GROWTH_TO_FEE_DENOMINATOR)
                 .unwrap()
                 .try_into()
                 .unwrap_or_else(|_| panic!("value too big to parse in `FeeGrowth::to_fee`")),
         )
     }
+
+    // same as `to_fee`, but never panics, fees past the max are clamped
+    pub fn saturating_to_fee(self, liquidity: Liquidity) -> FixedPoint {
+        FixedPoint::new(
+            (U256::from(self.get()) * liquidity.here::<U256>() / FEE_GROWTH_TO_FEE_DENOMINATOR)
+                .try_into()
+                .unwrap_or(u128::MAX),
+        )
+    }
 }
 
 impl FixedPoint {
@@ -161,6 +169,13 @@ pub mod tests {
             1_000000_000000_000000_000000
         );
         assert_eq!(TokenAmount::from_integer(1).get(), 1);
+        assert_eq!(PRICE_LIQUIDITY_DENOMINATOR, 1__0000_0000__0000_0000__00u128);
+        assert_eq!(
+            FEE_GROWTH_TO_FEE_DENOMINATOR,
+            U256::from(10).pow(U256::from(
+                FeeGrowth::scale() + Liquidity::scale() - FixedPoint::scale()
+            ))
+        );
     }
 
     #[test]
@@ -173,17 +188,20 @@ pub mod tests {
     fn test_from_fee() {
         // One
         {
-            let fee_growth = FeeGrowth::from_fee(Liquidity::from_integer(1), TokenAmount(1));
+            let fee_growth =
+                FeeGrowth::from_fee(Liquidity::from_integer(1), TokenAmount(1)).unwrap();
             assert_eq!(fee_growth, FeeGrowth::from_integer(1));
         }
         // Half
         {
-            let fee_growth = FeeGrowth::from_fee(Liquidity::from_integer(2), TokenAmount(1));
+            let fee_growth =
+                FeeGrowth::from_fee(Liquidity::from_integer(2), TokenAmount(1)).unwrap();
             assert_eq!(fee_growth, FeeGrowth::from_scale(5, 1))
         }
         // Little
         {
-            let fee_growth = FeeGrowth::from_fee(Liquidity::from_integer(u64::MAX), TokenAmount(1));
+            let fee_growth =
+                FeeGrowth::from_fee(Liquidity::from_integer(u64::MAX), TokenAmount(1)).unwrap();
             // real    5.42101086242752217003726400434970855712890625 × 10^-20
             // expected 54210
             assert_eq!(fee_growth, FeeGrowth::new(54210))
@@ -191,11 +209,40 @@ pub mod tests {
         // Fairly big
         {
             let fee_growth =
-                FeeGrowth::from_fee(Liquidity::from_integer(100), TokenAmount(1_000_000));
+                FeeGrowth::from_fee(Liquidity::from_integer(100), TokenAmount(1_000_000)).unwrap();
             assert_eq!(fee_growth, FeeGrowth::from_integer(10000))
         }
     }
 
+    #[test]
+    fn test_from_fee_dust_liquidity() {
+        // zero liquidity
+        {
+            let fee_growth = FeeGrowth::from_fee(Liquidity::new(0), TokenAmount(1));
+            assert_eq!(fee_growth, None);
+        }
+        // single unit of liquidity
+        {
+            let fee_growth = FeeGrowth::from_fee(Liquidity::new(1), TokenAmount(1));
+            assert_eq!(fee_growth, Some(FeeGrowth::from_integer(1_000_000)));
+        }
+        // single unit of liquidity, biggest fee that fits
+        {
+            let fee = TokenAmount(340282366);
+            let fee_growth = FeeGrowth::from_fee(Liquidity::new(1), fee);
+            assert_eq!(
+                fee_growth,
+                Some(FeeGrowth::from_integer(340_282_366_000_000u128))
+            );
+        }
+        // single unit of liquidity, overflow
+        {
+            let fee = TokenAmount(340282367);
+            let fee_growth = FeeGrowth::from_fee(Liquidity::new(1), fee);
+            assert_eq!(fee_growth, None);
+        }
+    }
+
     #[test]
     fn test_to_fee() {
         // equal
@@ -203,7 +250,7 @@ pub mod tests {
             let amount = TokenAmount(100);
             let liquidity = Liquidity::from_integer(1_000_000);
 
-            let fee_growth = FeeGrowth::from_fee(liquidity, amount);
+            let fee_growth = FeeGrowth::from_fee(liquidity, amount).unwrap();
             let out = fee_growth.to_fee(liquidity);
             assert_eq!(out, FixedPoint::from_decimal(amount));
         }
@@ -213,7 +260,7 @@ pub mod tests {
             let liquidity_before = Liquidity::from_integer(1_000_000);
             let liquidity_after = Liquidity::from_integer(10_000_000);
 
-            let fee_growth = FeeGrowth::from_fee(liquidity_before, amount);
+            let fee_growth = FeeGrowth::from_fee(liquidity_before, amount).unwrap();
             let out = fee_growth.to_fee(liquidity_after);
             assert_eq!(out, FixedPoint::from_integer(1000))
         }
@@ -222,7 +269,7 @@ pub mod tests {
             let amount = TokenAmount(100_000_000__000000);
             let liquidity = Liquidity::from_integer(2u128.pow(77));
 
-            let fee_growth = FeeGrowth::from_fee(liquidity, amount);
+            let fee_growth = FeeGrowth::from_fee(liquidity, amount).unwrap();
             // real    6.61744490042422139897126953655970282852649688720703125 × 10^-22
             // expected 661744490042422
             assert_eq!(fee_growth, FeeGrowth::new(661744490042422));
@@ -237,7 +284,7 @@ pub mod tests {
             let amount = TokenAmount(600000000000000000);
             let liquidity = Liquidity::from_integer(10000000000000000000u128);
 
-            let fee_growth = FeeGrowth::from_fee(liquidity, amount);
+            let fee_growth = FeeGrowth::from_fee(liquidity, amount).unwrap();
             // real     0.06
             // expected 0.06
             assert_eq!(fee_growth, FeeGrowth::new(60000000000000000000000));
@@ -249,6 +296,24 @@ pub mod tests {
         }
     }
 
+    #[test]
+    fn test_saturating_to_fee() {
+        // same as to_fee in range
+        {
+            let liquidity = Liquidity::from_integer(10_000_000);
+            let fee_growth = FeeGrowth::from_fee(liquidity, TokenAmount(100)).unwrap();
+            assert_eq!(
+                fee_growth.saturating_to_fee(liquidity),
+                fee_growth.to_fee(liquidity)
+            );
+        }
+        // clamped on overflow
+        {
+            let out = FeeGrowth::new(u128::MAX).saturating_to_fee(Liquidity::new(u128::MAX));
+            assert_eq!(out, FixedPoint::new(u128::MAX));
+        }
+    }
+
     #[test]
     fn test_decimal_ops() {
         let liquidity = Liquidity::new(4_902_430_892__340393);
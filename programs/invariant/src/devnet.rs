@@ -0,0 +1,59 @@
+use crate::instructions::{CreateFaucetMint, Faucet};
+use anchor_lang::__private::ErrorCode;
+use anchor_lang::prelude::*;
+use anchor_lang::{Accounts, AccountsExit};
+use std::collections::BTreeMap;
+use std::convert::TryInto;
+
+// sighash("global:create_faucet_mint"), the same as if it was declared in the program module
+pub const CREATE_FAUCET_MINT_DISCRIMINATOR: [u8; 8] = [23, 231, 164, 188, 88, 43, 20, 80];
+// sighash("global:faucet")
+pub const FAUCET_DISCRIMINATOR: [u8; 8] = [0, 98, 59, 30, 144, 142, 113, 12];
+
+// Faucet instructions are left out of the program module, so mainnet builds and the IDL don't
+// contain them. Devnet builds dispatch them from the program fallback, the way anchor does.
+pub fn dispatch(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    if data.len() < 8 {
+        return Err(ErrorCode::InstructionFallbackNotFound.into());
+    }
+    let (sighash, ix_data) = data.split_at(8);
+    let mut remaining_accounts = accounts;
+    let mut bumps = BTreeMap::new();
+
+    match sighash.try_into().unwrap() {
+        CREATE_FAUCET_MINT_DISCRIMINATOR => {
+            let accounts = CreateFaucetMint::try_accounts(
+                program_id,
+                &mut remaining_accounts,
+                ix_data,
+                &mut bumps,
+            )?;
+            accounts.handler()?;
+            accounts.exit(program_id)
+        }
+        FAUCET_DISCRIMINATOR => {
+            let (_index, amount) = <(u8, u64)>::deserialize(&mut &ix_data[..])
+                .map_err(|_| ErrorCode::InstructionDidNotDeserialize)?;
+            let accounts =
+                Faucet::try_accounts(program_id, &mut remaining_accounts, ix_data, &mut bumps)?;
+            accounts.handler(amount)?;
+            accounts.exit(program_id)
+        }
+        _ => Err(ErrorCode::InstructionFallbackNotFound.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::hash::hash;
+
+    #[test]
+    fn test_discriminators() {
+        assert_eq!(
+            CREATE_FAUCET_MINT_DISCRIMINATOR,
+            hash(b"global:create_faucet_mint").to_bytes()[..8]
+        );
+        assert_eq!(FAUCET_DISCRIMINATOR, hash(b"global:faucet").to_bytes()[..8]);
+    }
+}
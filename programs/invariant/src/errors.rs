@@ -64,4 +64,92 @@ pub enum ErrorCode {
     InvalidListOwner = 29, // 178d
     #[msg("Invalid tick spacing")]
     InvalidTickSpacing = 30, // 178e
+    #[msg("Invalid number of pools in batch")]
+    InvalidBatchSize = 31, // 178f
+    #[msg("Provided pool address is different than expected")]
+    InvalidPoolAddress = 32, // 1790
+    #[msg("Provided fee tier is different than expected")]
+    InvalidFeeTier = 33, // 1791
+    #[msg("Pool fee is out of bounds")]
+    InvalidPoolFee = 34, // 1792
+    #[msg("There is no pending pool fee")]
+    NoPendingPoolFee = 35, // 1793
+    #[msg("Pending pool fee is still timelocked")]
+    PoolFeeTimelocked = 36, // 1794
+    #[msg("Account would not be rent exempt")]
+    AccountNotRentExempt = 37, // 1795
+    #[msg("Account can not be closed into itself")]
+    InvalidCloseDestination = 38, // 1796
+    #[msg("Withdraw access list is full")]
+    WithdrawAclFull = 39, // 1797
+    #[msg("Withdrawer is not on the access list")]
+    InvalidWithdrawer = 40, // 1798
+    #[msg("Referrer is not on the whitelist")]
+    InvalidReferrer = 41, // 1799
+    #[msg("Delegated amount is not enough to cover the swap")]
+    InsufficientDelegation = 42, // 179a
+    #[msg("Session authority is invalid, expired or out of scope")]
+    InvalidSessionAuthority = 43, // 179b
+    #[msg("Token decimals are not supported")]
+    UnsupportedTokenDecimals = 44, // 179c
+    #[msg("Both ticks of the position exist")]
+    PositionNotOrphaned = 45, // 179d
+    #[msg("Hook program is not executable")]
+    InvalidHookProgram = 46, // 179e
+    #[msg("Hook program of the pool not found in remaining accounts")]
+    HookProgramNotFound = 47, // 179f
+    #[msg("Swap crosses too many ticks for the pool hook")]
+    TooManyHookCalls = 48, // 17a0
+    #[msg("Swap moves the price more than allowed in a single transaction")]
+    PriceChangeTooLarge = 49, // 17a1
+    #[msg("Pool accepts only withdrawals")]
+    PoolWithdrawalOnly = 50, // 17a2
+    #[msg("Route accounts do not match its hops")]
+    InvalidRoute = 51, // 17a3
+    #[msg("Route output is below the minimum")]
+    RouteAmountOutTooLow = 52, // 17a4
+    #[msg("Oracle of the pool not found in remaining accounts")]
+    OracleNotFound = 53, // 17a5
+    #[msg("Neither token of the position selected to claim")]
+    NoSideSelected = 54, // 17a6
+    #[msg("Rebate tiers have to be ascending with rebates up to 100%")]
+    InvalidRebateSchedule = 55, // 17a7
+    #[msg("Pool is in its launch window and the user is not whitelisted")]
+    LaunchWindowActive = 56, // 17a8
+    #[msg("Launch whitelist is full")]
+    LaunchWhitelistFull = 57, // 17a9
+    #[msg("Limit order has to be one tick wide and outside of the current price")]
+    InvalidLimitOrder = 58, // 17aa
+    #[msg("Price has not crossed the whole range of the limit order")]
+    LimitOrderNotFilled = 59, // 17ab
+    #[msg("Instruction is available only in devnet builds")]
+    DevnetOnly = 60, // 17ac
+    #[msg("Simulation completed, all changes reverted")]
+    SimulationOnly = 61, // 17ad
+    #[msg("TWAP window is empty or not covered by the oracle")]
+    InvalidTwapWindow = 62, // 17ae
+    #[msg("Referral fee over 100%")]
+    InvalidReferralFee = 63, // 17af
+    #[msg("Flash loans are disabled on the pool")]
+    FlashLoansDisabled = 64, // 17b0
+    #[msg("Flash loan was not repaid with the fee")]
+    FlashLoanNotRepaid = 65, // 17b1
+    #[msg("Flash loan fee over 100%")]
+    InvalidFlashLoanFee = 66, // 17b2
+    #[msg("Signer is not the proposed admin")]
+    InvalidPendingAdmin = 67, // 17b3
+    #[msg("Fee tier registry is full")]
+    FeeTierRegistryFull = 68, // 17b4
+    #[msg("Protocol is paused")]
+    ProtocolPaused = 69, // 17b5
+    #[msg("Intermediate hop of the route did not swap its whole input")]
+    RouteHopNotFilled = 70, // 17b6
+    #[msg("Account already has the current layout")]
+    AccountAlreadyMigrated = 71, // 17b7
+    #[msg("Limit order is filled, it can only be claimed")]
+    LimitOrderFilled = 72, // 17b8
+    #[msg("Base uri of the position NFT is too long or has a query")]
+    InvalidPositionNftUri = 73, // 17b9
+    #[msg("Metadata account is not the one of the position NFT")]
+    InvalidPositionNftMetadata = 74, // 17ba
 }
@@ -0,0 +1,282 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct ChangeFeeReceiverEvent {
+    pub pool: Pubkey,
+    pub old_fee_receiver: Pubkey,
+    pub new_fee_receiver: Pubkey,
+}
+
+#[event]
+pub struct ChangeProtocolFeeEvent {
+    pub pool: Pubkey,
+    pub old_protocol_fee: u128,
+    pub new_protocol_fee: u128,
+}
+
+#[event]
+pub struct CreatePoolEvent {
+    pub pool: Pubkey,
+    pub token_x: Pubkey,
+    pub token_y: Pubkey,
+    pub fee: u128,
+    pub tick_spacing: u16,
+    pub init_tick: i32,
+    pub sqrt_price: u128,
+}
+
+// Fee growth checkpoints of the position before and after the update, together with the
+// global growth and protocol fee the pool applied, so claimed fees can be reconciled
+// against `(after - before) * liquidity` exactly.
+#[event]
+pub struct ClaimFeeEvent {
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub liquidity: u128,
+    pub fee_growth_inside_x_before: u128,
+    pub fee_growth_inside_y_before: u128,
+    pub fee_growth_inside_x_after: u128,
+    pub fee_growth_inside_y_after: u128,
+    pub fee_growth_global_x: u128,
+    pub fee_growth_global_y: u128,
+    pub protocol_fee: u128,
+    pub fee_x: u64,
+    pub fee_y: u64,
+    pub rebate_x: u64, // paid out of the protocol fee on top of the fee
+    pub rebate_y: u64,
+}
+
+// Whole position right after creation together with the pool price it was opened at, so
+// indexers don't have to fetch the account, which can already be gone
+#[event]
+pub struct CreatePositionEvent {
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub id: u128,
+    pub lower_tick_index: i32,
+    pub upper_tick_index: i32,
+    pub liquidity: u128,
+    pub fee_growth_inside_x: u128,
+    pub fee_growth_inside_y: u128,
+    pub seconds_per_liquidity_inside: u128,
+    pub last_slot: u64,
+    pub sqrt_price: u128,
+    pub current_tick_index: i32,
+    // deposited tokens
+    pub amount_x: u64,
+    pub amount_y: u64,
+}
+
+#[event]
+pub struct RemovePositionEvent {
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub liquidity: u128,
+    pub fee_growth_inside_x_before: u128,
+    pub fee_growth_inside_y_before: u128,
+    pub fee_growth_inside_x_after: u128,
+    pub fee_growth_inside_y_after: u128,
+    pub fee_growth_global_x: u128,
+    pub fee_growth_global_y: u128,
+    pub protocol_fee: u128,
+    // withdrawn liquidity, without fees
+    pub amount_x: u64,
+    pub amount_y: u64,
+    pub fee_x: u64,
+    pub fee_y: u64,
+}
+
+// Part of the liquidity withdrawn, fees stay owed to the position
+#[event]
+pub struct DecreaseLiquidityEvent {
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub liquidity_delta: u128,
+    pub liquidity_after: u128,
+    pub amount_x: u64,
+    pub amount_y: u64,
+}
+
+// Liquidity added to an open position
+#[event]
+pub struct IncreaseLiquidityEvent {
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub liquidity_delta: u128,
+    pub liquidity_after: u128,
+    pub amount_x: u64,
+    pub amount_y: u64,
+}
+
+#[event]
+pub struct ForceCloseOrphanPositionEvent {
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub liquidity: u128,
+    pub lower_tick_missing: bool,
+    pub upper_tick_missing: bool,
+    pub amount_x: u64,
+    pub amount_y: u64,
+    pub fee_x: u64,
+    pub fee_y: u64,
+}
+
+#[event]
+pub struct TransferPositionOwnershipEvent {
+    pub pool: Pubkey,
+    pub id: u128,
+    pub old_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub liquidity: u128,
+}
+
+// Emitted by every swap, including each hop of a route. `fee` is the whole fee paid,
+// protocol and referral parts included.
+#[event]
+pub struct SwapEvent {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub x_to_y: bool,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub fee: u64,
+    pub protocol_fee: u64,
+    pub referral_fee: u64,
+    pub price_before: u128,
+    pub price_after: u128,
+    pub liquidity_before: u128,
+    pub liquidity_after: u128,
+    pub current_tick_index: i32,
+    pub ticks_crossed: u16,
+}
+
+// Liquidity of the pool right after crossing, `liquidity_change` applied according to `sign`
+// and the direction of the swap
+#[event]
+pub struct CrossTickEvent {
+    pub pool: Pubkey,
+    pub index: i32,
+    pub x_to_y: bool,
+    pub sign: bool,
+    pub liquidity_change: u128,
+    pub liquidity_after: u128,
+    pub sqrt_price: u128,
+}
+
+// Book of limit orders filled on a tick crossing, orders with a lower fill count can be claimed
+#[event]
+pub struct FillLimitOrdersEvent {
+    pub pool: Pubkey,
+    pub index: i32,
+    pub x_to_y: bool, // orders sell x for y
+    pub liquidity: u128,
+    pub fills: u64,
+}
+
+#[event]
+pub struct CreateLimitOrderEvent {
+    pub pool: Pubkey,
+    pub limit_order: Pubkey,
+    pub owner: Pubkey,
+    pub lower_tick_index: i32,
+    pub upper_tick_index: i32,
+    pub x_to_y: bool,
+    pub liquidity: u128,
+    pub amount: u64, // of the sold token
+}
+
+// Filled orders are claimed for the bought token, the others cancelled for what is left
+#[event]
+pub struct CloseLimitOrderEvent {
+    pub pool: Pubkey,
+    pub limit_order: Pubkey,
+    pub owner: Pubkey,
+    pub filled: bool,
+    pub amount_x: u64,
+    pub amount_y: u64,
+}
+
+#[event]
+pub struct SwapBoundedEvent {
+    pub pool: Pubkey,
+    pub x_to_y: bool,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub ticks_crossed: u16,
+    pub tick_limit: u16, // reached when equal to ticks_crossed, the rest was left unfilled
+}
+
+#[event]
+pub struct LaunchWindowSetEvent {
+    pub pool: Pubkey,
+    pub end_slot: u64,
+}
+
+// Emitted by the first swap or new position after the launch window
+#[event]
+pub struct LaunchWindowEndedEvent {
+    pub pool: Pubkey,
+    pub end_slot: u64,
+}
+
+// Tokens found in the reserves over the accounted balances, sent to the fee receiver
+#[event]
+pub struct SkimExcessEvent {
+    pub pool: Pubkey,
+    pub amount_x: u64,
+    pub amount_y: u64,
+}
+
+#[event]
+pub struct ClaimReferralFeesEvent {
+    pub pool: Pubkey,
+    pub referrer: Pubkey,
+    pub amount_x: u64,
+    pub amount_y: u64,
+}
+
+#[event]
+pub struct FlashLoanEvent {
+    pub pool: Pubkey,
+    pub borrower: Pubkey,
+    pub amount_x: u64,
+    pub amount_y: u64,
+    pub fee_x: u64,
+    pub fee_y: u64,
+}
+
+#[event]
+pub struct ProposeAdminEvent {
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+}
+
+#[event]
+pub struct ChangeAdminEvent {
+    pub old_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+#[event]
+pub struct WithdrawProtocolFeeEvent {
+    pub pool: Pubkey,
+    pub authority: Pubkey, // fee receiver or a withdrawer of the pool
+    pub amount_x: u64,
+    pub amount_y: u64,
+}
+
+// Emitted next to SwapEvent when the swap goes over a threshold of the pool
+#[event]
+pub struct LargeSwapEvent {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub x_to_y: bool,
+    pub amount_in: u64,
+    pub amount_out: u64,
+}
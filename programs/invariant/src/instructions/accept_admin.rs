@@ -0,0 +1,42 @@
+use crate::events::ChangeAdminEvent;
+use crate::structs::{AdminAction, AdminLog, State};
+use crate::util::get_current_slot;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(mut, seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut, seeds = [b"adminlogv1".as_ref()], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+    // a PDA such as a multisig vault signs through CPI
+    #[account(constraint = &state.load()?.pending_admin == pending_admin.key @ InvalidPendingAdmin)]
+    pub pending_admin: Signer<'info>,
+}
+
+impl<'info> AcceptAdmin<'info> {
+    pub fn handler(&self) -> ProgramResult {
+        msg!("INVARIANT: ACCEPT ADMIN");
+
+        let mut state = self.state.load_mut()?;
+        let old_admin = state.admin;
+        state.admin = self.pending_admin.key();
+        state.pending_admin = Pubkey::default();
+
+        self.admin_log.load_mut()?.append(
+            self.pending_admin.key(),
+            AdminAction::AcceptAdmin,
+            self.state.key(),
+            old_admin.to_bytes(),
+            self.pending_admin.key().to_bytes(),
+            get_current_slot(),
+        );
+        emit!(ChangeAdminEvent {
+            old_admin,
+            new_admin: self.pending_admin.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,182 @@
+use crate::events::CloseLimitOrderEvent;
+use crate::interfaces::send_tokens::SendTokens;
+use crate::math::{calculate_amount_delta, calculate_max_liquidity_per_tick};
+use crate::structs::limit_order::LimitOrder;
+use crate::structs::pool::Pool;
+use crate::structs::tick::Tick;
+use crate::structs::tickmap::Tickmap;
+use crate::util::{close_empty_tick, get_current_timestamp};
+use crate::ErrorCode::*;
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use anchor_spl::token::{Mint, TokenAccount, Transfer};
+
+// Takes an order which is not filled out of the pool, returning what is left of the sold token
+// and whatever was bought while the price was inside the range
+#[derive(Accounts)]
+pub struct CancelLimitOrder<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut,
+        close = owner,
+        seeds = [b"limitorderv1",
+        pool.key().as_ref(),
+        &limit_order.load()?.id.to_le_bytes()],
+        bump = limit_order.load()?.bump,
+        constraint = limit_order.load()?.pool == pool.key() @ InvalidLimitOrder,
+        constraint = limit_order.load()?.owner == owner.key() @ InvalidOwner
+    )]
+    pub limit_order: AccountLoader<'info, LimitOrder>,
+    #[account(mut,
+        seeds = [b"poolv1", token_x.key().as_ref(), token_y.key().as_ref(), &pool.load()?.fee_tier_fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        bump = pool.load()?.bump
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(mut,
+        constraint = tickmap.key() == pool.load()?.tickmap @ InvalidTickmap,
+        constraint = tickmap.to_account_info().owner == program_id @ InvalidTickmapOwner,
+    )]
+    pub tickmap: AccountLoader<'info, Tickmap>,
+    #[account(mut,
+        seeds = [b"tickv1", pool.key().as_ref(), &limit_order.load()?.lower_tick_index.to_le_bytes()],
+        bump = lower_tick.load()?.bump
+    )]
+    pub lower_tick: AccountLoader<'info, Tick>,
+    #[account(mut,
+        seeds = [b"tickv1", pool.key().as_ref(), &limit_order.load()?.upper_tick_index.to_le_bytes()],
+        bump = upper_tick.load()?.bump
+    )]
+    pub upper_tick: AccountLoader<'info, Tick>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(constraint = token_x.key() == pool.load()?.token_x @ InvalidTokenAccount)]
+    pub token_x: Account<'info, Mint>,
+    #[account(constraint = token_y.key() == pool.load()?.token_y @ InvalidTokenAccount)]
+    pub token_y: Account<'info, Mint>,
+    #[account(mut,
+        constraint = account_x.mint == token_x.key() @ InvalidMint,
+        constraint = &account_x.owner == owner.key @ InvalidOwner
+    )]
+    pub account_x: Box<Account<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = account_y.mint == token_y.key() @ InvalidMint,
+        constraint = &account_y.owner == owner.key @ InvalidOwner
+    )]
+    pub account_y: Box<Account<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = reserve_x.mint == token_x.key() @ InvalidMint,
+        constraint = &reserve_x.owner == program_authority.key @ InvalidOwner,
+        constraint = reserve_x.key() == pool.load()?.token_x_reserve @ InvalidTokenAccount
+    )]
+    pub reserve_x: Box<Account<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = reserve_y.mint == token_y.key() @ InvalidMint,
+        constraint = &reserve_y.owner == program_authority.key @ InvalidOwner,
+        constraint = reserve_y.key() == pool.load()?.token_y_reserve @ InvalidTokenAccount
+    )]
+    pub reserve_y: Box<Account<'info, TokenAccount>>,
+    #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
+    pub program_authority: AccountInfo<'info>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+}
+
+impl<'info> SendTokens<'info> for CancelLimitOrder<'info> {
+    fn send_x(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.reserve_x.to_account_info(),
+                to: self.account_x.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+
+    fn send_y(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.reserve_y.to_account_info(),
+                to: self.account_y.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+}
+
+impl<'info> CancelLimitOrder<'info> {
+    pub fn handler(&self) -> ProgramResult {
+        msg!("INVARIANT: CANCEL LIMIT ORDER");
+
+        let state = self.state.load()?;
+        let limit_order = self.limit_order.load()?;
+        let pool = &mut self.pool.load_mut()?;
+        let tickmap = &mut self.tickmap.load_mut()?;
+        let current_timestamp = get_current_timestamp();
+        let liquidity = limit_order.liquidity;
+
+        if !pool.liquidity.is_zero() {
+            pool.update_seconds_per_liquidity_global(current_timestamp);
+        } else {
+            pool.last_timestamp = current_timestamp;
+        }
+
+        {
+            let lower_tick = &mut self.lower_tick.load_mut()?;
+            let upper_tick = &mut self.upper_tick.load_mut()?;
+
+            let exit_tick: &mut Tick = match limit_order.x_to_y {
+                true => &mut **upper_tick,
+                false => &mut **lower_tick,
+            };
+            require!(!limit_order.is_filled(exit_tick), LimitOrderFilled);
+            exit_tick.update_limit_order_book(liquidity, limit_order.x_to_y, false)?;
+            exit_tick.limit_orders = exit_tick.limit_orders.checked_sub(1).unwrap();
+
+            let max_liquidity_per_tick = calculate_max_liquidity_per_tick(pool.tick_spacing);
+            lower_tick.update(liquidity, max_liquidity_per_tick, false, false)?;
+            upper_tick.update(liquidity, max_liquidity_per_tick, true, false)?;
+        }
+        for tick in &[
+            self.lower_tick.to_account_info(),
+            self.upper_tick.to_account_info(),
+        ] {
+            close_empty_tick(
+                tick,
+                tickmap,
+                pool.tick_spacing,
+                self.owner.to_account_info(),
+            )?;
+        }
+
+        // pool liquidity is updated when the price is inside the range
+        let (amount_x, amount_y) = calculate_amount_delta(
+            pool,
+            liquidity,
+            false,
+            limit_order.upper_tick_index,
+            limit_order.lower_tick_index,
+        )?;
+
+        emit!(CloseLimitOrderEvent {
+            pool: self.pool.key(),
+            limit_order: self.limit_order.key(),
+            owner: self.owner.key(),
+            filled: false,
+            amount_x: amount_x.0,
+            amount_y: amount_y.0,
+        });
+
+        pool.sub_reserves(amount_x, amount_y);
+        let signer: &[&[&[u8]]] = get_signer!(state.nonce);
+        token::transfer(self.send_x().with_signer(signer), amount_x.0)?;
+        token::transfer(self.send_y().with_signer(signer), amount_y.0)?;
+        pool.strict_check_reserves(
+            &self.reserve_x.to_account_info(),
+            &self.reserve_y.to_account_info(),
+        );
+        Ok(())
+    }
+}
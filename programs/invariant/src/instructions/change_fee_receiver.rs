@@ -1,4 +1,6 @@
-use crate::structs::{Pool, State};
+use crate::events::ChangeFeeReceiverEvent;
+use crate::structs::{AdminAction, AdminLog, Pool, State};
+use crate::util::get_current_slot;
 use crate::ErrorCode::*;
 use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
@@ -7,8 +9,10 @@ use anchor_spl::token::Mint;
 pub struct ChangeFeeReceiver<'info> {
     #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
     pub state: AccountLoader<'info, State>,
+    #[account(mut, seeds = [b"adminlogv1".as_ref()], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
     #[account(mut,
-        seeds = [b"poolv1", token_x.to_account_info().key.as_ref(), token_y.to_account_info().key.as_ref(), &pool.load()?.fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        seeds = [b"poolv1", token_x.to_account_info().key.as_ref(), token_y.to_account_info().key.as_ref(), &pool.load()?.fee_tier_fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
         bump = pool.load()?.bump
     )]
     pub pool: AccountLoader<'info, Pool>,
@@ -24,8 +28,23 @@ pub struct ChangeFeeReceiver<'info> {
 impl<'info> ChangeFeeReceiver<'info> {
     pub fn handler(&self) -> ProgramResult {
         let mut pool = self.pool.load_mut()?;
+        let old_fee_receiver = pool.fee_receiver;
         pool.fee_receiver = self.fee_receiver.key();
 
+        self.admin_log.load_mut()?.append(
+            self.admin.key(),
+            AdminAction::ChangeFeeReceiver,
+            self.pool.key(),
+            old_fee_receiver.to_bytes(),
+            self.fee_receiver.key().to_bytes(),
+            get_current_slot(),
+        );
+        emit!(ChangeFeeReceiverEvent {
+            pool: self.pool.key(),
+            old_fee_receiver,
+            new_fee_receiver: self.fee_receiver.key(),
+        });
+
         Ok(())
     }
 }
@@ -1,5 +1,7 @@
 use crate::decimals::*;
-use crate::structs::{Pool, State};
+use crate::events::ChangeProtocolFeeEvent;
+use crate::structs::{log_value, AdminAction, AdminLog, Pool, ProtocolFeeShare, State};
+use crate::util::get_current_slot;
 use crate::ErrorCode::*;
 use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
@@ -8,8 +10,10 @@ use anchor_spl::token::Mint;
 pub struct ChangeProtocolFee<'info> {
     #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump )]
     pub state: AccountLoader<'info, State>,
+    #[account(mut, seeds = [b"adminlogv1".as_ref()], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
     #[account(mut,
-        seeds = [b"poolv1", token_x.to_account_info().key.as_ref(), token_y.to_account_info().key.as_ref(), &pool.load()?.fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        seeds = [b"poolv1", token_x.to_account_info().key.as_ref(), token_y.to_account_info().key.as_ref(), &pool.load()?.fee_tier_fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
         bump = pool.load()?.bump
     )]
     pub pool: AccountLoader<'info, Pool>,
@@ -25,13 +29,27 @@ pub struct ChangeProtocolFee<'info> {
 
 impl<'info> ChangeProtocolFee<'info> {
     pub fn handler(&self, protocol_fee: FixedPoint) -> ProgramResult {
-        require!(
-            protocol_fee <= FixedPoint::from_integer(1),
-            InvalidProtocolFee
-        );
+        msg!("INVARIANT: CHANGE PROTOCOL FEE");
+        let protocol_fee = ProtocolFeeShare::new(protocol_fee)?.get();
         let pool = &mut self.pool.load_mut()?;
+        let old_protocol_fee = pool.protocol_fee;
         pool.protocol_fee = protocol_fee;
 
+        self.admin_log.load_mut()?.append(
+            self.admin.key(),
+            AdminAction::ChangeProtocolFee,
+            self.pool.key(),
+            log_value(old_protocol_fee.v),
+            log_value(protocol_fee.v),
+            get_current_slot(),
+        );
+
+        emit!(ChangeProtocolFeeEvent {
+            pool: self.pool.key(),
+            old_protocol_fee: old_protocol_fee.v,
+            new_protocol_fee: protocol_fee.v,
+        });
+
         Ok(())
     }
 }
@@ -0,0 +1,47 @@
+use crate::structs::{log_value, AdminAction, AdminLog, Pool, State};
+use crate::util::{get_current_slot, get_current_timestamp};
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+#[derive(Accounts)]
+pub struct ChangeTickEmaHalfLife<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut, seeds = [b"adminlogv1".as_ref()], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+    #[account(mut,
+        seeds = [b"poolv1", token_x.to_account_info().key.as_ref(), token_y.to_account_info().key.as_ref(), &pool.load()?.fee_tier_fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        bump = pool.load()?.bump
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(constraint = token_x.to_account_info().key == &pool.load()?.token_x @ InvalidTokenAccount) ]
+    pub token_x: Account<'info, Mint>,
+    #[account(constraint = token_y.to_account_info().key == &pool.load()?.token_y @ InvalidTokenAccount)]
+    pub token_y: Account<'info, Mint>,
+    #[account(constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+}
+
+impl<'info> ChangeTickEmaHalfLife<'info> {
+    pub fn handler(&self, half_life: u32) -> ProgramResult {
+        msg!("INVARIANT: CHANGE TICK EMA HALF LIFE");
+
+        let pool = &mut self.pool.load_mut()?;
+        // settle the average with the old half-life first
+        pool.update_tick_ema(get_current_timestamp());
+        let old_half_life = pool.tick_ema_half_life;
+        pool.tick_ema_half_life = half_life;
+
+        self.admin_log.load_mut()?.append(
+            self.admin.key(),
+            AdminAction::ChangeTickEmaHalfLife,
+            self.pool.key(),
+            log_value(old_half_life as u128),
+            log_value(half_life as u128),
+            get_current_slot(),
+        );
+
+        Ok(())
+    }
+}
@@ -1,7 +1,10 @@
 use crate::decimals::*;
+use crate::events::ClaimFeeEvent;
 use crate::interfaces::send_tokens::SendTokens;
 use crate::structs::pool::Pool;
 use crate::structs::position::Position;
+use crate::structs::position_nft::holds_position_nft;
+use crate::structs::rebate_schedule::{find_rebate_schedule, RebateSchedule};
 use crate::structs::tick::Tick;
 use crate::util::*;
 use crate::ErrorCode::*;
@@ -16,7 +19,7 @@ pub struct ClaimFee<'info> {
     #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
     pub state: AccountLoader<'info, State>,
     #[account(mut,
-        seeds = [b"poolv1", token_x.key().as_ref(), token_y.key().as_ref(), &pool.load()?.fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        seeds = [b"poolv1", token_x.key().as_ref(), token_y.key().as_ref(), &pool.load()?.fee_tier_fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
         bump = pool.load()?.bump
     )]
     pub pool: AccountLoader<'info, Pool>,
@@ -39,19 +42,26 @@ pub struct ClaimFee<'info> {
         constraint = upper_tick_index == position.load()?.upper_tick_index @ WrongTick
     )]
     pub upper_tick: AccountLoader<'info, Tick>,
-    pub owner: Signer<'info>,
+    pub owner: AccountInfo<'info>,
+    // signer, or a session key with claim permission (see `session` access control)
+    pub holder: AccountInfo<'info>,
+    #[account(
+        constraint = holds_position_nft(&*position.load()?, &position_nft_account) @ InvalidOwner,
+        constraint = &position_nft_account.owner == holder.key @ InvalidOwner
+    )]
+    pub position_nft_account: Box<Account<'info, TokenAccount>>,
     #[account(constraint = token_x.key() == pool.load()?.token_x @ InvalidTokenAccount)]
     pub token_x: Account<'info, Mint>,
     #[account(constraint = token_y.key() == pool.load()?.token_y @ InvalidTokenAccount)]
     pub token_y: Account<'info, Mint>,
     #[account(mut,
         constraint = account_x.mint == token_x.key() @ InvalidMint,
-        constraint = &account_x.owner == owner.key @ InvalidOwner,
+        constraint = &account_x.owner == holder.key @ InvalidOwner,
     )]
     pub account_x: Box<Account<'info, TokenAccount>>,
     #[account(mut,
         constraint = account_y.mint == token_y.key() @ InvalidMint,
-        constraint = &account_y.owner == owner.key @ InvalidOwner
+        constraint = &account_y.owner == holder.key @ InvalidOwner
     )]
     pub account_y: Box<Account<'info, TokenAccount>>,
     #[account(mut,
@@ -97,44 +107,129 @@ impl<'info> interfaces::SendTokens<'info> for ClaimFee<'info> {
 }
 
 impl<'info> ClaimFee<'info> {
-    pub fn handler(&self) -> ProgramResult {
+    // Claims fees of the selected sides only, fees of the other one stay owed to the position
+    pub fn handler(
+        &self,
+        remaining_accounts: &[AccountInfo],
+        claim_x: bool,
+        claim_y: bool,
+    ) -> ProgramResult {
         msg!("INVARIANT: CLAIM FEE");
+        require!(claim_x || claim_y, NoSideSelected);
 
         let state = self.state.load()?;
         let pool = &mut self.pool.load_mut()?;
         let position = &mut self.position.load_mut()?;
         let lower_tick = &mut self.lower_tick.load_mut()?;
         let upper_tick = &mut self.upper_tick.load_mut()?;
-        let current_timestamp = get_current_timestamp();
-
-        check_ticks(lower_tick.index, upper_tick.index, pool.tick_spacing)?;
-
-        position
-            .modify(
-                pool,
-                upper_tick,
-                lower_tick,
-                Liquidity::new(0),
-                true,
-                current_timestamp,
-            )
-            .unwrap();
-
-        let fee_to_collect_x = TokenAmount::from_decimal(position.tokens_owed_x);
-        let fee_to_collect_y = TokenAmount::from_decimal(position.tokens_owed_y);
-        position.tokens_owed_x =
-            position.tokens_owed_x - FixedPoint::from_decimal(fee_to_collect_x);
-        position.tokens_owed_y =
-            position.tokens_owed_y - FixedPoint::from_decimal(fee_to_collect_y);
+        let rebate_schedule = find_rebate_schedule(remaining_accounts);
+        let rebate_schedule = match &rebate_schedule {
+            Some(rebate_schedule) => Some(rebate_schedule.load()?),
+            None => None,
+        };
 
-        let signer: &[&[&[u8]]] = get_signer!(state.nonce);
+        let (amount_x, amount_y) = claim_position_fee(
+            pool,
+            self.pool.key(),
+            position,
+            self.position.key(),
+            lower_tick,
+            upper_tick,
+            rebate_schedule.as_deref(),
+            claim_x,
+            claim_y,
+            get_current_timestamp(),
+        )?;
 
-        let cpi_ctx_x = self.send_x().with_signer(signer);
-        let cpi_ctx_y = self.send_y().with_signer(signer);
+        let signer: &[&[&[u8]]] = get_signer!(state.nonce);
 
-        token::transfer(cpi_ctx_x, fee_to_collect_x.0)?;
-        token::transfer(cpi_ctx_y, fee_to_collect_y.0)?;
+        if claim_x {
+            token::transfer(self.send_x().with_signer(signer), amount_x.0)?;
+        }
+        if claim_y {
+            token::transfer(self.send_y().with_signer(signer), amount_y.0)?;
+        }
+        pool.strict_check_reserves(
+            &self.reserve_x.to_account_info(),
+            &self.reserve_y.to_account_info(),
+        );
 
         Ok(())
     }
 }
+
+// Brings the position up to date and takes its fees of the selected sides, with the rebate
+// moved out of the protocol fee of the pool. Returns the amounts owed to the owner.
+#[allow(clippy::too_many_arguments)]
+pub fn claim_position_fee(
+    pool: &mut Pool,
+    pool_key: Pubkey,
+    position: &mut Position,
+    position_key: Pubkey,
+    lower_tick: &mut Tick,
+    upper_tick: &mut Tick,
+    rebate_schedule: Option<&RebateSchedule>,
+    claim_x: bool,
+    claim_y: bool,
+    current_timestamp: u64,
+) -> Result<(TokenAmount, TokenAmount)> {
+    check_ticks(lower_tick.index, upper_tick.index, pool.tick_spacing)?;
+
+    let fee_growth_inside_x_before = position.fee_growth_inside_x;
+    let fee_growth_inside_y_before = position.fee_growth_inside_y;
+    position
+        .modify(
+            pool,
+            upper_tick,
+            lower_tick,
+            Liquidity::new(0),
+            true,
+            current_timestamp,
+        )
+        .unwrap();
+
+    let (fee_to_collect_x, fee_to_collect_y) = position.collect_fees(claim_x, claim_y);
+
+    let (rebate_x, rebate_y) = match rebate_schedule {
+        Some(rebate_schedule) => (
+            rebate_schedule.calculate_rebate(
+                position.maker_fees_x,
+                fee_to_collect_x,
+                TokenAmount(pool.fee_protocol_token_x),
+            ),
+            rebate_schedule.calculate_rebate(
+                position.maker_fees_y,
+                fee_to_collect_y,
+                TokenAmount(pool.fee_protocol_token_y),
+            ),
+        ),
+        None => (TokenAmount(0), TokenAmount(0)),
+    };
+    pool.fee_protocol_token_x -= rebate_x.0;
+    pool.fee_protocol_token_y -= rebate_y.0;
+
+    emit!(ClaimFeeEvent {
+        pool: pool_key,
+        position: position_key,
+        owner: position.owner,
+        liquidity: position.liquidity.v,
+        fee_growth_inside_x_before: fee_growth_inside_x_before.v,
+        fee_growth_inside_y_before: fee_growth_inside_y_before.v,
+        fee_growth_inside_x_after: position.fee_growth_inside_x.v,
+        fee_growth_inside_y_after: position.fee_growth_inside_y.v,
+        fee_growth_global_x: pool.fee_growth_global_x.v,
+        fee_growth_global_y: pool.fee_growth_global_y.v,
+        protocol_fee: pool.protocol_fee.v,
+        fee_x: fee_to_collect_x.0,
+        fee_y: fee_to_collect_y.0,
+        rebate_x: rebate_x.0,
+        rebate_y: rebate_y.0,
+    });
+
+    let (claimed_x, claimed_y) = (fee_to_collect_x + rebate_x, fee_to_collect_y + rebate_y);
+    position.total_claimed_x = position.total_claimed_x.checked_add(claimed_x.0).unwrap();
+    position.total_claimed_y = position.total_claimed_y.checked_add(claimed_y.0).unwrap();
+    pool.sub_reserves(claimed_x, claimed_y);
+
+    Ok((claimed_x, claimed_y))
+}
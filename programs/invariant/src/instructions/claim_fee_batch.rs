@@ -0,0 +1,170 @@
+use crate::decimals::*;
+use crate::instructions::claim_fee::claim_position_fee;
+use crate::interfaces::send_tokens::SendTokens;
+use crate::structs::pool::Pool;
+use crate::structs::position::Position;
+use crate::structs::position_nft::holds_position_nft;
+use crate::structs::rebate_schedule::find_rebate_schedule;
+use crate::structs::tick::Tick;
+use crate::util::*;
+use crate::ErrorCode::*;
+use crate::*;
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount, Transfer};
+
+pub const MAX_POSITIONS_IN_BATCH: usize = 16;
+// position, lower tick, upper tick and the position NFT account of the holder, the positions
+// are optionally followed by a rebate schedule
+const ACCOUNTS_PER_POSITION: usize = 4;
+
+#[derive(Accounts)]
+pub struct ClaimFeeBatch<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut,
+        seeds = [b"poolv1", token_x.key().as_ref(), token_y.key().as_ref(), &pool.load()?.fee_tier_fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        bump = pool.load()?.bump
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+    pub holder: Signer<'info>,
+    #[account(constraint = token_x.key() == pool.load()?.token_x @ InvalidTokenAccount)]
+    pub token_x: Account<'info, Mint>,
+    #[account(constraint = token_y.key() == pool.load()?.token_y @ InvalidTokenAccount)]
+    pub token_y: Account<'info, Mint>,
+    #[account(mut,
+        constraint = account_x.mint == token_x.key() @ InvalidMint,
+        constraint = &account_x.owner == holder.key @ InvalidOwner,
+    )]
+    pub account_x: Box<Account<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = account_y.mint == token_y.key() @ InvalidMint,
+        constraint = &account_y.owner == holder.key @ InvalidOwner
+    )]
+    pub account_y: Box<Account<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = reserve_x.mint == token_x.key() @ InvalidMint,
+        constraint = &reserve_x.owner == program_authority.key @ InvalidAuthority,
+        constraint = reserve_x.key() == pool.load()?.token_x_reserve @ InvalidTokenAccount
+    )]
+    pub reserve_x: Box<Account<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = reserve_y.mint == token_y.key() @ InvalidMint,
+        constraint = &reserve_y.owner == program_authority.key @ InvalidAuthority,
+        constraint = reserve_y.key() == pool.load()?.token_y_reserve @ InvalidTokenAccount
+    )]
+    pub reserve_y: Box<Account<'info, TokenAccount>>,
+    #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
+    pub program_authority: AccountInfo<'info>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+}
+
+impl<'info> interfaces::SendTokens<'info> for ClaimFeeBatch<'info> {
+    fn send_x(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.reserve_x.to_account_info(),
+                to: self.account_x.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+
+    fn send_y(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.reserve_y.to_account_info(),
+                to: self.account_y.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+}
+
+impl<'info> ClaimFeeBatch<'info> {
+    // Claims fees of many positions of the holder in the pool, paid out with a single transfer
+    // per token. Every position emits its own ClaimFeeEvent.
+    pub fn handler(
+        &self,
+        remaining_accounts: &[AccountInfo],
+        positions_count: u8,
+    ) -> ProgramResult {
+        msg!("INVARIANT: CLAIM FEE BATCH");
+        let positions_count = positions_count as usize;
+        require!(
+            positions_count != 0 && positions_count <= MAX_POSITIONS_IN_BATCH,
+            InvalidBatchSize
+        );
+        require!(
+            remaining_accounts.len() >= positions_count * ACCOUNTS_PER_POSITION,
+            InvalidBatchSize
+        );
+        let (position_accounts, rest) =
+            remaining_accounts.split_at(positions_count * ACCOUNTS_PER_POSITION);
+
+        let state = self.state.load()?;
+        let pool = &mut self.pool.load_mut()?;
+        let rebate_schedule = find_rebate_schedule(rest);
+        let rebate_schedule = match &rebate_schedule {
+            Some(rebate_schedule) => Some(rebate_schedule.load()?),
+            None => None,
+        };
+        let current_timestamp = get_current_timestamp();
+
+        let mut total_x = TokenAmount(0);
+        let mut total_y = TokenAmount(0);
+        for accounts in position_accounts.chunks(ACCOUNTS_PER_POSITION) {
+            let position_loader = AccountLoader::<'_, Position>::try_from(&accounts[0])?;
+            let lower_tick_loader = AccountLoader::<'_, Tick>::try_from(&accounts[1])?;
+            let upper_tick_loader = AccountLoader::<'_, Tick>::try_from(&accounts[2])?;
+            let nft_account = Account::<'_, TokenAccount>::try_from(&accounts[3])?;
+            let position = &mut position_loader.load_mut()?;
+            let lower_tick = &mut lower_tick_loader.load_mut()?;
+            let upper_tick = &mut upper_tick_loader.load_mut()?;
+
+            require!(holds_position_nft(position, &nft_account), InvalidOwner);
+            require!(nft_account.owner == self.holder.key(), InvalidOwner);
+            require!(position.pool == self.pool.key(), InvalidPoolAddress);
+            require!(
+                lower_tick.pool == self.pool.key() && { lower_tick.index } == {
+                    position.lower_tick_index
+                },
+                WrongTick
+            );
+            require!(
+                upper_tick.pool == self.pool.key() && { upper_tick.index } == {
+                    position.upper_tick_index
+                },
+                WrongTick
+            );
+
+            let (amount_x, amount_y) = claim_position_fee(
+                pool,
+                self.pool.key(),
+                position,
+                *accounts[0].key,
+                lower_tick,
+                upper_tick,
+                rebate_schedule.as_deref(),
+                true,
+                true,
+                current_timestamp,
+            )?;
+            total_x = total_x + amount_x;
+            total_y = total_y + amount_y;
+        }
+
+        let signer: &[&[&[u8]]] = get_signer!(state.nonce);
+        token::transfer(self.send_x().with_signer(signer), total_x.0)?;
+        token::transfer(self.send_y().with_signer(signer), total_y.0)?;
+        pool.strict_check_reserves(
+            &self.reserve_x.to_account_info(),
+            &self.reserve_y.to_account_info(),
+        );
+
+        Ok(())
+    }
+}
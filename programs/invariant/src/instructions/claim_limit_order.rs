@@ -0,0 +1,149 @@
+use crate::events::CloseLimitOrderEvent;
+use crate::interfaces::send_tokens::SendTokens;
+use crate::structs::limit_order::LimitOrder;
+use crate::structs::pool::Pool;
+use crate::structs::tick::Tick;
+use crate::structs::tickmap::Tickmap;
+use crate::util::close_empty_tick;
+use crate::ErrorCode::*;
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use anchor_spl::token::{Mint, TokenAccount, Transfer};
+
+// Settles a filled order, sending the bought token to the owner. Anyone can crank it, the
+// tokens and the rent go to the owner only.
+#[derive(Accounts)]
+pub struct ClaimLimitOrder<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut,
+        close = owner,
+        seeds = [b"limitorderv1",
+        pool.key().as_ref(),
+        &limit_order.load()?.id.to_le_bytes()],
+        bump = limit_order.load()?.bump,
+        constraint = limit_order.load()?.pool == pool.key() @ InvalidLimitOrder
+    )]
+    pub limit_order: AccountLoader<'info, LimitOrder>,
+    #[account(mut,
+        seeds = [b"poolv1", token_x.key().as_ref(), token_y.key().as_ref(), &pool.load()?.fee_tier_fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        bump = pool.load()?.bump
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(mut,
+        constraint = tickmap.key() == pool.load()?.tickmap @ InvalidTickmap,
+        constraint = tickmap.to_account_info().owner == program_id @ InvalidTickmapOwner,
+    )]
+    pub tickmap: AccountLoader<'info, Tickmap>,
+    // kept open by the orders waiting to be claimed through it
+    #[account(mut,
+        seeds = [b"tickv1", pool.key().as_ref(), &limit_order.load()?.exit_tick_index().to_le_bytes()],
+        bump = exit_tick.load()?.bump
+    )]
+    pub exit_tick: AccountLoader<'info, Tick>,
+    // the fill may have emptied it, so it may be closed and only the address is checked
+    #[account(mut,
+        seeds = [b"tickv1", pool.key().as_ref(), &limit_order.load()?.entry_tick_index().to_le_bytes()],
+        bump
+    )]
+    pub entry_tick: AccountInfo<'info>,
+    #[account(mut, constraint = limit_order.load()?.owner == owner.key() @ InvalidOwner)]
+    pub owner: AccountInfo<'info>,
+    #[account(constraint = token_x.key() == pool.load()?.token_x @ InvalidTokenAccount)]
+    pub token_x: Account<'info, Mint>,
+    #[account(constraint = token_y.key() == pool.load()?.token_y @ InvalidTokenAccount)]
+    pub token_y: Account<'info, Mint>,
+    #[account(mut,
+        constraint = account_x.mint == token_x.key() @ InvalidMint,
+        constraint = &account_x.owner == owner.key @ InvalidOwner
+    )]
+    pub account_x: Box<Account<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = account_y.mint == token_y.key() @ InvalidMint,
+        constraint = &account_y.owner == owner.key @ InvalidOwner
+    )]
+    pub account_y: Box<Account<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = reserve_x.mint == token_x.key() @ InvalidMint,
+        constraint = &reserve_x.owner == program_authority.key @ InvalidOwner,
+        constraint = reserve_x.key() == pool.load()?.token_x_reserve @ InvalidTokenAccount
+    )]
+    pub reserve_x: Box<Account<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = reserve_y.mint == token_y.key() @ InvalidMint,
+        constraint = &reserve_y.owner == program_authority.key @ InvalidOwner,
+        constraint = reserve_y.key() == pool.load()?.token_y_reserve @ InvalidTokenAccount
+    )]
+    pub reserve_y: Box<Account<'info, TokenAccount>>,
+    #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
+    pub program_authority: AccountInfo<'info>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+}
+
+impl<'info> SendTokens<'info> for ClaimLimitOrder<'info> {
+    fn send_x(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.reserve_x.to_account_info(),
+                to: self.account_x.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+
+    fn send_y(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.reserve_y.to_account_info(),
+                to: self.account_y.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+}
+
+impl<'info> ClaimLimitOrder<'info> {
+    pub fn handler(&self) -> ProgramResult {
+        msg!("INVARIANT: CLAIM LIMIT ORDER");
+
+        let state = self.state.load()?;
+        let limit_order = self.limit_order.load()?;
+        let pool = &mut self.pool.load_mut()?;
+        let tickmap = &mut self.tickmap.load_mut()?;
+
+        {
+            let exit_tick = &mut self.exit_tick.load_mut()?;
+            require!(limit_order.is_filled(exit_tick), LimitOrderNotFilled);
+            exit_tick.limit_orders = exit_tick.limit_orders.checked_sub(1).unwrap();
+        }
+        // the order account is closed by its own constraint
+        for tick in &[self.exit_tick.to_account_info(), self.entry_tick.clone()] {
+            close_empty_tick(tick, tickmap, pool.tick_spacing, self.owner.clone())?;
+        }
+
+        let (amount_x, amount_y) = limit_order.filled_amounts();
+
+        emit!(CloseLimitOrderEvent {
+            pool: self.pool.key(),
+            limit_order: self.limit_order.key(),
+            owner: self.owner.key(),
+            filled: true,
+            amount_x: amount_x.0,
+            amount_y: amount_y.0,
+        });
+
+        pool.sub_reserves(amount_x, amount_y);
+        let signer: &[&[&[u8]]] = get_signer!(state.nonce);
+        token::transfer(self.send_x().with_signer(signer), amount_x.0)?;
+        token::transfer(self.send_y().with_signer(signer), amount_y.0)?;
+        pool.strict_check_reserves(
+            &self.reserve_x.to_account_info(),
+            &self.reserve_y.to_account_info(),
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+use crate::events::ClaimReferralFeesEvent;
+use crate::interfaces::SendTokens;
+use crate::structs::{Pool, ReferralEarnings, State};
+use crate::ErrorCode::*;
+use crate::SEED;
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+pub struct ClaimReferralFees<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut,
+        seeds = [b"referralv1", pool.key().as_ref(), referrer.key.as_ref()],
+        bump = referral_earnings.load()?.bump
+    )]
+    pub referral_earnings: AccountLoader<'info, ReferralEarnings>,
+    #[account(mut)]
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(mut,
+        constraint = account_x.mint == pool.load()?.token_x @ InvalidMint
+    )]
+    pub account_x: Box<Account<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = account_y.mint == pool.load()?.token_y @ InvalidMint
+    )]
+    pub account_y: Box<Account<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = &reserve_x.owner == program_authority.key @ InvalidAuthority,
+        constraint = reserve_x.key() == pool.load()?.token_x_reserve @ InvalidTokenAccount
+    )]
+    pub reserve_x: Account<'info, TokenAccount>,
+    #[account(mut,
+        constraint = &reserve_y.owner == program_authority.key @ InvalidAuthority,
+        constraint = reserve_y.key() == pool.load()?.token_y_reserve @ InvalidTokenAccount
+    )]
+    pub reserve_y: Account<'info, TokenAccount>,
+    pub referrer: Signer<'info>,
+    #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
+    pub program_authority: AccountInfo<'info>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+}
+
+impl<'info> SendTokens<'info> for ClaimReferralFees<'info> {
+    fn send_x(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.reserve_x.to_account_info(),
+                to: self.account_x.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+
+    fn send_y(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.reserve_y.to_account_info(),
+                to: self.account_y.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+}
+
+impl<'info> ClaimReferralFees<'info> {
+    pub fn handler(&self) -> ProgramResult {
+        msg!("INVARIANT: CLAIM REFERRAL FEES");
+
+        let state = self.state.load()?;
+        let mut referral_earnings = self.referral_earnings.load_mut()?;
+
+        let signer: &[&[&[u8]]] = get_signer!(state.nonce);
+
+        if referral_earnings.amount_x != 0 {
+            token::transfer(
+                self.send_x().with_signer(signer),
+                referral_earnings.amount_x,
+            )?;
+        }
+        if referral_earnings.amount_y != 0 {
+            token::transfer(
+                self.send_y().with_signer(signer),
+                referral_earnings.amount_y,
+            )?;
+        }
+
+        self.pool.load_mut()?.sub_reserves(
+            TokenAmount(referral_earnings.amount_x),
+            TokenAmount(referral_earnings.amount_y),
+        );
+        emit!(ClaimReferralFeesEvent {
+            pool: self.pool.key(),
+            referrer: self.referrer.key(),
+            amount_x: referral_earnings.amount_x,
+            amount_y: referral_earnings.amount_y,
+        });
+        referral_earnings.amount_x = 0;
+        referral_earnings.amount_y = 0;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+use crate::args::{AmountSpec, Direction};
+use crate::decimals::*;
+use crate::instructions::swap::{swap_through_ticks, SwapTotals};
+use crate::structs::pool::Pool;
+use crate::structs::tickmap::Tickmap;
+use crate::structs::State;
+use crate::util::get_current_timestamp;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+#[derive(Accounts)]
+pub struct ConvertProtocolFees<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump,
+        constraint = !state.load()?.is_paused @ ProtocolPaused
+    )]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut,
+        seeds = [b"poolv1", token_x.key().as_ref(), token_y.key().as_ref(), &pool.load()?.fee_tier_fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        bump = pool.load()?.bump
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(
+        constraint = tickmap.to_account_info().key == &pool.load()?.tickmap @ InvalidTickmap,
+        constraint = tickmap.to_account_info().owner == program_id @ InvalidTickmapOwner
+    )]
+    pub tickmap: AccountLoader<'info, Tickmap>,
+    #[account(constraint = token_x.key() == pool.load()?.token_x @ InvalidTokenAccount)]
+    pub token_x: Account<'info, Mint>,
+    #[account(constraint = token_y.key() == pool.load()?.token_y @ InvalidTokenAccount)]
+    pub token_y: Account<'info, Mint>,
+    #[account(constraint = &pool.load()?.fee_receiver == authority.key @ InvalidAuthority)]
+    pub authority: Signer<'info>,
+}
+
+impl<'info> ConvertProtocolFees<'info> {
+    // Swaps protocol fee of one token into the other through the pool itself.
+    // Tokens never leave the reserves, only the protocol fee counters change.
+    // Price impact is bounded by `sqrt_price_limit`, crossed ticks go in remaining accounts.
+    pub fn handler(
+        ctx: Context<'_, '_, '_, 'info, ConvertProtocolFees<'info>>,
+        target_is_x: bool,
+        sqrt_price_limit: u128,
+    ) -> ProgramResult {
+        msg!("INVARIANT: CONVERT PROTOCOL FEES");
+
+        let mut pool = ctx.accounts.pool.load_mut()?;
+        let tickmap = ctx.accounts.tickmap.load()?;
+
+        let x_to_y = !target_is_x;
+        let amount = match x_to_y {
+            true => pool.fee_protocol_token_x,
+            false => pool.fee_protocol_token_y,
+        };
+        require!(amount != 0, ZeroAmount);
+
+        let SwapTotals {
+            amount_in,
+            amount_out,
+            ..
+        } = swap_through_ticks(
+            &mut pool,
+            &tickmap,
+            &ctx.accounts.pool.key(),
+            ctx.remaining_accounts,
+            Direction::from(x_to_y),
+            TokenAmount(amount),
+            AmountSpec::In,
+            Price::new(sqrt_price_limit),
+            FixedPoint::from_integer(0),
+            get_current_timestamp(),
+            None,
+        )?;
+        require!(amount_out.0 != 0, NoGainSwap);
+
+        // protocol share of the swap fee has been added to the input side in the meantime
+        match x_to_y {
+            true => {
+                pool.fee_protocol_token_x = pool.fee_protocol_token_x - amount_in.0;
+                pool.fee_protocol_token_y = pool.fee_protocol_token_y + amount_out.0;
+            }
+            false => {
+                pool.fee_protocol_token_y = pool.fee_protocol_token_y - amount_in.0;
+                pool.fee_protocol_token_x = pool.fee_protocol_token_x + amount_out.0;
+            }
+        }
+        pool.strict_check();
+
+        Ok(())
+    }
+}
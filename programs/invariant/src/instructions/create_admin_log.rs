@@ -0,0 +1,31 @@
+use crate::structs::{AdminLog, State};
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+#[derive(Accounts)]
+pub struct CreateAdminLog<'info> {
+    #[account(init, seeds = [b"adminlogv1".as_ref()], bump, payer = admin)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut, constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> CreateAdminLog<'info> {
+    pub fn handler(&self, bump: u8) -> ProgramResult {
+        msg!("INVARIANT: CREATE ADMIN LOG");
+
+        let admin_log = &mut self.admin_log.load_init()?;
+        **admin_log = AdminLog {
+            bump,
+            ..Default::default()
+        };
+
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use crate::ErrorCode::*;
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token;
+use anchor_spl::token::Mint;
+
+// Test token of devnet builds, the address depends only on the index so environments set up
+// by partners line up with ours
+#[derive(Accounts)]
+#[instruction(index: u8, decimals: u8)]
+pub struct CreateFaucetMint<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(init,
+        seeds = [b"faucetv1", &[index]],
+        bump, payer = payer,
+        mint::decimals = decimals,
+        mint::authority = program_authority
+    )]
+    pub mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
+    pub program_authority: AccountInfo<'info>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> CreateFaucetMint<'info> {
+    pub fn handler(&self) -> ProgramResult {
+        msg!("INVARIANT: CREATE FAUCET MINT");
+        Ok(())
+    }
+}
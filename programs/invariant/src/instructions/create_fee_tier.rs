@@ -1,20 +1,27 @@
+use crate::args::CreateFeeTierArgs;
 use crate::decimals::*;
 use crate::structs::fee_tier::FeeTier;
+use crate::structs::{log_value, AdminAction, AdminLog, FeeTierRegistry};
+use crate::util::get_current_slot;
 use crate::ErrorCode::*;
 use crate::*;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::system_program;
 
 #[derive(Accounts)]
-#[instruction(fee: u128, tick_spacing: u16)]
+#[instruction(args: CreateFeeTierArgs)]
 pub struct CreateFeeTier<'info> {
     #[account(init,
-        seeds = [b"feetierv1", program_id.as_ref(), &fee.to_le_bytes(), &tick_spacing.to_le_bytes()],
+        seeds = [b"feetierv1", program_id.as_ref(), &args.fee.to_le_bytes(), &args.tick_spacing.to_le_bytes()],
         bump, payer = admin
     )]
     pub fee_tier: AccountLoader<'info, FeeTier>,
+    #[account(mut, seeds = [b"feetierregistryv1".as_ref()], bump = fee_tier_registry.load()?.bump)]
+    pub fee_tier_registry: AccountLoader<'info, FeeTierRegistry>,
     #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
     pub state: AccountLoader<'info, State>,
+    #[account(mut, seeds = [b"adminlogv1".as_ref()], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
     #[account(mut, constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
     pub admin: Signer<'info>,
     pub rent: Sysvar<'info, Rent>,
@@ -35,6 +42,18 @@ impl<'info> CreateFeeTier<'info> {
             tick_spacing,
             bump,
         };
+        self.fee_tier_registry
+            .load_mut()?
+            .register(fee, tick_spacing)?;
+
+        self.admin_log.load_mut()?.append(
+            self.admin.key(),
+            AdminAction::CreateFeeTier,
+            self.fee_tier.key(),
+            log_value(0),
+            log_value(fee.v),
+            get_current_slot(),
+        );
 
         Ok(())
     }
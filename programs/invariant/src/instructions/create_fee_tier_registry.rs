@@ -0,0 +1,51 @@
+use crate::structs::{log_value, AdminAction, AdminLog, FeeTier, FeeTierRegistry, State};
+use crate::util::get_current_slot;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+#[derive(Accounts)]
+pub struct CreateFeeTierRegistry<'info> {
+    #[account(init, seeds = [b"feetierregistryv1".as_ref()], bump, payer = admin)]
+    pub fee_tier_registry: AccountLoader<'info, FeeTierRegistry>,
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut, seeds = [b"adminlogv1".as_ref()], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+    #[account(mut, constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> CreateFeeTierRegistry<'info> {
+    // fee tiers created before the registry are passed as remaining accounts to be listed
+    pub fn handler(&self, remaining_accounts: &[AccountInfo], bump: u8) -> ProgramResult {
+        msg!("INVARIANT: CREATE FEE TIER REGISTRY");
+
+        let fee_tier_registry = &mut self.fee_tier_registry.load_init()?;
+        **fee_tier_registry = FeeTierRegistry {
+            bump,
+            ..Default::default()
+        };
+
+        for account in remaining_accounts {
+            let fee_tier = AccountLoader::<FeeTier>::try_from(account)?;
+            let fee_tier = fee_tier.load()?;
+            fee_tier_registry.register(fee_tier.fee, fee_tier.tick_spacing)?;
+        }
+
+        // the new value is the number of fee tiers listed on creation
+        self.admin_log.load_mut()?.append(
+            self.admin.key(),
+            AdminAction::CreateFeeTierRegistry,
+            self.fee_tier_registry.key(),
+            log_value(0),
+            log_value(remaining_accounts.len() as u128),
+            get_current_slot(),
+        );
+
+        Ok(())
+    }
+}
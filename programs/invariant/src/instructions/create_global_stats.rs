@@ -0,0 +1,43 @@
+use crate::structs::{log_value, AdminAction, AdminLog, GlobalStats, State};
+use crate::util::get_current_slot;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+#[derive(Accounts)]
+pub struct CreateGlobalStats<'info> {
+    #[account(init, seeds = [b"globalstatsv1".as_ref()], bump, payer = admin)]
+    pub global_stats: AccountLoader<'info, GlobalStats>,
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut, seeds = [b"adminlogv1".as_ref()], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+    #[account(mut, constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> CreateGlobalStats<'info> {
+    pub fn handler(&self, bump: u8) -> ProgramResult {
+        msg!("INVARIANT: CREATE GLOBAL STATS");
+
+        let global_stats = &mut self.global_stats.load_init()?;
+        **global_stats = GlobalStats {
+            bump,
+            ..Default::default()
+        };
+
+        self.admin_log.load_mut()?.append(
+            self.admin.key(),
+            AdminAction::CreateGlobalStats,
+            self.global_stats.key(),
+            log_value(0),
+            log_value(0),
+            get_current_slot(),
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use crate::events::LaunchWindowSetEvent;
+use crate::structs::{log_value, AdminAction, AdminLog, LaunchConfig, Pool, State};
+use crate::util::get_current_slot;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+#[derive(Accounts)]
+pub struct CreateLaunchConfig<'info> {
+    #[account(init,
+        seeds = [b"launchv1", pool.key().as_ref()],
+        bump, payer = admin
+    )]
+    pub launch_config: AccountLoader<'info, LaunchConfig>,
+    #[account(mut)]
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut, seeds = [b"adminlogv1".as_ref()], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+    #[account(mut, constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> CreateLaunchConfig<'info> {
+    // Opens the launch window for `duration_slots`, whitelist is filled with set_launch_whitelisted
+    pub fn handler(&self, duration_slots: u64, bump: u8) -> ProgramResult {
+        msg!("INVARIANT: CREATE LAUNCH CONFIG");
+        require!(duration_slots != 0, ZeroAmount);
+
+        let launch_config = &mut self.launch_config.load_init()?;
+        **launch_config = LaunchConfig {
+            pool: self.pool.key(),
+            bump,
+            ..Default::default()
+        };
+
+        let pool = &mut self.pool.load_mut()?;
+        let end_slot = get_current_slot().saturating_add(duration_slots);
+        pool.launch_end_slot = end_slot;
+
+        emit!(LaunchWindowSetEvent {
+            pool: self.pool.key(),
+            end_slot,
+        });
+
+        self.admin_log.load_mut()?.append(
+            self.admin.key(),
+            AdminAction::CreateLaunchConfig,
+            self.pool.key(),
+            [0u8; 32],
+            log_value(end_slot as u128),
+            get_current_slot(),
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,205 @@
+use crate::decimals::*;
+use crate::events::CreateLimitOrderEvent;
+use crate::interfaces::take_tokens::TakeTokens;
+use crate::math::{calculate_amount_delta, calculate_max_liquidity_per_tick};
+use crate::structs::launch_config::check_launch_access;
+use crate::structs::limit_order::{limit_order_side, LimitOrder};
+use crate::structs::pool::Pool;
+use crate::structs::tick::Tick;
+use crate::structs::Tickmap;
+use crate::util::check_ticks;
+use crate::ErrorCode::*;
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token;
+use anchor_spl::token::{Mint, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+#[instruction( lower_tick_index: i32, upper_tick_index: i32)]
+pub struct CreateLimitOrder<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump,
+        constraint = !state.load()?.is_paused @ ProtocolPaused
+    )]
+    pub state: AccountLoader<'info, State>,
+    // orders take their id from the position iterator of the pool
+    #[account(init,
+        seeds = [b"limitorderv1",
+        pool.key().as_ref(),
+        &pool.load()?.position_iterator.to_le_bytes()],
+        bump, payer = payer
+    )]
+    pub limit_order: AccountLoader<'info, LimitOrder>,
+    #[account(mut,
+        seeds = [b"poolv1", token_x.key().as_ref(), token_y.key().as_ref(), &pool.load()?.fee_tier_fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        bump = pool.load()?.bump
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(mut,
+        constraint = tickmap.key() == pool.load()?.tickmap @ InvalidTickmap,
+        constraint = tickmap.to_account_info().owner == program_id @ InvalidTickmapOwner,
+    )]
+    pub tickmap: AccountLoader<'info, Tickmap>,
+    #[account(mut,
+        seeds = [b"tickv1", pool.key().as_ref(), &lower_tick_index.to_le_bytes()],
+        bump = lower_tick.load()?.bump
+    )]
+    pub lower_tick: AccountLoader<'info, Tick>,
+    #[account(mut,
+        seeds = [b"tickv1", pool.key().as_ref(), &upper_tick_index.to_le_bytes()],
+        bump = upper_tick.load()?.bump
+    )]
+    pub upper_tick: AccountLoader<'info, Tick>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub owner: Signer<'info>,
+    #[account(constraint = token_x.key() == pool.load()?.token_x @ InvalidTokenAccount)]
+    pub token_x: Account<'info, Mint>,
+    #[account(constraint = token_y.key() == pool.load()?.token_y @ InvalidTokenAccount)]
+    pub token_y: Account<'info, Mint>,
+    #[account(mut,
+        constraint = account_x.mint == token_x.key() @ InvalidMint,
+        constraint = &account_x.owner == owner.key @ InvalidOwner,
+    )]
+    pub account_x: Box<Account<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = account_y.mint == token_y.key() @ InvalidMint,
+        constraint = &account_y.owner == owner.key @ InvalidOwner
+    )]
+    pub account_y: Box<Account<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = reserve_x.mint == token_x.key() @ InvalidMint,
+        constraint = &reserve_x.owner == program_authority.key @ InvalidOwner,
+        constraint = reserve_x.key() == pool.load()?.token_x_reserve @ InvalidTokenAccount
+    )]
+    pub reserve_x: Box<Account<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = reserve_y.mint == token_y.key() @ InvalidMint,
+        constraint = &reserve_y.owner == program_authority.key @ InvalidOwner,
+        constraint = reserve_y.key() == pool.load()?.token_y_reserve @ InvalidTokenAccount
+    )]
+    pub reserve_y: Box<Account<'info, TokenAccount>>,
+    #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
+    pub program_authority: AccountInfo<'info>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> TakeTokens<'info> for CreateLimitOrder<'info> {
+    fn take_x(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.account_x.to_account_info(),
+                to: self.reserve_x.to_account_info(),
+                authority: self.owner.to_account_info().clone(),
+            },
+        )
+    }
+
+    fn take_y(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.account_y.to_account_info(),
+                to: self.reserve_y.to_account_info(),
+                authority: self.owner.to_account_info().clone(),
+            },
+        )
+    }
+}
+
+impl<'info> CreateLimitOrder<'info> {
+    // Places the order on a range next to the price, which holds only the sold token
+    pub fn handler(
+        &self,
+        remaining_accounts: &[AccountInfo],
+        liquidity_delta: Liquidity,
+        bump: u8,
+    ) -> ProgramResult {
+        msg!("INVARIANT: CREATE LIMIT ORDER");
+
+        let mut limit_order = self.limit_order.load_init()?;
+        let pool = &mut self.pool.load_mut()?;
+        require!(!pool.withdrawal_only, PoolWithdrawalOnly);
+        check_launch_access(pool, &self.pool.key(), remaining_accounts, self.owner.key)?;
+        let lower_tick = &mut self.lower_tick.load_mut()?;
+        let upper_tick = &mut self.upper_tick.load_mut()?;
+        let mut tickmap = self.tickmap.load_mut()?;
+
+        check_ticks(lower_tick.index, upper_tick.index, pool.tick_spacing)?;
+        require!(
+            upper_tick.index == lower_tick.index + pool.tick_spacing as i32,
+            InvalidLimitOrder
+        );
+        let x_to_y = limit_order_side(pool.current_tick_index, lower_tick.index, upper_tick.index)
+            .ok_or(InvalidLimitOrder)?;
+        require!(!liquidity_delta.is_zero(), InvalidLimitOrder);
+
+        if !tickmap.get(lower_tick.index, pool.tick_spacing) {
+            tickmap.flip(true, lower_tick.index, pool.tick_spacing)
+        }
+        if !tickmap.get(upper_tick.index, pool.tick_spacing) {
+            tickmap.flip(true, upper_tick.index, pool.tick_spacing)
+        }
+
+        // the range is away from the price, so the pool liquidity stays the same
+        let max_liquidity_per_tick = calculate_max_liquidity_per_tick(pool.tick_spacing);
+        lower_tick.update(liquidity_delta, max_liquidity_per_tick, false, true)?;
+        upper_tick.update(liquidity_delta, max_liquidity_per_tick, true, true)?;
+
+        let exit_tick: &mut Tick = match x_to_y {
+            true => &mut **upper_tick,
+            false => &mut **lower_tick,
+        };
+        exit_tick.update_limit_order_book(liquidity_delta, x_to_y, true)?;
+        exit_tick.limit_orders = exit_tick.limit_orders.checked_add(1).unwrap();
+        let (_, fills) = exit_tick.limit_order_book(x_to_y);
+
+        let id = pool.position_iterator;
+        pool.position_iterator = pool.position_iterator.checked_add(1).unwrap();
+
+        *limit_order = LimitOrder {
+            owner: self.owner.key(),
+            pool: self.pool.key(),
+            id,
+            liquidity: liquidity_delta,
+            lower_tick_index: lower_tick.index,
+            upper_tick_index: upper_tick.index,
+            x_to_y,
+            fills,
+            bump,
+        };
+
+        let (amount_x, amount_y) = calculate_amount_delta(
+            pool,
+            liquidity_delta,
+            true,
+            upper_tick.index,
+            lower_tick.index,
+        )?;
+
+        emit!(CreateLimitOrderEvent {
+            pool: self.pool.key(),
+            limit_order: self.limit_order.key(),
+            owner: self.owner.key(),
+            lower_tick_index: lower_tick.index,
+            upper_tick_index: upper_tick.index,
+            x_to_y,
+            liquidity: liquidity_delta.v,
+            amount: amount_x.0 + amount_y.0,
+        });
+
+        pool.add_reserves(amount_x, amount_y);
+        token::transfer(self.take_x(), amount_x.0)?;
+        token::transfer(self.take_y(), amount_y.0)?;
+        pool.strict_check_reserves(
+            &self.reserve_x.to_account_info(),
+            &self.reserve_y.to_account_info(),
+        );
+        Ok(())
+    }
+}
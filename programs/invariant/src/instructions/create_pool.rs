@@ -1,10 +1,10 @@
-use crate::decimals::*;
-use crate::math::calculate_price_sqrt;
+use crate::events::CreatePoolEvent;
 use crate::structs::fee_tier::FeeTier;
 use crate::structs::pool::Pool;
 use crate::structs::tickmap::Tickmap;
-use crate::structs::State;
+use crate::structs::{GlobalStats, State};
 use crate::util::check_tick;
+use crate::util::check_token_decimals;
 use crate::util::get_current_timestamp;
 use crate::ErrorCode::*;
 use anchor_lang::prelude::*;
@@ -27,6 +27,8 @@ pub struct CreatePool<'info> {
         bump = fee_tier.load()?.bump
     )]
     pub fee_tier: AccountLoader<'info, FeeTier>,
+    #[account(mut, seeds = [b"globalstatsv1".as_ref()], bump = global_stats.load()?.bump)]
+    pub global_stats: AccountLoader<'info, GlobalStats>,
     #[account(zero)]
     pub tickmap: AccountLoader<'info, Tickmap>,
     pub token_x: Account<'info, Mint>,
@@ -67,37 +69,39 @@ impl<'info> CreatePool<'info> {
             InvalidPoolTokenAddresses
         );
 
+        check_token_decimals(self.token_x.decimals, self.token_y.decimals)?;
+
         let pool = &mut self.pool.load_init()?;
         let fee_tier = self.fee_tier.load()?;
         let current_timestamp = get_current_timestamp();
 
         check_tick(init_tick, fee_tier.tick_spacing)?;
 
-        **pool = Pool {
+        **pool = Pool::new(
+            *token_x_address,
+            *token_y_address,
+            *self.token_x_reserve.to_account_info().key,
+            *self.token_y_reserve.to_account_info().key,
+            *self.tickmap.to_account_info().key,
+            fee_tier.fee,
+            fee_tier.tick_spacing,
+            init_tick,
+            self.state.load()?.admin,
+            bump,
+            current_timestamp,
+        );
+
+        emit!(CreatePoolEvent {
+            pool: self.pool.key(),
             token_x: *token_x_address,
             token_y: *token_y_address,
-            token_x_reserve: *self.token_x_reserve.to_account_info().key,
-            token_y_reserve: *self.token_y_reserve.to_account_info().key,
+            fee: fee_tier.fee.v,
             tick_spacing: fee_tier.tick_spacing,
-            fee: fee_tier.fee,
-            protocol_fee: FixedPoint::from_scale(1, 2),
-            liquidity: Liquidity::new(0),
-            sqrt_price: calculate_price_sqrt(init_tick),
-            current_tick_index: init_tick,
-            tickmap: *self.tickmap.to_account_info().key,
-            fee_growth_global_x: FeeGrowth::new(0),
-            fee_growth_global_y: FeeGrowth::new(0),
-            fee_protocol_token_x: 0,
-            fee_protocol_token_y: 0,
-            position_iterator: 0,
-            seconds_per_liquidity_global: FixedPoint::new(0),
-            start_timestamp: current_timestamp,
-            last_timestamp: current_timestamp,
-            fee_receiver: self.state.load()?.admin,
-            oracle_address: Pubkey::default(),
-            oracle_initialized: false,
-            bump,
-        };
+            init_tick,
+            sqrt_price: pool.sqrt_price.v,
+        });
+
+        self.global_stats.load_mut()?.total_pools += 1;
 
         Ok(())
     }
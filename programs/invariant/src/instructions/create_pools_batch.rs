@@ -0,0 +1,301 @@
+use crate::events::CreatePoolEvent;
+use crate::math::calculate_price_sqrt;
+use crate::structs::fee_tier::FeeTier;
+use crate::structs::global_stats::GlobalStats;
+use crate::structs::pool::Pool;
+use crate::structs::tickmap::Tickmap;
+use crate::structs::State;
+use crate::util::check_tick;
+use crate::util::check_token_decimals;
+use crate::util::get_current_timestamp;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_lang::solana_program::{system_instruction, system_program};
+use anchor_lang::AccountsExit;
+use anchor_spl::token::{self, InitializeAccount, Mint, Token};
+use std::cmp::Ordering;
+use std::slice::ChunksExact;
+
+pub const MAX_POOLS_IN_BATCH: usize = 4;
+// pool, fee_tier, tickmap, token_x, token_y, token_x_reserve, token_y_reserve
+const ACCOUNTS_PER_POOL: usize = 7;
+const TOKEN_ACCOUNT_LEN: u64 = 165;
+
+// Splits remaining accounts into one chunk per pool
+fn split_batch_accounts<T>(accounts: &[T], pools: usize) -> Result<ChunksExact<'_, T>> {
+    require!(pools != 0 && pools <= MAX_POOLS_IN_BATCH, InvalidBatchSize);
+    let pools_accounts = accounts.chunks_exact(ACCOUNTS_PER_POOL);
+    require!(
+        pools_accounts.len() == pools && pools_accounts.remainder().is_empty(),
+        InvalidBatchSize
+    );
+    Ok(pools_accounts)
+}
+
+#[derive(Accounts)]
+pub struct CreatePoolsBatch<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut, seeds = [b"globalstatsv1".as_ref()], bump = global_stats.load()?.bump)]
+    pub global_stats: AccountLoader<'info, GlobalStats>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(constraint = &state.load()?.authority == authority.key @ InvalidAuthority)]
+    pub authority: AccountInfo<'info>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> CreatePoolsBatch<'info> {
+    // Every pool is described by ACCOUNTS_PER_POOL consecutive remaining accounts.
+    // Tickmaps have to be allocated by the client beforehand (same as in create_pool),
+    // reserves have to be fresh keypairs signing the transaction.
+    pub fn handler(
+        ctx: Context<'_, '_, '_, 'info, CreatePoolsBatch<'info>>,
+        init_ticks: Vec<i32>,
+    ) -> ProgramResult {
+        msg!("INVARIANT: CREATE POOLS BATCH");
+
+        let pools_accounts = split_batch_accounts(ctx.remaining_accounts, init_ticks.len())?;
+
+        let fee_receiver = ctx.accounts.state.load()?.admin;
+        let current_timestamp = get_current_timestamp();
+
+        for (init_tick, accounts) in init_ticks.iter().zip(pools_accounts) {
+            ctx.accounts.create_pool(
+                ctx.program_id,
+                *init_tick,
+                accounts,
+                fee_receiver,
+                current_timestamp,
+            )?;
+        }
+
+        ctx.accounts.global_stats.load_mut()?.total_pools += init_ticks.len() as u64;
+
+        Ok(())
+    }
+
+    fn create_pool(
+        &self,
+        program_id: &Pubkey,
+        init_tick: i32,
+        accounts: &[AccountInfo<'info>],
+        fee_receiver: Pubkey,
+        current_timestamp: u64,
+    ) -> ProgramResult {
+        let pool_info = &accounts[0];
+        let fee_tier_info = &accounts[1];
+        let tickmap_info = &accounts[2];
+        let token_x_info = &accounts[3];
+        let token_y_info = &accounts[4];
+        let token_x_reserve_info = &accounts[5];
+        let token_y_reserve_info = &accounts[6];
+
+        // tokens
+        let token_x = Account::<'_, Mint>::try_from(token_x_info)?;
+        let token_y = Account::<'_, Mint>::try_from(token_y_info)?;
+        let token_x_address = token_x.key();
+        let token_y_address = token_y.key();
+        require!(
+            token_x_address
+                .to_string()
+                .cmp(&token_y_address.to_string())
+                == Ordering::Less,
+            InvalidPoolTokenAddresses
+        );
+        check_token_decimals(token_x.decimals, token_y.decimals)?;
+
+        // fee tier
+        let (fee, tick_spacing) = {
+            let fee_tier_loader = AccountLoader::<'_, FeeTier>::try_from(fee_tier_info)?;
+            let fee_tier = fee_tier_loader.load()?;
+            let fee = fee_tier.fee;
+            let tick_spacing = fee_tier.tick_spacing;
+            let fee_tier_address = Pubkey::create_program_address(
+                &[
+                    b"feetierv1",
+                    program_id.as_ref(),
+                    &fee.v.to_le_bytes(),
+                    &tick_spacing.to_le_bytes(),
+                    &[fee_tier.bump],
+                ],
+                program_id,
+            )
+            .map_err(|_| InvalidFeeTier)?;
+            require!(fee_tier_address == *fee_tier_info.key, InvalidFeeTier);
+            (fee, tick_spacing)
+        };
+
+        check_tick(init_tick, tick_spacing)?;
+
+        // tickmap
+        require!(tickmap_info.owner == program_id, InvalidTickmapOwner);
+        let tickmap = AccountLoader::<'_, Tickmap>::try_from_unchecked(program_id, tickmap_info)?;
+        drop(tickmap.load_init()?);
+        require!(
+            self.rent
+                .is_exempt(tickmap_info.lamports(), tickmap_info.data_len()),
+            InvalidTickmap
+        );
+
+        // pool
+        let (pool_address, bump) = Pubkey::find_program_address(
+            &[
+                b"poolv1",
+                token_x_address.as_ref(),
+                token_y_address.as_ref(),
+                &fee.v.to_le_bytes(),
+                &tick_spacing.to_le_bytes(),
+            ],
+            program_id,
+        );
+        require!(pool_address == *pool_info.key, InvalidPoolAddress);
+
+        let pool_space = 8 + std::mem::size_of::<Pool>();
+        invoke_signed(
+            &system_instruction::create_account(
+                self.payer.key,
+                pool_info.key,
+                self.rent.minimum_balance(pool_space),
+                pool_space as u64,
+                program_id,
+            ),
+            &[
+                self.payer.to_account_info(),
+                pool_info.clone(),
+                self.system_program.clone(),
+            ],
+            &[&[
+                b"poolv1",
+                token_x_address.as_ref(),
+                token_y_address.as_ref(),
+                &fee.v.to_le_bytes(),
+                &tick_spacing.to_le_bytes(),
+                &[bump],
+            ]],
+        )?;
+
+        // reserves
+        self.create_reserve(token_x_reserve_info, token_x_info)?;
+        self.create_reserve(token_y_reserve_info, token_y_info)?;
+
+        let pool_loader = AccountLoader::<'_, Pool>::try_from_unchecked(program_id, pool_info)?;
+        {
+            let pool = &mut pool_loader.load_init()?;
+            **pool = Pool::new(
+                token_x_address,
+                token_y_address,
+                *token_x_reserve_info.key,
+                *token_y_reserve_info.key,
+                *tickmap_info.key,
+                fee,
+                tick_spacing,
+                init_tick,
+                fee_receiver,
+                bump,
+                current_timestamp,
+            );
+        }
+        emit!(CreatePoolEvent {
+            pool: *pool_info.key,
+            token_x: token_x_address,
+            token_y: token_y_address,
+            fee: fee.v,
+            tick_spacing,
+            init_tick,
+            sqrt_price: calculate_price_sqrt(init_tick).v,
+        });
+
+        // writes discriminators
+        pool_loader.exit(program_id)?;
+        tickmap.exit(program_id)?;
+
+        Ok(())
+    }
+
+    fn create_reserve(
+        &self,
+        reserve: &AccountInfo<'info>,
+        mint: &AccountInfo<'info>,
+    ) -> ProgramResult {
+        invoke(
+            &system_instruction::create_account(
+                self.payer.key,
+                reserve.key,
+                self.rent.minimum_balance(TOKEN_ACCOUNT_LEN as usize),
+                TOKEN_ACCOUNT_LEN,
+                &token::ID,
+            ),
+            &[
+                self.payer.to_account_info(),
+                reserve.clone(),
+                self.system_program.clone(),
+            ],
+        )?;
+
+        token::initialize_account(CpiContext::new(
+            self.token_program.to_account_info(),
+            InitializeAccount {
+                account: reserve.clone(),
+                mint: mint.clone(),
+                authority: self.authority.clone(),
+                rent: self.rent.to_account_info(),
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_batch_accounts() {
+        // single pool
+        {
+            let accounts: Vec<usize> = (0..ACCOUNTS_PER_POOL).collect();
+            let pools: Vec<&[usize]> = split_batch_accounts(&accounts, 1).unwrap().collect();
+            assert_eq!(pools, vec![&accounts[..]]);
+        }
+        // full batch
+        {
+            let accounts: Vec<usize> = (0..MAX_POOLS_IN_BATCH * ACCOUNTS_PER_POOL).collect();
+            let pools: Vec<&[usize]> = split_batch_accounts(&accounts, MAX_POOLS_IN_BATCH)
+                .unwrap()
+                .collect();
+            assert_eq!(pools.len(), MAX_POOLS_IN_BATCH);
+            assert_eq!(
+                pools[1],
+                &accounts[ACCOUNTS_PER_POOL..2 * ACCOUNTS_PER_POOL]
+            );
+        }
+        // empty batch
+        {
+            let accounts: Vec<usize> = vec![];
+            assert!(split_batch_accounts(&accounts, 0).is_err());
+        }
+        // over the batch limit
+        {
+            let pools = MAX_POOLS_IN_BATCH + 1;
+            let accounts: Vec<usize> = (0..pools * ACCOUNTS_PER_POOL).collect();
+            assert!(split_batch_accounts(&accounts, pools).is_err());
+        }
+        // accounts not matching ticks
+        {
+            let accounts: Vec<usize> = (0..2 * ACCOUNTS_PER_POOL).collect();
+            assert!(split_batch_accounts(&accounts, 1).is_err());
+            assert!(split_batch_accounts(&accounts, 3).is_err());
+        }
+        // missing and excess trailing accounts
+        {
+            let accounts: Vec<usize> = (0..ACCOUNTS_PER_POOL - 1).collect();
+            assert!(split_batch_accounts(&accounts, 1).is_err());
+            let accounts: Vec<usize> = (0..ACCOUNTS_PER_POOL + 1).collect();
+            assert!(split_batch_accounts(&accounts, 1).is_err());
+        }
+    }
+}
@@ -1,23 +1,28 @@
+use crate::events::CreatePositionEvent;
 use crate::interfaces::take_tokens::TakeTokens;
+use crate::structs::launch_config::check_launch_access;
 use crate::structs::pool::Pool;
 use crate::structs::position::Position;
 use crate::structs::position_list::PositionList;
 use crate::structs::tick::Tick;
 use crate::structs::Tickmap;
-use crate::util::check_ticks;
+use crate::util::{check_ticks, mint_position_nft};
 use crate::ErrorCode::*;
 use crate::*;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::system_program;
 use decimals::*;
 
+use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token;
 use anchor_spl::token::{Mint, TokenAccount, Transfer};
 
 #[derive(Accounts)]
 #[instruction( lower_tick_index: i32, upper_tick_index: i32)]
 pub struct CreatePosition<'info> {
-    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump,
+        constraint = !state.load()?.is_paused @ ProtocolPaused
+    )]
     pub state: AccountLoader<'info, State>,
     #[account(init,
         seeds = [b"positionv1",
@@ -27,7 +32,7 @@ pub struct CreatePosition<'info> {
     )]
     pub position: AccountLoader<'info, Position>,
     #[account(mut,
-        seeds = [b"poolv1", token_x.key().as_ref(), token_y.key().as_ref(), &pool.load()?.fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        seeds = [b"poolv1", token_x.key().as_ref(), token_y.key().as_ref(), &pool.load()?.fee_tier_fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
         bump = pool.load()?.bump
     )]
     pub pool: AccountLoader<'info, Pool>,
@@ -80,10 +85,26 @@ pub struct CreatePosition<'info> {
         constraint = reserve_y.key() == pool.load()?.token_y_reserve @ InvalidTokenAccount
     )]
     pub reserve_y: Box<Account<'info, TokenAccount>>,
+    // the position takes its id from the position iterator of the pool
+    #[account(init,
+        seeds = [b"positionnftv1", pool.key().as_ref(), &pool.load()?.position_iterator.to_le_bytes()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = program_authority,
+        payer = payer
+    )]
+    pub position_nft_mint: Box<Account<'info, Mint>>,
+    #[account(init,
+        associated_token::mint = position_nft_mint,
+        associated_token::authority = owner,
+        payer = payer
+    )]
+    pub position_nft_account: Box<Account<'info, TokenAccount>>,
     #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
     pub program_authority: AccountInfo<'info>,
     #[account(address = token::ID)]
     pub token_program: AccountInfo<'info>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub rent: Sysvar<'info, Rent>,
     #[account(address = system_program::ID)]
     pub system_program: AccountInfo<'info>,
@@ -116,6 +137,7 @@ impl<'info> TakeTokens<'info> for CreatePosition<'info> {
 impl<'info> CreatePosition<'info> {
     pub fn handler(
         &self,
+        remaining_accounts: &[AccountInfo],
         liquidity_delta: Liquidity,
         slippage_limit_lower: Price,
         slippage_limit_upper: Price,
@@ -125,6 +147,13 @@ impl<'info> CreatePosition<'info> {
 
         let mut position = self.position.load_init()?;
         let mut pool = &mut self.pool.load_mut()?;
+        require!(!pool.withdrawal_only, PoolWithdrawalOnly);
+        check_launch_access(
+            &mut pool,
+            &self.pool.key(),
+            remaining_accounts,
+            self.owner.key,
+        )?;
         let lower_tick = &mut self.lower_tick.load_mut()?;
         let upper_tick = &mut self.upper_tick.load_mut()?;
         let mut position_list = self.position_list.load_mut()?;
@@ -165,6 +194,10 @@ impl<'info> CreatePosition<'info> {
             last_slot: slot,
             tokens_owed_x: FixedPoint::new(0),
             tokens_owed_y: FixedPoint::new(0),
+            maker_fees_x: FixedPoint::new(0),
+            maker_fees_y: FixedPoint::new(0),
+            total_claimed_x: 0,
+            total_claimed_y: 0,
             bump,
         };
 
@@ -177,8 +210,39 @@ impl<'info> CreatePosition<'info> {
             current_timestamp,
         )?;
 
+        emit!(CreatePositionEvent {
+            pool: self.pool.key(),
+            position: self.position.key(),
+            owner: self.owner.key(),
+            id: position.id,
+            lower_tick_index: position.lower_tick_index,
+            upper_tick_index: position.upper_tick_index,
+            liquidity: position.liquidity.v,
+            fee_growth_inside_x: position.fee_growth_inside_x.v,
+            fee_growth_inside_y: position.fee_growth_inside_y.v,
+            seconds_per_liquidity_inside: position.seconds_per_liquidity_inside.v,
+            last_slot: position.last_slot,
+            sqrt_price: pool.sqrt_price.v,
+            current_tick_index: pool.current_tick_index,
+            amount_x: amount_x.0,
+            amount_y: amount_y.0,
+        });
+
+        pool.add_reserves(amount_x, amount_y);
         token::transfer(self.take_x(), amount_x.0)?;
         token::transfer(self.take_y(), amount_y.0)?;
-        Ok(())
+        pool.strict_check_reserves(
+            &self.reserve_x.to_account_info(),
+            &self.reserve_y.to_account_info(),
+        );
+
+        let state = self.state.load()?;
+        mint_position_nft(
+            &self.token_program,
+            &self.position_nft_mint.to_account_info(),
+            &self.position_nft_account.to_account_info(),
+            &self.program_authority,
+            state.nonce,
+        )
     }
 }
@@ -0,0 +1,99 @@
+use crate::structs::position::Position;
+use crate::structs::position_nft::{
+    holds_position_nft, is_valid_position_nft_base_uri, position_nft_uri, POSITION_NFT_NAME,
+    POSITION_NFT_SYMBOL,
+};
+use crate::ErrorCode::*;
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token::{Mint, TokenAccount};
+use mpl_token_metadata::instruction::create_metadata_accounts_v2;
+use mpl_token_metadata::pda::find_metadata_account;
+
+// Metaplex metadata of a position NFT is optional, the holder creates it to have the position
+// rendered by wallets and marketplaces. The program is the update authority, so the attributes
+// in the uri follow the position on increase and decrease.
+#[derive(Accounts)]
+#[instruction(index: u32)]
+pub struct CreatePositionNftMetadata<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(
+        seeds = [b"positionv1",
+        owner.key().as_ref(),
+        &index.to_le_bytes()],
+        bump = position.load()?.bump
+    )]
+    pub position: AccountLoader<'info, Position>,
+    #[account(
+        seeds = [b"positionnftv1", position.load()?.pool.as_ref(), &position.load()?.id.to_le_bytes()],
+        bump
+    )]
+    pub position_nft_mint: Box<Account<'info, Mint>>,
+    #[account(mut,
+        constraint = metadata.key() == find_metadata_account(&position_nft_mint.key()).0 @ InvalidPositionNftMetadata
+    )]
+    pub metadata: AccountInfo<'info>,
+    pub owner: AccountInfo<'info>,
+    #[account(mut)]
+    pub holder: Signer<'info>,
+    #[account(
+        constraint = holds_position_nft(&*position.load()?, &position_nft_account) @ InvalidOwner,
+        constraint = &position_nft_account.owner == holder.key @ InvalidOwner
+    )]
+    pub position_nft_account: Box<Account<'info, TokenAccount>>,
+    #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
+    pub program_authority: AccountInfo<'info>,
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: AccountInfo<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> CreatePositionNftMetadata<'info> {
+    pub fn handler(&self, base_uri: String) -> ProgramResult {
+        msg!("INVARIANT: CREATE POSITION NFT METADATA");
+
+        require!(
+            is_valid_position_nft_base_uri(&base_uri),
+            InvalidPositionNftUri
+        );
+        let state = self.state.load()?;
+        let position = self.position.load()?;
+
+        let signer: &[&[&[u8]]] = get_signer!(state.nonce);
+        invoke_signed(
+            &create_metadata_accounts_v2(
+                mpl_token_metadata::ID,
+                self.metadata.key(),
+                self.position_nft_mint.key(),
+                self.program_authority.key(),
+                self.holder.key(),
+                self.program_authority.key(),
+                POSITION_NFT_NAME.to_string(),
+                POSITION_NFT_SYMBOL.to_string(),
+                position_nft_uri(&base_uri, &position),
+                None,
+                0,
+                true,
+                true,
+                None,
+                None,
+            ),
+            &[
+                self.metadata.clone(),
+                self.position_nft_mint.to_account_info(),
+                self.program_authority.clone(),
+                self.holder.to_account_info(),
+                self.program_authority.clone(),
+                self.system_program.clone(),
+                self.rent.to_account_info(),
+                self.token_metadata_program.clone(),
+            ],
+            signer,
+        )
+    }
+}
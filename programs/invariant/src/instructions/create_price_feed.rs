@@ -0,0 +1,42 @@
+use crate::structs::pool::Pool;
+use crate::structs::price_feed::PriceFeed;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+// Anyone can create a feed for any pool and window, as its content comes from the oracle only
+#[derive(Accounts)]
+#[instruction(window: u64)]
+pub struct CreatePriceFeed<'info> {
+    #[account(init,
+        seeds = [b"pricefeedv1", pool.key().as_ref(), &window.to_le_bytes()],
+        bump, payer = payer
+    )]
+    pub price_feed: AccountLoader<'info, PriceFeed>,
+    #[account(constraint = pool.load()?.oracle_initialized @ OracleNotFound)]
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> CreatePriceFeed<'info> {
+    pub fn handler(&self, window: u64, bump: u8) -> ProgramResult {
+        msg!("INVARIANT: CREATE PRICE FEED");
+        require!(window != 0, InvalidTwapWindow);
+
+        let pool = self.pool.load()?;
+        *self.price_feed.load_init()? = PriceFeed {
+            pool: self.pool.key(),
+            token_x: pool.token_x,
+            token_y: pool.token_y,
+            window,
+            bump,
+            ..Default::default()
+        };
+
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use crate::structs::{AdminAction, AdminLog, RebateSchedule, State};
+use crate::util::get_current_slot;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+#[derive(Accounts)]
+pub struct CreateRebateSchedule<'info> {
+    #[account(init,
+        seeds = [b"rebatesv1".as_ref()],
+        bump, payer = admin
+    )]
+    pub rebate_schedule: AccountLoader<'info, RebateSchedule>,
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut, seeds = [b"adminlogv1".as_ref()], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+    #[account(mut, constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> CreateRebateSchedule<'info> {
+    pub fn handler(&self, bump: u8) -> ProgramResult {
+        msg!("INVARIANT: CREATE REBATE SCHEDULE");
+
+        let rebate_schedule = &mut self.rebate_schedule.load_init()?;
+        **rebate_schedule = RebateSchedule {
+            bump,
+            ..Default::default()
+        };
+
+        self.admin_log.load_mut()?.append(
+            self.admin.key(),
+            AdminAction::CreateRebateSchedule,
+            self.rebate_schedule.key(),
+            [0u8; 32],
+            [0u8; 32],
+            get_current_slot(),
+        );
+
+        Ok(())
+    }
+}
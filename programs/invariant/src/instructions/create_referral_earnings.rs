@@ -0,0 +1,40 @@
+use crate::referral::whitelist::contains_owner;
+use crate::structs::{Pool, ReferralEarnings};
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+#[derive(Accounts)]
+pub struct CreateReferralEarnings<'info> {
+    #[account(init,
+        seeds = [b"referralv1", pool.key().as_ref(), referrer.key.as_ref()],
+        bump, payer = payer
+    )]
+    pub referral_earnings: AccountLoader<'info, ReferralEarnings>,
+    pub pool: AccountLoader<'info, Pool>,
+    pub referrer: AccountInfo<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> CreateReferralEarnings<'info> {
+    pub fn handler(&self, bump: u8) -> ProgramResult {
+        msg!("INVARIANT: CREATE REFERRAL EARNINGS");
+
+        require!(contains_owner(self.referrer.key()), InvalidReferrer);
+
+        let referral_earnings = &mut self.referral_earnings.load_init()?;
+        **referral_earnings = ReferralEarnings {
+            pool: self.pool.key(),
+            referrer: self.referrer.key(),
+            amount_x: 0,
+            amount_y: 0,
+            bump,
+        };
+
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use crate::structs::SessionAuthority;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+#[derive(Accounts)]
+pub struct CreateSessionAuthority<'info> {
+    #[account(init,
+        seeds = [b"sessionv1", owner.key.as_ref(), session_key.key.as_ref()],
+        bump, payer = owner
+    )]
+    pub session_authority: AccountLoader<'info, SessionAuthority>,
+    pub session_key: AccountInfo<'info>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> CreateSessionAuthority<'info> {
+    pub fn handler(&self, permissions: u8, expiry: u64, bump: u8) -> ProgramResult {
+        msg!("INVARIANT: CREATE SESSION AUTHORITY");
+
+        require!(permissions != 0, InvalidSessionAuthority);
+
+        let session_authority = &mut self.session_authority.load_init()?;
+        **session_authority = SessionAuthority {
+            owner: self.owner.key(),
+            session_key: self.session_key.key(),
+            permissions,
+            expiry,
+            bump,
+        };
+
+        Ok(())
+    }
+}
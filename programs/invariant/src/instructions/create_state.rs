@@ -3,21 +3,21 @@ use anchor_lang::prelude::*;
 use anchor_lang::solana_program::system_program;
 
 #[derive(Accounts)]
-#[instruction( nonce: u8)]
 pub struct CreateState<'info> {
     #[account(init, seeds = [b"statev1".as_ref()], bump, payer = admin)]
     pub state: AccountLoader<'info, State>,
     #[account(mut)]
     pub admin: Signer<'info>,
-    #[account(seeds = [b"Invariant".as_ref()], bump = nonce)]
+    #[account(seeds = [b"Invariant".as_ref()], bump)]
     pub program_authority: AccountInfo<'info>,
     pub rent: Sysvar<'info, Rent>,
     #[account(address = system_program::ID)]
     pub system_program: AccountInfo<'info>,
 }
 
-pub fn handler(ctx: Context<CreateState>, nonce: u8) -> ProgramResult {
+pub fn handler(ctx: Context<CreateState>) -> ProgramResult {
     msg!("INVARIANT: CREATE STATE");
+    let nonce = *ctx.bumps.get("program_authority").unwrap();
 
     let state = &mut ctx.accounts.state.load_init()?;
     **state = State {
@@ -25,6 +25,8 @@ pub fn handler(ctx: Context<CreateState>, nonce: u8) -> ProgramResult {
         authority: *ctx.accounts.program_authority.key,
         nonce,
         bump: *ctx.bumps.get("state").unwrap(),
+        pending_admin: Pubkey::default(),
+        is_paused: false,
     };
     Ok(())
 }
@@ -19,7 +19,7 @@ pub struct CreateTick<'info> {
     )]
     pub tick: AccountLoader<'info, Tick>,
     #[account(
-        seeds = [b"poolv1", token_x.key().as_ref(), token_y.key().as_ref(), &pool.load()?.fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        seeds = [b"poolv1", token_x.key().as_ref(), token_y.key().as_ref(), &pool.load()?.fee_tier_fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
         bump = pool.load()?.bump
     )]
     pub pool: AccountLoader<'info, Pool>,
@@ -76,6 +76,11 @@ impl<'info> CreateTick<'info> {
                 false => FixedPoint::new(0),
             },
             bump,
+            limit_order_liquidity_below: Liquidity::new(0),
+            limit_order_liquidity_above: Liquidity::new(0),
+            limit_order_fills_below: 0,
+            limit_order_fills_above: 0,
+            limit_orders: 0,
         };
 
         Ok(())
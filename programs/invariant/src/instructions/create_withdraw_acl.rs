@@ -0,0 +1,48 @@
+use crate::structs::{AdminAction, AdminLog, Pool, State, WithdrawAcl};
+use crate::util::get_current_slot;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+#[derive(Accounts)]
+pub struct CreateWithdrawAcl<'info> {
+    #[account(init,
+        seeds = [b"withdrawaclv1", pool.key().as_ref()],
+        bump, payer = admin
+    )]
+    pub withdraw_acl: AccountLoader<'info, WithdrawAcl>,
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut, seeds = [b"adminlogv1".as_ref()], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+    #[account(mut, constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> CreateWithdrawAcl<'info> {
+    pub fn handler(&self, bump: u8) -> ProgramResult {
+        msg!("INVARIANT: CREATE WITHDRAW ACL");
+
+        let withdraw_acl = &mut self.withdraw_acl.load_init()?;
+        **withdraw_acl = WithdrawAcl {
+            pool: self.pool.key(),
+            bump,
+            ..Default::default()
+        };
+
+        self.admin_log.load_mut()?.append(
+            self.admin.key(),
+            AdminAction::CreateWithdrawAcl,
+            self.withdraw_acl.key(),
+            [0u8; 32],
+            self.pool.key().to_bytes(),
+            get_current_slot(),
+        );
+
+        Ok(())
+    }
+}
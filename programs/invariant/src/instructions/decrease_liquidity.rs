@@ -0,0 +1,164 @@
+use crate::decimals::*;
+use crate::events::DecreaseLiquidityEvent;
+use crate::interfaces::send_tokens::SendTokens;
+use crate::structs::pool::Pool;
+use crate::structs::position::Position;
+use crate::structs::position_nft::holds_position_nft;
+use crate::structs::tick::Tick;
+use crate::util::refresh_position_nft_metadata;
+use crate::ErrorCode::*;
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use anchor_spl::token::{Mint, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+#[instruction(index: u32)]
+pub struct DecreaseLiquidity<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut,
+        seeds = [b"positionv1",
+        owner.key().as_ref(),
+        &index.to_le_bytes()],
+        bump = position.load()?.bump,
+        constraint = position.load()?.pool == pool.key() @ InvalidPositionIndex
+    )]
+    pub position: AccountLoader<'info, Position>,
+    #[account(mut,
+        seeds = [b"poolv1", token_x.key().as_ref(), token_y.key().as_ref(), &pool.load()?.fee_tier_fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        bump = pool.load()?.bump
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(mut,
+        seeds = [b"tickv1", pool.key().as_ref(), &position.load()?.lower_tick_index.to_le_bytes()],
+        bump = lower_tick.load()?.bump
+    )]
+    pub lower_tick: AccountLoader<'info, Tick>,
+    #[account(mut,
+        seeds = [b"tickv1", pool.key().as_ref(), &position.load()?.upper_tick_index.to_le_bytes()],
+        bump = upper_tick.load()?.bump
+    )]
+    pub upper_tick: AccountLoader<'info, Tick>,
+    pub owner: AccountInfo<'info>,
+    // signer, or a session key with rebalance permission (see `session` access control)
+    pub holder: AccountInfo<'info>,
+    #[account(
+        constraint = holds_position_nft(&*position.load()?, &position_nft_account) @ InvalidOwner,
+        constraint = &position_nft_account.owner == holder.key @ InvalidOwner
+    )]
+    pub position_nft_account: Box<Account<'info, TokenAccount>>,
+    #[account(constraint = token_x.key() == pool.load()?.token_x @ InvalidTokenAccount)]
+    pub token_x: Account<'info, Mint>,
+    #[account(constraint = token_y.key() == pool.load()?.token_y @ InvalidTokenAccount)]
+    pub token_y: Account<'info, Mint>,
+    #[account(mut,
+        constraint = account_x.mint == token_x.key() @ InvalidMint,
+        constraint = &account_x.owner == holder.key @ InvalidOwner
+    )]
+    pub account_x: Box<Account<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = account_y.mint == token_y.key() @ InvalidMint,
+        constraint = &account_y.owner == holder.key @ InvalidOwner
+    )]
+    pub account_y: Box<Account<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = reserve_x.mint == token_x.key() @ InvalidMint,
+        constraint = &reserve_x.owner == program_authority.key @ InvalidAuthority,
+        constraint = reserve_x.key() == pool.load()?.token_x_reserve @ InvalidTokenAccount
+    )]
+    pub reserve_x: Box<Account<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = reserve_y.mint == token_y.key() @ InvalidMint,
+        constraint = &reserve_y.owner == program_authority.key @ InvalidAuthority,
+        constraint = reserve_y.key() == pool.load()?.token_y_reserve @ InvalidTokenAccount
+    )]
+    pub reserve_y: Box<Account<'info, TokenAccount>>,
+    #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
+    pub program_authority: AccountInfo<'info>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+}
+
+impl<'info> SendTokens<'info> for DecreaseLiquidity<'info> {
+    fn send_x(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.reserve_x.to_account_info(),
+                to: self.account_x.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+
+    fn send_y(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.reserve_y.to_account_info(),
+                to: self.account_y.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+}
+
+impl<'info> DecreaseLiquidity<'info> {
+    // Withdraws a part of the liquidity, the position and its ticks stay open. Fees accrued so
+    // far are checkpointed into tokens owed and left for claim_fee.
+    pub fn handler(
+        &self,
+        remaining_accounts: &[AccountInfo<'info>],
+        liquidity_delta: Liquidity,
+    ) -> ProgramResult {
+        msg!("INVARIANT: DECREASE LIQUIDITY");
+
+        let state = self.state.load()?;
+        let position = &mut self.position.load_mut()?;
+        let pool = &mut self.pool.load_mut()?;
+        let lower_tick = &mut self.lower_tick.load_mut()?;
+        let upper_tick = &mut self.upper_tick.load_mut()?;
+
+        // the whole liquidity is withdrawn with remove_position, which also closes the accounts
+        require!(
+            !liquidity_delta.is_zero() && liquidity_delta < { position.liquidity },
+            InvalidPositionLiquidity
+        );
+
+        let (amount_x, amount_y) = position.modify(
+            pool,
+            upper_tick,
+            lower_tick,
+            liquidity_delta,
+            false,
+            get_current_timestamp(),
+        )?;
+
+        emit!(DecreaseLiquidityEvent {
+            pool: self.pool.key(),
+            position: self.position.key(),
+            owner: self.owner.key(),
+            liquidity_delta: liquidity_delta.v,
+            liquidity_after: position.liquidity.v,
+            amount_x: amount_x.0,
+            amount_y: amount_y.0,
+        });
+
+        pool.sub_reserves(amount_x, amount_y);
+        let signer: &[&[&[u8]]] = get_signer!(state.nonce);
+        token::transfer(self.send_x().with_signer(signer), amount_x.0)?;
+        token::transfer(self.send_y().with_signer(signer), amount_y.0)?;
+        pool.strict_check_reserves(
+            &self.reserve_x.to_account_info(),
+            &self.reserve_y.to_account_info(),
+        );
+
+        refresh_position_nft_metadata(
+            remaining_accounts,
+            position,
+            &self.program_authority,
+            state.nonce,
+        )
+    }
+}
@@ -0,0 +1,55 @@
+use crate::ErrorCode::*;
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use anchor_spl::token::{Mint, MintTo, TokenAccount};
+
+// in whole tokens, keeps a single call from flooding the test environment
+pub const MAX_FAUCET_AMOUNT: u64 = 1_000_000;
+
+#[derive(Accounts)]
+#[instruction(index: u8)]
+pub struct Faucet<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut, seeds = [b"faucetv1", &[index]], bump)]
+    pub mint: Account<'info, Mint>,
+    #[account(mut,
+        constraint = to.mint == mint.key() @ InvalidMint,
+        constraint = to.owner == owner.key() @ InvalidOwner
+    )]
+    pub to: Account<'info, TokenAccount>,
+    pub owner: Signer<'info>,
+    #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
+    pub program_authority: AccountInfo<'info>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+}
+
+impl<'info> Faucet<'info> {
+    pub fn handler(&self, amount: u64) -> ProgramResult {
+        msg!("INVARIANT: FAUCET");
+        require!(amount != 0, ZeroAmount);
+        require!(
+            amount
+                <= MAX_FAUCET_AMOUNT
+                    .saturating_mul(10u64.saturating_pow(self.mint.decimals as u32)),
+            LimitReached
+        );
+
+        let state = self.state.load()?;
+        let signer: &[&[&[u8]]] = get_signer!(state.nonce);
+        token::mint_to(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                MintTo {
+                    mint: self.mint.to_account_info(),
+                    to: self.to.to_account_info(),
+                    authority: self.program_authority.clone(),
+                },
+            )
+            .with_signer(signer),
+            amount,
+        )
+    }
+}
@@ -0,0 +1,147 @@
+use crate::decimals::*;
+use crate::events::FlashLoanEvent;
+use crate::interfaces::flash_loan_callback::{invoke_flash_loan_callback, FlashLoanCallback};
+use crate::interfaces::send_tokens::SendTokens;
+use crate::structs::pool::Pool;
+use crate::ErrorCode::*;
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+pub struct FlashLoan<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump,
+        constraint = !state.load()?.is_paused @ ProtocolPaused
+    )]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut,
+        seeds = [b"poolv1", account_x.mint.as_ref(), account_y.mint.as_ref(), &pool.load()?.fee_tier_fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        bump = pool.load()?.bump
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(mut)]
+    pub account_x: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub account_y: Account<'info, TokenAccount>,
+    #[account(mut,
+        constraint = reserve_x.mint == account_x.mint @ InvalidMint,
+        constraint = &reserve_x.owner == program_authority.key @ InvalidAuthority,
+        constraint = reserve_x.to_account_info().key == &pool.load()?.token_x_reserve @ InvalidTokenAccount
+    )]
+    pub reserve_x: Box<Account<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = reserve_y.mint == account_y.mint @ InvalidMint,
+        constraint = &reserve_y.owner == program_authority.key @ InvalidAuthority,
+        constraint = reserve_y.to_account_info().key == &pool.load()?.token_y_reserve @ InvalidTokenAccount
+    )]
+    pub reserve_y: Box<Account<'info, TokenAccount>>,
+    pub borrower: Signer<'info>,
+    #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
+    pub program_authority: AccountInfo<'info>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+}
+
+impl<'info> SendTokens<'info> for FlashLoan<'info> {
+    fn send_x(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.reserve_x.to_account_info(),
+                to: self.account_x.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+
+    fn send_y(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.reserve_y.to_account_info(),
+                to: self.account_y.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+}
+
+impl<'info> FlashLoan<'info> {
+    // Lends reserve tokens for the time of a callback to the program passed as the first
+    // remaining account. The loan is repaid by transferring tokens back to the reserves directly,
+    // so repayment is checked against the accounted reserve balances and swaps made by the
+    // callback can't pay it off. The fee is shared like a swap fee.
+    pub fn handler(
+        &self,
+        remaining_accounts: &[AccountInfo],
+        amount_x: u64,
+        amount_y: u64,
+        data: Vec<u8>,
+    ) -> ProgramResult {
+        msg!("INVARIANT: FLASH LOAN");
+        require!(amount_x != 0 || amount_y != 0, ZeroAmount);
+        let (callback_program, callback_accounts) =
+            remaining_accounts.split_first().ok_or(FlashLoanNotRepaid)?;
+
+        let nonce = self.state.load()?.nonce;
+        let (excess_before, fee_x, fee_y) = {
+            let pool = self.pool.load()?;
+            require!(pool.flash_loans_enabled, FlashLoansDisabled);
+            (
+                pool.reserves_excess(self.reserve_x.amount, self.reserve_y.amount),
+                pool.flash_loan_fee(TokenAmount(amount_x)),
+                pool.flash_loan_fee(TokenAmount(amount_y)),
+            )
+        };
+
+        let signer: &[&[&[u8]]] = get_signer!(nonce);
+        if amount_x != 0 {
+            token::transfer(self.send_x().with_signer(signer), amount_x)?;
+        }
+        if amount_y != 0 {
+            token::transfer(self.send_y().with_signer(signer), amount_y)?;
+        }
+
+        invoke_flash_loan_callback(
+            callback_program,
+            callback_accounts,
+            &FlashLoanCallback {
+                pool: self.pool.key(),
+                amount_x,
+                amount_y,
+                fee_x: fee_x.0,
+                fee_y: fee_y.0,
+                data,
+            },
+        )?;
+
+        let mut pool = self.pool.load_mut()?;
+        let excess_after = pool.reserves_excess(
+            token::accessor::amount(&self.reserve_x.to_account_info())?,
+            token::accessor::amount(&self.reserve_y.to_account_info())?,
+        );
+        require!(
+            excess_after.0 >= excess_before.0 + fee_x && excess_after.1 >= excess_before.1 + fee_y,
+            FlashLoanNotRepaid
+        );
+
+        pool.add_reserves(fee_x, fee_y);
+        pool.add_fee(fee_x, FixedPoint::from_integer(0), true);
+        pool.add_fee(fee_y, FixedPoint::from_integer(0), false);
+        pool.strict_check_reserves(
+            &self.reserve_x.to_account_info(),
+            &self.reserve_y.to_account_info(),
+        );
+
+        emit!(FlashLoanEvent {
+            pool: self.pool.key(),
+            borrower: self.borrower.key(),
+            amount_x,
+            amount_y,
+            fee_x: fee_x.0,
+            fee_y: fee_y.0,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,284 @@
+use crate::decimals::*;
+use crate::events::ForceCloseOrphanPositionEvent;
+use crate::interfaces::send_tokens::SendTokens;
+use crate::math::{calculate_amount_delta, calculate_max_liquidity_per_tick};
+use crate::structs::pool::Pool;
+use crate::structs::position::Position;
+use crate::structs::position_list::PositionList;
+use crate::structs::position_nft::holds_position_nft;
+use crate::structs::tick::Tick;
+use crate::structs::tickmap::Tickmap;
+use crate::structs::{log_value, AdminAction, AdminLog};
+use crate::util::{check_ticks, close, get_current_slot};
+use crate::ErrorCode::*;
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use anchor_spl::token::{Mint, TokenAccount, Transfer};
+
+// Recovers principal of a position one of whose tick accounts was closed, which makes
+// remove_position impossible. Missing ticks are bypassed, existing ones are updated as
+// usual. Fees accrued since the last update can't be computed without both ticks, so only
+// tokens already owed are paid out on top of the principal. They go to the holder of the
+// position NFT, which can't be burned without the holder and stays behind.
+#[derive(Accounts)]
+#[instruction(index: u32, lower_tick_index: i32, upper_tick_index: i32)]
+pub struct ForceCloseOrphanPosition<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut, seeds = [b"adminlogv1".as_ref()], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+    #[account(mut,
+        seeds = [b"positionv1",
+        owner.key().as_ref(),
+        &index.to_le_bytes()],
+        bump = removed_position.load()?.bump,
+        constraint = removed_position.load()?.pool == pool.key() @ InvalidPositionIndex
+    )]
+    pub removed_position: AccountLoader<'info, Position>,
+    #[account(mut,
+        seeds = [b"positionlistv1", owner.key().as_ref()],
+        bump = position_list.load()?.bump
+    )]
+    pub position_list: AccountLoader<'info, PositionList>,
+    #[account(mut,
+        close = owner,
+        seeds = [b"positionv1",
+        owner.key().as_ref(),
+        &(position_list.load()?.head - 1).to_le_bytes()],
+        bump = last_position.load()?.bump
+    )]
+    pub last_position: AccountLoader<'info, Position>,
+    #[account(mut,
+        seeds = [b"poolv1", token_x.key().as_ref(), token_y.key().as_ref(), &pool.load()?.fee_tier_fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        bump = pool.load()?.bump
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(mut,
+        constraint = tickmap.key() == pool.load()?.tickmap @ InvalidTickmap,
+        constraint = tickmap.to_account_info().owner == program_id @ InvalidTickmapOwner,
+    )]
+    pub tickmap: AccountLoader<'info, Tickmap>,
+    // may be closed, so only the address is checked
+    #[account(mut,
+        seeds = [b"tickv1", pool.key().as_ref(), &lower_tick_index.to_le_bytes()],
+        bump,
+        constraint = lower_tick_index == removed_position.load()?.lower_tick_index @ WrongTick
+    )]
+    pub lower_tick: AccountInfo<'info>,
+    #[account(mut,
+        seeds = [b"tickv1", pool.key().as_ref(), &upper_tick_index.to_le_bytes()],
+        bump,
+        constraint = upper_tick_index == removed_position.load()?.upper_tick_index @ WrongTick
+    )]
+    pub upper_tick: AccountInfo<'info>,
+    #[account(mut)]
+    pub owner: AccountInfo<'info>,
+    pub holder: AccountInfo<'info>,
+    #[account(
+        constraint = holds_position_nft(&*removed_position.load()?, &position_nft_account) @ InvalidOwner,
+        constraint = &position_nft_account.owner == holder.key @ InvalidOwner
+    )]
+    pub position_nft_account: Box<Account<'info, TokenAccount>>,
+    #[account(constraint = token_x.key() == pool.load()?.token_x @ InvalidTokenAccount)]
+    pub token_x: Account<'info, Mint>,
+    #[account(constraint = token_y.key() == pool.load()?.token_y @ InvalidTokenAccount)]
+    pub token_y: Account<'info, Mint>,
+    #[account(mut,
+        constraint = account_x.mint == token_x.key() @ InvalidMint,
+        constraint = &account_x.owner == holder.key @ InvalidOwner
+    )]
+    pub account_x: Box<Account<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = account_y.mint == token_y.key() @ InvalidMint,
+        constraint = &account_y.owner == holder.key @ InvalidOwner
+    )]
+    pub account_y: Box<Account<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = reserve_x.mint == token_x.key() @ InvalidMint,
+        constraint = &reserve_x.owner == program_authority.key @ InvalidAuthority,
+        constraint = reserve_x.key() == pool.load()?.token_x_reserve @ InvalidTokenAccount
+    )]
+    pub reserve_x: Box<Account<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = reserve_y.mint == token_y.key() @ InvalidMint,
+        constraint = &reserve_y.owner == program_authority.key @ InvalidAuthority,
+        constraint = reserve_y.key() == pool.load()?.token_y_reserve @ InvalidTokenAccount
+    )]
+    pub reserve_y: Box<Account<'info, TokenAccount>>,
+    #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
+    pub program_authority: AccountInfo<'info>,
+    #[account(constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+}
+
+impl<'info> SendTokens<'info> for ForceCloseOrphanPosition<'info> {
+    fn send_x(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.reserve_x.to_account_info(),
+                to: self.account_x.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+
+    fn send_y(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.reserve_y.to_account_info(),
+                to: self.account_y.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+}
+
+fn is_closed(info: &AccountInfo, program_id: &Pubkey) -> bool {
+    info.owner != program_id || info.data_is_empty()
+}
+
+impl<'info> ForceCloseOrphanPosition<'info> {
+    // Removes the position's liquidity from a tick which still exists, closing it when empty.
+    // Returns whether the tick was closed.
+    fn release_tick(
+        &self,
+        info: &AccountInfo<'info>,
+        liquidity: Liquidity,
+        is_upper: bool,
+        tick_spacing: u16,
+    ) -> Result<bool> {
+        let loader = AccountLoader::<Tick>::try_from(info)?;
+        let empty = {
+            let mut tick = loader.load_mut()?;
+            tick.update(
+                liquidity,
+                calculate_max_liquidity_per_tick(tick_spacing),
+                is_upper,
+                false,
+            )?;
+            tick.is_empty()
+        };
+        if empty {
+            **loader.load_mut()? = Default::default();
+            close(info.clone(), self.owner.to_account_info()).unwrap();
+        }
+        Ok(empty)
+    }
+
+    pub fn handler(
+        &self,
+        index: u32,
+        lower_tick_index: i32,
+        upper_tick_index: i32,
+        program_id: &Pubkey,
+    ) -> ProgramResult {
+        msg!("INVARIANT: FORCE CLOSE ORPHAN POSITION");
+
+        let lower_missing = is_closed(&self.lower_tick, program_id);
+        let upper_missing = is_closed(&self.upper_tick, program_id);
+        require!(lower_missing || upper_missing, PositionNotOrphaned);
+
+        let state = self.state.load()?;
+        let mut position_list = self.position_list.load_mut()?;
+        let removed_position = &mut self.removed_position.load_mut()?;
+        let pool = &mut self.pool.load_mut()?;
+        let tickmap = &mut self.tickmap.load_mut()?;
+        let current_timestamp = get_current_timestamp();
+
+        check_ticks(lower_tick_index, upper_tick_index, pool.tick_spacing)?;
+        let liquidity = removed_position.liquidity;
+
+        if !pool.liquidity.is_zero() {
+            pool.update_seconds_per_liquidity_global(current_timestamp);
+        } else {
+            pool.last_timestamp = current_timestamp;
+        }
+
+        for &(info, tick_index, missing, is_upper) in &[
+            (&self.lower_tick, lower_tick_index, lower_missing, false),
+            (&self.upper_tick, upper_tick_index, upper_missing, true),
+        ] {
+            let uninitialize = match missing {
+                true => tickmap.try_get(tick_index, pool.tick_spacing)?,
+                false => self.release_tick(info, liquidity, is_upper, pool.tick_spacing)?,
+            };
+            if uninitialize {
+                tickmap.try_flip(false, tick_index, pool.tick_spacing)?;
+            }
+        }
+
+        // principal only, pool liquidity is updated when the position is in range
+        let (amount_x, amount_y) =
+            calculate_amount_delta(pool, liquidity, false, upper_tick_index, lower_tick_index)?;
+        let fee_x = TokenAmount::from_decimal(removed_position.tokens_owed_x);
+        let fee_y = TokenAmount::from_decimal(removed_position.tokens_owed_y);
+
+        self.admin_log.load_mut()?.append(
+            self.admin.key(),
+            AdminAction::ForceCloseOrphanPosition,
+            self.removed_position.key(),
+            log_value(liquidity.v),
+            log_value(0),
+            get_current_slot(),
+        );
+        emit!(ForceCloseOrphanPositionEvent {
+            pool: self.pool.key(),
+            position: self.removed_position.key(),
+            owner: self.owner.key(),
+            liquidity: liquidity.v,
+            lower_tick_missing: lower_missing,
+            upper_tick_missing: upper_missing,
+            amount_x: amount_x.0,
+            amount_y: amount_y.0,
+            fee_x: fee_x.0,
+            fee_y: fee_y.0,
+        });
+
+        // Remove empty position, same as remove_position
+        position_list.head = position_list.head.checked_sub(1).unwrap();
+
+        if position_list.head != index {
+            let mut last_position = self.last_position.load_mut()?;
+
+            **removed_position = Position {
+                bump: removed_position.bump,
+                owner: last_position.owner,
+                pool: last_position.pool,
+                id: last_position.id,
+                liquidity: last_position.liquidity,
+                lower_tick_index: last_position.lower_tick_index,
+                upper_tick_index: last_position.upper_tick_index,
+                fee_growth_inside_x: last_position.fee_growth_inside_x,
+                fee_growth_inside_y: last_position.fee_growth_inside_y,
+                seconds_per_liquidity_inside: last_position.seconds_per_liquidity_inside,
+                last_slot: last_position.last_slot,
+                tokens_owed_x: last_position.tokens_owed_x,
+                tokens_owed_y: last_position.tokens_owed_y,
+                maker_fees_x: last_position.maker_fees_x,
+                maker_fees_y: last_position.maker_fees_y,
+                total_claimed_x: last_position.total_claimed_x,
+                total_claimed_y: last_position.total_claimed_y,
+            };
+
+            *last_position = Default::default();
+        } else {
+            **removed_position = Default::default();
+        }
+
+        pool.sub_reserves(amount_x + fee_x, amount_y + fee_y);
+        let signer: &[&[&[u8]]] = get_signer!(state.nonce);
+        token::transfer(self.send_x().with_signer(signer), (amount_x + fee_x).0)?;
+        token::transfer(self.send_y().with_signer(signer), (amount_y + fee_y).0)?;
+        pool.strict_check_reserves(
+            &self.reserve_x.to_account_info(),
+            &self.reserve_y.to_account_info(),
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,295 @@
+use crate::args::InitPoolAndPositionArgs;
+use crate::decimals::*;
+use crate::events::{CreatePoolEvent, CreatePositionEvent};
+use crate::interfaces::take_tokens::TakeTokens;
+use crate::math::calculate_price_sqrt;
+use crate::structs::fee_tier::FeeTier;
+use crate::structs::pool::Pool;
+use crate::structs::position::Position;
+use crate::structs::position_list::PositionList;
+use crate::structs::tick::Tick;
+use crate::structs::tickmap::Tickmap;
+use crate::structs::{GlobalStats, State};
+use crate::util::{check_tick, check_ticks, check_token_decimals, mint_position_nft};
+use crate::util::{get_current_slot, get_current_timestamp};
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token;
+use anchor_spl::token::{Mint, Token, TokenAccount, Transfer};
+use std::cmp::Ordering;
+
+#[derive(Accounts)]
+#[instruction(args: InitPoolAndPositionArgs)]
+pub struct InitPoolAndPosition<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump,
+        constraint = !state.load()?.is_paused @ ProtocolPaused
+    )]
+    pub state: AccountLoader<'info, State>,
+    #[account(init,
+        seeds = [b"poolv1", token_x.to_account_info().key.as_ref(), token_y.to_account_info().key.as_ref(), &fee_tier.load()?.fee.v.to_le_bytes(), &fee_tier.load()?.tick_spacing.to_le_bytes()],
+        bump, payer = payer
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(
+        seeds = [b"feetierv1", program_id.as_ref(), &fee_tier.load()?.fee.v.to_le_bytes(), &fee_tier.load()?.tick_spacing.to_le_bytes()],
+        bump = fee_tier.load()?.bump
+    )]
+    pub fee_tier: AccountLoader<'info, FeeTier>,
+    #[account(mut, seeds = [b"globalstatsv1".as_ref()], bump = global_stats.load()?.bump)]
+    pub global_stats: AccountLoader<'info, GlobalStats>,
+    #[account(zero)]
+    pub tickmap: AccountLoader<'info, Tickmap>,
+    #[account(init,
+        seeds = [b"tickv1", pool.key().as_ref(), &args.lower_tick_index.to_le_bytes()],
+        bump, payer = payer
+    )]
+    pub lower_tick: AccountLoader<'info, Tick>,
+    #[account(init,
+        seeds = [b"tickv1", pool.key().as_ref(), &args.upper_tick_index.to_le_bytes()],
+        bump, payer = payer
+    )]
+    pub upper_tick: AccountLoader<'info, Tick>,
+    #[account(init,
+        seeds = [b"positionv1",
+        owner.key.as_ref(),
+        &position_list.load()?.head.to_le_bytes()],
+        bump, payer = payer,
+    )]
+    pub position: AccountLoader<'info, Position>,
+    #[account(mut,
+        seeds = [b"positionlistv1", owner.key.as_ref()],
+        bump = position_list.load()?.bump
+    )]
+    pub position_list: AccountLoader<'info, PositionList>,
+    pub token_x: Box<Account<'info, Mint>>,
+    pub token_y: Box<Account<'info, Mint>>,
+    #[account(init,
+        token::mint = token_x,
+        token::authority = authority,
+        payer = payer,
+    )]
+    pub token_x_reserve: Box<Account<'info, TokenAccount>>,
+    #[account(init,
+        token::mint = token_y,
+        token::authority = authority,
+        payer = payer,
+    )]
+    pub token_y_reserve: Box<Account<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = account_x.mint == token_x.key() @ InvalidMint,
+        constraint = &account_x.owner == owner.key @ InvalidOwner
+    )]
+    pub account_x: Box<Account<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = account_y.mint == token_y.key() @ InvalidMint,
+        constraint = &account_y.owner == owner.key @ InvalidOwner
+    )]
+    pub account_y: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub owner: Signer<'info>,
+    // first position of the new pool, so its id is zero
+    #[account(init,
+        seeds = [b"positionnftv1", pool.key().as_ref(), &0u128.to_le_bytes()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = authority,
+        payer = payer
+    )]
+    pub position_nft_mint: Box<Account<'info, Mint>>,
+    #[account(init,
+        associated_token::mint = position_nft_mint,
+        associated_token::authority = owner,
+        payer = payer
+    )]
+    pub position_nft_account: Box<Account<'info, TokenAccount>>,
+    #[account(constraint = &state.load()?.authority == authority.key @ InvalidAuthority)]
+    pub authority: AccountInfo<'info>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> TakeTokens<'info> for InitPoolAndPosition<'info> {
+    fn take_x(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.account_x.to_account_info(),
+                to: self.token_x_reserve.to_account_info(),
+                authority: self.owner.to_account_info().clone(),
+            },
+        )
+    }
+
+    fn take_y(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.account_y.to_account_info(),
+                to: self.token_y_reserve.to_account_info(),
+                authority: self.owner.to_account_info().clone(),
+            },
+        )
+    }
+}
+
+pub struct InitBumps {
+    pub pool: u8,
+    pub lower_tick: u8,
+    pub upper_tick: u8,
+    pub position: u8,
+}
+
+// Nothing has accrued in a new pool, so outside values start at zero on either side of the price
+fn new_tick(pool: Pubkey, index: i32, bump: u8) -> Tick {
+    Tick {
+        pool,
+        index,
+        sign: true,
+        liquidity_change: Liquidity::new(0),
+        liquidity_gross: Liquidity::new(0),
+        sqrt_price: calculate_price_sqrt(index),
+        fee_growth_outside_x: FeeGrowth::new(0),
+        fee_growth_outside_y: FeeGrowth::new(0),
+        seconds_per_liquidity_outside: FixedPoint::new(0),
+        seconds_outside: 0,
+        bump,
+        limit_order_liquidity_below: Liquidity::new(0),
+        limit_order_liquidity_above: Liquidity::new(0),
+        limit_order_fills_below: 0,
+        limit_order_fills_above: 0,
+        limit_orders: 0,
+    }
+}
+
+impl<'info> InitPoolAndPosition<'info> {
+    // create_pool, create_tick for both ticks and create_position in a single instruction, so
+    // the pool never exists without liquidity at the price its creator has set
+    pub fn handler(&self, args: InitPoolAndPositionArgs, bumps: InitBumps) -> ProgramResult {
+        msg!("INVARIANT: INIT POOL AND POSITION");
+
+        let token_x_address = &self.token_x.key();
+        let token_y_address = &self.token_y.key();
+        require!(
+            token_x_address
+                .to_string()
+                .cmp(&token_y_address.to_string())
+                == Ordering::Less,
+            InvalidPoolTokenAddresses
+        );
+        check_token_decimals(self.token_x.decimals, self.token_y.decimals)?;
+
+        let pool = &mut self.pool.load_init()?;
+        let fee_tier = self.fee_tier.load()?;
+        let mut tickmap = self.tickmap.load_init()?;
+        let lower_tick = &mut self.lower_tick.load_init()?;
+        let upper_tick = &mut self.upper_tick.load_init()?;
+        let mut position = self.position.load_init()?;
+        let mut position_list = self.position_list.load_mut()?;
+        let current_timestamp = get_current_timestamp();
+        let tick_spacing = fee_tier.tick_spacing;
+
+        check_tick(args.init_tick, tick_spacing)?;
+        check_ticks(args.lower_tick_index, args.upper_tick_index, tick_spacing)?;
+
+        **pool = Pool::new(
+            *token_x_address,
+            *token_y_address,
+            *self.token_x_reserve.to_account_info().key,
+            *self.token_y_reserve.to_account_info().key,
+            *self.tickmap.to_account_info().key,
+            fee_tier.fee,
+            tick_spacing,
+            args.init_tick,
+            self.state.load()?.admin,
+            bumps.pool,
+            current_timestamp,
+        );
+
+        **lower_tick = new_tick(self.pool.key(), args.lower_tick_index, bumps.lower_tick);
+        **upper_tick = new_tick(self.pool.key(), args.upper_tick_index, bumps.upper_tick);
+        tickmap.flip(true, args.lower_tick_index, tick_spacing);
+        tickmap.flip(true, args.upper_tick_index, tick_spacing);
+
+        position_list.head = position_list.head.checked_add(1).unwrap();
+        position.initialized_id(pool);
+        *position = Position {
+            owner: *self.owner.to_account_info().key,
+            pool: *self.pool.to_account_info().key,
+            id: position.id,
+            liquidity: Liquidity::new(0),
+            lower_tick_index: args.lower_tick_index,
+            upper_tick_index: args.upper_tick_index,
+            fee_growth_inside_x: FeeGrowth::new(0),
+            fee_growth_inside_y: FeeGrowth::new(0),
+            seconds_per_liquidity_inside: FixedPoint::new(0),
+            last_slot: get_current_slot(),
+            tokens_owed_x: FixedPoint::new(0),
+            tokens_owed_y: FixedPoint::new(0),
+            maker_fees_x: FixedPoint::new(0),
+            maker_fees_y: FixedPoint::new(0),
+            total_claimed_x: 0,
+            total_claimed_y: 0,
+            bump: bumps.position,
+        };
+
+        let (amount_x, amount_y) = position.modify(
+            pool,
+            upper_tick,
+            lower_tick,
+            Liquidity::new(args.liquidity_delta),
+            true,
+            current_timestamp,
+        )?;
+
+        emit!(CreatePoolEvent {
+            pool: self.pool.key(),
+            token_x: *token_x_address,
+            token_y: *token_y_address,
+            fee: fee_tier.fee.v,
+            tick_spacing,
+            init_tick: args.init_tick,
+            sqrt_price: pool.sqrt_price.v,
+        });
+        emit!(CreatePositionEvent {
+            pool: self.pool.key(),
+            position: self.position.key(),
+            owner: self.owner.key(),
+            id: position.id,
+            lower_tick_index: position.lower_tick_index,
+            upper_tick_index: position.upper_tick_index,
+            liquidity: position.liquidity.v,
+            fee_growth_inside_x: position.fee_growth_inside_x.v,
+            fee_growth_inside_y: position.fee_growth_inside_y.v,
+            seconds_per_liquidity_inside: position.seconds_per_liquidity_inside.v,
+            last_slot: position.last_slot,
+            sqrt_price: pool.sqrt_price.v,
+            current_tick_index: pool.current_tick_index,
+            amount_x: amount_x.0,
+            amount_y: amount_y.0,
+        });
+
+        pool.add_reserves(amount_x, amount_y);
+        token::transfer(self.take_x(), amount_x.0)?;
+        token::transfer(self.take_y(), amount_y.0)?;
+        pool.strict_check_reserves(
+            &self.token_x_reserve.to_account_info(),
+            &self.token_y_reserve.to_account_info(),
+        );
+        mint_position_nft(
+            &self.token_program.to_account_info(),
+            &self.position_nft_mint.to_account_info(),
+            &self.position_nft_account.to_account_info(),
+            &self.authority,
+            self.state.load()?.nonce,
+        )?;
+
+        self.global_stats.load_mut()?.total_pools += 1;
+        Ok(())
+    }
+}
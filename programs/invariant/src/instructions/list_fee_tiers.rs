@@ -0,0 +1,32 @@
+use crate::args::{FeeTierReturnData, ListFeeTiersReturnData};
+use crate::structs::FeeTierRegistry;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
+
+#[derive(Accounts)]
+pub struct ListFeeTiers<'info> {
+    #[account(seeds = [b"feetierregistryv1".as_ref()], bump = fee_tier_registry.load()?.bump)]
+    pub fee_tier_registry: AccountLoader<'info, FeeTierRegistry>,
+}
+
+impl<'info> ListFeeTiers<'info> {
+    // meant to be simulated, clients read the fee tiers from return data
+    pub fn handler(&self) -> ProgramResult {
+        msg!("INVARIANT: LIST FEE TIERS");
+
+        let fee_tier_registry = self.fee_tier_registry.load()?;
+        let return_data = ListFeeTiersReturnData {
+            fee_tiers: fee_tier_registry
+                .fee_tiers()
+                .iter()
+                .map(|entry| FeeTierReturnData {
+                    fee: entry.fee.v,
+                    tick_spacing: entry.tick_spacing,
+                })
+                .collect(),
+        };
+        set_return_data(&return_data.try_to_vec()?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use crate::decimals::*;
+use crate::instructions::create_position::CreatePosition;
+use crate::math::get_max_liquidity;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+
+impl<'info> CreatePosition<'info> {
+    // Opens a position with tokens withdrawn from another AMM earlier in the same transaction.
+    // Liquidity is derived from the amounts on-chain, as the withdrawn amounts are known only at execution.
+    pub fn migrate_from_external_handler(
+        &self,
+        remaining_accounts: &[AccountInfo],
+        amount_x: u64,
+        amount_y: u64,
+        slippage_limit_lower: Price,
+        slippage_limit_upper: Price,
+        bump: u8,
+    ) -> ProgramResult {
+        msg!("INVARIANT: MIGRATE FROM EXTERNAL");
+
+        let liquidity_delta = {
+            let pool = self.pool.load()?;
+            let lower_tick = self.lower_tick.load()?;
+            let upper_tick = self.upper_tick.load()?;
+
+            get_max_liquidity(
+                TokenAmount(amount_x.min(self.account_x.amount)),
+                TokenAmount(amount_y.min(self.account_y.amount)),
+                lower_tick.sqrt_price,
+                upper_tick.sqrt_price,
+                pool.sqrt_price,
+            )
+            .ok_or(InvalidPositionLiquidity)?
+        };
+        require!(!liquidity_delta.is_zero(), PositionWithoutLiquidity);
+
+        self.handler(
+            remaining_accounts,
+            liquidity_delta,
+            slippage_limit_lower,
+            slippage_limit_upper,
+            bump,
+        )
+    }
+}
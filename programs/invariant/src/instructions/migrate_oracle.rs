@@ -0,0 +1,42 @@
+use crate::structs::oracle::Oracle;
+use crate::util::{check_legacy_account, realloc_account};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+// Oracle before records stored the square root of the price and the liquidity
+pub const LEGACY_ORACLE_SIZE: usize = 6150;
+
+#[derive(Accounts)]
+pub struct MigrateOracle<'info> {
+    // the legacy layout can't be loaded, so the account is checked in the handler
+    #[account(mut)]
+    pub oracle: AccountInfo<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> MigrateOracle<'info> {
+    // Anyone can migrate an oracle. Legacy records can't be converted, so the oracle starts
+    // over empty and TWAPs are available again once it records a new window.
+    pub fn handler(&self, program_id: &Pubkey) -> ProgramResult {
+        msg!("INVARIANT: MIGRATE ORACLE");
+
+        check_legacy_account::<Oracle>(&self.oracle, program_id, LEGACY_ORACLE_SIZE)?;
+
+        realloc_account(
+            &self.oracle,
+            &self.payer.to_account_info(),
+            &self.system_program,
+            8 + std::mem::size_of::<Oracle>(),
+        )?;
+
+        let oracle_loader = AccountLoader::<'_, Oracle>::try_from(&self.oracle)?;
+        let oracle = &mut oracle_loader.load_mut()?;
+        oracle.amount = 0;
+        oracle.init();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+use crate::structs::pool::Pool;
+use crate::util::{check_legacy_account, get_current_timestamp, realloc_account};
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token::TokenAccount;
+
+// Pool before the fields from `fee_tier_fee` on were appended. The size of the account tells
+// the version of its layout, fields appended later need their own migration.
+pub const LEGACY_POOL_SIZE: usize = 392;
+
+#[derive(Accounts)]
+pub struct MigratePool<'info> {
+    // the legacy layout can't be loaded, so the account is checked in the handler
+    #[account(mut)]
+    pub pool: AccountInfo<'info>,
+    pub token_x_reserve: Account<'info, TokenAccount>,
+    pub token_y_reserve: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> MigratePool<'info> {
+    // Anyone can migrate a pool, everything backfilled is derived from the accounts. Has to
+    // run once after the upgrade, legacy pools can't be used until then.
+    pub fn handler(&self, program_id: &Pubkey) -> ProgramResult {
+        msg!("INVARIANT: MIGRATE POOL");
+
+        check_legacy_account::<Pool>(&self.pool, program_id, LEGACY_POOL_SIZE)?;
+
+        realloc_account(
+            &self.pool,
+            &self.payer.to_account_info(),
+            &self.system_program,
+            8 + std::mem::size_of::<Pool>(),
+        )?;
+
+        let pool_loader = AccountLoader::<'_, Pool>::try_from(&self.pool)?;
+        let pool = &mut pool_loader.load_mut()?;
+
+        // seeds of legacy pools use the fee, it becomes the fee of the tier
+        let pool_address = Pubkey::create_program_address(
+            &[
+                b"poolv1",
+                pool.token_x.as_ref(),
+                pool.token_y.as_ref(),
+                &pool.fee.v.to_le_bytes(),
+                &pool.tick_spacing.to_le_bytes(),
+                &[pool.bump],
+            ],
+            program_id,
+        )
+        .map_err(|_| InvalidPoolAddress)?;
+        require!(pool_address == *self.pool.key, InvalidPoolAddress);
+        require!(
+            self.token_x_reserve.key() == pool.token_x_reserve,
+            InvalidTokenAccount
+        );
+        require!(
+            self.token_y_reserve.key() == pool.token_y_reserve,
+            InvalidTokenAccount
+        );
+
+        pool.migrate(
+            self.token_x_reserve.amount,
+            self.token_y_reserve.amount,
+            get_current_timestamp(),
+        );
+
+        Ok(())
+    }
+}
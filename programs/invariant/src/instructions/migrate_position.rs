@@ -0,0 +1,36 @@
+use crate::structs::position::Position;
+use crate::util::{check_legacy_account, realloc_account};
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+// Position before the maker fees and claimed totals were appended
+pub const LEGACY_POSITION_SIZE: usize = 193;
+
+#[derive(Accounts)]
+pub struct MigratePosition<'info> {
+    // the legacy layout can't be loaded, so the account is checked in the handler
+    #[account(mut)]
+    pub position: AccountInfo<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> MigratePosition<'info> {
+    // Anyone can migrate a position. Appended fields start zeroed, so rebate tiers and
+    // claimed totals count from the migration on.
+    pub fn handler(&self, program_id: &Pubkey) -> ProgramResult {
+        msg!("INVARIANT: MIGRATE POSITION");
+
+        check_legacy_account::<Position>(&self.position, program_id, LEGACY_POSITION_SIZE)?;
+
+        realloc_account(
+            &self.position,
+            &self.payer.to_account_info(),
+            &self.system_program,
+            8 + std::mem::size_of::<Position>(),
+        )
+    }
+}
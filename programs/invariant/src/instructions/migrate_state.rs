@@ -0,0 +1,52 @@
+use crate::structs::State;
+use crate::util::{check_legacy_account, realloc_account};
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+// State before `pending_admin` and `is_paused` were appended
+pub const LEGACY_STATE_SIZE: usize = 66;
+
+#[derive(Accounts)]
+pub struct MigrateState<'info> {
+    // the legacy layout can't be loaded, so the account is checked in the handler
+    #[account(mut, seeds = [b"statev1".as_ref()], bump)]
+    pub state: AccountInfo<'info>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> MigrateState<'info> {
+    // Has to run once after the upgrade, before anything else loads the state.
+    // Appended fields start zeroed, so there is no proposed admin and the protocol is unpaused.
+    pub fn handler(&self, program_id: &Pubkey) -> ProgramResult {
+        msg!("INVARIANT: MIGRATE STATE");
+
+        check_legacy_account::<State>(&self.state, program_id, LEGACY_STATE_SIZE)?;
+        // admin is the first field in both layouts
+        require!(
+            self.state.try_borrow_data()?[8..40] == self.admin.key.to_bytes(),
+            InvalidAdmin
+        );
+
+        realloc_account(
+            &self.state,
+            &self.admin.to_account_info(),
+            &self.system_program,
+            8 + std::mem::size_of::<State>(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_legacy_state_size() {
+        // pending_admin and is_paused
+        assert_eq!(LEGACY_STATE_SIZE + 32 + 1, std::mem::size_of::<State>());
+    }
+}
@@ -0,0 +1,36 @@
+use crate::structs::tick::Tick;
+use crate::util::{check_legacy_account, realloc_account};
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+// Tick before the limit order book was appended
+pub const LEGACY_TICK_SIZE: usize = 142;
+
+#[derive(Accounts)]
+pub struct MigrateTick<'info> {
+    // the legacy layout can't be loaded, so the account is checked in the handler
+    #[account(mut)]
+    pub tick: AccountInfo<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> MigrateTick<'info> {
+    // Anyone can migrate a tick, ticks start without limit orders. Swaps can't cross legacy
+    // ticks, so the initialized ticks of a pool have to be migrated together with it.
+    pub fn handler(&self, program_id: &Pubkey) -> ProgramResult {
+        msg!("INVARIANT: MIGRATE TICK");
+
+        check_legacy_account::<Tick>(&self.tick, program_id, LEGACY_TICK_SIZE)?;
+
+        realloc_account(
+            &self.tick,
+            &self.payer.to_account_info(),
+            &self.system_program,
+            8 + std::mem::size_of::<Tick>(),
+        )
+    }
+}
@@ -0,0 +1,64 @@
+use crate::structs::position::Position;
+use crate::util::mint_position_nft;
+use crate::ErrorCode::*;
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, TokenAccount};
+
+// Positions created before position NFTs have none, their owner mints it once. Until then the
+// position can't be managed, as every instruction checks the holder of the NFT.
+#[derive(Accounts)]
+#[instruction(index: u32)]
+pub struct MintPositionNft<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(
+        seeds = [b"positionv1",
+        owner.key().as_ref(),
+        &index.to_le_bytes()],
+        bump = position.load()?.bump
+    )]
+    pub position: AccountLoader<'info, Position>,
+    #[account(init,
+        seeds = [b"positionnftv1", position.load()?.pool.as_ref(), &position.load()?.id.to_le_bytes()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = program_authority,
+        payer = payer
+    )]
+    pub position_nft_mint: Box<Account<'info, Mint>>,
+    #[account(init,
+        associated_token::mint = position_nft_mint,
+        associated_token::authority = owner,
+        payer = payer
+    )]
+    pub position_nft_account: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub owner: Signer<'info>,
+    #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
+    pub program_authority: AccountInfo<'info>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> MintPositionNft<'info> {
+    pub fn handler(&self) -> ProgramResult {
+        msg!("INVARIANT: MINT POSITION NFT");
+
+        let state = self.state.load()?;
+        mint_position_nft(
+            &self.token_program,
+            &self.position_nft_mint.to_account_info(),
+            &self.position_nft_account.to_account_info(),
+            &self.program_authority,
+            state.nonce,
+        )
+    }
+}
@@ -1,31 +1,136 @@
+pub mod accept_admin;
+pub mod cancel_limit_order;
 pub mod change_fee_receiver;
 pub mod change_protocol_fee;
+pub mod change_tick_ema_half_life;
 pub mod claim_fee;
+pub mod claim_fee_batch;
+pub mod claim_limit_order;
+pub mod claim_referral_fees;
+pub mod convert_protocol_fees;
+pub mod create_admin_log;
+#[cfg(feature = "devnet")]
+pub mod create_faucet_mint;
 pub mod create_fee_tier;
+pub mod create_fee_tier_registry;
+pub mod create_global_stats;
+pub mod create_launch_config;
+pub mod create_limit_order;
 pub mod create_pool;
+pub mod create_pools_batch;
 pub mod create_position;
 pub mod create_position_list;
+pub mod create_position_nft_metadata;
+pub mod create_price_feed;
+pub mod create_rebate_schedule;
+pub mod create_referral_earnings;
+pub mod create_session_authority;
 pub mod create_state;
 pub mod create_tick;
+pub mod create_withdraw_acl;
+pub mod decrease_liquidity;
+#[cfg(feature = "devnet")]
+pub mod faucet;
+pub mod flash_loan;
+pub mod force_close_orphan_position;
+pub mod increase_liquidity;
+pub mod init_pool_and_position;
 pub mod initialize_oracle;
+pub mod list_fee_tiers;
+pub mod migrate_from_external;
+pub mod migrate_oracle;
+pub mod migrate_pool;
+pub mod migrate_position;
+pub mod migrate_state;
+pub mod migrate_tick;
+pub mod mint_position_nft;
+pub mod propose_admin;
+pub mod propose_pool_fee;
 pub mod remove_position;
+pub mod revoke_session_authority;
+pub mod set_flash_loan_fee;
+pub mod set_large_swap_threshold;
+pub mod set_launch_whitelisted;
+pub mod set_max_price_change;
+pub mod set_paused;
+pub mod set_pool_hook;
+pub mod set_rebate_schedule;
+pub mod set_referral_fee;
+pub mod set_withdrawal_only;
+pub mod set_withdrawer;
+pub mod skim_excess;
 pub mod swap;
+pub mod swap_route;
 pub mod transfer_position_ownership;
+pub mod update_pool_fee;
+pub mod update_price_feed;
 pub mod update_seconds_per_liquidity;
 pub mod withdraw_protocol_fee;
 
+pub use accept_admin::*;
+pub use cancel_limit_order::*;
 pub use change_fee_receiver::*;
 pub use change_protocol_fee::*;
+pub use change_tick_ema_half_life::*;
 pub use claim_fee::*;
+pub use claim_fee_batch::*;
+pub use claim_limit_order::*;
+pub use claim_referral_fees::*;
+pub use convert_protocol_fees::*;
+pub use create_admin_log::*;
+#[cfg(feature = "devnet")]
+pub use create_faucet_mint::*;
 pub use create_fee_tier::*;
+pub use create_fee_tier_registry::*;
+pub use create_global_stats::*;
+pub use create_launch_config::*;
+pub use create_limit_order::*;
 pub use create_pool::*;
+pub use create_pools_batch::*;
 pub use create_position::*;
 pub use create_position_list::*;
+pub use create_position_nft_metadata::*;
+pub use create_price_feed::*;
+pub use create_rebate_schedule::*;
+pub use create_referral_earnings::*;
+pub use create_session_authority::*;
 pub use create_state::*;
 pub use create_tick::*;
+pub use create_withdraw_acl::*;
+pub use decrease_liquidity::*;
+#[cfg(feature = "devnet")]
+pub use faucet::*;
+pub use flash_loan::*;
+pub use force_close_orphan_position::*;
+pub use increase_liquidity::*;
+pub use init_pool_and_position::*;
 pub use initialize_oracle::*;
+pub use list_fee_tiers::*;
+pub use migrate_oracle::*;
+pub use migrate_pool::*;
+pub use migrate_position::*;
+pub use migrate_state::*;
+pub use migrate_tick::*;
+pub use mint_position_nft::*;
+pub use propose_admin::*;
+pub use propose_pool_fee::*;
 pub use remove_position::*;
+pub use revoke_session_authority::*;
+pub use set_flash_loan_fee::*;
+pub use set_large_swap_threshold::*;
+pub use set_launch_whitelisted::*;
+pub use set_max_price_change::*;
+pub use set_paused::*;
+pub use set_pool_hook::*;
+pub use set_rebate_schedule::*;
+pub use set_referral_fee::*;
+pub use set_withdrawal_only::*;
+pub use set_withdrawer::*;
+pub use skim_excess::*;
 pub use swap::*;
+pub use swap_route::*;
 pub use transfer_position_ownership::*;
+pub use update_pool_fee::*;
+pub use update_price_feed::*;
 pub use update_seconds_per_liquidity::*;
 pub use withdraw_protocol_fee::*;
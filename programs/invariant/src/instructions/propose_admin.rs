@@ -0,0 +1,43 @@
+use crate::events::ProposeAdminEvent;
+use crate::structs::{AdminAction, AdminLog, State};
+use crate::util::get_current_slot;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    #[account(mut, seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut, seeds = [b"adminlogv1".as_ref()], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+    #[account(constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+    pub pending_admin: AccountInfo<'info>,
+}
+
+impl<'info> ProposeAdmin<'info> {
+    // admin changes only once the proposed key signs `accept_admin`, so a mistyped key
+    // can't lock the protocol. Proposing the default key withdraws the proposal.
+    pub fn handler(&self) -> ProgramResult {
+        msg!("INVARIANT: PROPOSE ADMIN");
+
+        let mut state = self.state.load_mut()?;
+        let old_pending_admin = state.pending_admin;
+        state.pending_admin = self.pending_admin.key();
+
+        self.admin_log.load_mut()?.append(
+            self.admin.key(),
+            AdminAction::ProposeAdmin,
+            self.state.key(),
+            old_pending_admin.to_bytes(),
+            self.pending_admin.key().to_bytes(),
+            get_current_slot(),
+        );
+        emit!(ProposeAdminEvent {
+            admin: self.admin.key(),
+            pending_admin: self.pending_admin.key(),
+        });
+
+        Ok(())
+    }
+}
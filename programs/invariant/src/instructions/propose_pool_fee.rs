@@ -0,0 +1,45 @@
+use crate::decimals::*;
+use crate::structs::{log_value, AdminAction, AdminLog, Pool, State};
+use crate::util::{get_current_slot, get_current_timestamp};
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+#[derive(Accounts)]
+pub struct ProposePoolFee<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut, seeds = [b"adminlogv1".as_ref()], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+    #[account(mut,
+        seeds = [b"poolv1", token_x.to_account_info().key.as_ref(), token_y.to_account_info().key.as_ref(), &pool.load()?.fee_tier_fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        bump = pool.load()?.bump
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(constraint = token_x.to_account_info().key == &pool.load()?.token_x @ InvalidTokenAccount) ]
+    pub token_x: Account<'info, Mint>,
+    #[account(constraint = token_y.to_account_info().key == &pool.load()?.token_y @ InvalidTokenAccount)]
+    pub token_y: Account<'info, Mint>,
+    #[account(constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+}
+
+impl<'info> ProposePoolFee<'info> {
+    pub fn handler(&self, fee: FixedPoint) -> ProgramResult {
+        msg!("INVARIANT: PROPOSE POOL FEE");
+
+        let pool = &mut self.pool.load_mut()?;
+        pool.propose_fee(fee, get_current_timestamp())?;
+
+        self.admin_log.load_mut()?.append(
+            self.admin.key(),
+            AdminAction::ProposePoolFee,
+            self.pool.key(),
+            log_value(pool.fee.v),
+            log_value(fee.v),
+            get_current_slot(),
+        );
+
+        Ok(())
+    }
+}
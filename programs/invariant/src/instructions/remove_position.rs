@@ -1,8 +1,10 @@
 use crate::decimals::*;
+use crate::events::RemovePositionEvent;
 use crate::interfaces::send_tokens::SendTokens;
 use crate::structs::pool::Pool;
 use crate::structs::position::Position;
 use crate::structs::position_list::PositionList;
+use crate::structs::position_nft::holds_position_nft;
 use crate::structs::tick::Tick;
 use crate::structs::tickmap::Tickmap;
 use crate::util::{check_ticks, close};
@@ -10,7 +12,7 @@ use crate::ErrorCode::*;
 use crate::*;
 use anchor_lang::prelude::*;
 use anchor_spl::token;
-use anchor_spl::token::{Mint, TokenAccount, Transfer};
+use anchor_spl::token::{Burn, CloseAccount, Mint, TokenAccount, Transfer};
 
 #[derive(Accounts)]
 #[instruction(index: i32, lower_tick_index: i32, upper_tick_index: i32)]
@@ -38,7 +40,7 @@ pub struct RemovePosition<'info> {
     )]
     pub last_position: AccountLoader<'info, Position>,
     #[account(mut,
-        seeds = [b"poolv1", token_x.key().as_ref(), token_y.key().as_ref(), &pool.load()?.fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        seeds = [b"poolv1", token_x.key().as_ref(), token_y.key().as_ref(), &pool.load()?.fee_tier_fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
         bump = pool.load()?.bump
     )]
     pub pool: AccountLoader<'info, Pool>,
@@ -59,20 +61,33 @@ pub struct RemovePosition<'info> {
         constraint = upper_tick_index == removed_position.load()?.upper_tick_index @ WrongTick
     )]
     pub upper_tick: AccountLoader<'info, Tick>,
+    // rent of the closed accounts goes back to the owner of the list, who paid it
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub owner: AccountInfo<'info>,
+    #[account(mut)]
+    pub holder: Signer<'info>,
+    #[account(mut,
+        seeds = [b"positionnftv1", pool.key().as_ref(), &removed_position.load()?.id.to_le_bytes()],
+        bump
+    )]
+    pub position_nft_mint: Box<Account<'info, Mint>>,
+    #[account(mut,
+        constraint = holds_position_nft(&*removed_position.load()?, &position_nft_account) @ InvalidOwner,
+        constraint = &position_nft_account.owner == holder.key @ InvalidOwner
+    )]
+    pub position_nft_account: Box<Account<'info, TokenAccount>>,
     #[account(constraint = token_x.key() == pool.load()?.token_x @ InvalidTokenAccount)]
     pub token_x: Account<'info, Mint>,
     #[account(constraint = token_y.key() == pool.load()?.token_y @ InvalidTokenAccount)]
     pub token_y: Account<'info, Mint>,
     #[account(mut,
         constraint = account_x.mint == token_x.key() @ InvalidMint,
-        constraint = &account_x.owner == owner.key @ InvalidOwner
+        constraint = &account_x.owner == holder.key @ InvalidOwner
     )]
     pub account_x: Box<Account<'info, TokenAccount>>,
     #[account(mut,
         constraint = account_y.mint == token_y.key() @ InvalidMint,
-        constraint = &account_y.owner == owner.key @ InvalidOwner
+        constraint = &account_y.owner == holder.key @ InvalidOwner
     )]
     pub account_y: Box<Account<'info, TokenAccount>>,
     #[account(mut,
@@ -144,6 +159,8 @@ impl<'info> RemovePosition<'info> {
             // validate ticks
             check_ticks(lower_tick.index, upper_tick.index, pool.tick_spacing)?;
             let liquidity_delta = removed_position.liquidity;
+            let fee_growth_inside_x_before = removed_position.fee_growth_inside_x;
+            let fee_growth_inside_y_before = removed_position.fee_growth_inside_y;
             let (amount_x, amount_y) = removed_position.modify(
                 pool,
                 upper_tick,
@@ -153,11 +170,45 @@ impl<'info> RemovePosition<'info> {
                 current_timestamp,
             )?;
 
-            let amount_x = amount_x + TokenAmount::from_decimal(removed_position.tokens_owed_x);
-            let amount_y = amount_y + TokenAmount::from_decimal(removed_position.tokens_owed_y);
+            // principal withdrawal must never depend on fee math, owed fees over the reserves left
+            // after the principal and the protocol fees are invalid and not paid. The bound only
+            // keeps the principal withdrawable, a smaller invalid value is still paid.
+            let available_x = { pool.reserve_x }
+                .saturating_sub(amount_x.0)
+                .saturating_sub(pool.fee_protocol_token_x);
+            let available_y = { pool.reserve_y }
+                .saturating_sub(amount_y.0)
+                .saturating_sub(pool.fee_protocol_token_y);
+            let (fee_x, fee_y) = removed_position
+                .withdrawable_fees(TokenAmount(available_x), TokenAmount(available_y));
+            strict_assert!(
+                amount_x.0 + fee_x.0 <= pool.reserve_x && amount_y.0 + fee_y.0 <= pool.reserve_y,
+                "removed position takes more than the reserves"
+            );
 
-            close_lower = lower_tick.liquidity_gross.is_zero();
-            close_upper = upper_tick.liquidity_gross.is_zero();
+            emit!(RemovePositionEvent {
+                pool: self.pool.key(),
+                position: self.removed_position.key(),
+                owner: self.owner.key(),
+                liquidity: liquidity_delta.v,
+                fee_growth_inside_x_before: fee_growth_inside_x_before.v,
+                fee_growth_inside_y_before: fee_growth_inside_y_before.v,
+                fee_growth_inside_x_after: removed_position.fee_growth_inside_x.v,
+                fee_growth_inside_y_after: removed_position.fee_growth_inside_y.v,
+                fee_growth_global_x: pool.fee_growth_global_x.v,
+                fee_growth_global_y: pool.fee_growth_global_y.v,
+                protocol_fee: pool.protocol_fee.v,
+                amount_x: amount_x.0,
+                amount_y: amount_y.0,
+                fee_x: fee_x.0,
+                fee_y: fee_y.0,
+            });
+
+            let amount_x = amount_x + fee_x;
+            let amount_y = amount_y + fee_y;
+
+            close_lower = lower_tick.is_empty();
+            close_upper = upper_tick.is_empty();
 
             (amount_x, amount_y)
         };
@@ -211,6 +262,10 @@ impl<'info> RemovePosition<'info> {
                 last_slot: last_position.last_slot,
                 tokens_owed_x: last_position.tokens_owed_x,
                 tokens_owed_y: last_position.tokens_owed_y,
+                maker_fees_x: last_position.maker_fees_x,
+                maker_fees_y: last_position.maker_fees_y,
+                total_claimed_x: last_position.total_claimed_x,
+                total_claimed_y: last_position.total_claimed_y,
             };
 
             *last_position = Default::default();
@@ -218,10 +273,34 @@ impl<'info> RemovePosition<'info> {
             **removed_position = Default::default();
         }
 
+        pool.sub_reserves(amount_x, amount_y);
         let signer: &[&[&[u8]]] = get_signer!(state.nonce);
         token::transfer(self.send_x().with_signer(signer), amount_x.0)?;
         token::transfer(self.send_y().with_signer(signer), amount_y.0)?;
+        pool.strict_check_reserves(
+            &self.reserve_x.to_account_info(),
+            &self.reserve_y.to_account_info(),
+        );
 
-        Ok(())
+        // the NFT goes together with the position, its emptied account is closed to the holder
+        token::burn(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                Burn {
+                    mint: self.position_nft_mint.to_account_info(),
+                    to: self.position_nft_account.to_account_info(),
+                    authority: self.holder.to_account_info(),
+                },
+            ),
+            1,
+        )?;
+        token::close_account(CpiContext::new(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: self.position_nft_account.to_account_info(),
+                destination: self.holder.to_account_info(),
+                authority: self.holder.to_account_info(),
+            },
+        ))
     }
 }
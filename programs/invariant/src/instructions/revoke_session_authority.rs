@@ -0,0 +1,25 @@
+use crate::structs::SessionAuthority;
+use crate::util::close;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RevokeSessionAuthority<'info> {
+    #[account(mut,
+        seeds = [b"sessionv1", owner.key.as_ref(), session_authority.load()?.session_key.as_ref()],
+        bump = session_authority.load()?.bump
+    )]
+    pub session_authority: AccountLoader<'info, SessionAuthority>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+impl<'info> RevokeSessionAuthority<'info> {
+    pub fn handler(&self) -> ProgramResult {
+        msg!("INVARIANT: REVOKE SESSION AUTHORITY");
+
+        close(
+            self.session_authority.to_account_info(),
+            self.owner.to_account_info(),
+        )
+    }
+}
@@ -0,0 +1,52 @@
+use crate::structs::{log_value, AdminAction, AdminLog, Pool, State, BPS_DENOMINATOR};
+use crate::util::get_current_slot;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+#[derive(Accounts)]
+pub struct SetFlashLoanFee<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut, seeds = [b"adminlogv1".as_ref()], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+    #[account(mut,
+        seeds = [b"poolv1", token_x.to_account_info().key.as_ref(), token_y.to_account_info().key.as_ref(), &pool.load()?.fee_tier_fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        bump = pool.load()?.bump
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(constraint = token_x.to_account_info().key == &pool.load()?.token_x @ InvalidTokenAccount) ]
+    pub token_x: Account<'info, Mint>,
+    #[account(constraint = token_y.to_account_info().key == &pool.load()?.token_y @ InvalidTokenAccount)]
+    pub token_y: Account<'info, Mint>,
+    #[account(constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+}
+
+impl<'info> SetFlashLoanFee<'info> {
+    // Fee of flash loans, charged on the borrowed amount. Pools accept flash loans only once it
+    // is set.
+    pub fn handler(&self, flash_loan_fee_bps: u16) -> ProgramResult {
+        msg!("INVARIANT: SET FLASH LOAN FEE");
+        require!(
+            flash_loan_fee_bps as u64 <= BPS_DENOMINATOR,
+            InvalidFlashLoanFee
+        );
+
+        let pool = &mut self.pool.load_mut()?;
+        let old_flash_loan_fee_bps = pool.flash_loan_fee_bps;
+        pool.flash_loan_fee_bps = flash_loan_fee_bps;
+        pool.flash_loans_enabled = true;
+
+        self.admin_log.load_mut()?.append(
+            self.admin.key(),
+            AdminAction::SetFlashLoanFee,
+            self.pool.key(),
+            log_value(old_flash_loan_fee_bps as u128),
+            log_value(flash_loan_fee_bps as u128),
+            get_current_slot(),
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+use crate::structs::{log_value, AdminAction, AdminLog, Pool, State};
+use crate::util::get_current_slot;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+#[derive(Accounts)]
+pub struct SetLargeSwapThreshold<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut, seeds = [b"adminlogv1".as_ref()], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+    #[account(mut,
+        seeds = [b"poolv1", token_x.to_account_info().key.as_ref(), token_y.to_account_info().key.as_ref(), &pool.load()?.fee_tier_fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        bump = pool.load()?.bump
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(constraint = token_x.to_account_info().key == &pool.load()?.token_x @ InvalidTokenAccount) ]
+    pub token_x: Account<'info, Mint>,
+    #[account(constraint = token_y.to_account_info().key == &pool.load()?.token_y @ InvalidTokenAccount)]
+    pub token_y: Account<'info, Mint>,
+    #[account(constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+}
+
+// both thresholds in one value, x in the lower and y in the upper 8 bytes
+fn log_thresholds(threshold_x: u64, threshold_y: u64) -> [u8; 32] {
+    log_value(threshold_x as u128 | (threshold_y as u128) << 64)
+}
+
+impl<'info> SetLargeSwapThreshold<'info> {
+    // Amounts of tokens over which swaps emit LargeSwapEvent, zero disables the threshold
+    pub fn handler(&self, threshold_x: u64, threshold_y: u64) -> ProgramResult {
+        msg!("INVARIANT: SET LARGE SWAP THRESHOLD");
+
+        let pool = &mut self.pool.load_mut()?;
+        let old_thresholds =
+            log_thresholds(pool.large_swap_threshold_x, pool.large_swap_threshold_y);
+        pool.large_swap_threshold_x = threshold_x;
+        pool.large_swap_threshold_y = threshold_y;
+
+        self.admin_log.load_mut()?.append(
+            self.admin.key(),
+            AdminAction::SetLargeSwapThreshold,
+            self.pool.key(),
+            old_thresholds,
+            log_thresholds(threshold_x, threshold_y),
+            get_current_slot(),
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use crate::structs::{log_value, AdminAction, AdminLog, LaunchConfig, State};
+use crate::util::get_current_slot;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetLaunchWhitelisted<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut, seeds = [b"adminlogv1".as_ref()], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+    #[account(mut,
+        seeds = [b"launchv1", launch_config.load()?.pool.as_ref()],
+        bump = launch_config.load()?.bump
+    )]
+    pub launch_config: AccountLoader<'info, LaunchConfig>,
+    #[account(constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+    pub user: AccountInfo<'info>,
+}
+
+impl<'info> SetLaunchWhitelisted<'info> {
+    pub fn handler(&self, whitelisted: bool) -> ProgramResult {
+        msg!("INVARIANT: SET LAUNCH WHITELISTED");
+
+        let mut launch_config = self.launch_config.load_mut()?;
+        let was_whitelisted = launch_config.is_whitelisted(self.user.key);
+        match whitelisted {
+            true => launch_config.add_user(self.user.key())?,
+            false => launch_config.remove_user(self.user.key)?,
+        }
+
+        self.admin_log.load_mut()?.append(
+            self.admin.key(),
+            AdminAction::SetLaunchWhitelisted,
+            self.user.key(),
+            log_value(was_whitelisted as u128),
+            log_value(whitelisted as u128),
+            get_current_slot(),
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+use crate::structs::{log_value, AdminAction, AdminLog, State};
+use crate::util::get_current_slot;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    #[account(mut, seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut, seeds = [b"adminlogv1".as_ref()], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+    #[account(constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+}
+
+impl<'info> SetPaused<'info> {
+    // same as withdrawal only on every pool at once, remove_position, claim_fee and
+    // protocol fee withdrawals stay available so users can exit during an incident
+    pub fn handler(&self, is_paused: bool) -> ProgramResult {
+        msg!("INVARIANT: SET PAUSED");
+
+        let mut state = self.state.load_mut()?;
+        let was_paused = state.is_paused;
+        state.is_paused = is_paused;
+
+        self.admin_log.load_mut()?.append(
+            self.admin.key(),
+            AdminAction::SetPaused,
+            self.state.key(),
+            log_value(was_paused as u128),
+            log_value(is_paused as u128),
+            get_current_slot(),
+        );
+
+        Ok(())
+    }
+}
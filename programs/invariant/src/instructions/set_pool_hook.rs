@@ -0,0 +1,49 @@
+use crate::structs::{AdminAction, AdminLog, Pool, State};
+use crate::util::get_current_slot;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+// Approves a program called on every tick crossed by swaps in the pool.
+// Passing the system program (default key) disables the hook.
+#[derive(Accounts)]
+pub struct SetPoolHook<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut, seeds = [b"adminlogv1".as_ref()], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+    #[account(mut,
+        seeds = [b"poolv1", token_x.to_account_info().key.as_ref(), token_y.to_account_info().key.as_ref(), &pool.load()?.fee_tier_fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        bump = pool.load()?.bump
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(constraint = token_x.to_account_info().key == &pool.load()?.token_x @ InvalidTokenAccount) ]
+    pub token_x: Account<'info, Mint>,
+    #[account(constraint = token_y.to_account_info().key == &pool.load()?.token_y @ InvalidTokenAccount)]
+    pub token_y: Account<'info, Mint>,
+    #[account(constraint = hook_program.executable @ InvalidHookProgram)]
+    pub hook_program: AccountInfo<'info>,
+    #[account(constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+}
+
+impl<'info> SetPoolHook<'info> {
+    pub fn handler(&self) -> ProgramResult {
+        msg!("INVARIANT: SET POOL HOOK");
+
+        let pool = &mut self.pool.load_mut()?;
+        let old_hook_program = pool.hook_program;
+        pool.hook_program = self.hook_program.key();
+
+        self.admin_log.load_mut()?.append(
+            self.admin.key(),
+            AdminAction::SetPoolHook,
+            self.pool.key(),
+            old_hook_program.to_bytes(),
+            self.hook_program.key().to_bytes(),
+            get_current_slot(),
+        );
+
+        Ok(())
+    }
+}
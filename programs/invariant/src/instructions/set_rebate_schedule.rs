@@ -0,0 +1,41 @@
+use crate::args::SetRebateScheduleArgs;
+use crate::structs::{log_value, AdminAction, AdminLog, RebateSchedule, State};
+use crate::util::get_current_slot;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetRebateSchedule<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut, seeds = [b"adminlogv1".as_ref()], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+    #[account(mut,
+        seeds = [b"rebatesv1".as_ref()],
+        bump = rebate_schedule.load()?.bump
+    )]
+    pub rebate_schedule: AccountLoader<'info, RebateSchedule>,
+    #[account(constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+}
+
+impl<'info> SetRebateSchedule<'info> {
+    pub fn handler(&self, args: SetRebateScheduleArgs) -> ProgramResult {
+        msg!("INVARIANT: SET REBATE SCHEDULE");
+
+        let mut rebate_schedule = self.rebate_schedule.load_mut()?;
+        let old_tiers_count = rebate_schedule.tiers_count;
+        rebate_schedule.set_tiers(&args.tiers)?;
+
+        self.admin_log.load_mut()?.append(
+            self.admin.key(),
+            AdminAction::SetRebateSchedule,
+            self.rebate_schedule.key(),
+            log_value(old_tiers_count as u128),
+            log_value(rebate_schedule.tiers_count as u128),
+            get_current_slot(),
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use crate::structs::{log_value, AdminAction, AdminLog, State, WithdrawAcl};
+use crate::util::get_current_slot;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetWithdrawer<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut, seeds = [b"adminlogv1".as_ref()], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+    #[account(mut,
+        seeds = [b"withdrawaclv1", withdraw_acl.load()?.pool.as_ref()],
+        bump = withdraw_acl.load()?.bump
+    )]
+    pub withdraw_acl: AccountLoader<'info, WithdrawAcl>,
+    #[account(constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+    pub withdrawer: AccountInfo<'info>,
+}
+
+impl<'info> SetWithdrawer<'info> {
+    pub fn handler(&self, allowed: bool) -> ProgramResult {
+        msg!("INVARIANT: SET WITHDRAWER");
+
+        let mut withdraw_acl = self.withdraw_acl.load_mut()?;
+        let was_allowed = withdraw_acl.is_withdrawer(self.withdrawer.key);
+        match allowed {
+            true => withdraw_acl.add_withdrawer(self.withdrawer.key())?,
+            false => withdraw_acl.remove_withdrawer(self.withdrawer.key)?,
+        }
+
+        self.admin_log.load_mut()?.append(
+            self.admin.key(),
+            AdminAction::SetWithdrawer,
+            self.withdrawer.key(),
+            log_value(was_allowed as u128),
+            log_value(allowed as u128),
+            get_current_slot(),
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+use crate::events::SkimExcessEvent;
+use crate::interfaces::SendTokens;
+use crate::structs::{log_value, AdminAction, AdminLog, Pool, State};
+use crate::util::get_current_slot;
+use crate::ErrorCode::*;
+use crate::SEED;
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+pub struct SkimExcess<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut, seeds = [b"adminlogv1".as_ref()], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+    #[account(
+        seeds = [b"poolv1", token_x.key().as_ref(), token_y.key().as_ref(), &pool.load()?.fee_tier_fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        bump = pool.load()?.bump
+    )]
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(constraint = token_x.key() == pool.load()?.token_x @ InvalidTokenAccount)]
+    pub token_x: Account<'info, Mint>,
+    #[account(constraint = token_y.key() == pool.load()?.token_y @ InvalidTokenAccount)]
+    pub token_y: Account<'info, Mint>,
+    #[account(mut,
+        constraint = account_x.mint == token_x.key() @ InvalidMint,
+        constraint = account_x.owner == pool.load()?.fee_receiver @ InvalidOwner
+    )]
+    pub account_x: Box<Account<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = account_y.mint == token_y.key() @ InvalidMint,
+        constraint = account_y.owner == pool.load()?.fee_receiver @ InvalidOwner
+    )]
+    pub account_y: Box<Account<'info, TokenAccount>>,
+    #[account(mut,
+        constraint = reserve_x.mint == token_x.key() @ InvalidMint,
+        constraint = &reserve_x.owner == program_authority.key @ InvalidAuthority,
+        constraint = reserve_x.key() == pool.load()?.token_x_reserve @ InvalidTokenAccount
+    )]
+    pub reserve_x: Account<'info, TokenAccount>,
+    #[account(mut,
+        constraint = reserve_y.mint == token_y.key() @ InvalidMint,
+        constraint = &reserve_y.owner == program_authority.key @ InvalidAuthority,
+        constraint = reserve_y.key() == pool.load()?.token_y_reserve @ InvalidTokenAccount
+    )]
+    pub reserve_y: Account<'info, TokenAccount>,
+    #[account(constraint = &state.load()?.admin == admin.key @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+    #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
+    pub program_authority: AccountInfo<'info>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+}
+
+impl<'info> SendTokens<'info> for SkimExcess<'info> {
+    fn send_x(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.reserve_x.to_account_info(),
+                to: self.account_x.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+
+    fn send_y(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.reserve_y.to_account_info(),
+                to: self.account_y.to_account_info(),
+                authority: self.program_authority.clone(),
+            },
+        )
+    }
+}
+
+impl<'info> SkimExcess<'info> {
+    // Tokens transferred to the reserves directly belong to no position, so they are sent to
+    // the fee receiver of the pool instead of staying there unaccounted
+    pub fn handler(&self) -> ProgramResult {
+        msg!("INVARIANT: SKIM EXCESS");
+
+        let state = self.state.load()?;
+        let pool = self.pool.load()?;
+        let (excess_x, excess_y) =
+            pool.reserves_excess(self.reserve_x.amount, self.reserve_y.amount);
+
+        let signer: &[&[&[u8]]] = get_signer!(state.nonce);
+        if !excess_x.is_zero() {
+            token::transfer(self.send_x().with_signer(signer), excess_x.0)?;
+        }
+        if !excess_y.is_zero() {
+            token::transfer(self.send_y().with_signer(signer), excess_y.0)?;
+        }
+
+        emit!(SkimExcessEvent {
+            pool: self.pool.key(),
+            amount_x: excess_x.0,
+            amount_y: excess_y.0,
+        });
+        self.admin_log.load_mut()?.append(
+            self.admin.key(),
+            AdminAction::SkimExcess,
+            self.pool.key(),
+            log_value(excess_x.0 as u128),
+            log_value(excess_y.0 as u128),
+            get_current_slot(),
+        );
+
+        Ok(())
+    }
+}
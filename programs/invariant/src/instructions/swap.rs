@@ -1,24 +1,39 @@
+use crate::args::SwapReturnData;
+use crate::events::{
+    CrossTickEvent, FillLimitOrdersEvent, LargeSwapEvent, SwapBoundedEvent, SwapEvent,
+};
 use crate::interfaces::send_tokens::SendTokens;
 use crate::interfaces::take_ref_tokens::TakeRefTokens;
 use crate::interfaces::take_tokens::TakeTokens;
+use crate::interfaces::tick_hook::{
+    invoke_tick_crossed_hook, TickCrossed, MAX_HOOK_CALLS_PER_SWAP,
+};
 use crate::log::get_tick_at_sqrt_price;
-use crate::math::compute_swap_step;
+use crate::math::{calculate_max_liquidity_per_tick, compute_swap_step};
+use crate::structs::global_stats::update_global_stats;
+use crate::structs::launch_config::check_launch_access;
+use crate::structs::oracle::Oracle;
 use crate::structs::pool::Pool;
+use crate::structs::referral_earnings::ReferralEarnings;
 use crate::structs::tick::Tick;
 use crate::structs::tickmap::Tickmap;
-use crate::util::get_closer_limit;
+use crate::util::{check_spend_authority, get_closer_limit};
 use crate::ErrorCode::*;
 use crate::*;
 use crate::{decimals::*, referral::whitelist::contains_owner};
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
+use anchor_lang::solana_program::program_option::COption;
 use anchor_spl::token::{TokenAccount, Transfer};
 
 #[derive(Accounts)]
 pub struct Swap<'info> {
-    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump,
+        constraint = !state.load()?.is_paused @ ProtocolPaused
+    )]
     pub state: AccountLoader<'info, State>,
     #[account(mut,
-        seeds = [b"poolv1", account_x.mint.as_ref(), account_y.mint.as_ref(), &pool.load()?.fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        seeds = [b"poolv1", account_x.mint.as_ref(), account_y.mint.as_ref(), &pool.load()?.fee_tier_fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
         bump = pool.load()?.bump
     )]
     pub pool: AccountLoader<'info, Pool>,
@@ -27,12 +42,13 @@ pub struct Swap<'info> {
         constraint = tickmap.to_account_info().owner == program_id @ InvalidTickmapOwner
     )]
     pub tickmap: AccountLoader<'info, Tickmap>,
+    // owned by the signer or delegated to it (e.g. a session key of a smart wallet)
     #[account(mut,
-        constraint = &account_x.owner == owner.key @ InvalidOwner
+        constraint = &account_x.owner == owner.key || account_x.delegate == COption::Some(owner.key()) @ InvalidOwner
     )]
     pub account_x: Account<'info, TokenAccount>,
     #[account(mut,
-        constraint = &account_y.owner == owner.key @ InvalidOwner
+        constraint = &account_y.owner == owner.key || account_y.delegate == COption::Some(owner.key()) @ InvalidOwner
     )]
     pub account_y: Account<'info, TokenAccount>,
     #[account(mut,
@@ -128,18 +144,26 @@ impl<'info> TakeRefTokens<'info> for Swap<'info> {
 impl<'info> Swap<'info> {
     pub fn handler(
         ctx: Context<'_, '_, '_, 'info, Swap<'info>>,
-        x_to_y: bool,
+        direction: Direction,
         amount: u64,
-        by_amount_in: bool, // whether amount specifies input or output
+        amount_spec: AmountSpec,
         sqrt_price_limit: u128,
+        tick_limit: Option<u16>, // swap ends after crossing that many ticks
     ) -> ProgramResult {
         msg!("INVARIANT: SWAP");
         require!(amount != 0, ZeroAmount);
+        let x_to_y = direction.is_x_to_y();
 
         let sqrt_price_limit = Price::new(sqrt_price_limit);
         let mut pool = ctx.accounts.pool.load_mut()?;
         let tickmap = ctx.accounts.tickmap.load()?;
         let state = ctx.accounts.state.load()?;
+        check_launch_access(
+            &mut pool,
+            &ctx.accounts.pool.key(),
+            ctx.remaining_accounts,
+            ctx.accounts.owner.key,
+        )?;
 
         let ref_account = match ctx
             .remaining_accounts
@@ -163,138 +187,125 @@ impl<'info> Swap<'info> {
             },
             None => None,
         };
+        // referral fees accumulated in the referrer's earnings account instead of transferred
+        let ref_earnings = ctx.remaining_accounts.iter().find_map(|account| {
+            AccountLoader::<'_, ReferralEarnings>::try_from(account)
+                .ok()
+                .filter(|loader| match loader.load() {
+                    Ok(earnings) => earnings.pool == ctx.accounts.pool.key(),
+                    Err(_) => false,
+                })
+        });
+        let is_referred = ref_account.is_some() || ref_earnings.is_some();
 
-        // limit is on the right side of price
-        if x_to_y {
-            require!(
-                { pool.sqrt_price } > sqrt_price_limit
-                    && sqrt_price_limit <= Price::new(MAX_SQRT_PRICE),
-                WrongLimit
-            );
-        } else {
-            require!(
-                { pool.sqrt_price } < sqrt_price_limit
-                    && sqrt_price_limit >= Price::new(MIN_SQRT_PRICE),
-                WrongLimit
-            );
-        }
+        let current_timestamp = get_current_timestamp();
+        let referral_fee = match is_referred {
+            true => pool.referral_fee(),
+            false => FixedPoint::from_integer(0),
+        };
+        let price_before = pool.sqrt_price;
+        let liquidity_before = pool.liquidity;
+        let protocol_fee_before = match x_to_y {
+            true => pool.fee_protocol_token_x,
+            false => pool.fee_protocol_token_y,
+        };
+        let SwapTotals {
+            amount_in: total_amount_in,
+            amount_out: total_amount_out,
+            amount_referral: total_amount_referral,
+            fee_amount: total_fee_amount,
+            ticks_crossed,
+        } = swap_through_ticks(
+            &mut pool,
+            &tickmap,
+            &ctx.accounts.pool.key(),
+            ctx.remaining_accounts,
+            direction,
+            TokenAmount(amount),
+            amount_spec,
+            sqrt_price_limit,
+            referral_fee,
+            current_timestamp,
+            tick_limit,
+        )?;
 
-        let mut remaining_amount = TokenAmount(amount);
+        if total_amount_out.0 == 0 {
+            return Err(ErrorCode::NoGainSwap.into());
+        }
+        pool.strict_check();
 
-        let mut total_amount_in = TokenAmount(0);
-        let mut total_amount_out = TokenAmount(0);
-        let mut total_amount_referral = TokenAmount(0);
+        let protocol_fee = match x_to_y {
+            true => pool.fee_protocol_token_x,
+            false => pool.fee_protocol_token_y,
+        } - protocol_fee_before;
+        let return_data = SwapReturnData {
+            lp_fee: total_fee_amount.0 - protocol_fee - total_amount_referral.0,
+            protocol_fee,
+            referral_fee: total_amount_referral.0,
+            price_before: price_before.v,
+            price_after: pool.sqrt_price.v,
+        };
+        set_return_data(&return_data.try_to_vec()?);
 
-        while !remaining_amount.is_zero() {
-            let (swap_limit, limiting_tick) = get_closer_limit(
-                sqrt_price_limit,
+        emit!(SwapEvent {
+            pool: ctx.accounts.pool.key(),
+            owner: *ctx.accounts.owner.key,
+            x_to_y,
+            amount_in: total_amount_in.0,
+            amount_out: total_amount_out.0,
+            fee: total_fee_amount.0,
+            protocol_fee,
+            referral_fee: total_amount_referral.0,
+            price_before: price_before.v,
+            price_after: pool.sqrt_price.v,
+            liquidity_before: liquidity_before.v,
+            liquidity_after: pool.liquidity.v,
+            current_tick_index: pool.current_tick_index,
+            ticks_crossed,
+        });
+        if pool.is_large_swap(x_to_y, total_amount_in, total_amount_out) {
+            emit!(LargeSwapEvent {
+                pool: ctx.accounts.pool.key(),
+                owner: *ctx.accounts.owner.key,
                 x_to_y,
-                pool.current_tick_index,
-                pool.tick_spacing,
-                &tickmap,
-            )?;
-
-            let result = compute_swap_step(
-                pool.sqrt_price,
-                swap_limit,
-                pool.liquidity,
-                remaining_amount,
-                by_amount_in,
-                pool.fee,
-            );
-            // make remaining amount smaller
-            if by_amount_in {
-                remaining_amount -= result.amount_in + result.fee_amount;
-            } else {
-                remaining_amount -= result.amount_out;
-            }
-
-            total_amount_referral += match ref_account.is_some() {
-                true => pool.add_fee(result.fee_amount, FixedPoint::from_scale(2, 1), x_to_y),
-                false => pool.add_fee(result.fee_amount, FixedPoint::from_integer(0), x_to_y),
-            };
-
-            pool.sqrt_price = result.next_price_sqrt;
-
-            total_amount_in += result.amount_in + result.fee_amount;
-            total_amount_out += result.amount_out;
-
-            // Fail if price would go over swap limit
-            if { pool.sqrt_price } == sqrt_price_limit && !remaining_amount.is_zero() {
-                return Err(ErrorCode::PriceLimitReached.into());
-            }
-
-            // crossing tick
-            // trunk-ignore(clippy/unnecessary_unwrap)
-            if result.next_price_sqrt == swap_limit && limiting_tick.is_some() {
-                let (tick_index, initialized) = limiting_tick.unwrap();
-
-                let is_enough_amount_to_cross = is_enough_amount_to_push_price(
-                    remaining_amount,
-                    result.next_price_sqrt,
-                    pool.liquidity,
-                    pool.fee,
-                    by_amount_in,
-                    x_to_y,
-                );
-
-                if initialized {
-                    // Calculating address of the crossed tick
-                    let (tick_address, _) = Pubkey::find_program_address(
-                        &[
-                            b"tickv1",
-                            ctx.accounts.pool.to_account_info().key.as_ref(),
-                            &tick_index.to_le_bytes(),
-                        ],
-                        ctx.program_id,
-                    );
-
-                    // Finding the correct tick in remaining accounts
-                    let loader = match ctx
-                        .remaining_accounts
-                        .iter()
-                        .find(|account| *account.key == tick_address)
-                    {
-                        Some(account) => AccountLoader::<'_, Tick>::try_from(account).unwrap(),
-                        None => return Err(ErrorCode::TickNotFound.into()),
-                    };
-                    let mut tick = loader.load_mut().unwrap();
-
-                    // crossing tick
-                    if !x_to_y || is_enough_amount_to_cross {
-                        msg!("INVARIANT: CROSSING TICK {} ", { tick.index });
-                        cross_tick(&mut tick, &mut pool, get_current_timestamp())?;
-                    } else if !remaining_amount.is_zero() {
-                        if by_amount_in {
-                            pool.add_fee(remaining_amount, FixedPoint::from_integer(0), x_to_y);
-                            total_amount_in += remaining_amount;
-                        }
-                        remaining_amount = TokenAmount(0);
-                    }
-                }
-                // set tick to limit (below if price is going down, because current tick should always be below price)
-                pool.current_tick_index = if x_to_y && is_enough_amount_to_cross {
-                    tick_index.checked_sub(pool.tick_spacing as i32).unwrap()
-                } else {
-                    tick_index
-                };
-            } else {
-                assert!(
-                    pool.current_tick_index
-                        .checked_rem(pool.tick_spacing.into())
-                        .unwrap()
-                        == 0,
-                    "tick not divisible by spacing"
-                );
-                pool.current_tick_index =
-                    get_tick_at_sqrt_price(result.next_price_sqrt, pool.tick_spacing);
-            }
+                amount_in: total_amount_in.0,
+                amount_out: total_amount_out.0,
+            });
         }
 
-        if total_amount_out.0 == 0 {
-            return Err(ErrorCode::NoGainSwap.into());
+        if let Some(tick_limit) = tick_limit {
+            emit!(SwapBoundedEvent {
+                pool: ctx.accounts.pool.key(),
+                x_to_y,
+                amount_in: total_amount_in.0,
+                amount_out: total_amount_out.0,
+                ticks_crossed,
+                tick_limit,
+            });
         }
 
+        let account_in = match x_to_y {
+            true => &ctx.accounts.account_x,
+            false => &ctx.accounts.account_y,
+        };
+        check_spend_authority(
+            &account_in.owner,
+            account_in.delegate,
+            account_in.delegated_amount,
+            ctx.accounts.owner.key,
+            total_amount_in.0,
+        )?;
+
+        // referral part transferred straight to the referrer never enters the reserve
+        let amount_in_reserve = match ref_earnings.is_none()
+            && ref_account.is_some()
+            && !total_amount_referral.is_zero()
+        {
+            true => total_amount_in - total_amount_referral,
+            false => total_amount_in,
+        };
+        pool.swap_reserves(x_to_y, amount_in_reserve, total_amount_out);
+
         // Execute swap
         let (take_ctx, send_ctx) = match x_to_y {
             true => (ctx.accounts.take_x(), ctx.accounts.send_y()),
@@ -304,6 +315,21 @@ impl<'info> Swap<'info> {
         let signer: &[&[&[u8]]] = get_signer!(state.nonce);
         token::transfer(send_ctx.with_signer(signer), total_amount_out.0)?;
 
+        update_global_stats(ctx.remaining_accounts, |stats| stats.total_swaps += 1)?;
+
+        if let Some(ref_earnings) = ref_earnings {
+            // referral part stays in the reserve until claimed
+            token::transfer(take_ctx, total_amount_in.0)?;
+            ref_earnings
+                .load_mut()?
+                .add(total_amount_referral.0, x_to_y);
+            pool.strict_check_reserves(
+                &ctx.accounts.reserve_x.to_account_info(),
+                &ctx.accounts.reserve_y.to_account_info(),
+            );
+            return Ok(());
+        }
+
         match ref_account.is_some() && !total_amount_referral.is_zero() {
             true => {
                 let take_ref_ctx = match x_to_y {
@@ -317,7 +343,275 @@ impl<'info> Swap<'info> {
                 token::transfer(take_ctx, total_amount_in.0)?;
             }
         }
+        pool.strict_check_reserves(
+            &ctx.accounts.reserve_x.to_account_info(),
+            &ctx.accounts.reserve_y.to_account_info(),
+        );
 
         Ok(())
     }
 }
+
+pub struct SwapTotals {
+    pub amount_in: TokenAmount,
+    pub amount_out: TokenAmount,
+    pub amount_referral: TokenAmount,
+    pub fee_amount: TokenAmount,
+    pub ticks_crossed: u16,
+}
+
+// Moves the pool price crossing initialized ticks passed in `remaining_accounts`.
+// Does not transfer any tokens, so it can be reused by instructions settling them differently.
+// With `tick_limit` the swap stops after crossing that many ticks, leaving the rest unfilled.
+#[allow(clippy::too_many_arguments)]
+pub fn swap_through_ticks<'info>(
+    pool: &mut Pool,
+    tickmap: &Tickmap,
+    pool_key: &Pubkey,
+    remaining_accounts: &[AccountInfo<'info>],
+    direction: Direction,
+    amount: TokenAmount,
+    amount_spec: AmountSpec,
+    sqrt_price_limit: Price,
+    referral_fee: FixedPoint,
+    current_timestamp: u64,
+    tick_limit: Option<u16>,
+) -> Result<SwapTotals> {
+    let (x_to_y, by_amount_in) = (direction.is_x_to_y(), amount_spec.is_in());
+    require!(!pool.withdrawal_only, PoolWithdrawalOnly);
+
+    // limit is on the right side of price
+    if x_to_y {
+        require!(
+            { pool.sqrt_price } > sqrt_price_limit
+                && sqrt_price_limit <= Price::new(MAX_SQRT_PRICE),
+            WrongLimit
+        );
+    } else {
+        require!(
+            { pool.sqrt_price } < sqrt_price_limit
+                && sqrt_price_limit >= Price::new(MIN_SQRT_PRICE),
+            WrongLimit
+        );
+    }
+
+    pool.update_tick_ema(current_timestamp);
+    let sqrt_price_before = pool.sqrt_price;
+
+    // the hook has to be passed, otherwise swaps could skip it
+    let hook_key = pool.hook_program;
+    let hook_program = match hook_key == Pubkey::default() {
+        true => None,
+        false => match remaining_accounts
+            .iter()
+            .find(|account| *account.key == hook_key && account.executable)
+        {
+            Some(account) => Some(account),
+            None => return Err(ErrorCode::HookProgramNotFound.into()),
+        },
+    };
+    let mut hook_calls = 0;
+
+    // ticks of the pool are loaded once, instead of searching for every crossed one. The stored
+    // bump makes checking the address of a tick cheaper than deriving it.
+    let tick_loaders: Vec<(i32, AccountLoader<'info, Tick>)> = remaining_accounts
+        .iter()
+        .filter_map(|account| {
+            let loader = AccountLoader::<'info, Tick>::try_from(account).ok()?;
+            let (pool, index, bump) = {
+                let tick = loader.load().ok()?;
+                (tick.pool, tick.index, tick.bump)
+            };
+            let tick_address = Pubkey::create_program_address(
+                &[b"tickv1", pool_key.as_ref(), &index.to_le_bytes(), &[bump]],
+                &crate::ID,
+            )
+            .ok()?;
+            (pool == *pool_key && tick_address == *account.key).then(|| (index, loader))
+        })
+        .collect();
+    let mut ticks_crossed: u16 = 0;
+
+    let mut remaining_amount = amount;
+
+    let mut total_amount_in = TokenAmount(0);
+    let mut total_amount_out = TokenAmount(0);
+    let mut total_amount_referral = TokenAmount(0);
+    let mut total_fee_amount = TokenAmount(0);
+
+    while !remaining_amount.is_zero() {
+        if tick_limit.map_or(false, |tick_limit| ticks_crossed >= tick_limit) {
+            break;
+        }
+        // bounded swaps opted into partial fills, so they end at the hook call cap like at the
+        // tick limit, other swaps fail on the crossing over the cap
+        if tick_limit.is_some() && hook_program.is_some() && hook_calls >= MAX_HOOK_CALLS_PER_SWAP {
+            break;
+        }
+
+        let (swap_limit, limiting_tick) = get_closer_limit(
+            sqrt_price_limit,
+            direction,
+            pool.current_tick_index,
+            pool.tick_spacing,
+            tickmap,
+        )?;
+
+        let result = compute_swap_step(
+            pool.sqrt_price,
+            swap_limit,
+            pool.liquidity,
+            remaining_amount,
+            amount_spec,
+            pool.fee,
+        );
+        // make remaining amount smaller
+        if by_amount_in {
+            remaining_amount -= result.amount_in + result.fee_amount;
+        } else {
+            remaining_amount -= result.amount_out;
+        }
+
+        total_amount_referral += pool.add_fee(result.fee_amount, referral_fee, x_to_y);
+        total_fee_amount += result.fee_amount;
+
+        pool.sqrt_price = result.next_price_sqrt;
+
+        total_amount_in += result.amount_in + result.fee_amount;
+        total_amount_out += result.amount_out;
+
+        // Fail if price would go over swap limit
+        if { pool.sqrt_price } == sqrt_price_limit && !remaining_amount.is_zero() {
+            return Err(ErrorCode::PriceLimitReached.into());
+        }
+
+        // crossing tick
+        // trunk-ignore(clippy/unnecessary_unwrap)
+        if result.next_price_sqrt == swap_limit && limiting_tick.is_some() {
+            let (tick_index, initialized) = limiting_tick.unwrap();
+
+            let is_enough_amount_to_cross = is_enough_amount_to_push_price(
+                remaining_amount,
+                result.next_price_sqrt,
+                pool.liquidity,
+                pool.fee,
+                amount_spec,
+                direction,
+            );
+
+            if initialized {
+                let loader = match tick_loaders.iter().find(|(index, _)| *index == tick_index) {
+                    Some((_, loader)) => loader,
+                    None => return Err(ErrorCode::TickNotFound.into()),
+                };
+                let mut tick = loader.load_mut()?;
+
+                // crossing tick
+                if !x_to_y || is_enough_amount_to_cross {
+                    msg!("INVARIANT: CROSSING TICK {} ", { tick.index });
+                    cross_tick(&mut tick, pool, current_timestamp)?;
+                    ticks_crossed += 1;
+                    emit!(CrossTickEvent {
+                        pool: *pool_key,
+                        index: tick_index,
+                        x_to_y,
+                        sign: tick.sign,
+                        liquidity_change: { tick.liquidity_change }.v,
+                        liquidity_after: pool.liquidity.v,
+                        sqrt_price: pool.sqrt_price.v,
+                    });
+
+                    // orders selling the token the swap buys are left behind the price
+                    let sells_x = !x_to_y;
+                    let (order_liquidity, _) = tick.limit_order_book(sells_x);
+                    if !order_liquidity.is_zero() {
+                        let range_tick_index = match sells_x {
+                            true => tick_index - pool.tick_spacing as i32,
+                            false => tick_index + pool.tick_spacing as i32,
+                        };
+                        let range_tick_loader = match tick_loaders
+                            .iter()
+                            .find(|(index, _)| *index == range_tick_index)
+                        {
+                            Some((_, loader)) => loader,
+                            None => return Err(ErrorCode::TickNotFound.into()),
+                        };
+                        tick.fill_limit_orders(
+                            &mut *range_tick_loader.load_mut()?,
+                            sells_x,
+                            calculate_max_liquidity_per_tick(pool.tick_spacing),
+                        )?;
+                        emit!(FillLimitOrdersEvent {
+                            pool: *pool_key,
+                            index: tick_index,
+                            x_to_y: sells_x,
+                            liquidity: order_liquidity.v,
+                            fills: tick.limit_order_book(sells_x).1,
+                        });
+                    }
+
+                    if let Some(hook_program) = hook_program {
+                        require!(hook_calls < MAX_HOOK_CALLS_PER_SWAP, TooManyHookCalls);
+                        hook_calls += 1;
+                        invoke_tick_crossed_hook(
+                            hook_program,
+                            &TickCrossed {
+                                pool: *pool_key,
+                                tick: tick_index,
+                                x_to_y,
+                            },
+                        )?;
+                    }
+                } else if !remaining_amount.is_zero() {
+                    if by_amount_in {
+                        pool.add_fee(remaining_amount, FixedPoint::from_integer(0), x_to_y);
+                        total_amount_in += remaining_amount;
+                        total_fee_amount += remaining_amount;
+                    }
+                    remaining_amount = TokenAmount(0);
+                }
+            }
+            // set tick to limit (below if price is going down, because current tick should always be below price)
+            pool.current_tick_index = if x_to_y && is_enough_amount_to_cross {
+                tick_index.checked_sub(pool.tick_spacing as i32).unwrap()
+            } else {
+                tick_index
+            };
+        } else {
+            assert!(
+                pool.current_tick_index
+                    .checked_rem(pool.tick_spacing.into())
+                    .unwrap()
+                    == 0,
+                "tick not divisible by spacing"
+            );
+            pool.current_tick_index =
+                get_tick_at_sqrt_price(result.next_price_sqrt, pool.tick_spacing);
+        }
+    }
+
+    pool.check_price_change(sqrt_price_before)?;
+
+    // the oracle has to be passed as well, otherwise swaps could skip records and skew the TWAP
+    if pool.oracle_initialized {
+        let oracle_key = pool.oracle_address;
+        let oracle = match remaining_accounts
+            .iter()
+            .find(|account| *account.key == oracle_key)
+        {
+            Some(account) => AccountLoader::<'_, Oracle>::try_from(account)?,
+            None => return Err(ErrorCode::OracleNotFound.into()),
+        };
+        oracle
+            .load_mut()?
+            .add_record(current_timestamp, pool.sqrt_price, pool.liquidity);
+    }
+
+    Ok(SwapTotals {
+        amount_in: total_amount_in,
+        amount_out: total_amount_out,
+        amount_referral: total_amount_referral,
+        fee_amount: total_fee_amount,
+        ticks_crossed,
+    })
+}
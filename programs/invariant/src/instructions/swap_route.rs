@@ -0,0 +1,223 @@
+use crate::args::RouteHop;
+use crate::decimals::*;
+use crate::events::{LargeSwapEvent, SwapEvent};
+use crate::instructions::swap::{swap_through_ticks, SwapTotals};
+use crate::structs::global_stats::update_global_stats;
+use crate::structs::launch_config::check_launch_access;
+use crate::structs::pool::Pool;
+use crate::structs::tickmap::Tickmap;
+use crate::util::{check_spend_authority, get_current_timestamp};
+use crate::ErrorCode::*;
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token::{TokenAccount, Transfer};
+
+pub const MAX_ROUTE_HOPS: usize = 4;
+// pool, tickmap, reserve_x, reserve_y followed by `RouteHop::tick_accounts` accounts,
+// the whole route is optionally followed by a single global stats account
+const ACCOUNTS_PER_HOP: usize = 4;
+
+#[derive(Accounts)]
+pub struct SwapRoute<'info> {
+    #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump,
+        constraint = !state.load()?.is_paused @ ProtocolPaused
+    )]
+    pub state: AccountLoader<'info, State>,
+    #[account(mut,
+        constraint = &account_in.owner == owner.key || account_in.delegate == COption::Some(owner.key()) @ InvalidOwner
+    )]
+    pub account_in: Account<'info, TokenAccount>,
+    #[account(mut,
+        constraint = &account_out.owner == owner.key || account_out.delegate == COption::Some(owner.key()) @ InvalidOwner
+    )]
+    pub account_out: Account<'info, TokenAccount>,
+    pub owner: Signer<'info>,
+    #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
+    pub program_authority: AccountInfo<'info>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+}
+
+impl<'info> SwapRoute<'info> {
+    fn transfer(
+        &self,
+        from: &AccountInfo<'info>,
+        to: &AccountInfo<'info>,
+        authority: &AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: from.clone(),
+                to: to.clone(),
+                authority: authority.clone(),
+            },
+        )
+    }
+
+    // Swaps exact input through a sequence of pools. Output of every hop goes straight from
+    // the reserve of its pool to the input reserve of the next one, so only the first input
+    // and the last output touch accounts of the owner.
+    pub fn handler(
+        ctx: Context<'_, '_, '_, 'info, SwapRoute<'info>>,
+        amount_in: u64,
+        min_amount_out: u64,
+        hops: Vec<RouteHop>,
+    ) -> ProgramResult {
+        msg!("INVARIANT: SWAP ROUTE");
+        require!(amount_in != 0, ZeroAmount);
+        require!(
+            !hops.is_empty() && hops.len() <= MAX_ROUTE_HOPS,
+            InvalidRoute
+        );
+
+        let state = ctx.accounts.state.load()?;
+        let signer: &[&[&[u8]]] = get_signer!(state.nonce);
+        let current_timestamp = get_current_timestamp();
+
+        let mut remaining_accounts = ctx.remaining_accounts;
+        let mut amount = TokenAmount(amount_in);
+        let mut mint = ctx.accounts.account_in.mint;
+        // reserve holding output of the previous hop
+        let mut source: Option<&AccountInfo<'info>> = None;
+
+        for hop in hops.iter() {
+            let hop_len = ACCOUNTS_PER_HOP + hop.tick_accounts as usize;
+            require!(remaining_accounts.len() >= hop_len, InvalidRoute);
+            let (accounts, rest) = remaining_accounts.split_at(hop_len);
+            remaining_accounts = rest;
+            let x_to_y = hop.direction.is_x_to_y();
+
+            let pool_loader = AccountLoader::<'_, Pool>::try_from(&accounts[0])?;
+            let tickmap_loader = AccountLoader::<'_, Tickmap>::try_from(&accounts[1])?;
+            let mut pool = pool_loader.load_mut()?;
+            let tickmap = tickmap_loader.load()?;
+            require!(*accounts[1].key == pool.tickmap, InvalidTickmap);
+            // no room for launch configs, pools in their launch window can't be routed through
+            check_launch_access(&mut pool, accounts[0].key, &[], ctx.accounts.owner.key)?;
+            require!(
+                *accounts[2].key == pool.token_x_reserve
+                    && *accounts[3].key == pool.token_y_reserve,
+                InvalidTokenAccount
+            );
+
+            let (reserve_in, reserve_out, mint_in, mint_out) = match x_to_y {
+                true => (&accounts[2], &accounts[3], pool.token_x, pool.token_y),
+                false => (&accounts[3], &accounts[2], pool.token_y, pool.token_x),
+            };
+            require!(mint_in == mint, InvalidRoute);
+
+            let price_before = pool.sqrt_price;
+            let liquidity_before = pool.liquidity;
+            let protocol_fee_before = match x_to_y {
+                true => pool.fee_protocol_token_x,
+                false => pool.fee_protocol_token_y,
+            };
+            let SwapTotals {
+                amount_in: hop_amount_in,
+                amount_out: hop_amount_out,
+                fee_amount: hop_fee_amount,
+                ticks_crossed,
+                ..
+            } = swap_through_ticks(
+                &mut pool,
+                &tickmap,
+                accounts[0].key,
+                &accounts[ACCOUNTS_PER_HOP..],
+                hop.direction,
+                amount,
+                AmountSpec::In,
+                Price::new(hop.sqrt_price_limit),
+                FixedPoint::from_integer(0),
+                current_timestamp,
+                None,
+            )?;
+            require!(hop_amount_out.0 != 0, NoGainSwap);
+            // the output of the previous hop is already in its reserve, so it has to be used up
+            require!(
+                source.is_none() || hop_amount_in == amount,
+                RouteHopNotFilled
+            );
+            pool.strict_check();
+            pool.swap_reserves(x_to_y, hop_amount_in, hop_amount_out);
+            emit!(SwapEvent {
+                pool: *accounts[0].key,
+                owner: *ctx.accounts.owner.key,
+                x_to_y,
+                amount_in: hop_amount_in.0,
+                amount_out: hop_amount_out.0,
+                fee: hop_fee_amount.0,
+                protocol_fee: match x_to_y {
+                    true => pool.fee_protocol_token_x,
+                    false => pool.fee_protocol_token_y,
+                } - protocol_fee_before,
+                referral_fee: 0,
+                price_before: price_before.v,
+                price_after: pool.sqrt_price.v,
+                liquidity_before: liquidity_before.v,
+                liquidity_after: pool.liquidity.v,
+                current_tick_index: pool.current_tick_index,
+                ticks_crossed,
+            });
+            // thresholds are per pool, so every hop is checked on its own
+            if pool.is_large_swap(x_to_y, hop_amount_in, hop_amount_out) {
+                emit!(LargeSwapEvent {
+                    pool: *accounts[0].key,
+                    owner: *ctx.accounts.owner.key,
+                    x_to_y,
+                    amount_in: hop_amount_in.0,
+                    amount_out: hop_amount_out.0,
+                });
+            }
+
+            match source {
+                Some(source) => token::transfer(
+                    ctx.accounts
+                        .transfer(source, reserve_in, &ctx.accounts.program_authority)
+                        .with_signer(signer),
+                    hop_amount_in.0,
+                )?,
+                None => {
+                    let account_in = &ctx.accounts.account_in;
+                    check_spend_authority(
+                        &account_in.owner,
+                        account_in.delegate,
+                        account_in.delegated_amount,
+                        ctx.accounts.owner.key,
+                        hop_amount_in.0,
+                    )?;
+                    token::transfer(
+                        ctx.accounts.transfer(
+                            &account_in.to_account_info(),
+                            reserve_in,
+                            &ctx.accounts.owner.to_account_info(),
+                        ),
+                        hop_amount_in.0,
+                    )?;
+                }
+            }
+
+            source = Some(reserve_out);
+            amount = hop_amount_out;
+            mint = mint_out;
+        }
+
+        require!(remaining_accounts.len() <= 1, InvalidRoute);
+        require!(mint == ctx.accounts.account_out.mint, InvalidMint);
+        require!(amount.0 >= min_amount_out, RouteAmountOutTooLow);
+
+        token::transfer(
+            ctx.accounts
+                .transfer(
+                    source.unwrap(),
+                    &ctx.accounts.account_out.to_account_info(),
+                    &ctx.accounts.program_authority,
+                )
+                .with_signer(signer),
+            amount.0,
+        )?;
+
+        update_global_stats(remaining_accounts, |stats| stats.total_swaps += 1)
+    }
+}
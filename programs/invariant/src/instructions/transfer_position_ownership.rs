@@ -1,3 +1,4 @@
+use crate::events::TransferPositionOwnershipEvent;
 use crate::structs::position::Position;
 use crate::structs::position_list::PositionList;
 use crate::ErrorCode::*;
@@ -74,10 +75,21 @@ impl<'info> TransferPositionOwnership<'info> {
                 seconds_per_liquidity_inside: removed_position.seconds_per_liquidity_inside,
                 tokens_owed_x: removed_position.tokens_owed_x,
                 tokens_owed_y: removed_position.tokens_owed_y,
+                maker_fees_x: removed_position.maker_fees_x,
+                maker_fees_y: removed_position.maker_fees_y,
+                total_claimed_x: removed_position.total_claimed_x,
+                total_claimed_y: removed_position.total_claimed_y,
                 last_slot: removed_position.last_slot,
                 bump, // assign new bump
             };
         }
+        emit!(TransferPositionOwnershipEvent {
+            pool: new_position.pool,
+            id: new_position.id,
+            old_owner: *self.owner.key,
+            new_owner: *self.recipient.key,
+            liquidity: new_position.liquidity.v,
+        });
 
         // when removed position is not the last one
         if owner_list.head != index {
@@ -95,6 +107,10 @@ impl<'info> TransferPositionOwnership<'info> {
                 seconds_per_liquidity_inside: last_position.seconds_per_liquidity_inside,
                 tokens_owed_x: last_position.tokens_owed_x,
                 tokens_owed_y: last_position.tokens_owed_y,
+                maker_fees_x: last_position.maker_fees_x,
+                maker_fees_y: last_position.maker_fees_y,
+                total_claimed_x: last_position.total_claimed_x,
+                total_claimed_y: last_position.total_claimed_y,
                 last_slot: last_position.last_slot,
                 bump: removed_position.bump, // stay with the same bump
             };
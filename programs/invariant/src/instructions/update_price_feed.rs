@@ -0,0 +1,37 @@
+use crate::structs::oracle::Oracle;
+use crate::structs::pool::Pool;
+use crate::structs::price_feed::PriceFeed;
+use crate::util::{get_current_slot, get_current_timestamp};
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+
+// Permissionless crank, fails until the oracle has records covering the whole window
+#[derive(Accounts)]
+pub struct UpdatePriceFeed<'info> {
+    #[account(mut,
+        seeds = [b"pricefeedv1", pool.key().as_ref(), &price_feed.load()?.window.to_le_bytes()],
+        bump = price_feed.load()?.bump
+    )]
+    pub price_feed: AccountLoader<'info, PriceFeed>,
+    pub pool: AccountLoader<'info, Pool>,
+    #[account(constraint = oracle.key() == pool.load()?.oracle_address @ OracleNotFound)]
+    pub oracle: AccountLoader<'info, Oracle>,
+}
+
+impl<'info> UpdatePriceFeed<'info> {
+    pub fn handler(&self) -> ProgramResult {
+        msg!("INVARIANT: UPDATE PRICE FEED");
+
+        let mut price_feed = self.price_feed.load_mut()?;
+        let oracle = self.oracle.load()?;
+        let current_timestamp = get_current_timestamp();
+        let start = current_timestamp
+            .checked_sub(price_feed.window)
+            .ok_or(InvalidTwapWindow)?;
+
+        let (sqrt_price, liquidity) = oracle.twap(start, current_timestamp)?;
+        price_feed.publish(sqrt_price, liquidity, current_timestamp, get_current_slot());
+
+        Ok(())
+    }
+}
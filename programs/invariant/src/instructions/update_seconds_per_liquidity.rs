@@ -13,7 +13,7 @@ use math::*;
 #[instruction(lower_tick_index: i32, upper_tick_index: i32, index: i32)]
 pub struct UpdateSecondsPerLiquidity<'info> {
     #[account(mut,
-        seeds = [b"poolv1", token_x.key().as_ref(), token_y.key().as_ref(), &pool.load()?.fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        seeds = [b"poolv1", token_x.key().as_ref(), token_y.key().as_ref(), &pool.load()?.fee_tier_fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
         bump = pool.load()?.bump
     )]
     pub pool: AccountLoader<'info, Pool>,
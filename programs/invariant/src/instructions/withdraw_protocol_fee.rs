@@ -1,4 +1,6 @@
+use crate::events::WithdrawProtocolFeeEvent;
 use crate::interfaces::SendTokens;
+use crate::structs::global_stats::GlobalStats;
 use crate::structs::pool::Pool;
 use crate::structs::state::State;
 use crate::ErrorCode::*;
@@ -12,10 +14,12 @@ pub struct WithdrawProtocolFee<'info> {
     #[account(seeds = [b"statev1".as_ref()], bump = state.load()?.bump)]
     pub state: AccountLoader<'info, State>,
     #[account(mut,
-        seeds = [b"poolv1", token_x.key().as_ref(), token_y.key().as_ref(), &pool.load()?.fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
+        seeds = [b"poolv1", token_x.key().as_ref(), token_y.key().as_ref(), &pool.load()?.fee_tier_fee.v.to_le_bytes(), &pool.load()?.tick_spacing.to_le_bytes()],
         bump = pool.load()?.bump
     )]
     pub pool: AccountLoader<'info, Pool>,
+    #[account(mut, seeds = [b"globalstatsv1".as_ref()], bump = global_stats.load()?.bump)]
+    pub global_stats: AccountLoader<'info, GlobalStats>,
     #[account(constraint = token_x.key() == pool.load()?.token_x @ InvalidTokenAccount)]
     pub token_x: Account<'info, Mint>,
     #[account(constraint = token_y.key() == pool.load()?.token_y @ InvalidTokenAccount)]
@@ -40,7 +44,7 @@ pub struct WithdrawProtocolFee<'info> {
         constraint = reserve_y.key() == pool.load()?.token_y_reserve @ InvalidTokenAccount
     )]
     pub reserve_y: Account<'info, TokenAccount>,
-    #[account(constraint = &pool.load()?.fee_receiver == authority.key @ InvalidAuthority)]
+    // fee receiver or a member of the pool withdraw ACL, checked in access control
     pub authority: Signer<'info>,
     #[account(constraint = &state.load()?.authority == program_authority.key @ InvalidAuthority)]
     pub program_authority: AccountInfo<'info>,
@@ -87,8 +91,25 @@ impl<'info> WithdrawProtocolFee<'info> {
         token::transfer(cpi_ctx_x, pool.fee_protocol_token_x)?;
         token::transfer(cpi_ctx_y, pool.fee_protocol_token_y)?;
 
+        let global_stats = &mut self.global_stats.load_mut()?;
+        global_stats.add_protocol_fee(pool.token_x, pool.fee_protocol_token_x);
+        global_stats.add_protocol_fee(pool.token_y, pool.fee_protocol_token_y);
+
+        let (fee_x, fee_y) = (pool.fee_protocol_token_x, pool.fee_protocol_token_y);
+        pool.sub_reserves(TokenAmount(fee_x), TokenAmount(fee_y));
         pool.fee_protocol_token_x = 0;
         pool.fee_protocol_token_y = 0;
+        pool.strict_check_reserves(
+            &self.reserve_x.to_account_info(),
+            &self.reserve_y.to_account_info(),
+        );
+
+        emit!(WithdrawProtocolFeeEvent {
+            pool: self.pool.key(),
+            authority: self.authority.key(),
+            amount_x: fee_x,
+            amount_y: fee_y,
+        });
 
         Ok(())
     }
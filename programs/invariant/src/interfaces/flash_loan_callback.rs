@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke;
+
+// sighash("global:on_flash_loan"), so borrowers can be written as regular anchor programs
+pub const FLASH_LOAN_DISCRIMINATOR: [u8; 8] = [195, 212, 238, 236, 80, 204, 73, 167];
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct FlashLoanCallback {
+    pub pool: Pubkey,
+    pub amount_x: u64,
+    pub amount_y: u64,
+    pub fee_x: u64,
+    pub fee_y: u64,
+    pub data: Vec<u8>, // passed through from the borrower
+}
+
+pub fn flash_loan_instruction(
+    callback_program: Pubkey,
+    accounts: &[AccountInfo],
+    args: &FlashLoanCallback,
+) -> Instruction {
+    let mut data = FLASH_LOAN_DISCRIMINATOR.to_vec();
+    data.extend(args.try_to_vec().unwrap());
+    Instruction {
+        program_id: callback_program,
+        accounts: accounts
+            .iter()
+            .map(|account| match account.is_writable {
+                true => AccountMeta::new(*account.key, account.is_signer),
+                false => AccountMeta::new_readonly(*account.key, account.is_signer),
+            })
+            .collect(),
+        data,
+    }
+}
+
+// The callback gets the accounts following its program in remaining accounts, with the
+// signer and writable flags they have in the flash loan
+pub fn invoke_flash_loan_callback(
+    callback_program: &AccountInfo,
+    accounts: &[AccountInfo],
+    args: &FlashLoanCallback,
+) -> ProgramResult {
+    let mut account_infos = accounts.to_vec();
+    account_infos.push(callback_program.clone());
+    invoke(
+        &flash_loan_instruction(*callback_program.key, accounts, args),
+        &account_infos,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::hash::hash;
+
+    #[test]
+    fn test_flash_loan_instruction() {
+        assert_eq!(
+            FLASH_LOAN_DISCRIMINATOR,
+            hash(b"global:on_flash_loan").to_bytes()[..8]
+        );
+
+        let callback_program = Pubkey::new_unique();
+        let args = FlashLoanCallback {
+            pool: Pubkey::new_unique(),
+            amount_x: 100,
+            amount_y: 0,
+            fee_x: 1,
+            fee_y: 0,
+            data: vec![1, 2, 3],
+        };
+        let ix = flash_loan_instruction(callback_program, &[], &args);
+        assert_eq!(ix.program_id, callback_program);
+        assert!(ix.accounts.is_empty());
+        assert_eq!(ix.data[..8], FLASH_LOAN_DISCRIMINATOR);
+        assert_eq!(
+            FlashLoanCallback::try_from_slice(&ix.data[8..]).unwrap(),
+            args
+        );
+    }
+}
@@ -1,7 +1,11 @@
+pub mod flash_loan_callback;
 pub mod send_tokens;
 pub mod take_tokens;
 pub mod take_ref_tokens;
+pub mod tick_hook;
 
+pub use flash_loan_callback::*;
 pub use send_tokens::*;
 pub use take_tokens::*;
 pub use take_ref_tokens::*;
+pub use tick_hook::*;
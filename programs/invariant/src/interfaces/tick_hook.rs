@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke;
+
+// sighash("global:on_tick_crossed"), so hooks can be written as regular anchor programs
+pub const TICK_CROSSED_DISCRIMINATOR: [u8; 8] = [118, 188, 212, 107, 132, 248, 205, 30];
+// hooks run inside the swap, capping the calls keeps swaps within compute limits
+pub const MAX_HOOK_CALLS_PER_SWAP: u8 = 4;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct TickCrossed {
+    pub pool: Pubkey,
+    pub tick: i32,
+    pub x_to_y: bool,
+}
+
+pub fn tick_crossed_instruction(hook_program: Pubkey, args: &TickCrossed) -> Instruction {
+    let mut data = TICK_CROSSED_DISCRIMINATOR.to_vec();
+    data.extend(args.try_to_vec().unwrap());
+    Instruction {
+        program_id: hook_program,
+        accounts: vec![],
+        data,
+    }
+}
+
+// The hook receives no accounts, so it can't make use of the swap's signers or writable accounts
+pub fn invoke_tick_crossed_hook(hook_program: &AccountInfo, args: &TickCrossed) -> ProgramResult {
+    invoke(
+        &tick_crossed_instruction(*hook_program.key, args),
+        &[hook_program.clone()],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::hash::hash;
+
+    #[test]
+    fn test_tick_crossed_instruction() {
+        assert_eq!(
+            TICK_CROSSED_DISCRIMINATOR,
+            hash(b"global:on_tick_crossed").to_bytes()[..8]
+        );
+
+        let hook_program = Pubkey::new_unique();
+        let args = TickCrossed {
+            pool: Pubkey::new_unique(),
+            tick: -10,
+            x_to_y: true,
+        };
+        let ix = tick_crossed_instruction(hook_program, &args);
+        assert_eq!(ix.program_id, hook_program);
+        assert!(ix.accounts.is_empty());
+        assert_eq!(ix.data[..8], TICK_CROSSED_DISCRIMINATOR);
+        assert_eq!(TickCrossed::try_from_slice(&ix.data[8..]).unwrap(), args);
+    }
+}
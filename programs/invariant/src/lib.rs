@@ -1,5 +1,9 @@
-mod decimals;
+pub mod args;
+pub mod decimals;
+#[cfg(feature = "devnet")]
+mod devnet;
 mod errors;
+pub mod events;
 mod instructions;
 mod interfaces;
 mod log;
@@ -13,16 +17,24 @@ mod util;
 use anchor_lang::prelude::*;
 use anchor_spl::token;
 
+use crate::args::*;
 use crate::decimals::*;
 use errors::ErrorCode;
 use errors::*;
 use instructions::*;
 use math::*;
-use structs::{Pool, State};
+use structs::{
+    Pool, SessionAuthority, State, WithdrawAcl, SESSION_PERMISSION_CLAIM,
+    SESSION_PERMISSION_REBALANCE,
+};
 use util::*;
 
 use instructions::claim_fee::ClaimFee;
 
+pub use util::get_current_timestamp;
+#[cfg(any(test, feature = "mocked-clock"))]
+pub use util::set_mocked_timestamp;
+
 declare_id!("HyaB3W9q6XdA5xwpU4XnSZV94htfmbmqJXZcEbRaJutt");
 const SEED: &str = "Invariant";
 
@@ -30,38 +42,131 @@ const SEED: &str = "Invariant";
 pub mod invariant {
     use super::*;
 
-    pub fn create_state(ctx: Context<CreateState>, nonce: u8) -> ProgramResult {
-        instructions::create_state::handler(ctx, nonce)
+    pub fn create_state(ctx: Context<CreateState>) -> ProgramResult {
+        instructions::create_state::handler(ctx)
     }
     #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
-    pub fn create_fee_tier(
-        ctx: Context<CreateFeeTier>,
-        fee: u128,
-        tick_spacing: u16,
-    ) -> ProgramResult {
+    pub fn create_admin_log(ctx: Context<CreateAdminLog>) -> ProgramResult {
+        ctx.accounts.handler(*ctx.bumps.get("admin_log").unwrap())
+    }
+
+    #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
+    pub fn create_global_stats(ctx: Context<CreateGlobalStats>) -> ProgramResult {
         ctx.accounts
-            .handler(fee, tick_spacing, *ctx.bumps.get("fee_tier").unwrap())
+            .handler(*ctx.bumps.get("global_stats").unwrap())
+    }
+
+    #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
+    pub fn create_fee_tier_registry(ctx: Context<CreateFeeTierRegistry>) -> ProgramResult {
+        ctx.accounts.handler(
+            ctx.remaining_accounts,
+            *ctx.bumps.get("fee_tier_registry").unwrap(),
+        )
+    }
+
+    #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
+    pub fn create_fee_tier(ctx: Context<CreateFeeTier>, args: CreateFeeTierArgs) -> ProgramResult {
+        ctx.accounts.handler(
+            args.fee,
+            args.tick_spacing,
+            *ctx.bumps.get("fee_tier").unwrap(),
+        )
+    }
+
+    pub fn list_fee_tiers(ctx: Context<ListFeeTiers>) -> ProgramResult {
+        ctx.accounts.handler()
     }
 
-    pub fn create_pool(ctx: Context<CreatePool>, init_tick: i32) -> ProgramResult {
+    pub fn create_pool(ctx: Context<CreatePool>, args: CreatePoolArgs) -> ProgramResult {
         ctx.accounts
-            .handler(init_tick, *ctx.bumps.get("pool").unwrap())
+            .handler(args.init_tick, *ctx.bumps.get("pool").unwrap())
+    }
+
+    pub fn init_pool_and_position(
+        ctx: Context<InitPoolAndPosition>,
+        args: InitPoolAndPositionArgs,
+    ) -> ProgramResult {
+        let bumps = InitBumps {
+            pool: *ctx.bumps.get("pool").unwrap(),
+            lower_tick: *ctx.bumps.get("lower_tick").unwrap(),
+            upper_tick: *ctx.bumps.get("upper_tick").unwrap(),
+            position: *ctx.bumps.get("position").unwrap(),
+        };
+        ctx.accounts.handler(args, bumps)
+    }
+
+    pub fn create_pools_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreatePoolsBatch<'info>>,
+        init_ticks: Vec<i32>,
+    ) -> ProgramResult {
+        CreatePoolsBatch::handler(ctx, init_ticks)
     }
 
     pub fn swap<'info>(
         ctx: Context<'_, '_, '_, 'info, Swap<'info>>,
-        x_to_y: bool,
-        amount: u64,
-        by_amount_in: bool, // whether amount specifies input or output
-        sqrt_price_limit: u128,
+        args: SwapArgs,
+    ) -> ProgramResult {
+        Swap::handler(
+            ctx,
+            args.direction,
+            args.amount,
+            args.amount_spec,
+            args.sqrt_price_limit,
+            None,
+        )
+    }
+
+    pub fn swap_bounded<'info>(
+        ctx: Context<'_, '_, '_, 'info, Swap<'info>>,
+        args: SwapBoundedArgs,
     ) -> ProgramResult {
-        Swap::handler(ctx, x_to_y, amount, by_amount_in, sqrt_price_limit)
+        Swap::handler(
+            ctx,
+            args.direction,
+            args.amount,
+            args.amount_spec,
+            args.sqrt_price_limit,
+            Some(args.tick_limit),
+        )
+    }
+
+    // Simulate instructions run the whole instruction, including transfers and events, then fail
+    // so the runtime reverts it. Logs of the failed transaction hold the would-be events.
+    pub fn simulate_swap<'info>(
+        ctx: Context<'_, '_, '_, 'info, Swap<'info>>,
+        args: SwapArgs,
+    ) -> ProgramResult {
+        Swap::handler(
+            ctx,
+            args.direction,
+            args.amount,
+            args.amount_spec,
+            args.sqrt_price_limit,
+            None,
+        )?;
+        Err(ErrorCode::SimulationOnly.into())
+    }
+
+    pub fn swap_route<'info>(
+        ctx: Context<'_, '_, '_, 'info, SwapRoute<'info>>,
+        args: SwapRouteArgs,
+    ) -> ProgramResult {
+        SwapRoute::handler(ctx, args.amount_in, args.min_amount_out, args.hops)
     }
 
     pub fn initialize_oracle(ctx: Context<InitializeOracle>) -> ProgramResult {
         ctx.accounts.handler()
     }
 
+    pub fn create_price_feed(ctx: Context<CreatePriceFeed>, window: u64) -> ProgramResult {
+        ctx.accounts
+            .handler(window, *ctx.bumps.get("price_feed").unwrap())
+    }
+
+    pub fn update_price_feed(ctx: Context<UpdatePriceFeed>) -> ProgramResult {
+        ctx.accounts.handler()
+    }
+
     pub fn create_tick(ctx: Context<CreateTick>, index: i32) -> ProgramResult {
         ctx.accounts.handler(index, *ctx.bumps.get("tick").unwrap())
     }
@@ -80,6 +185,7 @@ pub mod invariant {
         slippage_limit_upper: Price,
     ) -> ProgramResult {
         ctx.accounts.handler(
+            ctx.remaining_accounts,
             liquidity_delta,
             slippage_limit_lower,
             slippage_limit_upper,
@@ -87,6 +193,64 @@ pub mod invariant {
         )
     }
 
+    pub fn simulate_create_position(
+        ctx: Context<CreatePosition>,
+        _lower_tick_index: i32,
+        _upper_tick_index: i32,
+        liquidity_delta: Liquidity,
+        slippage_limit_lower: Price,
+        slippage_limit_upper: Price,
+    ) -> ProgramResult {
+        ctx.accounts.handler(
+            ctx.remaining_accounts,
+            liquidity_delta,
+            slippage_limit_lower,
+            slippage_limit_upper,
+            *ctx.bumps.get("position").unwrap(),
+        )?;
+        Err(ErrorCode::SimulationOnly.into())
+    }
+
+    pub fn migrate_from_external(
+        ctx: Context<CreatePosition>,
+        _lower_tick_index: i32,
+        _upper_tick_index: i32,
+        amount_x: u64,
+        amount_y: u64,
+        slippage_limit_lower: Price,
+        slippage_limit_upper: Price,
+    ) -> ProgramResult {
+        ctx.accounts.migrate_from_external_handler(
+            ctx.remaining_accounts,
+            amount_x,
+            amount_y,
+            slippage_limit_lower,
+            slippage_limit_upper,
+            *ctx.bumps.get("position").unwrap(),
+        )
+    }
+
+    pub fn create_limit_order(
+        ctx: Context<CreateLimitOrder>,
+        _lower_tick_index: i32,
+        _upper_tick_index: i32,
+        liquidity_delta: Liquidity,
+    ) -> ProgramResult {
+        ctx.accounts.handler(
+            ctx.remaining_accounts,
+            liquidity_delta,
+            *ctx.bumps.get("limit_order").unwrap(),
+        )
+    }
+
+    pub fn claim_limit_order(ctx: Context<ClaimLimitOrder>) -> ProgramResult {
+        ctx.accounts.handler()
+    }
+
+    pub fn cancel_limit_order(ctx: Context<CancelLimitOrder>) -> ProgramResult {
+        ctx.accounts.handler()
+    }
+
     pub fn remove_position(
         ctx: Context<RemovePosition>,
         index: u32,
@@ -97,6 +261,32 @@ pub mod invariant {
             .handler(index, lower_tick_index, upper_tick_index)
     }
 
+    #[access_control(session(&ctx.accounts.holder, ctx.remaining_accounts, SESSION_PERMISSION_REBALANCE))]
+    pub fn decrease_liquidity<'info>(
+        ctx: Context<'_, '_, '_, 'info, DecreaseLiquidity<'info>>,
+        _index: u32,
+        liquidity_delta: Liquidity,
+    ) -> ProgramResult {
+        ctx.accounts
+            .handler(ctx.remaining_accounts, liquidity_delta)
+    }
+
+    #[access_control(session(&ctx.accounts.holder, ctx.remaining_accounts, SESSION_PERMISSION_REBALANCE))]
+    pub fn increase_liquidity<'info>(
+        ctx: Context<'_, '_, '_, 'info, IncreaseLiquidity<'info>>,
+        _index: u32,
+        liquidity_delta: Liquidity,
+        slippage_limit_lower: Price,
+        slippage_limit_upper: Price,
+    ) -> ProgramResult {
+        ctx.accounts.handler(
+            ctx.remaining_accounts,
+            liquidity_delta,
+            slippage_limit_lower,
+            slippage_limit_upper,
+        )
+    }
+
     pub fn transfer_position_ownership(
         ctx: Context<TransferPositionOwnership>,
         index: u32,
@@ -105,12 +295,68 @@ pub mod invariant {
             .handler(index, *ctx.bumps.get("new_position").unwrap())
     }
 
+    pub fn mint_position_nft(ctx: Context<MintPositionNft>, _index: u32) -> ProgramResult {
+        ctx.accounts.handler()
+    }
+
+    pub fn create_position_nft_metadata(
+        ctx: Context<CreatePositionNftMetadata>,
+        _index: u32,
+        base_uri: String,
+    ) -> ProgramResult {
+        ctx.accounts.handler(base_uri)
+    }
+
+    #[access_control(session(&ctx.accounts.holder, ctx.remaining_accounts, SESSION_PERMISSION_CLAIM))]
     pub fn claim_fee(
         ctx: Context<ClaimFee>,
         _index: u32,
         _lower_tick_index: i32,
         _upper_tick_index: i32,
     ) -> ProgramResult {
+        ctx.accounts.handler(ctx.remaining_accounts, true, true)
+    }
+
+    #[access_control(session(&ctx.accounts.holder, ctx.remaining_accounts, SESSION_PERMISSION_CLAIM))]
+    pub fn claim_fee_side(
+        ctx: Context<ClaimFee>,
+        _index: u32,
+        _lower_tick_index: i32,
+        _upper_tick_index: i32,
+        claim_x: bool,
+        claim_y: bool,
+    ) -> ProgramResult {
+        ctx.accounts
+            .handler(ctx.remaining_accounts, claim_x, claim_y)
+    }
+
+    pub fn claim_fee_batch(ctx: Context<ClaimFeeBatch>, positions_count: u8) -> ProgramResult {
+        ctx.accounts
+            .handler(ctx.remaining_accounts, positions_count)
+    }
+
+    pub fn create_session_authority(
+        ctx: Context<CreateSessionAuthority>,
+        permissions: u8,
+        expiry: u64,
+    ) -> ProgramResult {
+        ctx.accounts.handler(
+            permissions,
+            expiry,
+            *ctx.bumps.get("session_authority").unwrap(),
+        )
+    }
+
+    pub fn revoke_session_authority(ctx: Context<RevokeSessionAuthority>) -> ProgramResult {
+        ctx.accounts.handler()
+    }
+
+    pub fn create_referral_earnings(ctx: Context<CreateReferralEarnings>) -> ProgramResult {
+        ctx.accounts
+            .handler(*ctx.bumps.get("referral_earnings").unwrap())
+    }
+
+    pub fn claim_referral_fees(ctx: Context<ClaimReferralFees>) -> ProgramResult {
         ctx.accounts.handler()
     }
 
@@ -123,11 +369,20 @@ pub mod invariant {
         ctx.accounts.handler()
     }
 
-    #[access_control(receiver(&ctx.accounts.pool, &ctx.accounts.authority))]
+    #[access_control(withdrawer(&ctx.accounts.pool, &ctx.accounts.authority, ctx.remaining_accounts))]
     pub fn withdraw_protocol_fee(ctx: Context<WithdrawProtocolFee>) -> ProgramResult {
         ctx.accounts.handler()
     }
 
+    #[access_control(receiver(&ctx.accounts.pool, &ctx.accounts.authority))]
+    pub fn convert_protocol_fees<'info>(
+        ctx: Context<'_, '_, '_, 'info, ConvertProtocolFees<'info>>,
+        target_is_x: bool,
+        sqrt_price_limit: u128,
+    ) -> ProgramResult {
+        ConvertProtocolFees::handler(ctx, target_is_x, sqrt_price_limit)
+    }
+
     #[access_control(receiver(&ctx.accounts.pool, &ctx.accounts.admin))]
     pub fn change_protocol_fee(
         ctx: Context<ChangeProtocolFee>,
@@ -140,6 +395,180 @@ pub mod invariant {
     pub fn change_fee_receiver(ctx: Context<ChangeFeeReceiver>) -> ProgramResult {
         ctx.accounts.handler()
     }
+
+    #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
+    pub fn create_withdraw_acl(ctx: Context<CreateWithdrawAcl>) -> ProgramResult {
+        ctx.accounts
+            .handler(*ctx.bumps.get("withdraw_acl").unwrap())
+    }
+
+    #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
+    pub fn set_withdrawer(ctx: Context<SetWithdrawer>, allowed: bool) -> ProgramResult {
+        ctx.accounts.handler(allowed)
+    }
+
+    #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
+    pub fn create_launch_config(
+        ctx: Context<CreateLaunchConfig>,
+        duration_slots: u64,
+    ) -> ProgramResult {
+        ctx.accounts
+            .handler(duration_slots, *ctx.bumps.get("launch_config").unwrap())
+    }
+
+    #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
+    pub fn set_launch_whitelisted(
+        ctx: Context<SetLaunchWhitelisted>,
+        whitelisted: bool,
+    ) -> ProgramResult {
+        ctx.accounts.handler(whitelisted)
+    }
+
+    #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
+    pub fn create_rebate_schedule(ctx: Context<CreateRebateSchedule>) -> ProgramResult {
+        ctx.accounts
+            .handler(*ctx.bumps.get("rebate_schedule").unwrap())
+    }
+
+    #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
+    pub fn set_rebate_schedule(
+        ctx: Context<SetRebateSchedule>,
+        args: SetRebateScheduleArgs,
+    ) -> ProgramResult {
+        ctx.accounts.handler(args)
+    }
+
+    #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
+    pub fn propose_pool_fee(ctx: Context<ProposePoolFee>, fee: FixedPoint) -> ProgramResult {
+        ctx.accounts.handler(fee)
+    }
+
+    #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
+    pub fn update_pool_fee(ctx: Context<UpdatePoolFee>) -> ProgramResult {
+        ctx.accounts.handler()
+    }
+
+    #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
+    pub fn propose_admin(ctx: Context<ProposeAdmin>) -> ProgramResult {
+        ctx.accounts.handler()
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> ProgramResult {
+        ctx.accounts.handler()
+    }
+
+    // the admin is checked in the handler, the legacy state can't be loaded by `admin`
+    pub fn migrate_state(ctx: Context<MigrateState>) -> ProgramResult {
+        ctx.accounts.handler(ctx.program_id)
+    }
+
+    pub fn migrate_pool(ctx: Context<MigratePool>) -> ProgramResult {
+        ctx.accounts.handler(ctx.program_id)
+    }
+
+    pub fn migrate_position(ctx: Context<MigratePosition>) -> ProgramResult {
+        ctx.accounts.handler(ctx.program_id)
+    }
+
+    pub fn migrate_oracle(ctx: Context<MigrateOracle>) -> ProgramResult {
+        ctx.accounts.handler(ctx.program_id)
+    }
+
+    pub fn migrate_tick(ctx: Context<MigrateTick>) -> ProgramResult {
+        ctx.accounts.handler(ctx.program_id)
+    }
+
+    #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
+    pub fn force_close_orphan_position(
+        ctx: Context<ForceCloseOrphanPosition>,
+        index: u32,
+        lower_tick_index: i32,
+        upper_tick_index: i32,
+    ) -> ProgramResult {
+        ctx.accounts
+            .handler(index, lower_tick_index, upper_tick_index, ctx.program_id)
+    }
+
+    #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
+    pub fn change_tick_ema_half_life(
+        ctx: Context<ChangeTickEmaHalfLife>,
+        half_life: u32,
+    ) -> ProgramResult {
+        ctx.accounts.handler(half_life)
+    }
+
+    #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
+    pub fn set_pool_hook(ctx: Context<SetPoolHook>) -> ProgramResult {
+        ctx.accounts.handler()
+    }
+
+    #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
+    pub fn set_max_price_change(
+        ctx: Context<SetMaxPriceChange>,
+        max_price_change_bps: u16,
+    ) -> ProgramResult {
+        ctx.accounts.handler(max_price_change_bps)
+    }
+
+    #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
+    pub fn set_withdrawal_only(
+        ctx: Context<SetWithdrawalOnly>,
+        withdrawal_only: bool,
+    ) -> ProgramResult {
+        ctx.accounts.handler(withdrawal_only)
+    }
+
+    #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
+    pub fn pause(ctx: Context<SetPaused>) -> ProgramResult {
+        ctx.accounts.handler(true)
+    }
+
+    #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
+    pub fn unpause(ctx: Context<SetPaused>) -> ProgramResult {
+        ctx.accounts.handler(false)
+    }
+
+    #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
+    pub fn set_referral_fee(ctx: Context<SetReferralFee>, referral_fee_bps: u16) -> ProgramResult {
+        ctx.accounts.handler(referral_fee_bps)
+    }
+
+    #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
+    pub fn skim_excess(ctx: Context<SkimExcess>) -> ProgramResult {
+        ctx.accounts.handler()
+    }
+
+    #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
+    pub fn set_flash_loan_fee(
+        ctx: Context<SetFlashLoanFee>,
+        flash_loan_fee_bps: u16,
+    ) -> ProgramResult {
+        ctx.accounts.handler(flash_loan_fee_bps)
+    }
+
+    #[access_control(admin(&ctx.accounts.state, &ctx.accounts.admin))]
+    pub fn set_large_swap_threshold(
+        ctx: Context<SetLargeSwapThreshold>,
+        threshold_x: u64,
+        threshold_y: u64,
+    ) -> ProgramResult {
+        ctx.accounts.handler(threshold_x, threshold_y)
+    }
+
+    pub fn flash_loan(
+        ctx: Context<FlashLoan>,
+        amount_x: u64,
+        amount_y: u64,
+        data: Vec<u8>,
+    ) -> ProgramResult {
+        ctx.accounts
+            .handler(ctx.remaining_accounts, amount_x, amount_y, data)
+    }
+
+    // Instructions not declared above, devnet builds handle their faucet instructions here
+    pub fn fallback(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+        dispatch_devnet(program_id, accounts, data)
+    }
 }
 
 fn admin(state_loader: &AccountLoader<State>, signer: &AccountInfo) -> Result<()> {
@@ -148,8 +577,54 @@ fn admin(state_loader: &AccountLoader<State>, signer: &AccountInfo) -> Result<()
     Ok(())
 }
 
+#[cfg(feature = "devnet")]
+use devnet::dispatch as dispatch_devnet;
+
+// faucet instructions exist only in devnet builds, see devnet::dispatch
+#[cfg(not(feature = "devnet"))]
+fn dispatch_devnet(_program_id: &Pubkey, _accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    Err(anchor_lang::__private::ErrorCode::InstructionFallbackNotFound.into())
+}
+
 fn receiver(pool_loader: &AccountLoader<Pool>, signer: &AccountInfo) -> Result<()> {
     let pool = pool_loader.load()?;
     require!(signer.key.eq(&pool.fee_receiver), Unauthorized);
     Ok(())
 }
+
+// Fee receiver or a member of the pool withdraw ACL passed as the first remaining account
+fn withdrawer(
+    pool_loader: &AccountLoader<Pool>,
+    signer: &AccountInfo,
+    remaining_accounts: &[AccountInfo],
+) -> Result<()> {
+    if receiver(pool_loader, signer).is_ok() {
+        return Ok(());
+    }
+    let acl_info = remaining_accounts.first().ok_or(ErrorCode::Unauthorized)?;
+    let acl_loader = AccountLoader::<WithdrawAcl>::try_from(acl_info)?;
+    let acl = acl_loader.load()?;
+    require!(acl.pool == pool_loader.key(), Unauthorized);
+    require!(acl.is_withdrawer(signer.key), Unauthorized);
+    Ok(())
+}
+
+// Position holder has to sign, unless a signing session key with the permission is passed
+// as the first remaining account, followed by its session authority
+fn session(holder: &AccountInfo, remaining_accounts: &[AccountInfo], permission: u8) -> Result<()> {
+    if holder.is_signer {
+        return Ok(());
+    }
+    require!(remaining_accounts.len() >= 2, InvalidSessionAuthority);
+    let session_key = &remaining_accounts[0];
+    require!(session_key.is_signer, InvalidSessionAuthority);
+
+    let session_loader = AccountLoader::<SessionAuthority>::try_from(&remaining_accounts[1])?;
+    let session = session_loader.load()?;
+    require!(session.owner == *holder.key, InvalidSessionAuthority);
+    require!(
+        session.allows(session_key.key, permission, get_current_timestamp()),
+        InvalidSessionAuthority
+    );
+    Ok(())
+}
@@ -4,3 +4,13 @@ macro_rules! get_signer {
         &[&[SEED.as_bytes(), &[$nonce]]]
     };
 }
+
+// Compiled in only with the `strict-invariants` feature (devnet builds) of the crate calling it,
+// so mainnet builds don't pay compute units for these checks
+#[macro_export]
+macro_rules! strict_assert {
+    ($($arg:tt)+) => {
+        #[cfg(feature = "strict-invariants")]
+        assert!($($arg)+);
+    };
+}
@@ -94,7 +94,7 @@ pub fn compute_swap_step(
     target_price_sqrt: Price,
     liquidity: Liquidity,
     amount: TokenAmount,
-    by_amount_in: bool,
+    amount_spec: AmountSpec,
     fee: FixedPoint,
 ) -> SwapResult {
     if liquidity.is_zero() {
@@ -107,6 +107,7 @@ pub fn compute_swap_step(
     }
 
     let x_to_y = current_price_sqrt >= target_price_sqrt;
+    let by_amount_in = amount_spec.is_in();
 
     let next_price_sqrt;
     let mut amount_in = TokenAmount(0);
@@ -309,17 +310,19 @@ fn get_next_sqrt_price_y_down(
     amount: TokenAmount,
     add: bool,
 ) -> Price {
+    // amount / L scaled to price, on U256 as amount * 10^24 alone overflows u128
+    // for amounts above ~3.4 * 10^14, reachable by mints with many decimals
+    let nominator = U256::from(amount.get()) * U256::from(Price::ONE) * U256::from(Price::ONE);
+    let denominator = U256::from(liquidity.get())
+        .checked_mul(U256::from(PRICE_LIQUIDITY_DENOMINATOR))
+        .unwrap();
     if add {
-        let quotient = Price::from_decimal(amount).big_div_by_number(
-            U256::from(liquidity.get())
-                .checked_mul(U256::from(PRICE_LIQUIDITY_DENOMINATOR))
-                .unwrap(),
-        );
+        let quotient = Price::new((nominator / denominator).try_into().unwrap());
         price_sqrt + quotient
     } else {
-        let quotient = Price::from_decimal(amount).big_div_by_number_up(
-            U256::from(liquidity.get())
-                .checked_mul(U256::from(PRICE_LIQUIDITY_DENOMINATOR))
+        let quotient = Price::new(
+            ((nominator + denominator - U256::from(1)) / denominator)
+                .try_into()
                 .unwrap(),
         );
         price_sqrt - quotient
@@ -464,14 +467,15 @@ pub fn is_enough_amount_to_push_price(
     current_price_sqrt: Price,
     liquidity: Liquidity,
     fee: FixedPoint,
-    by_amount_in: bool,
-    x_to_y: bool,
+    amount_spec: AmountSpec,
+    direction: Direction,
 ) -> bool {
     if liquidity.is_zero() {
         return true;
     }
 
-    let next_price_sqrt = if by_amount_in {
+    let x_to_y = direction.is_x_to_y();
+    let next_price_sqrt = if amount_spec.is_in() {
         let amount_after_fee = amount.big_mul(FixedPoint::from_integer(1) - fee);
         get_next_sqrt_price_from_input(current_price_sqrt, liquidity, amount_after_fee, x_to_y)
     } else {
@@ -481,6 +485,12 @@ pub fn is_enough_amount_to_push_price(
     current_price_sqrt.ne(&next_price_sqrt)
 }
 
+// shared with invariant-types, so off-chain deposits are computed exactly like on-chain ones
+mod liquidity {
+    include!("../invariant-types/src/liquidity.rs");
+}
+pub use liquidity::get_max_liquidity;
+
 pub fn calculate_max_liquidity_per_tick(tick_spacing: u16) -> Liquidity {
     const MAX_TICKS_AMOUNT_MEMORY_LIMITED: u128 = 2 * TICK_LIMIT as u128;
     const MAX_TICKS_AMOUNT_PRICE_LIMITED: u128 = 2 * MAX_TICK as u128 + 1;
@@ -521,7 +531,7 @@ mod tests {
             let amount = TokenAmount(1);
             let fee = FixedPoint::from_scale(6, 4);
 
-            let result = compute_swap_step(price, target, liquidity, amount, true, fee);
+            let result = compute_swap_step(price, target, liquidity, amount, AmountSpec::In, fee);
 
             let expected_result = SwapResult {
                 next_price_sqrt: price,
@@ -539,8 +549,10 @@ mod tests {
             let amount = TokenAmount(20);
             let fee = FixedPoint::from_scale(6, 4);
 
-            let result_in = compute_swap_step(price, target, liquidity, amount, true, fee);
-            let result_out = compute_swap_step(price, target, liquidity, amount, false, fee);
+            let result_in =
+                compute_swap_step(price, target, liquidity, amount, AmountSpec::In, fee);
+            let result_out =
+                compute_swap_step(price, target, liquidity, amount, AmountSpec::Out, fee);
 
             let expected_result = SwapResult {
                 next_price_sqrt: target,
@@ -559,7 +571,7 @@ mod tests {
             let amount = TokenAmount(1000000);
             let fee = FixedPoint::from_scale(6, 4);
 
-            let result = compute_swap_step(price, target, liquidity, amount, true, fee);
+            let result = compute_swap_step(price, target, liquidity, amount, AmountSpec::In, fee);
             let expected_result = SwapResult {
                 next_price_sqrt: Price::new(1013331333333_333333333333),
                 amount_in: TokenAmount(999400),
@@ -576,7 +588,7 @@ mod tests {
             let amount = TokenAmount(2000000);
             let fee = FixedPoint::from_scale(6, 4);
 
-            let result = compute_swap_step(price, target, liquidity, amount, false, fee);
+            let result = compute_swap_step(price, target, liquidity, amount, AmountSpec::Out, fee);
             let expected_result = SwapResult {
                 next_price_sqrt: Price::new(100999999600000_000000000000),
                 amount_in: TokenAmount(197), // (5000000000000 * (101 - 100.9999996)) /  (101 * 100.9999996)
@@ -592,7 +604,7 @@ mod tests {
 
             let liquidity = Liquidity::new(20006000_000000);
             let amount = TokenAmount(1_000_000);
-            let by_amount_in = true;
+            let by_amount_in = AmountSpec::In;
             let fee = FixedPoint::from_scale(6, 4); // 0.0006 -> 0.06%
 
             let result = compute_swap_step(
@@ -617,7 +629,7 @@ mod tests {
             let target_price_sqrt = Price::from_integer(1);
             let liquidity = Liquidity::new(u128::MAX / 1_000000);
             let amount = TokenAmount(1);
-            let by_amount_in = false;
+            let by_amount_in = AmountSpec::Out;
             let fee = FixedPoint::from_scale(6, 4); // 0.0006 -> 0.06%
 
             let result = compute_swap_step(
@@ -642,7 +654,7 @@ mod tests {
             let target_price_sqrt = Price::from_scale(1999500149965u128, 12);
             let liquidity = Liquidity::from_integer(100_000000000000_000000000000u128);
             let amount = TokenAmount(10);
-            let by_amount_in = true;
+            let by_amount_in = AmountSpec::In;
             let fee = FixedPoint::from_scale(6, 4); // 0.0006 -> 0.06%
 
             let result = compute_swap_step(
@@ -667,7 +679,7 @@ mod tests {
             let target_price_sqrt = Price::from_scale(100005, 5); // 1.00005
             let liquidity = Liquidity::from_integer(368944000000_000000000000u128);
             let amount = TokenAmount(1);
-            let by_amount_in = true;
+            let by_amount_in = AmountSpec::In;
             let fee = FixedPoint::from_scale(6, 4); // 0.0006 -> 0.06%
 
             let result = compute_swap_step(
@@ -692,7 +704,7 @@ mod tests {
             let target_price_sqrt = Price::from_scale(100005, 5); // 1.00005
             let liquidity = Liquidity::from_integer(368944000000_000000000000u128);
             let amount = TokenAmount(1);
-            let by_amount_in = false;
+            let by_amount_in = AmountSpec::Out;
             let fee = FixedPoint::from_scale(6, 4); // 0.0006 -> 0.06%
 
             let result = compute_swap_step(
@@ -717,7 +729,7 @@ mod tests {
             let target_price_sqrt = Price::from_scale(100005, 5); // 1.00005
             let liquidity = Liquidity::new(0);
             let amount = TokenAmount(100000);
-            let by_amount_in = true;
+            let by_amount_in = AmountSpec::In;
             let fee = FixedPoint::from_scale(6, 4); // 0.0006 -> 0.06%
 
             let result = compute_swap_step(
@@ -742,7 +754,7 @@ mod tests {
             let target_price_sqrt = Price::from_scale(100005, 5); // 1.00005
             let liquidity = Liquidity::new(0);
             let amount = TokenAmount(100000);
-            let by_amount_in = false;
+            let by_amount_in = AmountSpec::Out;
             let fee = FixedPoint::from_scale(6, 4); // 0.0006 -> 0.06%
 
             let result = compute_swap_step(
@@ -767,7 +779,7 @@ mod tests {
             let target_price_sqrt = Price::from_integer(1);
             let liquidity = Liquidity::from_integer(50000000);
             let amount = TokenAmount(1000);
-            let by_amount_in = true;
+            let by_amount_in = AmountSpec::In;
             let fee = FixedPoint::new(0);
 
             let result = compute_swap_step(
@@ -794,7 +806,7 @@ mod tests {
             let one_token = TokenAmount(1);
             let tokens_with_same_output = TokenAmount(85);
             let zero_token = TokenAmount(0);
-            let by_amount_in = false;
+            let by_amount_in = AmountSpec::Out;
             let max_fee = FixedPoint::from_scale(9, 1);
             let min_fee = FixedPoint::from_integer(0);
 
@@ -1136,6 +1148,17 @@ mod tests {
 
     #[test]
     fn test_get_next_sqrt_price_y_down() {
+        // amount too big to be scaled to a price on its own, e.g. a mint with 18 decimals
+        {
+            let price_sqrt = Price::from_integer(1);
+            let liquidity = Liquidity::from_integer(u64::MAX);
+            let amount = TokenAmount(u64::MAX);
+
+            let result = get_next_sqrt_price_y_down(price_sqrt, liquidity, amount, true);
+            assert_eq!(result, Price::from_integer(2));
+            let result = get_next_sqrt_price_y_down(price_sqrt, liquidity, amount, false);
+            assert_eq!(result, Price::new(0));
+        }
         {
             let price_sqrt = Price::from_integer(1);
             let liquidity = Liquidity::from_integer(1);
@@ -1557,8 +1580,8 @@ mod tests {
         // -20 crossing tick with 1 token amount by amount in
         {
             let amount = TokenAmount(1);
-            let by_amount_in = true;
-            let x_to_y = true;
+            let by_amount_in = AmountSpec::In;
+            let x_to_y = Direction::XtoY;
 
             let result = is_enough_amount_to_push_price(
                 amount,
@@ -1573,8 +1596,8 @@ mod tests {
         // -20 crossing tick with 1 token amount by amount out
         {
             let amount = TokenAmount(1);
-            let by_amount_in = false;
-            let x_to_y = true;
+            let by_amount_in = AmountSpec::Out;
+            let x_to_y = Direction::XtoY;
 
             let result = is_enough_amount_to_push_price(
                 amount,
@@ -1589,8 +1612,8 @@ mod tests {
         // -20 crossing tick with 2 token amount by amount in
         {
             let amount = TokenAmount(2);
-            let by_amount_in = true;
-            let x_to_y = true;
+            let by_amount_in = AmountSpec::In;
+            let x_to_y = Direction::XtoY;
 
             let result = is_enough_amount_to_push_price(
                 amount,
@@ -1612,32 +1635,32 @@ mod tests {
                 current_price_sqrt,
                 max_liquidity,
                 fee,
-                false,
-                true,
+                AmountSpec::Out,
+                Direction::XtoY,
             );
             let result_by_amount_out_y_to_x = is_enough_amount_to_push_price(
                 zero_amount,
                 current_price_sqrt,
                 max_liquidity,
                 fee,
-                false,
-                false,
+                AmountSpec::Out,
+                Direction::YtoX,
             );
             let result_by_amount_in_x_to_y = is_enough_amount_to_push_price(
                 zero_amount,
                 current_price_sqrt,
                 max_liquidity,
                 fee,
-                true,
-                true,
+                AmountSpec::In,
+                Direction::XtoY,
             );
             let result_by_amount_in_y_to_x = is_enough_amount_to_push_price(
                 zero_amount,
                 current_price_sqrt,
                 max_liquidity,
                 fee,
-                true,
-                false,
+                AmountSpec::In,
+                Direction::YtoX,
             );
             assert_eq!(result_by_amount_out_x_to_y, false);
             assert_eq!(result_by_amount_out_y_to_x, false);
@@ -1648,8 +1671,8 @@ mod tests {
         {
             let no_liquidity = Decimal::new(0);
             let amount = TokenAmount(1);
-            let by_amount_in = true;
-            let x_to_y = true;
+            let by_amount_in = AmountSpec::In;
+            let x_to_y = Direction::XtoY;
 
             let result = is_enough_amount_to_push_price(
                 amount,
@@ -1815,4 +1838,51 @@ mod tests {
                 .eq(&U256::from_str("C9F1D0F9A36142B8E4CBC87BC4509E926142668A984E1EB3F").unwrap()));
         }
     }
+
+    #[test]
+    fn test_get_max_liquidity() {
+        let lower_sqrt_price = calculate_price_sqrt(-1000);
+        let upper_sqrt_price = calculate_price_sqrt(1000);
+        let amount = TokenAmount(1_000_000);
+        // below range, only x
+        {
+            let liquidity = get_max_liquidity(
+                amount,
+                TokenAmount(0),
+                lower_sqrt_price,
+                upper_sqrt_price,
+                calculate_price_sqrt(-2000),
+            )
+            .unwrap();
+            let x = get_delta_x(lower_sqrt_price, upper_sqrt_price, liquidity, true).unwrap();
+            assert!(x <= amount);
+        }
+        // above range, only y
+        {
+            let liquidity = get_max_liquidity(
+                TokenAmount(0),
+                amount,
+                lower_sqrt_price,
+                upper_sqrt_price,
+                calculate_price_sqrt(2000),
+            )
+            .unwrap();
+            let y = get_delta_y(lower_sqrt_price, upper_sqrt_price, liquidity, true).unwrap();
+            assert!(y <= amount);
+        }
+        // in range, limited by the smaller side
+        {
+            let current_sqrt_price = Price::from_integer(1);
+            let liquidity = get_max_liquidity(
+                amount,
+                TokenAmount(10),
+                lower_sqrt_price,
+                upper_sqrt_price,
+                current_sqrt_price,
+            )
+            .unwrap();
+            let y = get_delta_y(lower_sqrt_price, current_sqrt_price, liquidity, true).unwrap();
+            assert!(y <= TokenAmount(10));
+        }
+    }
 }
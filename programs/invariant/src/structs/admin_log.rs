@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+
+pub const ADMIN_LOG_SIZE: usize = 16;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(u8)]
+pub enum AdminAction {
+    CreateFeeTier = 0,
+    ChangeFeeReceiver = 1,
+    CreateWithdrawAcl = 2,
+    SetWithdrawer = 3,
+    ProposePoolFee = 4,
+    UpdatePoolFee = 5,
+    ChangeTickEmaHalfLife = 6,
+    ForceCloseOrphanPosition = 7,
+    SetPoolHook = 8,
+    SetMaxPriceChange = 9,
+    SetWithdrawalOnly = 10,
+    CreateRebateSchedule = 11,
+    SetRebateSchedule = 12,
+    CreateLaunchConfig = 13,
+    SetLaunchWhitelisted = 14,
+    SetReferralFee = 15,
+    SkimExcess = 16,
+    SetFlashLoanFee = 17,
+    ProposeAdmin = 18,
+    AcceptAdmin = 19,
+    SetPaused = 20,
+    SetLargeSwapThreshold = 21,
+    ChangeProtocolFee = 22,
+    CreateGlobalStats = 23,
+    CreateFeeTierRegistry = 24,
+}
+
+// Values are stored as raw bytes, so both keys and numbers fit (see `log_value`)
+#[zero_copy]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug)]
+pub struct AdminLogEntry {
+    pub actor: Pubkey,
+    pub action: u8,
+    pub target: Pubkey,
+    pub old_value: [u8; 32],
+    pub new_value: [u8; 32],
+    pub slot: u64,
+}
+
+// Ring buffer of the latest admin changes, written by all admin instructions
+#[account(zero_copy)]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug)]
+pub struct AdminLog {
+    pub head: u64,
+    pub entries: [AdminLogEntry; ADMIN_LOG_SIZE],
+    pub bump: u8,
+}
+
+impl AdminLog {
+    pub fn append(
+        &mut self,
+        actor: Pubkey,
+        action: AdminAction,
+        target: Pubkey,
+        old_value: [u8; 32],
+        new_value: [u8; 32],
+        slot: u64,
+    ) {
+        let index = (self.head % ADMIN_LOG_SIZE as u64) as usize;
+        self.entries[index] = AdminLogEntry {
+            actor,
+            action: action as u8,
+            target,
+            old_value,
+            new_value,
+            slot,
+        };
+        self.head += 1;
+    }
+
+    pub fn latest(&self) -> Option<&AdminLogEntry> {
+        match self.head {
+            0 => None,
+            head => Some(&self.entries[((head - 1) % ADMIN_LOG_SIZE as u64) as usize]),
+        }
+    }
+}
+
+pub fn log_value(value: u128) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    bytes[..16].copy_from_slice(&value.to_le_bytes());
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_append() {
+        let mut log = AdminLog::default();
+        assert!(log.latest().is_none());
+
+        let actor = Pubkey::new_unique();
+        let target = Pubkey::new_unique();
+        log.append(
+            actor,
+            AdminAction::ChangeFeeReceiver,
+            target,
+            Pubkey::default().to_bytes(),
+            actor.to_bytes(),
+            1,
+        );
+        let latest = log.latest().unwrap();
+        assert_eq!({ latest.actor }, actor);
+        assert_eq!(latest.action, AdminAction::ChangeFeeReceiver as u8);
+        assert_eq!({ latest.new_value }, actor.to_bytes());
+        assert_eq!({ log.head }, 1);
+
+        // wraps around overwriting the oldest entries
+        for slot in 2..=ADMIN_LOG_SIZE as u64 + 1 {
+            log.append(
+                actor,
+                AdminAction::ProposePoolFee,
+                target,
+                log_value(0),
+                log_value(slot as u128),
+                slot,
+            );
+        }
+        assert_eq!({ log.head }, ADMIN_LOG_SIZE as u64 + 1);
+        assert_eq!({ log.entries[0].slot }, ADMIN_LOG_SIZE as u64 + 1);
+        assert_eq!({ log.latest().unwrap().slot }, ADMIN_LOG_SIZE as u64 + 1);
+        assert_eq!({ log.entries[1].slot }, 2);
+    }
+}
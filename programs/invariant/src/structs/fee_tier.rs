@@ -1,11 +1 @@
-use crate::decimals::FixedPoint;
-use anchor_lang::prelude::*;
-
-#[account(zero_copy)]
-#[repr(packed)]
-#[derive(PartialEq, Default, Debug)]
-pub struct FeeTier {
-    pub fee: FixedPoint,
-    pub tick_spacing: u16,
-    pub bump: u8,
-}
+pub use super::layout::FeeTier;
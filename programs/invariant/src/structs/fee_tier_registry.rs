@@ -0,0 +1,73 @@
+use crate::decimals::FixedPoint;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+
+// Bounded so the whole list fits the 1024 bytes of return data of `list_fee_tiers`
+pub const FEE_TIER_REGISTRY_SIZE: usize = 32;
+
+#[zero_copy]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug)]
+pub struct FeeTierEntry {
+    pub fee: FixedPoint,
+    pub tick_spacing: u16,
+}
+
+// Every fee tier in the order of creation, appended to by `create_fee_tier`
+#[account(zero_copy)]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug)]
+pub struct FeeTierRegistry {
+    pub count: u16,
+    pub entries: [FeeTierEntry; FEE_TIER_REGISTRY_SIZE],
+    pub bump: u8,
+}
+
+impl FeeTierRegistry {
+    pub fn register(&mut self, fee: FixedPoint, tick_spacing: u16) -> Result<()> {
+        let count = self.count as usize;
+        // fee tier PDAs are unique, a duplicate can only come from the backfill
+        if self
+            .fee_tiers()
+            .iter()
+            .any(|entry| ({ entry.fee }, { entry.tick_spacing }) == (fee, tick_spacing))
+        {
+            return Ok(());
+        }
+        require!(count < FEE_TIER_REGISTRY_SIZE, FeeTierRegistryFull);
+
+        self.entries[count] = FeeTierEntry { fee, tick_spacing };
+        self.count += 1;
+        Ok(())
+    }
+
+    pub fn fee_tiers(&self) -> &[FeeTierEntry] {
+        &self.entries[..self.count as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register() {
+        let mut registry = FeeTierRegistry::default();
+        assert!(registry.fee_tiers().is_empty());
+
+        registry.register(FixedPoint::from_scale(3, 3), 10).unwrap();
+        registry
+            .register(FixedPoint::from_scale(3, 3), 100)
+            .unwrap();
+        // same pair again is not listed twice
+        registry.register(FixedPoint::from_scale(3, 3), 10).unwrap();
+        assert_eq!({ registry.count }, 2);
+        assert_eq!({ registry.fee_tiers()[1].tick_spacing }, 100);
+
+        for tick_spacing in 2..FEE_TIER_REGISTRY_SIZE as u16 {
+            registry.register(FixedPoint::new(1), tick_spacing).unwrap();
+        }
+        assert_eq!(registry.fee_tiers().len(), FEE_TIER_REGISTRY_SIZE);
+        assert!(registry.register(FixedPoint::new(2), 1).is_err());
+    }
+}
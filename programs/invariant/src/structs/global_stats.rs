@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+pub const MAX_STATS_MINTS: usize = 16;
+
+#[zero_copy]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug)]
+pub struct MintProtocolFee {
+    pub mint: Pubkey,
+    pub amount: u128,
+}
+
+// Protocol wide counters. Pool creation and protocol fee withdrawals are rare and require the
+// account, so total_pools and protocol_fees are exact. Swaps pass it optionally in remaining
+// accounts to avoid a write lock on a single global account, so total_swaps is best-effort.
+#[account(zero_copy)]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug)]
+pub struct GlobalStats {
+    pub total_pools: u64,
+    pub total_swaps: u64,
+    pub protocol_fees: [MintProtocolFee; MAX_STATS_MINTS],
+    pub protocol_fees_count: u8,
+    pub bump: u8,
+}
+
+impl GlobalStats {
+    // mints over the capacity of the list are not tracked
+    pub fn add_protocol_fee(&mut self, mint: Pubkey, amount: u64) {
+        if amount == 0 {
+            return;
+        }
+        let count = self.protocol_fees_count as usize;
+        let index = match self.protocol_fees[..count]
+            .iter()
+            .position(|fee| fee.mint == mint)
+        {
+            Some(index) => index,
+            None if count < MAX_STATS_MINTS => {
+                self.protocol_fees[count].mint = mint;
+                self.protocol_fees_count += 1;
+                count
+            }
+            None => return,
+        };
+        let fee = &mut self.protocol_fees[index];
+        fee.amount = { fee.amount }.saturating_add(amount as u128);
+    }
+}
+
+pub fn update_global_stats<F: FnOnce(&mut GlobalStats)>(
+    remaining_accounts: &[AccountInfo],
+    update: F,
+) -> ProgramResult {
+    if let Some(loader) = remaining_accounts
+        .iter()
+        .find_map(|account| AccountLoader::<GlobalStats>::try_from(account).ok())
+    {
+        update(&mut *loader.load_mut()?);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_protocol_fee() {
+        let mut stats = GlobalStats::default();
+        let mint = Pubkey::new_unique();
+
+        stats.add_protocol_fee(mint, 0);
+        assert_eq!(stats.protocol_fees_count, 0);
+
+        stats.add_protocol_fee(mint, 10);
+        stats.add_protocol_fee(mint, 5);
+        assert_eq!(stats.protocol_fees_count, 1);
+        assert_eq!({ stats.protocol_fees[0].amount }, 15);
+
+        for _ in 1..MAX_STATS_MINTS {
+            stats.add_protocol_fee(Pubkey::new_unique(), 1);
+        }
+        assert_eq!(stats.protocol_fees_count as usize, MAX_STATS_MINTS);
+        // list is full
+        let untracked = Pubkey::new_unique();
+        stats.add_protocol_fee(untracked, 1);
+        assert_eq!(stats.protocol_fees_count as usize, MAX_STATS_MINTS);
+        assert!(!stats.protocol_fees.iter().any(|fee| fee.mint == untracked));
+        // tracked mints still accumulate
+        stats.add_protocol_fee(mint, 1);
+        assert_eq!({ stats.protocol_fees[0].amount }, 16);
+    }
+}
@@ -0,0 +1,123 @@
+use crate::events::LaunchWindowEndedEvent;
+use crate::structs::pool::Pool;
+use crate::util::get_current_slot;
+use crate::*;
+use anchor_lang::prelude::*;
+
+pub const MAX_LAUNCH_WHITELIST: usize = 16;
+
+// Addresses allowed to swap and open positions in a pool before its `launch_end_slot`
+#[account(zero_copy)]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug)]
+pub struct LaunchConfig {
+    pub pool: Pubkey,
+    pub whitelist: [Pubkey; MAX_LAUNCH_WHITELIST],
+    pub whitelist_count: u8,
+    pub bump: u8,
+}
+
+impl LaunchConfig {
+    pub fn is_whitelisted(&self, user: &Pubkey) -> bool {
+        self.whitelist[..self.whitelist_count as usize].contains(user)
+    }
+
+    pub fn add_user(&mut self, user: Pubkey) -> Result<()> {
+        if self.is_whitelisted(&user) {
+            return Ok(());
+        }
+        let count = self.whitelist_count as usize;
+        if count >= MAX_LAUNCH_WHITELIST {
+            return Err(ErrorCode::LaunchWhitelistFull.into());
+        }
+
+        self.whitelist[count] = user;
+        self.whitelist_count += 1;
+        Ok(())
+    }
+
+    pub fn remove_user(&mut self, user: &Pubkey) -> Result<()> {
+        let count = self.whitelist_count as usize;
+        let index = match self.whitelist[..count].iter().position(|u| u == user) {
+            Some(index) => index,
+            None => return Ok(()),
+        };
+
+        // keep the list dense by moving the last user into the gap
+        self.whitelist[index] = self.whitelist[count - 1];
+        self.whitelist[count - 1] = Pubkey::default();
+        self.whitelist_count -= 1;
+        Ok(())
+    }
+}
+
+// While the launch window of the pool is open its launch config has to be passed in remaining
+// accounts, with `user` on the whitelist. The first access after the window ends closes it
+// for good, so the expiry shows up as an event.
+pub fn check_launch_access(
+    pool: &mut Pool,
+    pool_key: &Pubkey,
+    remaining_accounts: &[AccountInfo],
+    user: &Pubkey,
+) -> ProgramResult {
+    let end_slot = pool.launch_end_slot;
+    if end_slot == 0 {
+        return Ok(());
+    }
+    if get_current_slot() >= end_slot {
+        pool.launch_end_slot = 0;
+        emit!(LaunchWindowEndedEvent {
+            pool: *pool_key,
+            end_slot,
+        });
+        return Ok(());
+    }
+
+    let launch_config = remaining_accounts
+        .iter()
+        .find_map(|account| AccountLoader::<LaunchConfig>::try_from(account).ok())
+        .ok_or(ErrorCode::LaunchWindowActive)?;
+    let launch_config = launch_config.load()?;
+    require!(
+        launch_config.pool == *pool_key && launch_config.is_whitelisted(user),
+        LaunchWindowActive
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_whitelist() {
+        let mut config = LaunchConfig::default();
+        let first = Pubkey::new_unique();
+        let second = Pubkey::new_unique();
+
+        assert!(!config.is_whitelisted(&first));
+        assert!(!config.is_whitelisted(&Pubkey::default()));
+
+        config.add_user(first).unwrap();
+        config.add_user(second).unwrap();
+        // adding twice is a no-op
+        config.add_user(first).unwrap();
+        assert_eq!(config.whitelist_count, 2);
+        assert!(config.is_whitelisted(&first));
+
+        config.remove_user(&first).unwrap();
+        assert_eq!(config.whitelist_count, 1);
+        assert!(!config.is_whitelisted(&first));
+        assert!(config.is_whitelisted(&second));
+        // removing a missing user is a no-op
+        config.remove_user(&first).unwrap();
+        assert_eq!(config.whitelist_count, 1);
+
+        // full list
+        config.remove_user(&second).unwrap();
+        for _ in 0..MAX_LAUNCH_WHITELIST {
+            config.add_user(Pubkey::new_unique()).unwrap();
+        }
+        assert!(config.add_user(Pubkey::new_unique()).is_err());
+    }
+}
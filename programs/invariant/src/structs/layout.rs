@@ -0,0 +1,177 @@
+// Layouts of the program accounts. invariant-types includes this file as well, so both crates
+// are built from the same definitions. Only the structs go here, methods are kept in the
+// module of each account, and everything used has to resolve the same way in both crates.
+use crate::decimals::*;
+use anchor_lang::prelude::*;
+
+#[account(zero_copy)]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug, AnchorDeserialize)]
+pub struct State {
+    pub admin: Pubkey,
+    pub nonce: u8,
+    pub authority: Pubkey,
+    pub bump: u8,
+    pub pending_admin: Pubkey, // set by `propose_admin`, default when there is no proposal
+    pub is_paused: bool,       // blocks swaps and new liquidity, never withdrawals
+}
+
+#[account(zero_copy)]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug, AnchorDeserialize)]
+pub struct FeeTier {
+    pub fee: FixedPoint,
+    pub tick_spacing: u16,
+    pub bump: u8,
+}
+
+#[account(zero_copy)]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug, AnchorDeserialize)]
+pub struct Pool {
+    pub token_x: Pubkey,
+    pub token_y: Pubkey,
+    pub token_x_reserve: Pubkey,
+    pub token_y_reserve: Pubkey,
+    pub position_iterator: u128,
+    pub tick_spacing: u16,
+    pub fee: FixedPoint,
+    pub protocol_fee: FixedPoint,
+    pub liquidity: Liquidity,
+    pub sqrt_price: Price, // square root of token y paid for a single token x, same in events
+    pub current_tick_index: i32, // nearest tick below the current price
+    pub tickmap: Pubkey,
+    pub fee_growth_global_x: FeeGrowth,
+    pub fee_growth_global_y: FeeGrowth,
+    pub fee_protocol_token_x: u64, // should be changed to TokenAmount when Armani implements tuple structs
+    pub fee_protocol_token_y: u64,
+    pub seconds_per_liquidity_global: FixedPoint,
+    pub start_timestamp: u64,
+    pub last_timestamp: u64,
+    pub fee_receiver: Pubkey,
+    pub oracle_address: Pubkey,
+    pub oracle_initialized: bool,
+    pub bump: u8,
+    pub fee_tier_fee: FixedPoint, // fee of the tier the pool was created with, used in pool seeds
+    pub pending_fee: FixedPoint,
+    pub pending_fee_timestamp: u64, // earliest moment pending fee can be applied, zero if none
+    pub tick_ema: i64, // time weighted moving average of current tick scaled by TICK_EMA_DENOMINATOR
+    pub tick_ema_half_life: u32, // in seconds
+    pub tick_ema_last_timestamp: u64,
+    pub hook_program: Pubkey, // called on every initialized tick crossed, default key if none
+    pub max_price_change_bps_per_tx: u16, // zero if unlimited
+    pub withdrawal_only: bool, // swaps and new positions disabled, set when sunsetting the pool
+    pub launch_end_slot: u64, // until then only the launch whitelist swaps and opens positions, zero if none
+    pub referral_fee_bps: u16, // share of the swap fee credited to a referrer
+    // reserve balances as moved by the program, direct transfers to the reserves are excluded
+    pub reserve_x: u64,
+    pub reserve_y: u64,
+    pub flash_loan_fee_bps: u16,
+    pub flash_loans_enabled: bool, // off until the admin sets the flash loan fee
+    // swaps moving more of either token emit LargeSwapEvent, zero disables the threshold
+    pub large_swap_threshold_x: u64,
+    pub large_swap_threshold_y: u64,
+}
+
+#[account(zero_copy)]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug, AnchorDeserialize)]
+pub struct Position {
+    pub owner: Pubkey,
+    pub pool: Pubkey,
+    pub id: u128, // unique inside pool
+    pub liquidity: Liquidity,
+    pub lower_tick_index: i32,
+    pub upper_tick_index: i32,
+    pub fee_growth_inside_x: FeeGrowth,
+    pub fee_growth_inside_y: FeeGrowth,
+    pub seconds_per_liquidity_inside: FixedPoint,
+    pub last_slot: u64,
+    pub tokens_owed_x: FixedPoint,
+    pub tokens_owed_y: FixedPoint,
+    pub bump: u8,
+    // all fees ever credited to the position, tiers of the rebate schedule are based on them
+    pub maker_fees_x: FixedPoint,
+    pub maker_fees_y: FixedPoint,
+    // lifetime fees paid out by claim_fee, rebates included
+    pub total_claimed_x: u64,
+    pub total_claimed_y: u64,
+}
+
+#[account(zero_copy)]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug, AnchorDeserialize)]
+pub struct PositionList {
+    pub head: u32,
+    pub bump: u8,
+}
+
+#[account(zero_copy)]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug, AnchorDeserialize)]
+pub struct Tick {
+    pub pool: Pubkey,
+    pub index: i32,
+    pub sign: bool, // true means positive
+    pub liquidity_change: Liquidity,
+    pub liquidity_gross: Liquidity,
+    pub sqrt_price: Price,
+    pub fee_growth_outside_x: FeeGrowth,
+    pub fee_growth_outside_y: FeeGrowth,
+    pub seconds_per_liquidity_outside: FixedPoint,
+    pub seconds_outside: u64,
+    pub bump: u8,
+    // liquidity of limit orders exiting their range through this tick, the ones below fill when
+    // the price crosses up, the ones above when it crosses down
+    pub limit_order_liquidity_below: Liquidity,
+    pub limit_order_liquidity_above: Liquidity,
+    pub limit_order_fills_below: u64,
+    pub limit_order_fills_above: u64,
+    pub limit_orders: u32, // orders exiting through this tick which are not claimed yet
+}
+
+#[account(zero_copy)]
+#[repr(packed)]
+#[derive(AnchorDeserialize)]
+pub struct Tickmap {
+    pub bitmap: [u8; 11091], // Tick limit / 4
+}
+
+#[account(zero_copy)]
+#[repr(packed)]
+#[derive(AnchorDeserialize)]
+pub struct Oracle {
+    pub data: [Record; 256],
+    pub head: u16,
+    pub amount: u16,
+    pub size: u16,
+}
+
+// Pool state after the last swap of `timestamp`, holds until the next record
+#[zero_copy]
+#[repr(packed)]
+#[derive(Default, Debug, PartialEq, AnchorDeserialize)]
+pub struct Record {
+    pub timestamp: u64,
+    pub sqrt_price: Price,
+    pub liquidity: Liquidity,
+}
+
+// Pool TWAP in a fixed layout for external consumers such as lending markets. Kept fresh by
+// the permissionless `update_price_feed` crank, consumers should reject feeds older than their
+// tolerance.
+#[account(zero_copy)]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug, AnchorDeserialize)]
+pub struct PriceFeed {
+    pub pool: Pubkey,
+    pub token_x: Pubkey,
+    pub token_y: Pubkey,
+    pub window: u64,          // seconds the averages are taken over
+    pub sqrt_price: Price,    // same direction as the pool sqrt_price, y per x
+    pub price: Price,         // squared sqrt_price, in raw token units
+    pub liquidity: Liquidity, // low values mean the price is cheap to move
+    pub publish_time: u64,
+    pub publish_slot: u64,
+    pub bump: u8,
+}
@@ -0,0 +1,129 @@
+use crate::decimals::*;
+use crate::math::{calculate_price_sqrt, get_delta_x, get_delta_y};
+use crate::structs::tick::Tick;
+use anchor_lang::prelude::*;
+
+// Liquidity one tick spacing wide, placed entirely in the token it sells. The liquidity of
+// orders is tracked in the book of the tick the price leaves the range through. Swaps crossing
+// that tick fill the whole book, taking its liquidity out of the pool for good, so the order
+// holds the bought token from then on and can be claimed at any later price. Orders don't earn
+// swap fees.
+#[account(zero_copy)]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug)]
+pub struct LimitOrder {
+    pub owner: Pubkey,
+    pub pool: Pubkey,
+    pub id: u128,
+    pub liquidity: Liquidity,
+    pub lower_tick_index: i32,
+    pub upper_tick_index: i32,
+    pub x_to_y: bool, // sells x for y
+    pub fills: u64,   // fill count of the book when the order was placed
+    pub bump: u8,
+}
+
+impl LimitOrder {
+    // Tick the price crosses when filling the order
+    pub fn exit_tick_index(&self) -> i32 {
+        match self.x_to_y {
+            true => self.upper_tick_index,
+            false => self.lower_tick_index,
+        }
+    }
+
+    // Other tick of the range
+    pub fn entry_tick_index(&self) -> i32 {
+        match self.x_to_y {
+            true => self.lower_tick_index,
+            false => self.upper_tick_index,
+        }
+    }
+
+    pub fn is_filled(&self, exit_tick: &Tick) -> bool {
+        let (_, fills) = exit_tick.limit_order_book(self.x_to_y);
+        fills > self.fills
+    }
+
+    // Bought token the order is settled with, rounded down like positions leaving the pool
+    pub fn filled_amounts(&self) -> (TokenAmount, TokenAmount) {
+        let lower_sqrt_price = calculate_price_sqrt(self.lower_tick_index);
+        let upper_sqrt_price = calculate_price_sqrt(self.upper_tick_index);
+        match self.x_to_y {
+            true => (
+                TokenAmount(0),
+                get_delta_y(lower_sqrt_price, upper_sqrt_price, self.liquidity, false).unwrap(),
+            ),
+            false => (
+                get_delta_x(lower_sqrt_price, upper_sqrt_price, self.liquidity, false).unwrap(),
+                TokenAmount(0),
+            ),
+        }
+    }
+}
+
+// Token the order sells, none when the range contains the current tick
+pub fn limit_order_side(
+    current_tick_index: i32,
+    lower_tick_index: i32,
+    upper_tick_index: i32,
+) -> Option<bool> {
+    if current_tick_index < lower_tick_index {
+        Some(true)
+    } else if current_tick_index >= upper_tick_index {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_limit_order_fill() {
+        // range above the price holds only x
+        assert_eq!(limit_order_side(-1, 0, 10), Some(true));
+        // range below the price holds only y
+        assert_eq!(limit_order_side(10, 0, 10), Some(false));
+        assert_eq!(limit_order_side(0, 0, 10), None);
+        assert_eq!(limit_order_side(9, 0, 10), None);
+
+        let sell_x = LimitOrder {
+            liquidity: Liquidity::from_integer(1_000_000),
+            lower_tick_index: 0,
+            upper_tick_index: 10,
+            x_to_y: true,
+            fills: 2,
+            ..Default::default()
+        };
+        let mut tick = Tick {
+            index: 10,
+            limit_order_fills_below: 2,
+            limit_order_fills_above: 5,
+            ..Default::default()
+        };
+        assert_eq!(sell_x.exit_tick_index(), 10);
+        assert_eq!(sell_x.entry_tick_index(), 0);
+        assert!(!sell_x.is_filled(&tick));
+        tick.limit_order_fills_below = 3;
+        assert!(sell_x.is_filled(&tick));
+
+        let sell_y = LimitOrder {
+            x_to_y: false,
+            fills: 5,
+            ..sell_x
+        };
+        assert_eq!(sell_y.exit_tick_index(), 0);
+        assert!(!sell_y.is_filled(&tick));
+
+        // the bought token only
+        let (amount_x, amount_y) = sell_x.filled_amounts();
+        assert_eq!(amount_x, TokenAmount(0));
+        assert_eq!(amount_y, TokenAmount(500));
+        let (amount_x, amount_y) = sell_y.filled_amounts();
+        assert_eq!(amount_x, TokenAmount(499));
+        assert_eq!(amount_y, TokenAmount(0));
+    }
+}
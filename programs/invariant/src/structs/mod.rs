@@ -1,17 +1,40 @@
+pub mod admin_log;
 pub mod fee_tier;
+pub mod fee_tier_registry;
+pub mod global_stats;
+pub mod launch_config;
+mod layout;
+pub mod limit_order;
 pub mod oracle;
 pub mod pool;
 pub mod position;
 pub mod position_list;
+pub mod position_nft;
+pub mod price_feed;
+pub mod rebate_schedule;
+pub mod referral_earnings;
+pub mod session_authority;
 pub mod state;
 pub mod tick;
 pub mod tickmap;
+pub mod withdraw_acl;
 
+pub use admin_log::*;
 pub use fee_tier::*;
+pub use fee_tier_registry::*;
+pub use global_stats::*;
+pub use launch_config::*;
+pub use limit_order::*;
 pub use oracle::*;
 pub use pool::*;
 pub use position::*;
 pub use position_list::*;
+pub use position_nft::*;
+pub use price_feed::*;
+pub use rebate_schedule::*;
+pub use referral_earnings::*;
+pub use session_authority::*;
 pub use state::*;
 pub use tick::*;
 pub use tickmap::*;
+pub use withdraw_acl::*;
@@ -1,26 +1,22 @@
+pub use super::layout::{Oracle, Record};
 use crate::decimals::*;
 use anchor_lang::prelude::*;
 
 const SIZE: u16 = 256; // UPDATE IN ARRAYS AS WELL!
 
-#[account(zero_copy)]
-#[repr(packed)]
-pub struct Oracle {
-    pub data: [Record; 256],
-    pub head: u16,
-    pub amount: u16,
-    pub size: u16,
-}
-
-#[zero_copy]
-pub struct Record {
-    pub timestamp: u64,
-    pub price: Price,
-}
-
 impl Oracle {
-    pub fn add_record(&mut self, timestamp: u64, price: Price) {
-        let record = Record { timestamp, price };
+    pub fn add_record(&mut self, timestamp: u64, sqrt_price: Price, liquidity: Liquidity) {
+        let record = Record {
+            timestamp,
+            sqrt_price,
+            liquidity,
+        };
+
+        // swaps within the same second update a single record
+        if self.amount > 0 && { self.data[self.head as usize].timestamp } == timestamp {
+            self.data[self.head as usize] = record;
+            return;
+        }
 
         self.head = (self.head + 1) % self.size;
         self.data[self.head as usize] = record;
@@ -30,6 +26,40 @@ impl Oracle {
         }
     }
 
+    // Time weighted averages of sqrt price and liquidity over [start, end), same as
+    // Oracle::twap of invariant-types. The window can't start before the oldest record.
+    pub fn twap(&self, start: u64, end: u64) -> Result<(Price, Liquidity)> {
+        require!(start < end && self.amount > 0, InvalidTwapWindow);
+        let size = self.size as usize;
+        let amount = self.amount as usize;
+        let oldest = (self.head as usize + size + 1 - amount) % size;
+        let record = |i: usize| self.data[(oldest + i) % size];
+        require!({ record(0).timestamp } <= start, InvalidTwapWindow);
+
+        let mut price_sum = U256::from(0);
+        let mut liquidity_sum = U256::from(0);
+        for i in 0..amount {
+            let current = record(i);
+            let from = { current.timestamp }.max(start);
+            let to = match i + 1 < amount {
+                true => { record(i + 1).timestamp }.min(end),
+                false => end,
+            };
+            if to <= from {
+                continue;
+            }
+            let duration = U256::from(to - from);
+            price_sum = price_sum + U256::from(current.sqrt_price.v) * duration;
+            liquidity_sum = liquidity_sum + U256::from(current.liquidity.v) * duration;
+        }
+
+        let window = U256::from(end - start);
+        Ok((
+            Price::new((price_sum / window).as_u128()),
+            Liquidity::new((liquidity_sum / window).as_u128()),
+        ))
+    }
+
     pub fn init(&mut self) {
         self.size = SIZE;
         self.head = SIZE - 1;
@@ -45,7 +75,8 @@ mod tests {
         print!("{}", std::mem::align_of::<Oracle>());
         let mut oracle = Oracle {
             data: [Record {
-                price: Price::from_integer(0),
+                sqrt_price: Price::from_integer(0),
+                liquidity: Liquidity::new(0),
                 timestamp: 0,
             }; 256],
             head: SIZE - 1,
@@ -58,26 +89,79 @@ mod tests {
 
         // fill
         while index < SIZE as u64 {
-            oracle.add_record(index, Price::new(index as u128));
+            oracle.add_record(
+                index,
+                Price::new(index as u128),
+                Liquidity::new(index as u128),
+            );
 
             assert_eq!(oracle.head as u64, index);
             assert_eq!(oracle.amount as u64, index + 1);
             assert_eq!({ oracle.data[oracle.head as usize].timestamp }, index);
-            assert_eq!({ oracle.data[oracle.head as usize].price.v }, index as u128);
+            assert_eq!(
+                { oracle.data[oracle.head as usize].sqrt_price.v },
+                index as u128
+            );
 
             index += 1;
         }
 
         // second fill
         while index < 2 * SIZE as u64 {
-            oracle.add_record(index, Price::new(index as u128));
+            oracle.add_record(
+                index,
+                Price::new(index as u128),
+                Liquidity::new(index as u128),
+            );
 
             assert_eq!(oracle.head as u64, index - SIZE as u64);
             assert_eq!(oracle.amount as u64, SIZE as u64);
             assert_eq!({ oracle.data[oracle.head as usize].timestamp }, index);
-            assert_eq!({ oracle.data[oracle.head as usize].price.v }, index as u128);
+            assert_eq!(
+                { oracle.data[oracle.head as usize].sqrt_price.v },
+                index as u128
+            );
 
             index += 1;
         }
+
+        // same timestamp overwrites the last record
+        let head = oracle.head;
+        oracle.add_record(index - 1, Price::new(7), Liquidity::new(8));
+        assert_eq!({ oracle.head }, head);
+        assert_eq!({ oracle.amount }, SIZE);
+        assert_eq!({ oracle.data[head as usize].sqrt_price.v }, 7);
+        assert_eq!({ oracle.data[head as usize].liquidity.v }, 8);
+    }
+
+    #[test]
+    fn test_twap() {
+        let mut oracle = Oracle {
+            data: [Record {
+                sqrt_price: Price::new(0),
+                liquidity: Liquidity::new(0),
+                timestamp: 0,
+            }; 256],
+            head: 0,
+            amount: 0,
+            size: 0,
+        };
+        assert!(oracle.twap(0, 1).is_err());
+
+        oracle.init();
+        oracle.add_record(10, Price::new(100), Liquidity::new(1));
+        oracle.add_record(20, Price::new(200), Liquidity::new(3));
+        oracle.add_record(40, Price::new(400), Liquidity::new(5));
+
+        // (100 * 10 + 200 * 20 + 400 * 10) / 40, (1 * 10 + 3 * 20 + 5 * 10) / 40
+        assert_eq!(
+            oracle.twap(10, 50).unwrap(),
+            (Price::new(225), Liquidity::new(3))
+        );
+        // cut in the middle of records
+        assert_eq!(oracle.twap(15, 25).unwrap().0, Price::new(150));
+        // before the oldest record and empty
+        assert!(oracle.twap(5, 20).is_err());
+        assert!(oracle.twap(20, 20).is_err());
     }
 }
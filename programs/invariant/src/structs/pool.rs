@@ -1,37 +1,135 @@
+pub use super::layout::Pool;
 use crate::*;
 use anchor_lang::prelude::*;
 use decimals::*;
 
-#[account(zero_copy)]
-#[repr(packed)]
-#[derive(PartialEq, Default, Debug)]
-pub struct Pool {
-    pub token_x: Pubkey,
-    pub token_y: Pubkey,
-    pub token_x_reserve: Pubkey,
-    pub token_y_reserve: Pubkey,
-    pub position_iterator: u128,
-    pub tick_spacing: u16,
-    pub fee: FixedPoint,
-    pub protocol_fee: FixedPoint,
-    pub liquidity: Liquidity,
-    pub sqrt_price: Price,
-    pub current_tick_index: i32, // nearest tick below the current price
-    pub tickmap: Pubkey,
-    pub fee_growth_global_x: FeeGrowth,
-    pub fee_growth_global_y: FeeGrowth,
-    pub fee_protocol_token_x: u64, // should be changed to TokenAmount when Armani implements tuple structs
-    pub fee_protocol_token_y: u64,
-    pub seconds_per_liquidity_global: FixedPoint,
-    pub start_timestamp: u64,
-    pub last_timestamp: u64,
-    pub fee_receiver: Pubkey,
-    pub oracle_address: Pubkey,
-    pub oracle_initialized: bool,
-    pub bump: u8,
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+// 10%
+pub const MAX_POOL_FEE: FixedPoint = FixedPoint {
+    v: FixedPoint::ONE / 10,
+};
+// a week for liquidity providers to react to a proposed fee change
+pub const POOL_FEE_TIMELOCK: u64 = 7 * 24 * 60 * 60;
+
+pub const TICK_EMA_DENOMINATOR: i64 = 1_000_000;
+// 20%
+pub const DEFAULT_REFERRAL_FEE_BPS: u16 = 2_000;
+pub const DEFAULT_FLASH_LOAN_FEE_BPS: u16 = 9;
+pub const DEFAULT_TICK_EMA_HALF_LIFE: u32 = 10 * 60;
+
+// Part of every swap fee going to the protocol, as stored in `Pool::protocol_fee`. Splitting
+// the fee amount, instead of applying separate percentages to it, accounts for every token.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProtocolFeeShare(FixedPoint);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FeeSplit {
+    pub lp_fee: TokenAmount,
+    pub protocol_fee: TokenAmount,
+    pub referral_fee: TokenAmount,
+}
+
+impl ProtocolFeeShare {
+    pub fn new(share: FixedPoint) -> Result<Self> {
+        require!(share <= FixedPoint::from_integer(1), InvalidProtocolFee);
+        Ok(Self(share))
+    }
+
+    pub fn get(&self) -> FixedPoint {
+        self.0
+    }
+
+    // Protocol part is rounded up, referral part is taken from what is left, the rest goes to
+    // liquidity providers. Parts always add up to the fee.
+    pub fn split(&self, fee: TokenAmount, ref_percentage: FixedPoint) -> FeeSplit {
+        let protocol_fee = TokenAmount::from_decimal_up(fee.big_mul_up(self.0));
+        let protocol_fee = TokenAmount(protocol_fee.0.min(fee.0));
+        let referral_fee = match ref_percentage.is_zero() {
+            true => TokenAmount(0),
+            false => TokenAmount::from_decimal(fee.big_mul(ref_percentage)),
+        };
+        let referral_fee = TokenAmount(referral_fee.0.min((fee - protocol_fee).0));
+        FeeSplit {
+            lp_fee: fee - protocol_fee - referral_fee,
+            protocol_fee,
+            referral_fee,
+        }
+    }
 }
 
 impl Pool {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        token_x: Pubkey,
+        token_y: Pubkey,
+        token_x_reserve: Pubkey,
+        token_y_reserve: Pubkey,
+        tickmap: Pubkey,
+        fee: FixedPoint,
+        tick_spacing: u16,
+        init_tick: i32,
+        fee_receiver: Pubkey,
+        bump: u8,
+        current_timestamp: u64,
+    ) -> Self {
+        Self {
+            token_x,
+            token_y,
+            token_x_reserve,
+            token_y_reserve,
+            tick_spacing,
+            fee,
+            protocol_fee: FixedPoint::from_scale(1, 2),
+            liquidity: Liquidity::new(0),
+            sqrt_price: calculate_price_sqrt(init_tick),
+            current_tick_index: init_tick,
+            tickmap,
+            fee_growth_global_x: FeeGrowth::new(0),
+            fee_growth_global_y: FeeGrowth::new(0),
+            fee_protocol_token_x: 0,
+            fee_protocol_token_y: 0,
+            position_iterator: 0,
+            seconds_per_liquidity_global: FixedPoint::new(0),
+            start_timestamp: current_timestamp,
+            last_timestamp: current_timestamp,
+            fee_receiver,
+            oracle_address: Pubkey::default(),
+            oracle_initialized: false,
+            bump,
+            fee_tier_fee: fee,
+            pending_fee: FixedPoint::new(0),
+            pending_fee_timestamp: 0,
+            tick_ema: init_tick as i64 * TICK_EMA_DENOMINATOR,
+            tick_ema_half_life: DEFAULT_TICK_EMA_HALF_LIFE,
+            tick_ema_last_timestamp: current_timestamp,
+            hook_program: Pubkey::default(),
+            max_price_change_bps_per_tx: 0,
+            withdrawal_only: false,
+            launch_end_slot: 0,
+            referral_fee_bps: DEFAULT_REFERRAL_FEE_BPS,
+            reserve_x: 0,
+            reserve_y: 0,
+            flash_loan_fee_bps: DEFAULT_FLASH_LOAN_FEE_BPS,
+            flash_loans_enabled: false,
+            large_swap_threshold_x: 0,
+            large_swap_threshold_y: 0,
+        }
+    }
+
+    // Fills the fields appended after the pool was created, the same as `new` would. Fields
+    // missing here start zeroed. Reserves are set to the balances of the reserve accounts.
+    pub fn migrate(&mut self, reserve_x: u64, reserve_y: u64, current_timestamp: u64) {
+        self.fee_tier_fee = self.fee;
+        self.tick_ema = self.current_tick_index as i64 * TICK_EMA_DENOMINATOR;
+        self.tick_ema_half_life = DEFAULT_TICK_EMA_HALF_LIFE;
+        self.tick_ema_last_timestamp = current_timestamp;
+        self.referral_fee_bps = DEFAULT_REFERRAL_FEE_BPS;
+        self.reserve_x = reserve_x;
+        self.reserve_y = reserve_y;
+        self.flash_loan_fee_bps = DEFAULT_FLASH_LOAN_FEE_BPS;
+    }
+
     #[allow(unaligned_references)]
     pub fn add_fee(
         &mut self,
@@ -39,26 +137,38 @@ impl Pool {
         ref_percentage: FixedPoint,
         in_x: bool,
     ) -> TokenAmount {
-        let protocol_fee = TokenAmount::from_decimal_up(amount.big_mul_up(self.protocol_fee));
-        let ref_fee = match ref_percentage.is_zero() {
-            true => TokenAmount(0),
-            false => TokenAmount::from_decimal(amount.big_mul(ref_percentage)),
-        };
-        let pool_fee = amount - protocol_fee - ref_fee;
+        let FeeSplit {
+            lp_fee: pool_fee,
+            protocol_fee,
+            referral_fee: ref_fee,
+        } = self.protocol_fee_share().split(amount, ref_percentage);
 
-        if (pool_fee.is_zero() && protocol_fee.is_zero()) || self.liquidity.is_zero() {
+        if pool_fee.is_zero() && protocol_fee.is_zero() {
             return ref_fee;
         }
-        let fee_growth = FeeGrowth::from_fee(self.liquidity, pool_fee);
+        // fee growth can not be represented without or with dust liquidity, such fee goes to
+        // the protocol
+        let fee_growth = match self.liquidity.is_zero() {
+            true => None,
+            false => FeeGrowth::from_fee(self.liquidity, pool_fee),
+        };
+        let (fee_growth, protocol_fee) = match fee_growth {
+            Some(fee_growth) => (fee_growth, protocol_fee),
+            None => (FeeGrowth::new(0), protocol_fee + pool_fee),
+        };
 
         if in_x {
+            let fee_growth_before = self.fee_growth_global_x;
             self.fee_growth_global_x = self.fee_growth_global_x.unchecked_add(fee_growth);
+            strict_assert_fee_growth_monotonic(fee_growth_before, self.fee_growth_global_x);
             self.fee_protocol_token_x = self
                 .fee_protocol_token_x
                 .checked_add(protocol_fee.0)
                 .unwrap();
         } else {
+            let fee_growth_before = self.fee_growth_global_y;
             self.fee_growth_global_y = self.fee_growth_global_y.unchecked_add(fee_growth);
+            strict_assert_fee_growth_monotonic(fee_growth_before, self.fee_growth_global_y);
             self.fee_protocol_token_y = self
                 .fee_protocol_token_y
                 .checked_add(protocol_fee.0)
@@ -67,6 +177,81 @@ impl Pool {
         ref_fee
     }
 
+    pub fn referral_fee(&self) -> FixedPoint {
+        FixedPoint::from_scale(self.referral_fee_bps, 4)
+    }
+
+    // rounded up, so no loan is free
+    pub fn flash_loan_fee(&self, amount: TokenAmount) -> TokenAmount {
+        let fee = (amount.0 as u128 * self.flash_loan_fee_bps as u128 + BPS_DENOMINATOR as u128
+            - 1)
+            / BPS_DENOMINATOR as u128;
+        TokenAmount(fee as u64)
+    }
+
+    pub fn protocol_fee_share(&self) -> ProtocolFeeShare {
+        // validated by ProtocolFeeShare::new when set
+        ProtocolFeeShare(self.protocol_fee)
+    }
+
+    pub fn add_reserves(&mut self, amount_x: TokenAmount, amount_y: TokenAmount) {
+        self.reserve_x = self.reserve_x.checked_add(amount_x.0).unwrap();
+        self.reserve_y = self.reserve_y.checked_add(amount_y.0).unwrap();
+    }
+
+    pub fn sub_reserves(&mut self, amount_x: TokenAmount, amount_y: TokenAmount) {
+        self.reserve_x = self.reserve_x.checked_sub(amount_x.0).unwrap();
+        self.reserve_y = self.reserve_y.checked_sub(amount_y.0).unwrap();
+    }
+
+    pub fn swap_reserves(&mut self, x_to_y: bool, amount_in: TokenAmount, amount_out: TokenAmount) {
+        let zero = TokenAmount(0);
+        match x_to_y {
+            true => {
+                self.add_reserves(amount_in, zero);
+                self.sub_reserves(zero, amount_out);
+            }
+            false => {
+                self.add_reserves(zero, amount_in);
+                self.sub_reserves(amount_out, zero);
+            }
+        }
+    }
+
+    // Either token moved by the swap over its threshold
+    pub fn is_large_swap(
+        &self,
+        x_to_y: bool,
+        amount_in: TokenAmount,
+        amount_out: TokenAmount,
+    ) -> bool {
+        let (amount_x, amount_y) = match x_to_y {
+            true => (amount_in, amount_out),
+            false => (amount_out, amount_in),
+        };
+        let exceeds = |amount: TokenAmount, threshold: u64| threshold != 0 && amount.0 > threshold;
+        exceeds(amount_x, self.large_swap_threshold_x)
+            || exceeds(amount_y, self.large_swap_threshold_y)
+    }
+
+    // Tokens in the reserves the pool does not account for
+    pub fn reserves_excess(&self, balance_x: u64, balance_y: u64) -> (TokenAmount, TokenAmount) {
+        (
+            TokenAmount(balance_x.saturating_sub(self.reserve_x)),
+            TokenAmount(balance_y.saturating_sub(self.reserve_y)),
+        )
+    }
+
+    // Accounted reserves never exceed the actual balances, no-op without the `strict-invariants` feature
+    #[allow(unused_variables)]
+    pub fn strict_check_reserves(&self, reserve_x: &AccountInfo, reserve_y: &AccountInfo) {
+        strict_assert!(
+            anchor_spl::token::accessor::amount(reserve_x).unwrap() >= self.reserve_x
+                && anchor_spl::token::accessor::amount(reserve_y).unwrap() >= self.reserve_y,
+            "reserve balance below the accounted one"
+        );
+    }
+
     pub fn update_liquidity_safely(&mut self, liquidity_delta: Liquidity, add: bool) -> Result<()> {
         // validate in decrease liquidity case
         if !add && { self.liquidity } < liquidity_delta {
@@ -90,10 +275,97 @@ impl Pool {
         self.last_timestamp = current_timestamp;
     }
 
+    pub fn propose_fee(&mut self, fee: FixedPoint, current_timestamp: u64) -> Result<()> {
+        if fee > MAX_POOL_FEE || fee == { self.fee } {
+            return Err(ErrorCode::InvalidPoolFee.into());
+        }
+        self.pending_fee = fee;
+        self.pending_fee_timestamp = current_timestamp.checked_add(POOL_FEE_TIMELOCK).unwrap();
+
+        Ok(())
+    }
+
+    pub fn apply_pending_fee(&mut self, current_timestamp: u64) -> Result<()> {
+        if self.pending_fee_timestamp == 0 {
+            return Err(ErrorCode::NoPendingPoolFee.into());
+        }
+        if current_timestamp < self.pending_fee_timestamp {
+            return Err(ErrorCode::PoolFeeTimelocked.into());
+        }
+        self.fee = self.pending_fee;
+        self.pending_fee = FixedPoint::new(0);
+        self.pending_fee_timestamp = 0;
+
+        Ok(())
+    }
+
+    // Should be called before the price moves, so the average accounts for the tick
+    // that held since the last update. Weight of the new sample is elapsed / (elapsed + half_life),
+    // a cheap approximation of 1 - 2 ^ (-elapsed / half_life) exact at elapsed == half_life.
+    pub fn update_tick_ema(&mut self, current_timestamp: u64) {
+        let elapsed = current_timestamp.saturating_sub(self.tick_ema_last_timestamp);
+        if elapsed == 0 {
+            return;
+        }
+        let ema = self.tick_ema as i128;
+        let target = self.current_tick_index as i128 * TICK_EMA_DENOMINATOR as i128;
+        let delta =
+            (target - ema) * elapsed as i128 / (elapsed as i128 + self.tick_ema_half_life as i128);
+
+        self.tick_ema = (ema + delta) as i64;
+        self.tick_ema_last_timestamp = current_timestamp;
+    }
+
+    // rounded down, same as current_tick_index
+    pub fn get_tick_ema(&self) -> i32 {
+        { self.tick_ema }.div_euclid(TICK_EMA_DENOMINATOR) as i32
+    }
+
+    // Price moves of a single transaction are limited for pools used as on-chain oracles.
+    // Compared on the price itself, so the limit is the same in both directions of the pair.
+    pub fn check_price_change(&self, sqrt_price_before: Price) -> Result<()> {
+        let max_bps = self.max_price_change_bps_per_tx as u64;
+        if max_bps == 0 {
+            return Ok(());
+        }
+        let before = U256::from(sqrt_price_before.v) * U256::from(sqrt_price_before.v);
+        let after = U256::from({ self.sqrt_price }.v) * U256::from({ self.sqrt_price }.v);
+        let denominator = U256::from(BPS_DENOMINATOR);
+
+        let within_limit = match after >= before {
+            true => after * denominator <= before * U256::from(BPS_DENOMINATOR + max_bps),
+            false => {
+                after * denominator >= before * U256::from(BPS_DENOMINATOR.saturating_sub(max_bps))
+            }
+        };
+        require!(within_limit, PriceChangeTooLarge);
+        Ok(())
+    }
+
     pub fn set_oracle(&mut self, address: Pubkey) {
         self.oracle_address = address;
         self.oracle_initialized = true;
     }
+
+    // Sanity checks of the pool state, no-op without the `strict-invariants` feature
+    pub fn strict_check(&self) {
+        strict_assert!(
+            { self.sqrt_price } >= Price::new(MIN_SQRT_PRICE) && { self.sqrt_price }
+                <= Price::new(MAX_SQRT_PRICE),
+            "sqrt_price out of bounds"
+        );
+        strict_assert!(
+            self.current_tick_index >= -crate::structs::tickmap::MAX_TICK
+                && self.current_tick_index <= crate::structs::tickmap::MAX_TICK,
+            "current tick index out of bounds"
+        );
+    }
+}
+
+// accumulator wrapping around is not expected in practice, so fee growth never decreases
+#[allow(unused_variables)]
+fn strict_assert_fee_growth_monotonic(before: FeeGrowth, after: FeeGrowth) {
+    strict_assert!(after >= before, "fee growth decreased");
 }
 
 #[cfg(test)]
@@ -101,6 +373,79 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_new() {
+        let token_x = Pubkey::new_unique();
+        let token_y = Pubkey::new_unique();
+        let fee = FixedPoint::from_scale(6, 4);
+        let pool = Pool::new(
+            token_x,
+            token_y,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            fee,
+            10,
+            -20,
+            Pubkey::new_unique(),
+            255,
+            100,
+        );
+
+        assert_eq!({ pool.token_x }, token_x);
+        assert_eq!({ pool.token_y }, token_y);
+        assert_eq!({ pool.fee }, fee);
+        assert_eq!({ pool.fee_tier_fee }, fee);
+        assert_eq!({ pool.tick_spacing }, 10);
+        assert_eq!({ pool.current_tick_index }, -20);
+        assert_eq!({ pool.sqrt_price }, calculate_price_sqrt(-20));
+        assert_eq!({ pool.tick_ema }, -20 * TICK_EMA_DENOMINATOR);
+        assert_eq!({ pool.start_timestamp }, 100);
+        assert_eq!({ pool.last_timestamp }, 100);
+        assert_eq!({ pool.tick_ema_last_timestamp }, 100);
+        assert_eq!({ pool.liquidity }, Liquidity::new(0));
+        assert_eq!({ pool.protocol_fee }, FixedPoint::from_scale(1, 2));
+        assert_eq!({ pool.referral_fee_bps }, DEFAULT_REFERRAL_FEE_BPS);
+        assert!(!pool.oracle_initialized);
+        assert_eq!({ pool.hook_program }, Pubkey::default());
+    }
+
+    #[test]
+    fn test_migrate() {
+        let pool = Pool {
+            reserve_x: 1_000,
+            reserve_y: 2_000,
+            ..Pool::new(
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                FixedPoint::from_scale(6, 4),
+                10,
+                -20,
+                Pubkey::new_unique(),
+                255,
+                100,
+            )
+        };
+        // appended fields of a legacy pool read as zeros after the realloc
+        let mut legacy = Pool {
+            fee_tier_fee: FixedPoint::new(0),
+            tick_ema: 0,
+            tick_ema_half_life: 0,
+            tick_ema_last_timestamp: 0,
+            referral_fee_bps: 0,
+            reserve_x: 0,
+            reserve_y: 0,
+            flash_loan_fee_bps: 0,
+            ..pool
+        };
+
+        legacy.migrate(1_000, 2_000, 100);
+        assert_eq!(legacy, pool);
+    }
+
     #[test]
     fn test_update_liquidity_safely_pool() {
         // Invalid pool liquidity
@@ -186,6 +531,319 @@ mod tests {
             assert_eq!({ pool.fee_protocol_token_y }, 0);
             assert_eq!(ref_fee, TokenAmount(0));
         }
+        // dust liquidity, pool fee overflows fee growth and goes to protocol fee
+        {
+            let mut pool = Pool {
+                liquidity: Liquidity::new(1),
+                ..pool.clone()
+            };
+            let amount = TokenAmount::new(1_000_000_000);
+            let ref_fee = pool.add_fee(amount, FixedPoint::from_integer(0), true);
+
+            assert_eq!({ pool.fee_growth_global_x }, FeeGrowth::new(0));
+            assert_eq!({ pool.fee_growth_global_y }, FeeGrowth::new(0));
+            assert_eq!({ pool.fee_protocol_token_x }, 1_000_000_000);
+            assert_eq!({ pool.fee_protocol_token_y }, 0);
+            assert_eq!(ref_fee, TokenAmount(0));
+        }
+        // dust liquidity, pool fee still fits
+        {
+            let mut pool = Pool {
+                liquidity: Liquidity::new(1),
+                ..pool.clone()
+            };
+            let amount = TokenAmount::new(10);
+            let ref_fee = pool.add_fee(amount, FixedPoint::from_integer(0), true);
+
+            assert_eq!(
+                { pool.fee_growth_global_x },
+                FeeGrowth::from_integer(8_000_000)
+            );
+            assert_eq!({ pool.fee_protocol_token_x }, 2);
+            assert_eq!(ref_fee, TokenAmount(0));
+        }
+        // no liquidity, pool fee goes to protocol fee
+        {
+            let mut pool = Pool {
+                liquidity: Liquidity::new(0),
+                ..pool.clone()
+            };
+            let amount = TokenAmount::new(10);
+            let ref_fee = pool.add_fee(amount, FixedPoint::from_scale(2, 1), true);
+
+            assert_eq!({ pool.fee_growth_global_x }, FeeGrowth::new(0));
+            assert_eq!({ pool.fee_protocol_token_x }, 8);
+            assert_eq!(ref_fee, TokenAmount(2));
+        }
+        // whole fee to the protocol leaves nothing for the referrer
+        {
+            let mut pool = Pool {
+                protocol_fee: FixedPoint::from_integer(1),
+                ..pool.clone()
+            };
+            let amount = TokenAmount::new(10);
+            let ref_fee = pool.add_fee(amount, FixedPoint::from_scale(2, 1), true);
+
+            assert_eq!({ pool.fee_growth_global_x }, FeeGrowth::new(0));
+            assert_eq!({ pool.fee_protocol_token_x }, 10);
+            assert_eq!(ref_fee, TokenAmount(0));
+        }
+    }
+
+    #[test]
+    fn test_referral_fee() {
+        let pool = Pool {
+            referral_fee_bps: DEFAULT_REFERRAL_FEE_BPS,
+            ..Default::default()
+        };
+        assert_eq!(pool.referral_fee(), FixedPoint::from_scale(2, 1));
+        let pool = Pool {
+            referral_fee_bps: 1,
+            ..Default::default()
+        };
+        assert_eq!(pool.referral_fee(), FixedPoint::from_scale(1, 4));
+    }
+
+    #[test]
+    fn test_flash_loan_fee() {
+        let pool = Pool {
+            flash_loan_fee_bps: DEFAULT_FLASH_LOAN_FEE_BPS,
+            ..Default::default()
+        };
+        assert_eq!(
+            pool.flash_loan_fee(TokenAmount(1_000_000)),
+            TokenAmount(900)
+        );
+        assert_eq!(pool.flash_loan_fee(TokenAmount(1)), TokenAmount(1));
+        assert_eq!(pool.flash_loan_fee(TokenAmount(0)), TokenAmount(0));
+        assert_eq!(
+            Pool::default().flash_loan_fee(TokenAmount(1_000_000)),
+            TokenAmount(0)
+        );
+    }
+
+    #[test]
+    fn test_reserves() {
+        let mut pool = Pool::default();
+        pool.add_reserves(TokenAmount(100), TokenAmount(50));
+        pool.sub_reserves(TokenAmount(30), TokenAmount(0));
+        assert_eq!({ pool.reserve_x }, 70);
+        assert_eq!({ pool.reserve_y }, 50);
+
+        pool.swap_reserves(false, TokenAmount(10), TokenAmount(20));
+        assert_eq!({ pool.reserve_x }, 50);
+        assert_eq!({ pool.reserve_y }, 60);
+
+        assert_eq!(
+            pool.reserves_excess(55, 60),
+            (TokenAmount(5), TokenAmount(0))
+        );
+        // balance below the accounted one is not an excess
+        assert_eq!(
+            pool.reserves_excess(40, 60),
+            (TokenAmount(0), TokenAmount(0))
+        );
+    }
+
+    #[test]
+    fn test_is_large_swap() {
+        let pool = Pool {
+            large_swap_threshold_x: 1000,
+            ..Default::default()
+        };
+        assert!(pool.is_large_swap(true, TokenAmount(1001), TokenAmount(5)));
+        assert!(!pool.is_large_swap(true, TokenAmount(1000), TokenAmount(5)));
+        // x is the output of y to x swaps, y has no threshold
+        assert!(pool.is_large_swap(false, TokenAmount(u64::MAX), TokenAmount(1001)));
+        assert!(!pool.is_large_swap(false, TokenAmount(u64::MAX), TokenAmount(999)));
+        assert!(!Pool::default().is_large_swap(true, TokenAmount(u64::MAX), TokenAmount(u64::MAX)));
+    }
+
+    #[test]
+    fn test_protocol_fee_share_split() {
+        assert!(ProtocolFeeShare::new(FixedPoint::from_integer(1)).is_ok());
+        assert!(ProtocolFeeShare::new(FixedPoint::new(FixedPoint::ONE + 1)).is_err());
+
+        let split = ProtocolFeeShare::new(FixedPoint::from_scale(2, 1))
+            .unwrap()
+            .split(TokenAmount(7), FixedPoint::from_scale(2, 1));
+        // protocol rounded up, referral down
+        assert_eq!(split.protocol_fee, TokenAmount(2));
+        assert_eq!(split.referral_fee, TokenAmount(1));
+        assert_eq!(split.lp_fee, TokenAmount(4));
+
+        // parts always add up to the fee
+        for share in [0, 1, 10, 33, 50, 99, 100] {
+            let share = ProtocolFeeShare::new(FixedPoint::from_scale(share, 2)).unwrap();
+            for ref_percentage in [0, 20, 100] {
+                let ref_percentage = FixedPoint::from_scale(ref_percentage, 2);
+                for fee in (0..200).chain([u64::MAX / 3, u64::MAX]) {
+                    let split = share.split(TokenAmount(fee), ref_percentage);
+                    assert_eq!(
+                        split.lp_fee + split.protocol_fee + split.referral_fee,
+                        TokenAmount(fee)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_pool_fee_timelock() {
+        let pool = Pool {
+            fee: FixedPoint::from_scale(3, 3),
+            fee_tier_fee: FixedPoint::from_scale(3, 3),
+            ..Default::default()
+        };
+        let proposed_at = 1_000;
+        // out of bounds
+        {
+            let mut pool = pool.clone();
+            let result = pool.propose_fee(FixedPoint::from_scale(11, 2), proposed_at);
+            assert!(result.is_err());
+            let result = pool.propose_fee(FixedPoint::from_scale(3, 3), proposed_at);
+            assert!(result.is_err());
+        }
+        // nothing to apply
+        {
+            let mut pool = pool.clone();
+            let result = pool.apply_pending_fee(proposed_at);
+            assert!(result.is_err());
+        }
+        // timelocked
+        {
+            let mut pool = pool.clone();
+            pool.propose_fee(FixedPoint::from_scale(1, 3), proposed_at)
+                .unwrap();
+            let result = pool.apply_pending_fee(proposed_at + POOL_FEE_TIMELOCK - 1);
+            assert!(result.is_err());
+            assert_eq!({ pool.fee }, FixedPoint::from_scale(3, 3));
+        }
+        // applied after timelock, seed fee is kept
+        {
+            let mut pool = pool.clone();
+            pool.propose_fee(MAX_POOL_FEE, proposed_at).unwrap();
+            pool.apply_pending_fee(proposed_at + POOL_FEE_TIMELOCK)
+                .unwrap();
+            assert_eq!({ pool.fee }, FixedPoint::from_scale(1, 1));
+            assert_eq!({ pool.fee_tier_fee }, FixedPoint::from_scale(3, 3));
+            assert_eq!({ pool.pending_fee }, FixedPoint::new(0));
+            assert_eq!({ pool.pending_fee_timestamp }, 0);
+            assert!(pool
+                .apply_pending_fee(proposed_at + POOL_FEE_TIMELOCK)
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn test_check_price_change() {
+        let before = Price::from_integer(1);
+        let pool = Pool {
+            sqrt_price: before,
+            max_price_change_bps_per_tx: 100,
+            ..Default::default()
+        };
+        // sqrt(1.01) rounded down
+        let up_limit = Price::new(1004987562112089027021926);
+        // sqrt(0.99) rounded up
+        let down_limit = Price::new(994987437106619954734480);
+
+        // unlimited
+        {
+            let pool = Pool {
+                sqrt_price: Price::from_integer(2),
+                max_price_change_bps_per_tx: 0,
+                ..pool
+            };
+            assert!(pool.check_price_change(before).is_ok());
+        }
+        // exactly at the limit going up
+        {
+            let pool = Pool {
+                sqrt_price: up_limit,
+                ..pool
+            };
+            assert!(pool.check_price_change(before).is_ok());
+            let pool = Pool {
+                sqrt_price: up_limit + Price::new(1),
+                ..pool
+            };
+            assert!(pool.check_price_change(before).is_err());
+        }
+        // exactly at the limit going down
+        {
+            let pool = Pool {
+                sqrt_price: down_limit,
+                ..pool
+            };
+            assert!(pool.check_price_change(before).is_ok());
+            let pool = Pool {
+                sqrt_price: down_limit - Price::new(1),
+                ..pool
+            };
+            assert!(pool.check_price_change(before).is_err());
+        }
+        // limit over 100% can't underflow going down
+        {
+            let pool = Pool {
+                sqrt_price: Price::new(MIN_SQRT_PRICE),
+                max_price_change_bps_per_tx: u16::MAX,
+                ..pool
+            };
+            assert!(pool.check_price_change(before).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_update_tick_ema() {
+        let pool = Pool {
+            current_tick_index: 0,
+            tick_ema: 0,
+            tick_ema_half_life: 600,
+            tick_ema_last_timestamp: 1_000,
+            ..Default::default()
+        };
+        // same second
+        {
+            let mut pool = Pool {
+                current_tick_index: 100,
+                ..pool
+            };
+            pool.update_tick_ema(1_000);
+            assert_eq!({ pool.tick_ema }, 0);
+            assert_eq!(pool.get_tick_ema(), 0);
+        }
+        // single half-life moves the average halfway
+        {
+            let mut pool = Pool {
+                current_tick_index: 100,
+                ..pool
+            };
+            pool.update_tick_ema(1_600);
+            assert_eq!({ pool.tick_ema }, 50 * TICK_EMA_DENOMINATOR);
+            assert_eq!(pool.get_tick_ema(), 50);
+            assert_eq!({ pool.tick_ema_last_timestamp }, 1_600);
+        }
+        // rounded down for negative ticks
+        {
+            let mut pool = Pool {
+                current_tick_index: -3,
+                ..pool
+            };
+            pool.update_tick_ema(1_600);
+            assert_eq!({ pool.tick_ema }, -3 * TICK_EMA_DENOMINATOR / 2);
+            assert_eq!(pool.get_tick_ema(), -2);
+        }
+        // zero half-life follows spot tick
+        {
+            let mut pool = Pool {
+                current_tick_index: -221818,
+                tick_ema_half_life: 0,
+                ..pool
+            };
+            pool.update_tick_ema(1_001);
+            assert_eq!(pool.get_tick_ema(), -221818);
+        }
     }
 
     #[test]
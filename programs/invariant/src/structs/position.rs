@@ -1,28 +1,10 @@
+pub use super::layout::Position;
 use crate::decimals::*;
 use crate::structs::pool::Pool;
 use crate::structs::tick::Tick;
 use crate::*;
 use anchor_lang::prelude::*;
 
-#[account(zero_copy)]
-#[repr(packed)]
-#[derive(PartialEq, Default, Debug)]
-pub struct Position {
-    pub owner: Pubkey,
-    pub pool: Pubkey,
-    pub id: u128, // unique inside pool
-    pub liquidity: Liquidity,
-    pub lower_tick_index: i32,
-    pub upper_tick_index: i32,
-    pub fee_growth_inside_x: FeeGrowth,
-    pub fee_growth_inside_y: FeeGrowth,
-    pub seconds_per_liquidity_inside: FixedPoint,
-    pub last_slot: u64,
-    pub tokens_owed_x: FixedPoint,
-    pub tokens_owed_y: FixedPoint,
-    pub bump: u8,
-}
-
 impl Position {
     pub fn modify(
         &mut self,
@@ -85,25 +67,65 @@ impl Position {
             ErrorCode::EmptyPositionPokes
         );
 
-        // calculate accumulated fee
+        // calculate accumulated fee, saturating so that fee growth can never block a withdrawal
         let tokens_owed_x = fee_growth_inside_x
             .unchecked_sub(self.fee_growth_inside_x)
-            .to_fee(self.liquidity);
+            .saturating_to_fee(self.liquidity);
         let tokens_owed_y = fee_growth_inside_y
             .unchecked_sub(self.fee_growth_inside_y)
-            .to_fee(self.liquidity);
+            .saturating_to_fee(self.liquidity);
 
         self.liquidity = self.calculate_new_liquidity_safely(sign, liquidity_delta)?;
         self.fee_growth_inside_x = fee_growth_inside_x;
         self.fee_growth_inside_y = fee_growth_inside_y;
-        // trunk-ignore(clippy/assign_op_pattern)
-        self.tokens_owed_x = self.tokens_owed_x + tokens_owed_x;
-        // trunk-ignore(clippy/assign_op_pattern)
-        self.tokens_owed_y = self.tokens_owed_y + tokens_owed_y;
+        self.tokens_owed_x = FixedPoint::new(self.tokens_owed_x.v.saturating_add(tokens_owed_x.v));
+        self.tokens_owed_y = FixedPoint::new(self.tokens_owed_y.v.saturating_add(tokens_owed_y.v));
+        self.maker_fees_x = FixedPoint::new(self.maker_fees_x.v.saturating_add(tokens_owed_x.v));
+        self.maker_fees_y = FixedPoint::new(self.maker_fees_y.v.saturating_add(tokens_owed_y.v));
 
         Ok(())
     }
 
+    // Owed fees in tokens, given the amounts left in the pool for them. Owed fees over that
+    // amount are invalid and not paid at all, so the principal can always be withdrawn.
+    pub fn withdrawable_fees(
+        &self,
+        available_x: TokenAmount,
+        available_y: TokenAmount,
+    ) -> (TokenAmount, TokenAmount) {
+        let fee = |tokens_owed: FixedPoint, available: TokenAmount| {
+            match TokenAmount::checked_from_decimal(tokens_owed) {
+                Ok(fee) if fee <= available => fee,
+                _ => TokenAmount(0),
+            }
+        };
+        (
+            fee(self.tokens_owed_x, available_x),
+            fee(self.tokens_owed_y, available_y),
+        )
+    }
+
+    // Takes whole tokens owed on the selected sides, the remainder and the other side stay accrued
+    pub fn collect_fees(&mut self, claim_x: bool, claim_y: bool) -> (TokenAmount, TokenAmount) {
+        let collect = |tokens_owed: &mut FixedPoint, claim: bool| {
+            if !claim {
+                return TokenAmount(0);
+            }
+            let amount = TokenAmount::from_decimal(*tokens_owed);
+            *tokens_owed = *tokens_owed - FixedPoint::from_decimal(amount);
+            amount
+        };
+        let mut tokens_owed_x = self.tokens_owed_x;
+        let mut tokens_owed_y = self.tokens_owed_y;
+        let fees = (
+            collect(&mut tokens_owed_x, claim_x),
+            collect(&mut tokens_owed_y, claim_y),
+        );
+        self.tokens_owed_x = tokens_owed_x;
+        self.tokens_owed_y = tokens_owed_y;
+        fees
+    }
+
     pub fn initialized_id(&mut self, pool: &mut Pool) {
         self.id = pool.position_iterator;
         pool.position_iterator = pool.position_iterator.checked_add(1).unwrap();
@@ -265,6 +287,9 @@ mod tests {
             assert_eq!({ position.fee_growth_inside_y }, FeeGrowth::from_integer(5));
             assert_eq!({ position.tokens_owed_x }, FixedPoint::from_integer(101));
             assert_eq!({ position.tokens_owed_y }, FixedPoint::from_integer(101));
+            // only the fees earned now count towards rebate tiers
+            assert_eq!({ position.maker_fees_x }, FixedPoint::from_integer(1));
+            assert_eq!({ position.maker_fees_y }, FixedPoint::from_integer(1));
         }
         // previous fee_growth_inside close to max and current close to 0
         {
@@ -358,4 +383,166 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_remove_never_fails_on_fee_growth() {
+        // xorshift, deterministic so failures are reproducible
+        let mut seed = 0x2545_f491_4f6c_dd1du64;
+        let mut next = || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed
+        };
+
+        for _ in 0..2000 {
+            let mut random_u128 = || (next() as u128) << 64 | next() as u128;
+            let fee_growth_global_x = FeeGrowth::new(random_u128());
+            let fee_growth_global_y = FeeGrowth::new(random_u128());
+            let fee_growth_outside = [
+                FeeGrowth::new(random_u128()),
+                FeeGrowth::new(random_u128()),
+                FeeGrowth::new(random_u128()),
+                FeeGrowth::new(random_u128()),
+            ];
+            let fee_growth_inside_x = FeeGrowth::new(random_u128());
+            let fee_growth_inside_y = FeeGrowth::new(random_u128());
+            let tokens_owed_x = FixedPoint::new(random_u128());
+            let tokens_owed_y = FixedPoint::new(random_u128());
+
+            let liquidity = Liquidity::from_integer(next() % 1_000_000_000_000 + 1);
+            let lower_tick_index = -((next() % 1000) as i32);
+            let upper_tick_index = lower_tick_index + (next() % 1000) as i32 + 1;
+            let current_tick_index = (next() % 4000) as i32 - 2000;
+            let in_range =
+                current_tick_index >= lower_tick_index && current_tick_index < upper_tick_index;
+
+            let mut position = Position {
+                liquidity,
+                lower_tick_index,
+                upper_tick_index,
+                fee_growth_inside_x,
+                fee_growth_inside_y,
+                tokens_owed_x,
+                tokens_owed_y,
+                ..Default::default()
+            };
+            let mut pool = Pool {
+                current_tick_index,
+                sqrt_price: calculate_price_sqrt(current_tick_index),
+                liquidity: if in_range {
+                    liquidity
+                } else {
+                    Liquidity::new(0)
+                },
+                fee_growth_global_x,
+                fee_growth_global_y,
+                tick_spacing: 1,
+                ..Default::default()
+            };
+            let mut lower_tick = Tick {
+                index: lower_tick_index,
+                sign: true,
+                liquidity_change: liquidity,
+                liquidity_gross: liquidity,
+                fee_growth_outside_x: fee_growth_outside[0],
+                fee_growth_outside_y: fee_growth_outside[1],
+                ..Default::default()
+            };
+            let mut upper_tick = Tick {
+                index: upper_tick_index,
+                sign: false,
+                liquidity_change: liquidity,
+                liquidity_gross: liquidity,
+                fee_growth_outside_x: fee_growth_outside[2],
+                fee_growth_outside_y: fee_growth_outside[3],
+                ..Default::default()
+            };
+
+            let (amount_x, amount_y) = position
+                .modify(
+                    &mut pool,
+                    &mut upper_tick,
+                    &mut lower_tick,
+                    liquidity,
+                    false,
+                    1234567890,
+                )
+                .unwrap();
+            assert!(position.liquidity.is_zero());
+            assert!({ position.tokens_owed_x } >= tokens_owed_x);
+            assert!({ position.tokens_owed_y } >= tokens_owed_y);
+
+            // fees are either within what is left in the reserves after the principal or not paid
+            let reserve_x = next();
+            let reserve_y = next();
+            let (fee_x, fee_y) = position.withdrawable_fees(
+                TokenAmount(reserve_x.saturating_sub(amount_x.0)),
+                TokenAmount(reserve_y.saturating_sub(amount_y.0)),
+            );
+            assert!(fee_x.0 <= reserve_x.saturating_sub(amount_x.0));
+            assert!(fee_y.0 <= reserve_y.saturating_sub(amount_y.0));
+        }
+    }
+
+    #[test]
+    fn test_withdrawable_fees() {
+        let position = Position {
+            tokens_owed_x: FixedPoint::from_scale(25, 1),
+            tokens_owed_y: FixedPoint::from_integer(3),
+            ..Default::default()
+        };
+        // within the accounted fees
+        assert_eq!(
+            position.withdrawable_fees(TokenAmount(2), TokenAmount(10)),
+            (TokenAmount(2), TokenAmount(3))
+        );
+        // over the accounted fees only the principal is returned
+        assert_eq!(
+            position.withdrawable_fees(TokenAmount(1), TokenAmount(2)),
+            (TokenAmount(0), TokenAmount(0))
+        );
+        // owed fees not fitting in a token amount
+        let position = Position {
+            tokens_owed_x: FixedPoint::new(u128::MAX),
+            ..position
+        };
+        assert_eq!(
+            position.withdrawable_fees(TokenAmount(u64::MAX), TokenAmount(3)),
+            (TokenAmount(0), TokenAmount(3))
+        );
+    }
+
+    #[test]
+    fn test_collect_fees() {
+        let position = Position {
+            tokens_owed_x: FixedPoint::from_scale(25, 1),
+            tokens_owed_y: FixedPoint::from_integer(3),
+            ..Default::default()
+        };
+        // both sides
+        {
+            let mut position = position;
+            let fees = position.collect_fees(true, true);
+            assert_eq!(fees, (TokenAmount(2), TokenAmount(3)));
+            assert_eq!({ position.tokens_owed_x }, FixedPoint::from_scale(5, 1));
+            assert_eq!({ position.tokens_owed_y }, FixedPoint::from_integer(0));
+        }
+        // only x, y stays accrued
+        {
+            let mut position = position;
+            let fees = position.collect_fees(true, false);
+            assert_eq!(fees, (TokenAmount(2), TokenAmount(0)));
+            assert_eq!({ position.tokens_owed_x }, FixedPoint::from_scale(5, 1));
+            assert_eq!({ position.tokens_owed_y }, FixedPoint::from_integer(3));
+        }
+        // only y, x stays accrued
+        {
+            let mut position = position;
+            let fees = position.collect_fees(false, true);
+            assert_eq!(fees, (TokenAmount(0), TokenAmount(3)));
+            assert_eq!({ position.tokens_owed_x }, FixedPoint::from_scale(25, 1));
+            assert_eq!({ position.tokens_owed_y }, FixedPoint::from_integer(0));
+        }
+    }
 }
@@ -1,9 +1 @@
-use anchor_lang::prelude::*;
-
-#[account(zero_copy)]
-#[repr(packed)]
-#[derive(PartialEq, Default, Debug)]
-pub struct PositionList {
-    pub head: u32,
-    pub bump: u8,
-}
+pub use super::layout::PositionList;
@@ -0,0 +1,68 @@
+use crate::structs::position::Position;
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+// Every position is represented by an NFT, minted with create_position and burned with
+// remove_position. Whoever holds it controls the position, the owner of the position is only
+// the position list it is kept in. The mint is derived from the pool and the id of the
+// position, which stay the same when the position is moved inside or between lists.
+pub fn position_nft_mint(pool: &Pubkey, id: u128) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"positionnftv1", pool.as_ref(), &id.to_le_bytes()],
+        &crate::ID,
+    )
+    .0
+}
+
+// Whether the token account holds the NFT of the position, its owner is the holder then
+pub fn holds_position_nft(position: &Position, nft_account: &TokenAccount) -> bool {
+    nft_account.mint == position_nft_mint(&{ position.pool }, position.id)
+        && nft_account.amount == 1
+}
+
+pub const POSITION_NFT_NAME: &str = "Invariant Position";
+pub const POSITION_NFT_SYMBOL: &str = "INVPOS";
+// leaves room for the attributes within the 200 bytes Metaplex allows for the uri
+pub const MAX_POSITION_NFT_BASE_URI_LENGTH: usize = 64;
+
+// Metadata of a position NFT is optional, its uri is the base chosen by the holder with the pool,
+// range and liquidity of the position appended, kept up to date on increase and decrease
+pub fn position_nft_uri(base_uri: &str, position: &Position) -> String {
+    format!(
+        "{}?pool={}&lower={}&upper={}&liquidity={}",
+        base_uri,
+        { position.pool },
+        { position.lower_tick_index },
+        { position.upper_tick_index },
+        { position.liquidity }.v
+    )
+}
+
+pub fn is_valid_position_nft_base_uri(base_uri: &str) -> bool {
+    base_uri.len() <= MAX_POSITION_NFT_BASE_URI_LENGTH && !base_uri.contains('?')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decimals::Liquidity;
+
+    #[test]
+    fn test_position_nft_uri() {
+        let position = Position {
+            pool: Pubkey::new_unique(),
+            lower_tick_index: -20,
+            upper_tick_index: 20,
+            liquidity: Liquidity { v: u128::MAX },
+            ..Default::default()
+        };
+        let base_uri = "a".repeat(MAX_POSITION_NFT_BASE_URI_LENGTH);
+        let uri = position_nft_uri(&base_uri, &position);
+
+        assert!(uri.ends_with(&format!("&lower=-20&upper=20&liquidity={}", u128::MAX)));
+        assert!(uri.len() <= mpl_token_metadata::state::MAX_URI_LENGTH);
+        assert!(is_valid_position_nft_base_uri(&base_uri));
+        assert!(!is_valid_position_nft_base_uri(&format!("{}a", base_uri)));
+        assert!(!is_valid_position_nft_base_uri("https://a.b/?c"));
+    }
+}
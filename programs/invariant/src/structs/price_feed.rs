@@ -0,0 +1,32 @@
+pub use super::layout::PriceFeed;
+use crate::decimals::*;
+
+impl PriceFeed {
+    pub fn publish(&mut self, sqrt_price: Price, liquidity: Liquidity, timestamp: u64, slot: u64) {
+        self.sqrt_price = sqrt_price;
+        self.price = sqrt_price.big_mul(sqrt_price);
+        self.liquidity = liquidity;
+        self.publish_time = timestamp;
+        self.publish_slot = slot;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_publish() {
+        let mut feed = PriceFeed::default();
+        feed.publish(
+            Price::from_scale(15, 1),
+            Liquidity::from_integer(10),
+            100,
+            200,
+        );
+        assert_eq!({ feed.price }, Price::from_scale(225, 2));
+        assert_eq!({ feed.liquidity }, Liquidity::from_integer(10));
+        assert_eq!({ feed.publish_time }, 100);
+        assert_eq!({ feed.publish_slot }, 200);
+    }
+}
@@ -0,0 +1,161 @@
+use crate::args::RebateTierArgs;
+use crate::decimals::*;
+use crate::*;
+use anchor_lang::prelude::*;
+
+pub const MAX_REBATE_TIERS: usize = 8;
+
+#[zero_copy]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug)]
+pub struct RebateTier {
+    pub min_maker_fees: u64,
+    pub rebate: FixedPoint,
+}
+
+// Boosts fee share of large liquidity providers. On claim, a position gets on top of its fee
+// the rebate of the highest tier its maker fees of the token reached, paid out of the protocol
+// fee of the pool. Thresholds are raw token amounts, compared separately for both tokens.
+#[account(zero_copy)]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug)]
+pub struct RebateSchedule {
+    pub tiers: [RebateTier; MAX_REBATE_TIERS],
+    pub tiers_count: u8,
+    pub bump: u8,
+}
+
+impl RebateSchedule {
+    pub fn set_tiers(&mut self, tiers: &[RebateTierArgs]) -> Result<()> {
+        require!(tiers.len() <= MAX_REBATE_TIERS, InvalidRebateSchedule);
+        for (i, tier) in tiers.iter().enumerate() {
+            require!(
+                tier.rebate <= FixedPoint::from_integer(1).v,
+                InvalidRebateSchedule
+            );
+            if i > 0 {
+                require!(
+                    tier.min_maker_fees > tiers[i - 1].min_maker_fees,
+                    InvalidRebateSchedule
+                );
+            }
+        }
+
+        self.tiers = [RebateTier::default(); MAX_REBATE_TIERS];
+        for (i, tier) in tiers.iter().enumerate() {
+            self.tiers[i] = RebateTier {
+                min_maker_fees: tier.min_maker_fees,
+                rebate: FixedPoint::new(tier.rebate),
+            };
+        }
+        self.tiers_count = tiers.len() as u8;
+        Ok(())
+    }
+
+    pub fn rebate(&self, maker_fees: FixedPoint) -> FixedPoint {
+        let maker_fees = TokenAmount::from_decimal(maker_fees);
+        self.tiers[..self.tiers_count as usize]
+            .iter()
+            .rev()
+            .find(|tier| maker_fees.0 >= { tier.min_maker_fees })
+            .map(|tier| tier.rebate)
+            .unwrap_or(FixedPoint::new(0))
+    }
+
+    // Rebate on top of a claimed fee, capped by the protocol fee it is paid from
+    pub fn calculate_rebate(
+        &self,
+        maker_fees: FixedPoint,
+        fee: TokenAmount,
+        protocol_fee: TokenAmount,
+    ) -> TokenAmount {
+        let rebate = fee.big_mul(self.rebate(maker_fees));
+        TokenAmount(rebate.0.min(protocol_fee.0))
+    }
+}
+
+// The schedule is passed optionally in remaining accounts, claims without it get no rebate
+pub fn find_rebate_schedule<'info>(
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Option<AccountLoader<'info, RebateSchedule>> {
+    remaining_accounts
+        .iter()
+        .find_map(|account| AccountLoader::<RebateSchedule>::try_from(account).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tier(min_maker_fees: u64, rebate: u128) -> RebateTierArgs {
+        RebateTierArgs {
+            min_maker_fees,
+            rebate: FixedPoint::from_scale(rebate, 2).v,
+        }
+    }
+
+    #[test]
+    fn test_set_tiers() {
+        let mut schedule = RebateSchedule::default();
+
+        schedule.set_tiers(&[tier(100, 5), tier(1000, 10)]).unwrap();
+        assert_eq!(schedule.tiers_count, 2);
+        assert_eq!({ schedule.tiers[1].min_maker_fees }, 1000);
+
+        // replacing clears tiers over the new count
+        schedule.set_tiers(&[tier(100, 5)]).unwrap();
+        assert_eq!(schedule.tiers_count, 1);
+        assert_eq!(schedule.tiers[1], RebateTier::default());
+
+        // not ascending
+        assert!(schedule.set_tiers(&[tier(1000, 5), tier(100, 10)]).is_err());
+        assert!(schedule.set_tiers(&[tier(100, 5), tier(100, 10)]).is_err());
+        // rebate over 100%
+        assert!(schedule.set_tiers(&[tier(100, 101)]).is_err());
+        // too many tiers
+        let tiers: Vec<RebateTierArgs> =
+            (0..=MAX_REBATE_TIERS as u64).map(|i| tier(i, 1)).collect();
+        assert!(schedule.set_tiers(&tiers).is_err());
+        // failed updates leave the schedule as it was
+        assert_eq!(schedule.tiers_count, 1);
+    }
+
+    #[test]
+    fn test_calculate_rebate() {
+        let mut schedule = RebateSchedule::default();
+        assert_eq!(
+            schedule.rebate(FixedPoint::from_integer(1000)),
+            FixedPoint::new(0)
+        );
+
+        schedule.set_tiers(&[tier(100, 5), tier(1000, 10)]).unwrap();
+        // below the first tier
+        assert_eq!(
+            schedule.rebate(FixedPoint::from_integer(99)),
+            FixedPoint::new(0)
+        );
+        assert_eq!(
+            schedule.rebate(FixedPoint::from_integer(100)),
+            FixedPoint::from_scale(5, 2)
+        );
+        assert_eq!(
+            schedule.rebate(FixedPoint::from_integer(5000)),
+            FixedPoint::from_scale(10, 2)
+        );
+
+        // 10% of the claimed fee
+        let rebate = schedule.calculate_rebate(
+            FixedPoint::from_integer(1000),
+            TokenAmount(200),
+            TokenAmount(100),
+        );
+        assert_eq!(rebate, TokenAmount(20));
+        // capped by the protocol fee
+        let rebate = schedule.calculate_rebate(
+            FixedPoint::from_integer(1000),
+            TokenAmount(200),
+            TokenAmount(15),
+        );
+        assert_eq!(rebate, TokenAmount(15));
+    }
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+// Referral fees of a single referrer accumulated in the pool reserves until claimed
+#[account(zero_copy)]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug)]
+pub struct ReferralEarnings {
+    pub pool: Pubkey,
+    pub referrer: Pubkey,
+    pub amount_x: u64,
+    pub amount_y: u64,
+    pub bump: u8,
+}
+
+impl ReferralEarnings {
+    pub fn add(&mut self, amount: u64, x: bool) {
+        match x {
+            true => self.amount_x = self.amount_x.checked_add(amount).unwrap(),
+            false => self.amount_y = self.amount_y.checked_add(amount).unwrap(),
+        }
+    }
+}
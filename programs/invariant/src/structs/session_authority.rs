@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+pub const SESSION_PERMISSION_CLAIM: u8 = 1;
+pub const SESSION_PERMISSION_REBALANCE: u8 = 1 << 1;
+
+// Lets a hot key act on positions held by the owner within permissions until expiry
+#[account(zero_copy)]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug)]
+pub struct SessionAuthority {
+    pub owner: Pubkey,
+    pub session_key: Pubkey,
+    pub permissions: u8,
+    pub expiry: u64,
+    pub bump: u8,
+}
+
+impl SessionAuthority {
+    pub fn allows(&self, session_key: &Pubkey, permission: u8, now: u64) -> bool {
+        self.session_key == *session_key
+            && self.permissions & permission == permission
+            && now < self.expiry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allows() {
+        let session_key = Pubkey::new_unique();
+        let session = SessionAuthority {
+            owner: Pubkey::new_unique(),
+            session_key,
+            permissions: SESSION_PERMISSION_CLAIM,
+            expiry: 100,
+            bump: 0,
+        };
+
+        assert!(session.allows(&session_key, SESSION_PERMISSION_CLAIM, 99));
+        // expired
+        assert!(!session.allows(&session_key, SESSION_PERMISSION_CLAIM, 100));
+        // out of scope
+        assert!(!session.allows(&session_key, SESSION_PERMISSION_REBALANCE, 0));
+        // different key
+        assert!(!session.allows(&Pubkey::new_unique(), SESSION_PERMISSION_CLAIM, 0));
+    }
+}
@@ -1,24 +1,8 @@
+pub use super::layout::Tick;
 use crate::*;
 use anchor_lang::prelude::*;
 use decimals::*;
 
-#[account(zero_copy)]
-#[repr(packed)]
-#[derive(PartialEq, Default, Debug)]
-pub struct Tick {
-    pub pool: Pubkey,
-    pub index: i32,
-    pub sign: bool, // true means positive
-    pub liquidity_change: Liquidity,
-    pub liquidity_gross: Liquidity,
-    pub sqrt_price: Price,
-    pub fee_growth_outside_x: FeeGrowth,
-    pub fee_growth_outside_y: FeeGrowth,
-    pub seconds_per_liquidity_outside: FixedPoint,
-    pub seconds_outside: u64,
-    pub bump: u8,
-}
-
 impl Tick {
     pub fn update(
         &mut self,
@@ -37,6 +21,70 @@ impl Tick {
         Ok(())
     }
 
+    // Ticks stay initialized while they hold liquidity or orders waiting to be claimed
+    pub fn is_empty(&self) -> bool {
+        self.liquidity_gross.is_zero() && self.limit_orders == 0
+    }
+
+    // Liquidity and fill count of the limit orders exiting through the tick, orders selling x
+    // are below it
+    pub fn limit_order_book(&self, sells_x: bool) -> (Liquidity, u64) {
+        match sells_x {
+            true => (
+                self.limit_order_liquidity_below,
+                self.limit_order_fills_below,
+            ),
+            false => (
+                self.limit_order_liquidity_above,
+                self.limit_order_fills_above,
+            ),
+        }
+    }
+
+    pub fn update_limit_order_book(
+        &mut self,
+        liquidity_delta: Liquidity,
+        sells_x: bool,
+        add: bool,
+    ) -> Result<()> {
+        let (liquidity, _) = self.limit_order_book(sells_x);
+        if !add && liquidity < liquidity_delta {
+            return Err(ErrorCode::InvalidTickLiquidity.into());
+        }
+        let liquidity = match add {
+            true => liquidity + liquidity_delta,
+            false => liquidity - liquidity_delta,
+        };
+        match sells_x {
+            true => self.limit_order_liquidity_below = liquidity,
+            false => self.limit_order_liquidity_above = liquidity,
+        }
+        Ok(())
+    }
+
+    // Fills the orders the price leaves behind when crossing the tick. Their liquidity is taken
+    // out of both ticks of their range, so they stay filled when the price comes back.
+    // `range_tick` is the other tick of that range.
+    pub fn fill_limit_orders(
+        &mut self,
+        range_tick: &mut Tick,
+        sells_x: bool,
+        max_liquidity_per_tick: Liquidity,
+    ) -> Result<()> {
+        let (liquidity, fills) = self.limit_order_book(sells_x);
+
+        // orders selling x exit through their upper tick
+        self.update(liquidity, max_liquidity_per_tick, sells_x, false)?;
+        range_tick.update(liquidity, max_liquidity_per_tick, !sells_x, false)?;
+
+        self.update_limit_order_book(liquidity, sells_x, false)?;
+        match sells_x {
+            true => self.limit_order_fills_below = fills.checked_add(1).unwrap(),
+            false => self.limit_order_fills_above = fills.checked_add(1).unwrap(),
+        }
+        Ok(())
+    }
+
     fn update_liquidity_change(&mut self, liquidity_delta: Liquidity, add: bool) {
         if self.sign ^ add {
             if { self.liquidity_change } > liquidity_delta {
@@ -202,4 +250,59 @@ mod tests {
             assert!(result.is_err());
         }
     }
+
+    #[test]
+    fn test_fill_limit_orders() {
+        let max_liquidity = Liquidity::new(u128::MAX);
+        let liquidity = Liquidity::from_integer(5);
+        let position = Liquidity::from_integer(3);
+
+        // position on both ticks and an order selling x on the range below `upper`
+        let mut lower = Tick {
+            index: -10,
+            ..Default::default()
+        };
+        let mut upper = Tick {
+            index: 0,
+            ..Default::default()
+        };
+        lower.update(position, max_liquidity, false, true).unwrap();
+        upper.update(position, max_liquidity, true, true).unwrap();
+        let (lower_before, upper_before) = (lower, upper);
+
+        lower.update(liquidity, max_liquidity, false, true).unwrap();
+        upper.update(liquidity, max_liquidity, true, true).unwrap();
+        upper
+            .update_limit_order_book(liquidity, true, true)
+            .unwrap();
+        assert_eq!(upper.limit_order_book(true), (liquidity, 0));
+        assert_eq!(upper.limit_order_book(false), (Liquidity::new(0), 0));
+
+        upper
+            .fill_limit_orders(&mut lower, true, max_liquidity)
+            .unwrap();
+        assert_eq!(upper.limit_order_book(true), (Liquidity::new(0), 1));
+        assert_eq!({ upper.liquidity_gross }, { upper_before.liquidity_gross });
+        assert_eq!({ upper.liquidity_change }, {
+            upper_before.liquidity_change
+        });
+        assert_eq!(upper.sign, upper_before.sign);
+        assert_eq!(lower, lower_before);
+
+        // liquidity of orders can't go below zero
+        assert!(upper
+            .update_limit_order_book(liquidity, true, false)
+            .is_err());
+
+        let empty = Tick {
+            liquidity_gross: Liquidity::new(0),
+            ..Default::default()
+        };
+        assert!(empty.is_empty());
+        assert!(!Tick {
+            limit_orders: 1,
+            ..empty
+        }
+        .is_empty());
+    }
 }
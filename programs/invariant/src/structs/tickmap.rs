@@ -1,3 +1,4 @@
+pub use super::layout::Tickmap;
 use anchor_lang::prelude::*;
 use std::convert::TryInto;
 
@@ -5,12 +6,6 @@ pub const TICK_LIMIT: i32 = 44_364; // If you change it update length of array a
 pub const TICK_SEARCH_RANGE: i32 = 256;
 pub const MAX_TICK: i32 = 221_818; // log(1.0001, sqrt(2^64-1))
 
-#[account(zero_copy)]
-#[repr(packed)]
-pub struct Tickmap {
-    pub bitmap: [u8; 11091], // Tick limit / 4
-}
-
 impl Default for Tickmap {
     fn default() -> Self {
         Tickmap { bitmap: [0; 11091] }
@@ -35,6 +30,19 @@ fn tick_to_position(tick: i32, tick_spacing: u16) -> (usize, u8) {
     (byte, bit)
 }
 
+// Same as tick_to_position for indexes coming from outside, which may not fit in the bitmap
+fn checked_tick_to_position(tick: i32, tick_spacing: u16) -> Result<(usize, u8)> {
+    require!(tick_spacing > 0, InvalidTickSpacing);
+    let tick_spacing = tick_spacing as i32;
+    require!(
+        tick % tick_spacing == 0
+            && (-MAX_TICK..=MAX_TICK).contains(&tick)
+            && (-TICK_LIMIT + 1..TICK_LIMIT).contains(&(tick / tick_spacing)),
+        InvalidTickIndex
+    );
+    Ok(tick_to_position(tick, tick_spacing as u16))
+}
+
 pub fn get_search_limit(tick: i32, tick_spacing: u16, up: bool) -> i32 {
     let index = tick / tick_spacing as i32;
 
@@ -70,6 +78,21 @@ impl Tickmap {
         self.bitmap[byte] ^= 1 << bit;
     }
 
+    pub fn try_get(&self, tick: i32, tick_spacing: u16) -> Result<bool> {
+        let (byte, bit) = checked_tick_to_position(tick, tick_spacing)?;
+        Ok((self.bitmap[byte] >> bit) % 2 == 1)
+    }
+
+    pub fn try_flip(&mut self, value: bool, tick: i32, tick_spacing: u16) -> Result<()> {
+        let (byte, bit) = checked_tick_to_position(tick, tick_spacing)?;
+        require!(
+            ((self.bitmap[byte] >> bit) % 2 == 1) != value,
+            InvalidTickIndex
+        );
+        self.bitmap[byte] ^= 1 << bit;
+        Ok(())
+    }
+
     pub fn get(&self, tick: i32, tick_spacing: u16) -> bool {
         let (byte, bit) = tick_to_position(tick, tick_spacing);
         let value = (self.bitmap[byte] >> bit) % 2;
@@ -424,4 +447,45 @@ mod tests {
             assert_eq!(result, expected);
         }
     }
+
+    #[test]
+    fn test_checked_access_at_extremes() {
+        for tick_spacing in [1u16, 2, 4, 5, 8, 10, 16, 20, 50, 64, 100, 1000, u16::MAX] {
+            let spacing = tick_spacing as i32;
+            let max_tick = (MAX_TICK / spacing).min(TICK_LIMIT - 1) * spacing;
+            let mut map = Tickmap::default();
+
+            for tick in [max_tick, -max_tick, 0] {
+                assert!(!map.try_get(tick, tick_spacing).unwrap());
+                map.try_flip(true, tick, tick_spacing).unwrap();
+                assert!(map.try_get(tick, tick_spacing).unwrap());
+                // flipping to the same value
+                assert!(map.try_flip(true, tick, tick_spacing).is_err());
+                assert_eq!(
+                    map.try_get(tick, tick_spacing).unwrap(),
+                    map.get(tick, tick_spacing)
+                );
+                map.try_flip(false, tick, tick_spacing).unwrap();
+            }
+
+            // one step past the edges
+            assert!(map.try_get(max_tick + spacing, tick_spacing).is_err());
+            assert!(map.try_get(-max_tick - spacing, tick_spacing).is_err());
+            assert!(map
+                .try_flip(true, max_tick + spacing, tick_spacing)
+                .is_err());
+            // far outside and not divisible
+            assert!(map
+                .try_get(i32::MAX - i32::MAX % spacing, tick_spacing)
+                .is_err());
+            assert!(map
+                .try_get(i32::MIN - i32::MIN % spacing, tick_spacing)
+                .is_err());
+            if tick_spacing > 1 {
+                assert!(map.try_get(1, tick_spacing).is_err());
+            }
+            assert!(map.bitmap.iter().all(|byte| *byte == 0));
+        }
+        assert!(Tickmap::default().try_get(0, 0).is_err());
+    }
 }
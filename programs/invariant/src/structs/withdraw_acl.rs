@@ -0,0 +1,85 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+pub const MAX_WITHDRAWERS: usize = 8;
+
+// Accounts allowed to withdraw protocol fee of a pool besides its fee receiver
+#[account(zero_copy)]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug)]
+pub struct WithdrawAcl {
+    pub pool: Pubkey,
+    pub withdrawers: [Pubkey; MAX_WITHDRAWERS],
+    pub withdrawers_count: u8,
+    pub bump: u8,
+}
+
+impl WithdrawAcl {
+    pub fn is_withdrawer(&self, withdrawer: &Pubkey) -> bool {
+        self.withdrawers[..self.withdrawers_count as usize].contains(withdrawer)
+    }
+
+    pub fn add_withdrawer(&mut self, withdrawer: Pubkey) -> Result<()> {
+        if self.is_withdrawer(&withdrawer) {
+            return Ok(());
+        }
+        let count = self.withdrawers_count as usize;
+        if count >= MAX_WITHDRAWERS {
+            return Err(ErrorCode::WithdrawAclFull.into());
+        }
+
+        self.withdrawers[count] = withdrawer;
+        self.withdrawers_count += 1;
+        Ok(())
+    }
+
+    pub fn remove_withdrawer(&mut self, withdrawer: &Pubkey) -> Result<()> {
+        let count = self.withdrawers_count as usize;
+        let index = self.withdrawers[..count]
+            .iter()
+            .position(|w| w == withdrawer)
+            .ok_or(ErrorCode::InvalidWithdrawer)?;
+
+        // keep the list dense by moving the last withdrawer into the gap
+        self.withdrawers[index] = self.withdrawers[count - 1];
+        self.withdrawers[count - 1] = Pubkey::default();
+        self.withdrawers_count -= 1;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_withdrawers() {
+        let mut acl = WithdrawAcl::default();
+        let first = Pubkey::new_unique();
+        let second = Pubkey::new_unique();
+
+        assert!(!acl.is_withdrawer(&first));
+        assert!(!acl.is_withdrawer(&Pubkey::default()));
+
+        acl.add_withdrawer(first).unwrap();
+        acl.add_withdrawer(second).unwrap();
+        // adding twice is a no-op
+        acl.add_withdrawer(first).unwrap();
+        assert_eq!(acl.withdrawers_count, 2);
+        assert!(acl.is_withdrawer(&first));
+        assert!(acl.is_withdrawer(&second));
+
+        acl.remove_withdrawer(&first).unwrap();
+        assert_eq!(acl.withdrawers_count, 1);
+        assert!(!acl.is_withdrawer(&first));
+        assert!(acl.is_withdrawer(&second));
+        assert!(acl.remove_withdrawer(&first).is_err());
+
+        // full list
+        acl.remove_withdrawer(&second).unwrap();
+        for _ in 0..MAX_WITHDRAWERS {
+            acl.add_withdrawer(Pubkey::new_unique()).unwrap();
+        }
+        assert!(acl.add_withdrawer(Pubkey::new_unique()).is_err());
+    }
+}
@@ -1,11 +1,23 @@
 use anchor_lang::__private::ErrorCode;
 use anchor_lang::__private::CLOSED_ACCOUNT_DISCRIMINATOR;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_lang::solana_program::program_option::COption;
+use anchor_lang::solana_program::system_instruction;
+use anchor_lang::Discriminator;
+use anchor_spl::token::MintTo;
+use mpl_token_metadata::instruction::update_metadata_accounts_v2;
+use mpl_token_metadata::pda::find_metadata_account;
+use mpl_token_metadata::state::{DataV2, Metadata};
 use std::cell::RefMut;
 use std::convert::TryInto;
 use std::io::Write;
 
 use crate::math::calculate_price_sqrt;
 use crate::structs::pool::Pool;
+use crate::structs::position::Position;
+use crate::structs::position_nft::{
+    position_nft_mint, position_nft_uri, POSITION_NFT_NAME, POSITION_NFT_SYMBOL,
+};
 use crate::structs::tick::Tick;
 use crate::structs::tickmap::Tickmap;
 use crate::structs::tickmap::{get_search_limit, MAX_TICK, TICK_LIMIT};
@@ -42,11 +54,12 @@ pub fn check_tick(tick_index: i32, tick_spacing: u16) -> Result<()> {
 // and compares its price to the price limit of the trade
 pub fn get_closer_limit(
     sqrt_price_limit: Price,
-    x_to_y: bool,
+    direction: Direction,
     current_tick: i32,
     tick_spacing: u16,
     tickmap: &Tickmap,
 ) -> Result<(Price, Option<(i32, bool)>)> {
+    let x_to_y = direction.is_x_to_y();
     let closes_tick_index = if x_to_y {
         tickmap.prev_initialized(current_tick, tick_spacing)
     } else {
@@ -108,6 +121,10 @@ pub fn cross_tick(tick: &mut RefMut<Tick>, pool: &mut Pool, current_timestamp: u
         // trunk-ignore(clippy/assign_op_pattern)
         pool.liquidity = pool.liquidity + tick.liquidity_change;
     } else {
+        strict_assert!(
+            { pool.liquidity } >= { tick.liquidity_change },
+            "pool liquidity would be negative after crossing tick"
+        );
         // trunk-ignore(clippy/assign_op_pattern)
         pool.liquidity = pool.liquidity - tick.liquidity_change;
     }
@@ -115,7 +132,23 @@ pub fn cross_tick(tick: &mut RefMut<Tick>, pool: &mut Pool, current_timestamp: u
     Ok(())
 }
 
+// Tests have no Clock sysvar, so the time can be pinned with set_mocked_timestamp. Tests of
+// other crates, like the staker, get it with the `mocked-clock` feature.
+#[cfg(any(test, feature = "mocked-clock"))]
+thread_local! {
+    static MOCKED_TIMESTAMP: std::cell::Cell<Option<u64>> = std::cell::Cell::new(None);
+}
+
+#[cfg(any(test, feature = "mocked-clock"))]
+pub fn set_mocked_timestamp(timestamp: Option<u64>) {
+    MOCKED_TIMESTAMP.with(|mocked| mocked.set(timestamp));
+}
+
 pub fn get_current_timestamp() -> u64 {
+    #[cfg(any(test, feature = "mocked-clock"))]
+    if let Some(timestamp) = MOCKED_TIMESTAMP.with(|mocked| mocked.get()) {
+        return timestamp;
+    }
     Clock::get().unwrap().unix_timestamp.try_into().unwrap()
 }
 
@@ -127,6 +160,9 @@ pub fn close<'info>(
     info: AccountInfo<'info>,
     sol_destination: AccountInfo<'info>,
 ) -> ProgramResult {
+    // closing an account into itself would burn its lamports together with the data
+    require!(info.key != sol_destination.key, InvalidCloseDestination);
+
     // Transfer tokens from the account to the sol_destination.
     let dest_starting_lamports = sol_destination.lamports();
     **sol_destination.lamports.borrow_mut() =
@@ -143,6 +179,174 @@ pub fn close<'info>(
     Ok(())
 }
 
+// Closes a tick left without liquidity and limit orders, unless it is closed already
+pub fn close_empty_tick<'info>(
+    info: &AccountInfo<'info>,
+    tickmap: &mut Tickmap,
+    tick_spacing: u16,
+    sol_destination: AccountInfo<'info>,
+) -> ProgramResult {
+    if info.owner != &crate::ID || info.data_is_empty() {
+        return Ok(());
+    }
+    let loader = AccountLoader::<Tick>::try_from(info)?;
+    let (empty, index) = {
+        let tick = loader.load()?;
+        (tick.is_empty(), tick.index)
+    };
+    if empty && tickmap.get(index, tick_spacing) {
+        *loader.load_mut()? = Default::default();
+        close(info.clone(), sol_destination)?;
+        tickmap.flip(false, index, tick_spacing);
+    }
+    Ok(())
+}
+
+// Mints the only token of a position NFT. The program stays the mint authority, as Metaplex
+// metadata has to be signed by it, and never mints another token of the same mint.
+pub fn mint_position_nft<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    nft_account: &AccountInfo<'info>,
+    program_authority: &AccountInfo<'info>,
+    nonce: u8,
+) -> ProgramResult {
+    let signer: &[&[&[u8]]] = get_signer!(nonce);
+    token::mint_to(
+        CpiContext::new(
+            token_program.clone(),
+            MintTo {
+                mint: mint.clone(),
+                to: nft_account.clone(),
+                authority: program_authority.clone(),
+            },
+        )
+        .with_signer(signer),
+        1,
+    )
+}
+
+// Rewrites the uri of the position NFT metadata with the current range and liquidity. Positions
+// without metadata are skipped, as well as calls which don't pass the metadata account and the
+// metadata program in the remaining accounts.
+pub fn refresh_position_nft_metadata<'info>(
+    remaining_accounts: &[AccountInfo<'info>],
+    position: &Position,
+    program_authority: &AccountInfo<'info>,
+    nonce: u8,
+) -> ProgramResult {
+    let mint = position_nft_mint(&{ position.pool }, position.id);
+    let (metadata_address, _) = find_metadata_account(&mint);
+    let metadata_info = match remaining_accounts
+        .iter()
+        .find(|account| *account.key == metadata_address)
+    {
+        Some(account) if *account.owner == mpl_token_metadata::ID => account,
+        _ => return Ok(()),
+    };
+    let metadata_program = remaining_accounts
+        .iter()
+        .find(|account| *account.key == mpl_token_metadata::ID)
+        .ok_or(InvalidPositionNftMetadata)?;
+
+    let metadata = Metadata::from_account_info(metadata_info)?;
+    let uri = metadata.data.uri.trim_end_matches(char::from(0));
+    let base_uri = uri.split('?').next().unwrap();
+
+    let signer: &[&[&[u8]]] = get_signer!(nonce);
+    invoke_signed(
+        &update_metadata_accounts_v2(
+            mpl_token_metadata::ID,
+            metadata_address,
+            *program_authority.key,
+            None,
+            Some(DataV2 {
+                name: POSITION_NFT_NAME.to_string(),
+                symbol: POSITION_NFT_SYMBOL.to_string(),
+                uri: position_nft_uri(base_uri, position),
+                seller_fee_basis_points: 0,
+                creators: None,
+                collection: None,
+                uses: None,
+            }),
+            None,
+            None,
+        ),
+        &[
+            metadata_info.clone(),
+            program_authority.clone(),
+            metadata_program.clone(),
+        ],
+        signer,
+    )
+}
+
+// Checks an account of the program still has the layout it was created with, accounts like
+// that can't be loaded until `realloc_account` grows them to the current one
+pub fn check_legacy_account<T: Discriminator>(
+    info: &AccountInfo,
+    program_id: &Pubkey,
+    legacy_size: usize,
+) -> ProgramResult {
+    if info.owner != program_id {
+        return Err(ErrorCode::AccountNotProgramOwned.into());
+    }
+    let data = info.try_borrow_data()?;
+    require!(data.len() == 8 + legacy_size, AccountAlreadyMigrated);
+    if data[..8] != T::discriminator() {
+        return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+    }
+    Ok(())
+}
+
+// Grows an account appended to since it was created, the payer tops it up to the new rent
+// exempt minimum. Added bytes are zeroed, so new fields have to be valid as zeros.
+pub fn realloc_account<'info>(
+    info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    len: usize,
+) -> ProgramResult {
+    let top_up = Rent::get()?
+        .minimum_balance(len)
+        .saturating_sub(info.lamports());
+    if top_up > 0 {
+        invoke(
+            &system_instruction::transfer(payer.key, info.key, top_up),
+            &[payer.clone(), info.clone(), system_program.clone()],
+        )?;
+    }
+    info.realloc(len, true)
+}
+
+// Token account can be spent by its owner or by a delegate within the approved amount
+pub fn check_spend_authority(
+    owner: &Pubkey,
+    delegate: COption<Pubkey>,
+    delegated_amount: u64,
+    authority: &Pubkey,
+    amount: u64,
+) -> Result<()> {
+    if owner == authority {
+        return Ok(());
+    }
+    require!(delegate == COption::Some(*authority), InvalidOwner);
+    require!(delegated_amount >= amount, InsufficientDelegation);
+    Ok(())
+}
+
+// Amounts are u64 in the smallest units, so a mint with more decimals than that
+// couldn't hold a meaningful balance and its prices would not fit in the tick range
+pub const MAX_TOKEN_DECIMALS: u8 = 18;
+
+pub fn check_token_decimals(decimals_x: u8, decimals_y: u8) -> Result<()> {
+    require!(
+        decimals_x <= MAX_TOKEN_DECIMALS && decimals_y <= MAX_TOKEN_DECIMALS,
+        UnsupportedTokenDecimals
+    );
+    Ok(())
+}
+
 #[cfg(test)]
 mod test {
     use std::cell::RefCell;
@@ -156,7 +360,7 @@ mod test {
         // tick limit closer
         {
             let (result, from_tick) =
-                get_closer_limit(Price::from_integer(5), true, 100, 1, tickmap)?;
+                get_closer_limit(Price::from_integer(5), Direction::XtoY, 100, 1, tickmap)?;
 
             let expected = Price::from_integer(5);
             assert_eq!(result, expected);
@@ -165,7 +369,7 @@ mod test {
         // trade limit closer
         {
             let (result, from_tick) =
-                get_closer_limit(Price::from_scale(1, 1), true, 100, 1, tickmap)?;
+                get_closer_limit(Price::from_scale(1, 1), Direction::XtoY, 100, 1, tickmap)?;
             let expected = Price::from_integer(1);
             assert_eq!(result, expected);
             assert_eq!(from_tick, Some((0, true)));
@@ -173,7 +377,7 @@ mod test {
         // other direction
         {
             let (result, from_tick) =
-                get_closer_limit(Price::from_integer(2), false, -5, 1, tickmap)?;
+                get_closer_limit(Price::from_integer(2), Direction::YtoX, -5, 1, tickmap)?;
             let expected = Price::from_integer(1);
             assert_eq!(result, expected);
             assert_eq!(from_tick, Some((0, true)));
@@ -181,7 +385,7 @@ mod test {
         // other direction
         {
             let (result, from_tick) =
-                get_closer_limit(Price::from_scale(1, 1), false, -100, 10, tickmap)?;
+                get_closer_limit(Price::from_scale(1, 1), Direction::YtoX, -100, 10, tickmap)?;
             let expected = Price::from_scale(1, 1);
             assert_eq!(result, expected);
             assert_eq!(from_tick, None);
@@ -381,4 +585,58 @@ mod test {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_close() {
+        let (key, owner) = (Pubkey::new_unique(), ID);
+        let mut lamports = 10;
+        let mut data = vec![0u8; 8];
+        let info = AccountInfo::new(
+            &key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+        // closing into itself
+        assert!(close(info.clone(), info.clone()).is_err());
+        assert_eq!(info.lamports(), 10);
+    }
+
+    #[test]
+    fn test_mocked_timestamp() {
+        set_mocked_timestamp(Some(1_650_000_000));
+        assert_eq!(get_current_timestamp(), 1_650_000_000);
+        set_mocked_timestamp(Some(1_650_000_060));
+        assert_eq!(get_current_timestamp(), 1_650_000_060);
+        set_mocked_timestamp(None);
+    }
+
+    #[test]
+    fn test_check_spend_authority() {
+        let owner = Pubkey::new_unique();
+        let session = Pubkey::new_unique();
+
+        // owner
+        check_spend_authority(&owner, COption::None, 0, &owner, 100).unwrap();
+        // delegate within approved amount
+        check_spend_authority(&owner, COption::Some(session), 100, &session, 100).unwrap();
+        // delegate over approved amount
+        assert!(check_spend_authority(&owner, COption::Some(session), 99, &session, 100).is_err());
+        // not a delegate
+        assert!(check_spend_authority(&owner, COption::None, 100, &session, 1).is_err());
+        assert!(check_spend_authority(&owner, COption::Some(owner), 100, &session, 1).is_err());
+    }
+
+    #[test]
+    fn test_check_token_decimals() {
+        check_token_decimals(0, 0).unwrap();
+        check_token_decimals(0, MAX_TOKEN_DECIMALS).unwrap();
+        check_token_decimals(MAX_TOKEN_DECIMALS, 6).unwrap();
+        assert!(check_token_decimals(MAX_TOKEN_DECIMALS + 1, 6).is_err());
+        assert!(check_token_decimals(9, u8::MAX).is_err());
+    }
 }
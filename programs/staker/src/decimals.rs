@@ -1,5 +1,4 @@
 use core::convert::TryFrom;
-use core::convert::TryInto;
 pub use decimal::*;
 
 use anchor_lang::prelude::*;
@@ -50,6 +49,6 @@ pub struct Seconds {
 
 impl Seconds {
     pub fn now() -> Self {
-        Seconds::new(Clock::get().unwrap().unix_timestamp.try_into().unwrap())
+        Seconds::new(crate::util::get_current_timestamp())
     }
 }
@@ -48,4 +48,24 @@ pub enum ErrorCode {
     InvalidMint = 21, // 1785
     #[msg("Incentive pool is different from stake pool")]
     DifferentIncentivePool = 22, // 1786
+    #[msg("Staker is paused")]
+    Paused = 23, // 1787
+    #[msg("Admin address is different than expected")]
+    InvalidAdmin = 24, // 1788
+    #[msg("New claim time has to be later than the current one")]
+    InvalidClaimTime = 25, // 1789
+    #[msg("Reward mint is not on the allowlist")]
+    RewardMintNotAllowed = 26, // 178a
+    #[msg("Too many extra rewards")]
+    TooManyRewards = 27, // 178b
+    #[msg("Lock period is not one of the supported ones")]
+    InvalidLockPeriod = 28, // 178c
+    #[msg("Stake is locked")]
+    StakeLocked = 29, // 178d
+    #[msg("Position is already staked in the incentive")]
+    StakeAlreadyExists = 30, // 178e
+    #[msg("Account can not be closed into itself")]
+    InvalidCloseDestination = 31, // 178f
+    #[msg("Account already has the current layout")]
+    AccountAlreadyMigrated = 32, // 1790
 }
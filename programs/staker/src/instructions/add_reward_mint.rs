@@ -0,0 +1,37 @@
+use crate::structs::*;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token::Mint;
+
+#[derive(Accounts)]
+pub struct AddRewardMint<'info> {
+    #[account(
+        seeds = [b"configv1".as_ref()],
+        bump = config.load()?.bump
+    )]
+    pub config: AccountLoader<'info, Config>,
+    #[account(init,
+        seeds = [b"rewardmintv1".as_ref(), mint.key().as_ref()],
+        bump,
+        payer = admin
+    )]
+    pub reward_mint: AccountLoader<'info, RewardMint>,
+    pub mint: Account<'info, Mint>,
+    #[account(mut, constraint = config.load()?.admin == admin.key() @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+}
+
+pub fn handler(ctx: Context<AddRewardMint>) -> ProgramResult {
+    msg!("ADD REWARD MINT");
+
+    let reward_mint = &mut ctx.accounts.reward_mint.load_init()?;
+    **reward_mint = RewardMint {
+        mint: ctx.accounts.mint.key(),
+        bump: *ctx.bumps.get("reward_mint").unwrap(),
+    };
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use crate::structs::*;
+use crate::util::create_user_stake;
+use crate::ErrorCode::*;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use invariant::structs::Position;
+
+#[derive(Accounts)]
+#[instruction(index: u32)]
+pub struct AdoptStake<'info> {
+    #[account(mut,
+        close = owner,
+        constraint = legacy_stake.load()?.incentive == incentive.key() @ InvalidStake,
+        constraint = legacy_stake.load()?.position == position.key() @ InvalidStake,
+        constraint = legacy_stake.key() != user_stake.key() @ InvalidStake
+    )]
+    pub legacy_stake: AccountLoader<'info, UserStake>,
+    #[account(mut,
+        seeds = [b"staker", incentive.key().as_ref(), position.load()?.pool.as_ref(), &position.load()?.id.to_le_bytes()],
+        bump)]
+    pub user_stake: AccountInfo<'info>,
+    #[account(
+        seeds = [b"positionv1",
+        owner.key.as_ref(),
+        &index.to_le_bytes(),],
+        bump = position.load()?.bump,
+        seeds::program = invariant::ID
+    )]
+    pub position: AccountLoader<'info, Position>,
+    #[account(mut)]
+    pub incentive: AccountLoader<'info, Incentive>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+// Moves a stake created at a non-canonical address to the canonical one. When the position
+// already has a canonical stake, the legacy one is a duplicate and is only closed.
+pub fn handler(ctx: Context<AdoptStake>) -> ProgramResult {
+    msg!("ADOPT STAKE");
+    let legacy_stake = *ctx.accounts.legacy_stake.load()?;
+
+    if !ctx.accounts.user_stake.data_is_empty() {
+        let mut incentive = ctx.accounts.incentive.load_mut()?;
+        require!(incentive.num_of_stakes > 0, NoStakes);
+        incentive.num_of_stakes -= 1;
+        return Ok(());
+    }
+
+    let bump = *ctx.bumps.get("user_stake").unwrap();
+    let incentive_key = ctx.accounts.incentive.key();
+    let position = ctx.accounts.position.load()?;
+    let id = { position.id }.to_le_bytes();
+    create_user_stake(
+        ctx.program_id,
+        &ctx.accounts.user_stake,
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.system_program,
+        &ctx.accounts.rent,
+        &[
+            b"staker",
+            incentive_key.as_ref(),
+            position.pool.as_ref(),
+            &id,
+            &[bump],
+        ],
+        UserStake {
+            bump,
+            ..legacy_stake
+        },
+    )
+}
@@ -1,7 +1,8 @@
 use crate::structs::{Incentive, UserStake};
 use crate::ErrorCode::*;
 use anchor_lang::prelude::*;
-use invariant::structs::Position;
+use anchor_spl::token::TokenAccount;
+use invariant::structs::{holds_position_nft, Position};
 
 #[derive(Accounts)]
 #[instruction(index: u32)]
@@ -22,7 +23,14 @@ pub struct CloseStakeByOwner<'info> {
         seeds::program = invariant::ID
     )]
     pub position: AccountLoader<'info, Position>,
-    pub owner: Signer<'info>,
+    #[account(mut)]
+    pub owner: AccountInfo<'info>,
+    #[account(
+        constraint = holds_position_nft(&*position.load()?, &position_nft_account) @ InvalidOwner,
+        constraint = &position_nft_account.owner == holder.key @ InvalidOwner
+    )]
+    pub position_nft_account: Box<Account<'info, TokenAccount>>,
+    pub holder: Signer<'info>,
 }
 
 pub fn handler(ctx: Context<CloseStakeByOwner>, _index: i32) -> ProgramResult {
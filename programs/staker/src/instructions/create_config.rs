@@ -0,0 +1,35 @@
+use crate::program::Staker;
+use crate::structs::*;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+// Only the upgrade authority of the program can create the config, so nobody can take the admin
+// role by front-running the deployment
+#[derive(Accounts)]
+pub struct CreateConfig<'info> {
+    #[account(init, seeds = [b"configv1".as_ref()], bump, payer = admin)]
+    pub config: AccountLoader<'info, Config>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(constraint = program.programdata_address() == Some(program_data.key()) @ InvalidAdmin)]
+    pub program: Program<'info, Staker>,
+    #[account(constraint = program_data.upgrade_authority_address == Some(admin.key()) @ InvalidAdmin)]
+    pub program_data: Account<'info, ProgramData>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+}
+
+pub fn handler(ctx: Context<CreateConfig>) -> ProgramResult {
+    msg!("CREATE CONFIG");
+
+    let config = &mut ctx.accounts.config.load_init()?;
+    **config = Config {
+        admin: ctx.accounts.admin.key(),
+        paused: false,
+        bump: *ctx.bumps.get("config").unwrap(),
+        reward_mint_allowlist: false,
+    };
+    Ok(())
+}
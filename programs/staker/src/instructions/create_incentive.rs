@@ -1,8 +1,11 @@
 use crate::decimals::*;
 use crate::structs::*;
+use crate::util::REWARD_MINT_SEED;
 use crate::ErrorCode::*;
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::system_program;
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::solana_program::{system_instruction, system_program};
+use anchor_spl::token::spl_token;
 use anchor_spl::token::Mint;
 use anchor_spl::token::{self, TokenAccount, Transfer};
 use invariant::program::Invariant;
@@ -13,8 +16,13 @@ const MAX_DURATION: u64 = 31_556_926; //year in sec
 const WEEK: u64 = 604_800; //week in sec
 
 #[derive(Accounts)]
-#[instruction(nonce: u8)]
 pub struct CreateIncentive<'info> {
+    #[account(
+        seeds = [b"configv1".as_ref()],
+        bump = config.load()?.bump,
+        constraint = !config.load()?.paused @ Paused
+    )]
+    pub config: AccountLoader<'info, Config>,
     #[account(init, payer = founder)]
     pub incentive: AccountLoader<'info, Incentive>,
     #[account(init,
@@ -32,7 +40,7 @@ pub struct CreateIncentive<'info> {
     pub pool: AccountLoader<'info, Pool>,
     #[account(mut)]
     pub founder: Signer<'info>,
-    #[account(seeds = [b"staker".as_ref()], bump = nonce)]
+    #[account(seeds = [b"staker".as_ref()], bump)]
     pub staker_authority: AccountInfo<'info>,
     pub incentive_token: Account<'info, Mint>,
     #[account(address = token::ID)]
@@ -61,15 +69,66 @@ impl<'info> DepositToken<'info> for CreateIncentive<'info> {
     }
 }
 
-pub fn handler(
-    ctx: Context<CreateIncentive>,
-    nonce: u8,
+impl<'info> CreateIncentive<'info> {
+    // Wraps lamports of the founder straight into the incentive account
+    fn deposit_native(&self, amount: u64) -> ProgramResult {
+        invoke(
+            &system_instruction::transfer(
+                self.founder.key,
+                &self.incentive_token_account.key(),
+                amount,
+            ),
+            &[
+                self.founder.to_account_info(),
+                self.incentive_token_account.to_account_info(),
+                self.system_program.clone(),
+            ],
+        )?;
+        invoke(
+            &spl_token::instruction::sync_native(&token::ID, &self.incentive_token_account.key())?,
+            &[self.incentive_token_account.to_account_info()],
+        )
+    }
+}
+
+// With the allowlist on, the RewardMint entry of the mint is passed as a remaining account
+fn is_allowed_mint(accounts: &[AccountInfo], mint: &Pubkey, program_id: &Pubkey) -> bool {
+    let (address, _) =
+        Pubkey::find_program_address(&[REWARD_MINT_SEED.as_bytes(), mint.as_ref()], program_id);
+    accounts.iter().any(|account| {
+        *account.key == address && account.owner == program_id && !account.data_is_empty()
+    })
+}
+
+// Extra rewards are deposited from remaining accounts passed in pairs of an empty token account
+// owned by the staker authority and the founder token account of the same mint. Allowlist
+// entries, if needed, follow them.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateIncentive<'info>>,
     reward: TokenAmount,
     start_time: Seconds,
     end_time: Seconds,
+    extra_rewards: Vec<TokenAmount>,
 ) -> ProgramResult {
     msg!("CREATE INCENTIVE");
+    let nonce = *ctx.bumps.get("staker_authority").unwrap();
     require!((reward) != TokenAmount::new(0), ZeroAmount);
+    require!(extra_rewards.len() <= MAX_EXTRA_REWARDS, TooManyRewards);
+    require!(
+        ctx.remaining_accounts.len() >= extra_rewards.len() * 2,
+        InvalidTokenAccount
+    );
+    let allowlist = ctx.accounts.config.load()?.reward_mint_allowlist;
+    if allowlist {
+        require!(
+            is_allowed_mint(
+                ctx.remaining_accounts,
+                &ctx.accounts.incentive_token.key(),
+                ctx.program_id
+            ),
+            RewardMintNotAllowed
+        );
+    }
 
     require!(
         (start_time + Seconds::new(MAX_TIME_BEFORE_START)) >= Seconds::now(),
@@ -92,12 +151,76 @@ pub fn handler(
         end_time,
         end_claim_time: end_time + Seconds::new(WEEK),
         nonce,
+        extra_rewards: Default::default(),
+        extra_rewards_count: 0,
+        accrued_reward: TokenAmount::new(0),
+        accrued_seconds: Seconds::new(0),
     };
 
     //send tokens to incentive
-    let cpi_ctx = ctx.accounts.deposit();
+    if ctx.accounts.incentive_token.key() == spl_token::native_mint::ID {
+        ctx.accounts.deposit_native(reward.get())?;
+    } else {
+        let cpi_ctx = ctx.accounts.deposit();
+
+        token::transfer(cpi_ctx, reward.get())?;
+    }
 
-    token::transfer(cpi_ctx, reward.get())?;
+    let mut mints = vec![ctx.accounts.incentive_token.key()];
+    for (extra_reward, accounts) in extra_rewards
+        .iter()
+        .zip(ctx.remaining_accounts.chunks_exact(2))
+    {
+        let (incentive_token_info, founder_token_info) = (&accounts[0], &accounts[1]);
+        let incentive_token_account = Account::<'_, TokenAccount>::try_from(incentive_token_info)?;
+        let founder_token_account = Account::<'_, TokenAccount>::try_from(founder_token_info)?;
+
+        require!(!extra_reward.is_zero(), ZeroAmount);
+        require!(
+            incentive_token_account.owner == ctx.accounts.staker_authority.key(),
+            InvalidTokenAccount
+        );
+        require!(incentive_token_account.amount == 0, InvalidTokenAccount);
+        require!(
+            founder_token_account.mint == incentive_token_account.mint,
+            InvalidMint
+        );
+        require!(
+            founder_token_account.owner == ctx.accounts.founder.key(),
+            InvalidOwner
+        );
+        require!(!mints.contains(&incentive_token_account.mint), InvalidMint);
+        if allowlist {
+            require!(
+                is_allowed_mint(
+                    ctx.remaining_accounts,
+                    &incentive_token_account.mint,
+                    ctx.program_id
+                ),
+                RewardMintNotAllowed
+            );
+        }
+        mints.push(incentive_token_account.mint);
+
+        let index = incentive.extra_rewards_count as usize;
+        incentive.extra_rewards[index] = ExtraReward {
+            token_account: incentive_token_info.key(),
+            total_reward_unclaimed: *extra_reward,
+        };
+        incentive.extra_rewards_count += 1;
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.clone(),
+                Transfer {
+                    from: founder_token_info.clone(),
+                    to: incentive_token_info.clone(),
+                    authority: ctx.accounts.founder.to_account_info(),
+                },
+            ),
+            extra_reward.get(),
+        )?;
+    }
 
     Ok(())
 }
@@ -1,13 +1,13 @@
 use crate::decimals::*;
+use crate::instructions::withdraw::transfer_extra_rewards;
 use crate::structs::*;
 use crate::util;
 use crate::ErrorCode::*;
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, TokenAccount, Transfer};
+use anchor_spl::token::{self, CloseAccount, Mint, TokenAccount, Transfer};
 use util::STAKER_SEED;
 
 #[derive(Accounts)]
-#[instruction(nonce: u8)]
 pub struct ReturnFounds<'info> {
     #[account(mut,
         close = founder,
@@ -23,7 +23,7 @@ pub struct ReturnFounds<'info> {
     #[account(mut)]
     pub founder_token_account: Account<'info, TokenAccount>,
     pub incentive_token: Account<'info, Mint>,
-    #[account(seeds = [b"staker".as_ref()], bump = nonce)]
+    #[account(seeds = [b"staker".as_ref()], bump)]
     pub staker_authority: AccountInfo<'info>,
     pub founder: Signer<'info>,
     #[account(address = token::ID)]
@@ -41,9 +41,22 @@ impl<'info> ReturnFounds<'info> {
             },
         )
     }
+
+    fn close_incentive_token_account(&self) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: self.incentive_token_account.to_account_info(),
+                destination: self.founder.to_account_info(),
+                authority: self.staker_authority.to_account_info().clone(),
+            },
+        )
+    }
 }
 
-pub fn handler(ctx: Context<ReturnFounds>, nonce: u8) -> ProgramResult {
+// Extra rewards left are returned like the main one, with accounts passed as in Withdraw
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, ReturnFounds<'info>>) -> ProgramResult {
+    let nonce = *ctx.bumps.get("staker_authority").unwrap();
     {
         let incentive = ctx.accounts.incentive.load()?;
         require!(Seconds::now() > { incentive.end_time }, TooEarly);
@@ -55,6 +68,31 @@ pub fn handler(ctx: Context<ReturnFounds>, nonce: u8) -> ProgramResult {
         let cpi_ctx = ctx.accounts.return_to_founder().with_signer(signer);
 
         token::transfer(cpi_ctx, remaining_reward.get())?;
+
+        let remaining_extra_rewards: Vec<TokenAmount> = incentive
+            .extra_rewards()
+            .iter()
+            .map(|extra_reward| extra_reward.total_reward_unclaimed)
+            .collect();
+        transfer_extra_rewards(
+            &incentive,
+            &remaining_extra_rewards,
+            ctx.remaining_accounts,
+            &incentive.founder,
+            &ctx.accounts.staker_authority,
+            &ctx.accounts.token_program,
+            signer,
+        )?;
+
+        // rent of the emptied reward account goes back to the founder together with the incentive
+        ctx.accounts.incentive_token_account.reload()?;
+        if ctx.accounts.incentive_token_account.amount == 0 {
+            token::close_account(
+                ctx.accounts
+                    .close_incentive_token_account()
+                    .with_signer(signer),
+            )?;
+        }
     }
 
     Ok(())
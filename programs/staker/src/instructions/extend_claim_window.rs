@@ -0,0 +1,31 @@
+use crate::decimals::*;
+use crate::structs::*;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+
+// Used when claiming was broken for a while, so users don't lose rewards because of the deadline
+#[derive(Accounts)]
+pub struct ExtendClaimWindow<'info> {
+    #[account(
+        seeds = [b"configv1".as_ref()],
+        bump = config.load()?.bump
+    )]
+    pub config: AccountLoader<'info, Config>,
+    #[account(mut)]
+    pub incentive: AccountLoader<'info, Incentive>,
+    #[account(constraint = config.load()?.admin == admin.key() @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ExtendClaimWindow>, new_end_claim_time: Seconds) -> ProgramResult {
+    msg!("EXTEND CLAIM WINDOW");
+
+    let incentive = &mut ctx.accounts.incentive.load_mut()?;
+    require!(
+        new_end_claim_time > { incentive.end_claim_time },
+        InvalidClaimTime
+    );
+
+    incentive.end_claim_time = new_end_claim_time;
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use crate::structs::*;
+use crate::util::{check_legacy_account, realloc_account};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+// Incentive before the extra rewards and the accrued reward were appended
+pub const LEGACY_INCENTIVE_SIZE: usize = 145;
+
+#[derive(Accounts)]
+pub struct MigrateIncentive<'info> {
+    // the legacy layout can't be loaded, so the account is checked in the handler
+    #[account(mut)]
+    pub incentive: AccountInfo<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+}
+
+// Anyone can migrate an incentive. Appended fields start zeroed, which is no extra rewards and
+// nothing accrued at an older reward rate, as the rate couldn't be changed before.
+pub fn handler(ctx: Context<MigrateIncentive>) -> ProgramResult {
+    msg!("MIGRATE INCENTIVE");
+
+    check_legacy_account::<Incentive>(
+        &ctx.accounts.incentive,
+        ctx.program_id,
+        LEGACY_INCENTIVE_SIZE,
+    )?;
+
+    realloc_account(
+        &ctx.accounts.incentive,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program,
+        8 + std::mem::size_of::<Incentive>(),
+    )
+}
@@ -0,0 +1,41 @@
+use crate::structs::*;
+use crate::util::{check_legacy_account, realloc_account};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+// UserStake before the lock and the boost were appended
+pub const LEGACY_USER_STAKE_SIZE: usize = 97;
+
+#[derive(Accounts)]
+pub struct MigrateUserStake<'info> {
+    // the legacy layout can't be loaded, so the account is checked in the handler
+    #[account(mut)]
+    pub user_stake: AccountInfo<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+}
+
+// Anyone can migrate a stake. Legacy stakes have no lock and the weight of a stake without one.
+pub fn handler(ctx: Context<MigrateUserStake>) -> ProgramResult {
+    msg!("MIGRATE USER STAKE");
+
+    check_legacy_account::<UserStake>(
+        &ctx.accounts.user_stake,
+        ctx.program_id,
+        LEGACY_USER_STAKE_SIZE,
+    )?;
+
+    realloc_account(
+        &ctx.accounts.user_stake,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program,
+        8 + std::mem::size_of::<UserStake>(),
+    )?;
+
+    let user_stake_loader = AccountLoader::<'_, UserStake>::try_from(&ctx.accounts.user_stake)?;
+    user_stake_loader.load_mut()?.boost = BOOST_DENOMINATOR;
+
+    Ok(())
+}
@@ -1,13 +1,35 @@
+pub mod add_reward_mint;
+pub mod adopt_stake;
 pub mod close_stake_by_owner;
+pub mod create_config;
 pub mod create_incentive;
 pub mod end_incentive;
+pub mod extend_claim_window;
+pub mod migrate_incentive;
+pub mod migrate_user_stake;
+pub mod pause_all;
+pub mod remove_reward_mint;
 pub mod remove_stake;
+pub mod set_reward_mint_allowlist;
 pub mod stake;
+pub mod update_reward_rate;
 pub mod withdraw;
+pub mod withdraw_native;
 
+pub use add_reward_mint::*;
+pub use adopt_stake::*;
 pub use close_stake_by_owner::*;
+pub use create_config::*;
 pub use create_incentive::*;
 pub use end_incentive::*;
+pub use extend_claim_window::*;
+pub use migrate_incentive::*;
+pub use migrate_user_stake::*;
+pub use pause_all::*;
+pub use remove_reward_mint::*;
 pub use remove_stake::*;
+pub use set_reward_mint_allowlist::*;
 pub use stake::*;
+pub use update_reward_rate::*;
 pub use withdraw::*;
+pub use withdraw_native::*;
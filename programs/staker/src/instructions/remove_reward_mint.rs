@@ -0,0 +1,26 @@
+use crate::structs::*;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+
+// Existing incentives of the mint are not affected, only new ones can't be created
+#[derive(Accounts)]
+pub struct RemoveRewardMint<'info> {
+    #[account(
+        seeds = [b"configv1".as_ref()],
+        bump = config.load()?.bump
+    )]
+    pub config: AccountLoader<'info, Config>,
+    #[account(mut,
+        close = admin,
+        seeds = [b"rewardmintv1".as_ref(), reward_mint.load()?.mint.as_ref()],
+        bump = reward_mint.load()?.bump
+    )]
+    pub reward_mint: AccountLoader<'info, RewardMint>,
+    #[account(mut, constraint = config.load()?.admin == admin.key() @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(_ctx: Context<RemoveRewardMint>) -> ProgramResult {
+    msg!("REMOVE REWARD MINT");
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use crate::structs::*;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetRewardMintAllowlist<'info> {
+    #[account(mut,
+        seeds = [b"configv1".as_ref()],
+        bump = config.load()?.bump
+    )]
+    pub config: AccountLoader<'info, Config>,
+    #[account(constraint = config.load()?.admin == admin.key() @ InvalidAdmin)]
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetRewardMintAllowlist>, enabled: bool) -> ProgramResult {
+    msg!("SET REWARD MINT ALLOWLIST");
+
+    let config = &mut ctx.accounts.config.load_mut()?;
+    config.reward_mint_allowlist = enabled;
+    Ok(())
+}
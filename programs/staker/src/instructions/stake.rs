@@ -1,21 +1,21 @@
 use crate::decimals::*;
 use crate::structs::*;
-use crate::util::get_current_slot;
+use crate::util::{create_user_stake, get_current_slot};
 use crate::ErrorCode::*;
 
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::system_program;
 use invariant::program::Invariant;
-use invariant::structs::Position;
+use invariant::structs::{Position, State};
 
 #[derive(Accounts)]
 #[instruction(index: u32)]
 pub struct CreateUserStake<'info> {
-    #[account(init,
+    // created by the handler, so a second stake of the position fails with StakeAlreadyExists
+    #[account(mut,
         seeds = [b"staker", incentive.key().as_ref(), position.load()?.pool.as_ref(), &position.load()?.id.to_le_bytes() ],
-        payer = signer,
         bump)]
-    pub user_stake: AccountLoader<'info, UserStake>,
+    pub user_stake: AccountInfo<'info>,
     #[account(
         seeds = [b"positionv1",
         owner.key.as_ref(),
@@ -28,6 +28,20 @@ pub struct CreateUserStake<'info> {
         constraint = incentive.load()?.pool == position.load()?.pool @ DifferentIncentivePool
     )]
     pub incentive: AccountLoader<'info, Incentive>,
+    #[account(
+        seeds = [b"configv1".as_ref()],
+        bump = config.load()?.bump,
+        constraint = !config.load()?.paused @ Paused
+    )]
+    pub config: AccountLoader<'info, Config>,
+    // pausing the whole protocol stops staking as well
+    #[account(
+        seeds = [b"statev1".as_ref()],
+        bump = invariant_state.load()?.bump,
+        seeds::program = invariant::ID,
+        constraint = !invariant_state.load()?.is_paused @ Paused
+    )]
+    pub invariant_state: AccountLoader<'info, State>,
     pub owner: AccountInfo<'info>,
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -38,30 +52,55 @@ pub struct CreateUserStake<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
-pub fn handler(ctx: Context<CreateUserStake>) -> ProgramResult {
+// Locking the stake for one of LOCK_BOOSTS periods boosts its reward, the lock has to end
+// before the incentive does, so final claims are never blocked
+pub fn handler(ctx: Context<CreateUserStake>, lock_period: Option<u64>) -> ProgramResult {
     msg!("STAKE");
     let mut incentive = ctx.accounts.incentive.load_mut()?;
     require!(Seconds::now() >= { incentive.start_time }, NotStarted);
     require!(Seconds::now() < { incentive.end_time }, Ended);
     require!(incentive.num_of_stakes < u64::MAX, NoStakes);
+    let boost = lock_boost(lock_period).ok_or(InvalidLockPeriod)?;
+    let lock_end = Seconds::now() + Seconds::new(lock_period.unwrap_or(0));
+    require!(lock_end <= { incentive.end_time }, InvalidLockPeriod);
 
-    let user_stake = &mut ctx.accounts.user_stake.load_init()?;
     let position = ctx.accounts.position.load()?;
     let update_slot = position.last_slot;
     let slot = get_current_slot();
     require!(slot == update_slot, SlotsAreNotEqual);
 
-    **user_stake = UserStake {
+    let bump = *ctx.bumps.get("user_stake").unwrap();
+    let user_stake = UserStake {
         position: ctx.accounts.position.key(),
         liquidity: Liquidity::new({ position.liquidity }.get()),
         incentive: ctx.accounts.incentive.key(),
-        bump: *ctx.bumps.get("user_stake").unwrap(),
+        bump,
         seconds_per_liquidity_initial: SecondsPerLiquidity::from_decimal(
             position.seconds_per_liquidity_inside,
         ),
+        lock_end,
+        boost,
     };
-    incentive.num_of_stakes += 1;
     let liquidity = user_stake.liquidity;
     require!(!liquidity.is_zero(), ZeroLiquidity);
+
+    let incentive_key = ctx.accounts.incentive.key();
+    let id = { position.id }.to_le_bytes();
+    create_user_stake(
+        ctx.program_id,
+        &ctx.accounts.user_stake,
+        &ctx.accounts.signer.to_account_info(),
+        &ctx.accounts.system_program,
+        &ctx.accounts.rent,
+        &[
+            b"staker",
+            incentive_key.as_ref(),
+            position.pool.as_ref(),
+            &id,
+            &[bump],
+        ],
+        user_stake,
+    )?;
+    incentive.num_of_stakes += 1;
     Ok(())
 }
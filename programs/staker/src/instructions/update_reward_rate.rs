@@ -0,0 +1,85 @@
+use crate::decimals::*;
+use crate::structs::*;
+use crate::util::STAKER_SEED;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+pub struct UpdateRewardRate<'info> {
+    #[account(mut,
+        constraint = incentive.load()?.founder == founder.key() @ InvalidFounder
+    )]
+    pub incentive: AccountLoader<'info, Incentive>,
+    #[account(mut,
+        constraint = incentive_token_account.owner == staker_authority.key() @ InvalidTokenAccount,
+        constraint = incentive.load()?.token_account == incentive_token_account.key() @ InvalidTokenAccount
+    )]
+    pub incentive_token_account: Account<'info, TokenAccount>,
+    #[account(mut,
+        constraint = founder_token_account.key() != incentive_token_account.key() @ InvalidTokenAccount,
+        constraint = founder_token_account.mint == incentive_token_account.mint @ InvalidMint,
+        constraint = founder_token_account.owner == founder.key() @ InvalidOwner
+    )]
+    pub founder_token_account: Account<'info, TokenAccount>,
+    #[account(seeds = [b"staker".as_ref()], bump)]
+    pub staker_authority: AccountInfo<'info>,
+    pub founder: Signer<'info>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+}
+
+impl<'info> UpdateRewardRate<'info> {
+    fn deposit(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.founder_token_account.to_account_info(),
+                to: self.incentive_token_account.to_account_info(),
+                authority: self.founder.to_account_info().clone(),
+            },
+        )
+    }
+
+    fn return_to_founder(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.incentive_token_account.to_account_info(),
+                to: self.founder_token_account.to_account_info(),
+                authority: self.staker_authority.to_account_info().clone(),
+            },
+        )
+    }
+}
+
+// `future_reward` is distributed over the rest of the incentive, which sets the new emission rate.
+// Reward accrued so far is checkpointed at the old rate and stays reserved for the seconds that
+// earned it, so lowering the rate never takes back what stakers already earned.
+pub fn handler(ctx: Context<UpdateRewardRate>, future_reward: TokenAmount) -> ProgramResult {
+    msg!("UPDATE REWARD RATE");
+    let nonce = *ctx.bumps.get("staker_authority").unwrap();
+
+    let mut incentive = ctx.accounts.incentive.load_mut()?;
+    let now = Seconds::now();
+    require!(now < { incentive.end_time }, Ended);
+
+    let previous_reward = incentive.total_reward_unclaimed;
+    incentive.checkpoint_reward(now);
+    let reward = incentive.accrued_reward + future_reward;
+    require!(!reward.is_zero(), ZeroReward);
+    incentive.total_reward_unclaimed = reward;
+
+    if reward > previous_reward {
+        token::transfer(ctx.accounts.deposit(), (reward - previous_reward).get())?;
+    } else if reward < previous_reward {
+        let seeds = &[STAKER_SEED.as_bytes(), &[nonce]];
+        let signer = &[&seeds[..]];
+        token::transfer(
+            ctx.accounts.return_to_founder().with_signer(signer),
+            (previous_reward - reward).get(),
+        )?;
+    }
+
+    Ok(())
+}
@@ -5,10 +5,10 @@ use crate::util::*;
 use crate::ErrorCode::*;
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, TokenAccount, Transfer};
-use invariant::structs::Position;
+use invariant::structs::{holds_position_nft, Position};
 
 #[derive(Accounts)]
-#[instruction(index: u32, nonce: u8)]
+#[instruction(index: u32)]
 pub struct Withdraw<'info> {
     #[account(mut,
         seeds = [b"staker", incentive.key().as_ref(), position.load()?.pool.as_ref(), &position.load()?.id.to_le_bytes()],
@@ -31,12 +31,15 @@ pub struct Withdraw<'info> {
         seeds::program = invariant::ID
     )]
     pub position: AccountLoader<'info, Position>,
+    // rewards go to whoever holds the position NFT
+    #[account(constraint = holds_position_nft(&*position.load()?, &position_nft_account) @ InvalidOwner)]
+    pub position_nft_account: Box<Account<'info, TokenAccount>>,
     #[account(mut,
         constraint = owner_token_account.key() != incentive_token_account.key() @ InvalidTokenAccount,
-        constraint = owner_token_account.owner == position.load()?.owner @ InvalidOwner
+        constraint = owner_token_account.owner == position_nft_account.owner @ InvalidOwner
     )]
     pub owner_token_account: Account<'info, TokenAccount>,
-    #[account(seeds = [b"staker".as_ref()], bump = nonce)]
+    #[account(seeds = [b"staker".as_ref()], bump)]
     pub staker_authority: AccountInfo<'info>,
     #[account(mut)]
     pub owner: AccountInfo<'info>,
@@ -57,42 +60,123 @@ impl<'info> Withdraw<'info> {
     }
 }
 
-pub fn handler(ctx: Context<Withdraw>, _index: i32, nonce: u8) -> ProgramResult {
-    msg!("WITHDRAW");
+// Settles the reward of a single stake and updates the incentive accordingly. Extra rewards
+// are settled for the same seconds inside, each against its own unclaimed reward.
+pub fn claim_reward(
+    incentive: &mut Incentive,
+    user_stake: &mut UserStake,
+    position: &Position,
+) -> Result<(TokenAmount, [TokenAmount; MAX_EXTRA_REWARDS]), ProgramError> {
+    let update_slot = position.last_slot;
+    let slot = get_current_slot();
+
+    require!(slot == update_slot, SlotsAreNotEqual);
+    // liquidity taken out of the position with decrease_liquidity stops earning rewards
+    let position_liquidity = Liquidity::new({ position.liquidity }.get());
+    if position_liquidity < { user_stake.liquidity } {
+        user_stake.liquidity = position_liquidity;
+    }
+    require!(user_stake.liquidity.v != 0, ZeroSecondsStaked);
+    require!(Seconds::now() >= { user_stake.lock_end }, StakeLocked);
+
+    let seconds_per_liquidity_inside =
+        SecondsPerLiquidity::new(position.seconds_per_liquidity_inside.v);
+
+    let current_time = Seconds::now();
+    require!(current_time > { incentive.start_time }, NotStarted);
+    if current_time <= { incentive.end_time } {
+        require!(
+            incentive.total_reward_unclaimed != TokenAmount::new(0),
+            ZeroAmount
+        );
+    }
 
-    let mut incentive = ctx.accounts.incentive.load_mut()?;
-    {
-        let user_stake = &mut ctx.accounts.user_stake.load_mut()?;
-        let position = ctx.accounts.position.load()?;
+    let seconds_inside = calculate_seconds_inside(
+        user_stake.liquidity,
+        user_stake.seconds_per_liquidity_initial,
+        seconds_per_liquidity_inside,
+        user_stake.boost,
+    );
+    let (reward, extra_rewards) = incentive.settle(seconds_inside, current_time);
+    user_stake.seconds_per_liquidity_initial = seconds_per_liquidity_inside;
 
-        let update_slot = position.last_slot;
-        let slot = get_current_slot();
+    Ok((reward, extra_rewards))
+}
 
-        require!(slot == update_slot, SlotsAreNotEqual);
-        require!(user_stake.liquidity.v != 0, ZeroSecondsStaked);
+// Extra rewards are paid from the incentive token accounts passed in remaining accounts, each
+// followed by the token account of the receiver, in the order of the incentive extra rewards
+pub fn transfer_extra_rewards<'info>(
+    incentive: &Incentive,
+    rewards: &[TokenAmount],
+    remaining_accounts: &[AccountInfo<'info>],
+    receiver: &Pubkey,
+    staker_authority: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    signer: &[&[&[u8]]],
+) -> ProgramResult {
+    let extra_rewards = incentive.extra_rewards();
+    require!(
+        remaining_accounts.len() >= extra_rewards.len() * 2,
+        InvalidTokenAccount
+    );
+
+    for ((extra_reward, reward), accounts) in extra_rewards
+        .iter()
+        .zip(rewards)
+        .zip(remaining_accounts.chunks_exact(2))
+    {
+        let (source, destination) = (&accounts[0], &accounts[1]);
+        require!(
+            *source.key == extra_reward.token_account,
+            InvalidTokenAccount
+        );
+        require!(source.key != destination.key, InvalidTokenAccount);
+        let destination_account = Account::<'_, TokenAccount>::try_from(destination)?;
+        require!(destination_account.owner == *receiver, InvalidOwner);
 
-        let seconds_per_liquidity_inside =
-            SecondsPerLiquidity::new(position.seconds_per_liquidity_inside.v);
+        if !reward.is_zero() {
+            let cpi_ctx = CpiContext::new(
+                token_program.clone(),
+                Transfer {
+                    from: source.clone(),
+                    to: destination.clone(),
+                    authority: staker_authority.clone(),
+                },
+            );
+            token::transfer(cpi_ctx.with_signer(signer), reward.get())?;
+        }
+    }
+    Ok(())
+}
 
-        let reward_unclaimed = incentive.total_reward_unclaimed;
+// Stakes are closed on the first withdraw after the incentive ended
+pub fn close_stake_if_ended<'info>(
+    incentive: &mut Incentive,
+    user_stake: AccountInfo<'info>,
+    owner: AccountInfo<'info>,
+) -> ProgramResult {
+    if Seconds::now() > { incentive.end_time } {
+        require!(incentive.num_of_stakes > 0, NoStakes);
+        close(user_stake, owner).unwrap();
 
-        require!(reward_unclaimed != TokenAmount::new(0), ZeroAmount);
+        incentive.num_of_stakes -= 1;
+    }
+    Ok(())
+}
 
-        let (seconds_inside, reward) = calculate_reward(
-            reward_unclaimed,
-            incentive.total_seconds_claimed,
-            incentive.start_time,
-            incentive.end_time,
-            user_stake.liquidity,
-            user_stake.seconds_per_liquidity_initial,
-            seconds_per_liquidity_inside,
-            Seconds::now(),
-        )
-        .unwrap();
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, Withdraw<'info>>,
+    _index: i32,
+) -> ProgramResult {
+    msg!("WITHDRAW");
+    let nonce = *ctx.bumps.get("staker_authority").unwrap();
+
+    let mut incentive = ctx.accounts.incentive.load_mut()?;
+    {
+        let user_stake = &mut ctx.accounts.user_stake.load_mut()?;
+        let position = ctx.accounts.position.load()?;
 
-        incentive.total_seconds_claimed = incentive.total_seconds_claimed + seconds_inside;
-        incentive.total_reward_unclaimed = reward_unclaimed - reward;
-        user_stake.seconds_per_liquidity_initial = seconds_per_liquidity_inside;
+        let (reward, extra_rewards) = claim_reward(&mut incentive, user_stake, &position)?;
 
         let seeds = &[STAKER_SEED.as_bytes(), &[nonce]];
         let signer = &[&seeds[..]];
@@ -102,18 +186,20 @@ pub fn handler(ctx: Context<Withdraw>, _index: i32, nonce: u8) -> ProgramResult
         if !reward.is_zero() {
             token::transfer(cpi_ctx, reward.get())?;
         }
+        transfer_extra_rewards(
+            &incentive,
+            &extra_rewards,
+            ctx.remaining_accounts,
+            &ctx.accounts.position_nft_account.owner,
+            &ctx.accounts.staker_authority,
+            &ctx.accounts.token_program,
+            signer,
+        )?;
     }
 
-    if Seconds::now() > { incentive.end_time } {
-        require!(incentive.num_of_stakes > 0, NoStakes);
-        close(
-            ctx.accounts.user_stake.to_account_info(),
-            ctx.accounts.owner.to_account_info(),
-        )
-        .unwrap();
-
-        incentive.num_of_stakes -= 1;
-    }
-
-    Ok(())
+    close_stake_if_ended(
+        &mut incentive,
+        ctx.accounts.user_stake.to_account_info(),
+        ctx.accounts.owner.to_account_info(),
+    )
 }
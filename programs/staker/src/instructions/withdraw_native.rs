@@ -0,0 +1,127 @@
+use crate::instructions::withdraw::{claim_reward, close_stake_if_ended, transfer_extra_rewards};
+use crate::structs::*;
+use crate::util::*;
+use crate::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token::spl_token::native_mint;
+use anchor_spl::token::{self, CloseAccount, Mint, TokenAccount, Transfer};
+use invariant::structs::{holds_position_nft, Position};
+
+// Same as Withdraw for incentives paid in wrapped SOL. Reward is moved to a temporary
+// wSOL account which is closed right away, so the holder receives native SOL. Extra rewards
+// are paid out as tokens, same as in Withdraw.
+#[derive(Accounts)]
+#[instruction(index: u32)]
+pub struct WithdrawNative<'info> {
+    #[account(mut,
+        seeds = [b"staker", incentive.key().as_ref(), position.load()?.pool.as_ref(), &position.load()?.id.to_le_bytes()],
+        bump = user_stake.load()?.bump,
+    )]
+    pub user_stake: AccountLoader<'info, UserStake>,
+    #[account(mut,
+        constraint = user_stake.load()?.incentive == incentive.key() @ InvalidIncentive
+    )]
+    pub incentive: AccountLoader<'info, Incentive>,
+    #[account(mut,
+        constraint = incentive_token_account.owner == staker_authority.key() @ InvalidTokenAccount,
+        constraint = incentive_token_account.mint == native_mint.key() @ InvalidMint
+    )]
+    pub incentive_token_account: Account<'info, TokenAccount>,
+    #[account(
+        seeds = [b"positionv1",
+        owner.key.as_ref(),
+        &index.to_le_bytes(),],
+        bump = position.load()?.bump,
+        seeds::program = invariant::ID
+    )]
+    pub position: AccountLoader<'info, Position>,
+    #[account(
+        constraint = holds_position_nft(&*position.load()?, &position_nft_account) @ InvalidOwner,
+        constraint = &position_nft_account.owner == holder.key @ InvalidOwner
+    )]
+    pub position_nft_account: Box<Account<'info, TokenAccount>>,
+    #[account(init,
+        token::mint = native_mint,
+        token::authority = staker_authority,
+        payer = holder,
+    )]
+    pub unwrap_account: Account<'info, TokenAccount>,
+    #[account(address = native_mint::ID)]
+    pub native_mint: Account<'info, Mint>,
+    #[account(seeds = [b"staker".as_ref()], bump)]
+    pub staker_authority: AccountInfo<'info>,
+    #[account(mut)]
+    pub owner: AccountInfo<'info>,
+    #[account(mut)]
+    pub holder: Signer<'info>,
+    #[account(address = token::ID)]
+    pub token_program: AccountInfo<'info>,
+    #[account(address = system_program::ID)]
+    pub system_program: AccountInfo<'info>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> WithdrawNative<'info> {
+    fn withdraw(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.incentive_token_account.to_account_info(),
+                to: self.unwrap_account.to_account_info(),
+                authority: self.staker_authority.to_account_info().clone(),
+            },
+        )
+    }
+
+    fn unwrap(&self) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: self.unwrap_account.to_account_info(),
+                destination: self.holder.to_account_info(),
+                authority: self.staker_authority.to_account_info().clone(),
+            },
+        )
+    }
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, WithdrawNative<'info>>,
+    _index: i32,
+) -> ProgramResult {
+    msg!("WITHDRAW NATIVE");
+    let nonce = *ctx.bumps.get("staker_authority").unwrap();
+
+    let mut incentive = ctx.accounts.incentive.load_mut()?;
+    {
+        let user_stake = &mut ctx.accounts.user_stake.load_mut()?;
+        let position = ctx.accounts.position.load()?;
+
+        let (reward, extra_rewards) = claim_reward(&mut incentive, user_stake, &position)?;
+
+        let seeds = &[STAKER_SEED.as_bytes(), &[nonce]];
+        let signer = &[&seeds[..]];
+
+        if !reward.is_zero() {
+            token::transfer(ctx.accounts.withdraw().with_signer(signer), reward.get())?;
+        }
+        // returns the reward together with the rent of the temporary account
+        token::close_account(ctx.accounts.unwrap().with_signer(signer))?;
+        transfer_extra_rewards(
+            &incentive,
+            &extra_rewards,
+            ctx.remaining_accounts,
+            ctx.accounts.holder.key,
+            &ctx.accounts.staker_authority,
+            &ctx.accounts.token_program,
+            signer,
+        )?;
+    }
+
+    close_stake_if_ended(
+        &mut incentive,
+        ctx.accounts.user_stake.to_account_info(),
+        ctx.accounts.owner.to_account_info(),
+    )
+}
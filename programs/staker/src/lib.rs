@@ -1,4 +1,4 @@
-mod decimals;
+pub mod decimals;
 mod errors;
 mod instructions;
 mod math;
@@ -19,26 +19,79 @@ pub mod staker {
 
     use super::*;
 
-    pub fn create_incentive(
-        ctx: Context<CreateIncentive>,
-        nonce: u8,
+    pub fn create_config(ctx: Context<CreateConfig>) -> ProgramResult {
+        instructions::create_config::handler(ctx)
+    }
+
+    pub fn pause_all(ctx: Context<PauseAll>, paused: bool) -> ProgramResult {
+        instructions::pause_all::handler(ctx, paused)
+    }
+
+    pub fn set_reward_mint_allowlist(
+        ctx: Context<SetRewardMintAllowlist>,
+        enabled: bool,
+    ) -> ProgramResult {
+        instructions::set_reward_mint_allowlist::handler(ctx, enabled)
+    }
+
+    pub fn add_reward_mint(ctx: Context<AddRewardMint>) -> ProgramResult {
+        instructions::add_reward_mint::handler(ctx)
+    }
+
+    pub fn remove_reward_mint(ctx: Context<RemoveRewardMint>) -> ProgramResult {
+        instructions::remove_reward_mint::handler(ctx)
+    }
+
+    pub fn create_incentive<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateIncentive<'info>>,
         reward: TokenAmount,
         start_time: Seconds,
         end_time: Seconds,
+        extra_rewards: Vec<TokenAmount>,
     ) -> ProgramResult {
-        instructions::create_incentive::handler(ctx, nonce, reward, start_time, end_time)
+        instructions::create_incentive::handler(ctx, reward, start_time, end_time, extra_rewards)
     }
 
-    pub fn stake(ctx: Context<CreateUserStake>, _index: i32) -> ProgramResult {
-        instructions::stake::handler(ctx)
+    pub fn stake(
+        ctx: Context<CreateUserStake>,
+        _index: i32,
+        lock_period: Option<u64>,
+    ) -> ProgramResult {
+        instructions::stake::handler(ctx, lock_period)
     }
 
-    pub fn withdraw(ctx: Context<Withdraw>, _index: i32, nonce: u8) -> ProgramResult {
-        instructions::withdraw::handler(ctx, _index, nonce)
+    pub fn withdraw<'info>(
+        ctx: Context<'_, '_, '_, 'info, Withdraw<'info>>,
+        _index: i32,
+    ) -> ProgramResult {
+        instructions::withdraw::handler(ctx, _index)
     }
 
-    pub fn end_incentive(ctx: Context<ReturnFounds>, nonce: u8) -> ProgramResult {
-        instructions::end_incentive::handler(ctx, nonce)
+    pub fn withdraw_native<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawNative<'info>>,
+        _index: i32,
+    ) -> ProgramResult {
+        instructions::withdraw_native::handler(ctx, _index)
+    }
+
+    pub fn end_incentive<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReturnFounds<'info>>,
+    ) -> ProgramResult {
+        instructions::end_incentive::handler(ctx)
+    }
+
+    pub fn extend_claim_window(
+        ctx: Context<ExtendClaimWindow>,
+        new_end_claim_time: Seconds,
+    ) -> ProgramResult {
+        instructions::extend_claim_window::handler(ctx, new_end_claim_time)
+    }
+
+    pub fn update_reward_rate(
+        ctx: Context<UpdateRewardRate>,
+        future_reward: TokenAmount,
+    ) -> ProgramResult {
+        instructions::update_reward_rate::handler(ctx, future_reward)
     }
 
     pub fn remove_stake(ctx: Context<RemoveStake>) -> ProgramResult {
@@ -48,4 +101,16 @@ pub mod staker {
     pub fn close_stake_by_owner(ctx: Context<CloseStakeByOwner>, _index: i32) -> ProgramResult {
         instructions::close_stake_by_owner::handler(ctx, _index)
     }
+
+    pub fn adopt_stake(ctx: Context<AdoptStake>, _index: u32) -> ProgramResult {
+        instructions::adopt_stake::handler(ctx)
+    }
+
+    pub fn migrate_incentive(ctx: Context<MigrateIncentive>) -> ProgramResult {
+        instructions::migrate_incentive::handler(ctx)
+    }
+
+    pub fn migrate_user_stake(ctx: Context<MigrateUserStake>) -> ProgramResult {
+        instructions::migrate_user_stake::handler(ctx)
+    }
 }
@@ -1,4 +1,5 @@
 use crate::decimals::*;
+use crate::structs::BOOST_DENOMINATOR;
 use crate::ErrorCode;
 use crate::Result;
 use std::cmp;
@@ -12,21 +13,152 @@ pub fn calculate_reward(
     seconds_per_liquidity_inside_initial: SecondsPerLiquidity,
     seconds_per_liquidity_inside: SecondsPerLiquidity,
     current_time: Seconds,
+    boost: u16,
 ) -> Result<(Seconds, TokenAmount)> {
     if current_time <= start_time {
         return Err(ErrorCode::NotStarted.into());
     }
 
+    let seconds_inside = calculate_seconds_inside(
+        liquidity,
+        seconds_per_liquidity_inside_initial,
+        seconds_per_liquidity_inside,
+        boost,
+    );
+
+    let result = calculate_reward_for_seconds(
+        total_reward_unclaimed,
+        total_seconds_claimed,
+        start_time,
+        end_time,
+        seconds_inside,
+        current_time,
+    );
+    Ok((seconds_inside, result))
+}
+
+// Share of the unclaimed reward of `seconds_inside` out of the unclaimed seconds
+pub fn calculate_reward_for_seconds(
+    total_reward_unclaimed: TokenAmount,
+    total_seconds_claimed: Seconds,
+    start_time: Seconds,
+    end_time: Seconds,
+    seconds_inside: Seconds,
+    current_time: Seconds,
+) -> TokenAmount {
+    // boosted stakes can outweigh all seconds left, the reward is capped by what is left then
+    let total_seconds_unclaimed = cmp::max(end_time, current_time)
+        .get()
+        .saturating_sub(start_time.get())
+        .saturating_sub(total_seconds_claimed.get());
+    if seconds_inside.get() >= total_seconds_unclaimed {
+        return total_reward_unclaimed;
+    }
+
+    total_reward_unclaimed * seconds_inside / Seconds::new(total_seconds_unclaimed)
+}
+
+// Seconds inside are weighted by the lock boost of the stake, BOOST_DENOMINATOR being 1x
+pub fn calculate_seconds_inside(
+    liquidity: Liquidity,
+    seconds_per_liquidity_inside_initial: SecondsPerLiquidity,
+    seconds_per_liquidity_inside: SecondsPerLiquidity,
+    boost: u16,
+) -> Seconds {
     let seconds_inside = Seconds::from_decimal(
         seconds_per_liquidity_inside.unchecked_sub(seconds_per_liquidity_inside_initial)
             * liquidity,
     );
+    Seconds::new((seconds_inside.get() as u128 * boost as u128 / BOOST_DENOMINATOR as u128) as u64)
+}
 
-    let total_seconds_unclaimed =
-        cmp::max(end_time, current_time) - start_time - total_seconds_claimed;
+// Rounding dust the last stake can take on top of its pro-rated reward
+pub const LAST_STAKE_DUST_TOLERANCE: u64 = 1_000;
 
-    let result = total_reward_unclaimed * seconds_inside / total_seconds_unclaimed;
-    Ok((seconds_inside, result))
+// Reward of a claim made after the incentive ended. Rounding and seconds counted before the
+// stake was created can leave fewer unclaimed seconds than the remaining stakes hold, so instead
+// of failing the claim is pro-rated over what is left and capped by it. The last stake also takes
+// the rounding dust, reward nobody earned stays for the founder to take back with end_incentive.
+pub fn calculate_final_reward(
+    total_reward_unclaimed: TokenAmount,
+    total_seconds_claimed: Seconds,
+    start_time: Seconds,
+    end_time: Seconds,
+    seconds_inside: Seconds,
+    current_time: Seconds,
+    is_last_stake: bool,
+) -> TokenAmount {
+    let total_seconds_unclaimed = cmp::max(end_time, current_time)
+        .get()
+        .saturating_sub(start_time.get())
+        .saturating_sub(total_seconds_claimed.get());
+
+    if seconds_inside.get() >= total_seconds_unclaimed {
+        return total_reward_unclaimed;
+    }
+    let reward = (total_reward_unclaimed.get() as u128 * seconds_inside.get() as u128
+        / total_seconds_unclaimed as u128) as u64;
+    match is_last_stake {
+        true => TokenAmount::new(cmp::min(
+            reward.saturating_add(LAST_STAKE_DUST_TOLERANCE),
+            total_reward_unclaimed.get(),
+        )),
+        false => TokenAmount::new(reward),
+    }
+}
+
+// Unclaimed seconds elapsed so far and the part of unclaimed reward they earned at the current
+// emission rate, used as a checkpoint when the rate changes, so the change applies only to the
+// rest of the incentive.
+pub fn calculate_accrued_reward(
+    total_reward_unclaimed: TokenAmount,
+    total_seconds_claimed: Seconds,
+    start_time: Seconds,
+    end_time: Seconds,
+    current_time: Seconds,
+) -> (Seconds, TokenAmount) {
+    if current_time <= start_time {
+        return (Seconds::new(0), TokenAmount::new(0));
+    }
+
+    let total_seconds_unclaimed = end_time
+        .get()
+        .saturating_sub(start_time.get())
+        .saturating_sub(total_seconds_claimed.get());
+    let elapsed_seconds_unclaimed = cmp::min(current_time, end_time)
+        .get()
+        .saturating_sub(start_time.get())
+        .saturating_sub(total_seconds_claimed.get());
+
+    if total_seconds_unclaimed == 0 {
+        return (Seconds::new(0), total_reward_unclaimed);
+    }
+    (
+        Seconds::new(elapsed_seconds_unclaimed),
+        TokenAmount::new(
+            (total_reward_unclaimed.get() as u128 * elapsed_seconds_unclaimed as u128
+                / total_seconds_unclaimed as u128) as u64,
+        ),
+    )
+}
+
+// Part of `seconds_inside` paid from the reward accrued before the last rate change, at the rate
+// it was accrued at. Seconds of a claim are settled against the accrued reward first.
+pub fn calculate_accrued_part(
+    accrued_reward: TokenAmount,
+    accrued_seconds: Seconds,
+    seconds_inside: Seconds,
+) -> (Seconds, TokenAmount) {
+    if seconds_inside >= accrued_seconds {
+        return (accrued_seconds, accrued_reward);
+    }
+    (
+        seconds_inside,
+        TokenAmount::new(
+            (accrued_reward.get() as u128 * seconds_inside.get() as u128
+                / accrued_seconds.get() as u128) as u64,
+        ),
+    )
 }
 
 #[cfg(test)]
@@ -45,6 +177,7 @@ mod tests {
             SecondsPerLiquidity::new(4_000_000),
             SecondsPerLiquidity::new(10_000_000),
             Seconds::new(1637002232),
+            BOOST_DENOMINATOR,
         )
         .unwrap();
         assert_eq!(result, TokenAmount::new(2));
@@ -61,6 +194,7 @@ mod tests {
             SecondsPerLiquidity::new(10_000_000),
             SecondsPerLiquidity::new(35_000_000),
             Seconds::new(50),
+            BOOST_DENOMINATOR,
         )
         .unwrap();
         assert_eq!(result, TokenAmount::new(500));
@@ -79,6 +213,7 @@ mod tests {
             SecondsPerLiquidity::new(0),
             SecondsPerLiquidity::from_integer(2),
             Seconds::new(120),
+            BOOST_DENOMINATOR,
         )
         .unwrap();
 
@@ -98,6 +233,7 @@ mod tests {
             SecondsPerLiquidity::from_integer(0),
             SecondsPerLiquidity::from_integer(1),
             Seconds::new(300),
+            BOOST_DENOMINATOR,
         )
         .unwrap();
 
@@ -117,6 +253,7 @@ mod tests {
             SecondsPerLiquidity::from_integer(0),
             SecondsPerLiquidity::from_integer(1),
             Seconds::new(201),
+            BOOST_DENOMINATOR,
         )
         .unwrap();
 
@@ -136,6 +273,7 @@ mod tests {
             SecondsPerLiquidity::from_integer(0),
             SecondsPerLiquidity::from_integer(2),
             Seconds::new(120),
+            BOOST_DENOMINATOR,
         )
         .unwrap();
 
@@ -155,6 +293,7 @@ mod tests {
             SecondsPerLiquidity::from_integer(0),
             SecondsPerLiquidity::from_integer(2),
             Seconds::new(120),
+            BOOST_DENOMINATOR,
         )
         .unwrap();
 
@@ -174,6 +313,7 @@ mod tests {
             SecondsPerLiquidity::from_integer(2),
             SecondsPerLiquidity::from_integer(2),
             Seconds::new(120),
+            BOOST_DENOMINATOR,
         )
         .unwrap();
 
@@ -193,6 +333,7 @@ mod tests {
             SecondsPerLiquidity::from_integer(0),
             SecondsPerLiquidity::from_integer(2),
             Seconds::new(120),
+            BOOST_DENOMINATOR,
         )
         .unwrap();
 
@@ -212,6 +353,7 @@ mod tests {
             SecondsPerLiquidity::from_integer(0),
             SecondsPerLiquidity::from_integer(2),
             Seconds::new(99),
+            BOOST_DENOMINATOR,
         )
         .is_err();
 
@@ -230,6 +372,7 @@ mod tests {
             SecondsPerLiquidity::new(4_000_000),
             SecondsPerLiquidity::new(10_000_000),
             Seconds::new(1637002232),
+            BOOST_DENOMINATOR,
         )
         .unwrap();
         assert_eq!(result, TokenAmount::new(0));
@@ -248,6 +391,7 @@ mod tests {
             SecondsPerLiquidity::new(u128::MAX),
             SecondsPerLiquidity::new(6_000_000),
             Seconds::new(1637002232),
+            BOOST_DENOMINATOR,
         )
         .unwrap();
 
@@ -266,6 +410,7 @@ mod tests {
             SecondsPerLiquidity::new(6_000_000),
             SecondsPerLiquidity::new(6_000_000),
             Seconds::new(1637002232),
+            BOOST_DENOMINATOR,
         )
         .unwrap();
 
@@ -285,10 +430,234 @@ mod tests {
             SecondsPerLiquidity::new(340282366920938463463374607431768211446),
             SecondsPerLiquidity::new(2),
             Seconds::new(1661334904),
+            BOOST_DENOMINATOR,
         )
         .unwrap();
 
         assert_eq!(result, TokenAmount::new(4490775));
         assert_eq!(seconds_inside, Seconds::new(1164));
     }
+
+    #[test]
+    fn test_calculate_reward_boosted() {
+        // 1.5x weight of test_calculate_reward_3
+        let (seconds_inside, result) = calculate_reward(
+            TokenAmount::new(1000),
+            Seconds::new(0),
+            Seconds::new(100),
+            Seconds::new(200),
+            Liquidity::from_integer(10),
+            SecondsPerLiquidity::new(0),
+            SecondsPerLiquidity::from_integer(2),
+            Seconds::new(120),
+            15_000,
+        )
+        .unwrap();
+        assert_eq!(result, TokenAmount::new(300));
+        assert_eq!(seconds_inside, Seconds::new(30));
+
+        // boosted seconds over the seconds left take the whole reward
+        let (seconds_inside, result) = calculate_reward(
+            TokenAmount::new(1000),
+            Seconds::new(0),
+            Seconds::new(100),
+            Seconds::new(200),
+            Liquidity::from_integer(100),
+            SecondsPerLiquidity::from_integer(0),
+            SecondsPerLiquidity::from_integer(1),
+            Seconds::new(200),
+            20_000,
+        )
+        .unwrap();
+        assert_eq!(result, TokenAmount::new(1000));
+        assert_eq!(seconds_inside, Seconds::new(200));
+    }
+
+    #[test]
+    fn test_calculate_accrued_reward() {
+        // not started
+        {
+            let result = calculate_accrued_reward(
+                TokenAmount::new(1000),
+                Seconds::new(0),
+                Seconds::new(100),
+                Seconds::new(200),
+                Seconds::new(50),
+            );
+            assert_eq!(result, (Seconds::new(0), TokenAmount::new(0)));
+        }
+        // in the middle
+        {
+            let result = calculate_accrued_reward(
+                TokenAmount::new(1000),
+                Seconds::new(0),
+                Seconds::new(100),
+                Seconds::new(200),
+                Seconds::new(125),
+            );
+            assert_eq!(result, (Seconds::new(25), TokenAmount::new(250)));
+        }
+        // claimed seconds are already paid out
+        {
+            let result = calculate_accrued_reward(
+                TokenAmount::new(900),
+                Seconds::new(10),
+                Seconds::new(100),
+                Seconds::new(200),
+                Seconds::new(130),
+            );
+            assert_eq!(result, (Seconds::new(20), TokenAmount::new(200)));
+        }
+        // after end everything is accrued
+        {
+            let result = calculate_accrued_reward(
+                TokenAmount::new(1000),
+                Seconds::new(0),
+                Seconds::new(100),
+                Seconds::new(200),
+                Seconds::new(300),
+            );
+            assert_eq!(result, (Seconds::new(100), TokenAmount::new(1000)));
+        }
+    }
+
+    #[test]
+    fn test_calculate_accrued_part() {
+        // claim within the accrued seconds
+        {
+            let result =
+                calculate_accrued_part(TokenAmount::new(500), Seconds::new(50), Seconds::new(10));
+            assert_eq!(result, (Seconds::new(10), TokenAmount::new(100)));
+        }
+        // claim spanning the rate change takes the whole accrued reward
+        {
+            let result =
+                calculate_accrued_part(TokenAmount::new(500), Seconds::new(50), Seconds::new(60));
+            assert_eq!(result, (Seconds::new(50), TokenAmount::new(500)));
+        }
+        // no rate change so far
+        {
+            let result =
+                calculate_accrued_part(TokenAmount::new(0), Seconds::new(0), Seconds::new(60));
+            assert_eq!(result, (Seconds::new(0), TokenAmount::new(0)));
+        }
+    }
+
+    #[test]
+    fn test_calculate_final_reward() {
+        // same as the regular reward while the incentive is funded
+        {
+            let result = calculate_final_reward(
+                TokenAmount::new(1000),
+                Seconds::new(0),
+                Seconds::new(100),
+                Seconds::new(200),
+                Seconds::new(50),
+                Seconds::new(300),
+                false,
+            );
+            let (_, expected) = calculate_reward(
+                TokenAmount::new(1000),
+                Seconds::new(0),
+                Seconds::new(100),
+                Seconds::new(200),
+                Liquidity::from_integer(50),
+                SecondsPerLiquidity::from_integer(0),
+                SecondsPerLiquidity::from_integer(1),
+                Seconds::new(300),
+                BOOST_DENOMINATOR,
+            )
+            .unwrap();
+            assert_eq!(result, TokenAmount::new(250));
+            assert_eq!(result, expected);
+        }
+        // exactly at the exhaustion boundary
+        {
+            let result = calculate_final_reward(
+                TokenAmount::new(7),
+                Seconds::new(180),
+                Seconds::new(100),
+                Seconds::new(200),
+                Seconds::new(20),
+                Seconds::new(200),
+                false,
+            );
+            assert_eq!(result, TokenAmount::new(7));
+        }
+        // one second past the boundary, capped instead of failing
+        {
+            let result = calculate_final_reward(
+                TokenAmount::new(7),
+                Seconds::new(180),
+                Seconds::new(100),
+                Seconds::new(200),
+                Seconds::new(21),
+                Seconds::new(200),
+                false,
+            );
+            assert_eq!(result, TokenAmount::new(7));
+        }
+        // claimed seconds already exceed the duration
+        {
+            let result = calculate_final_reward(
+                TokenAmount::new(3),
+                Seconds::new(150),
+                Seconds::new(100),
+                Seconds::new(200),
+                Seconds::new(10),
+                Seconds::new(200),
+                false,
+            );
+            assert_eq!(result, TokenAmount::new(3));
+        }
+        // nothing left
+        {
+            let result = calculate_final_reward(
+                TokenAmount::new(0),
+                Seconds::new(100),
+                Seconds::new(100),
+                Seconds::new(200),
+                Seconds::new(10),
+                Seconds::new(200),
+                false,
+            );
+            assert_eq!(result, TokenAmount::new(0));
+        }
+        // last stake takes the rounding dust
+        {
+            let result = calculate_final_reward(
+                TokenAmount::new(5),
+                Seconds::new(90),
+                Seconds::new(100),
+                Seconds::new(200),
+                Seconds::new(1),
+                Seconds::new(200),
+                true,
+            );
+            assert_eq!(result, TokenAmount::new(5));
+        }
+        // but not the reward it did not earn
+        {
+            let result = calculate_final_reward(
+                TokenAmount::new(1_000_000),
+                Seconds::new(0),
+                Seconds::new(100),
+                Seconds::new(200),
+                Seconds::new(10),
+                Seconds::new(200),
+                true,
+            );
+            assert_eq!(
+                result,
+                TokenAmount::new(100_000 + LAST_STAKE_DUST_TOLERANCE)
+            );
+        }
+    }
+
+    #[test]
+    fn test_seconds_now_mocked() {
+        crate::util::set_mocked_timestamp(Some(1_650_000_000));
+        assert_eq!(Seconds::now(), Seconds::new(1_650_000_000));
+        crate::util::set_mocked_timestamp(None);
+    }
 }
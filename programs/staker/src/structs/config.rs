@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+#[account(zero_copy)]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug)]
+pub struct Config {
+    pub admin: Pubkey,
+    pub paused: bool, // blocks create_incentive and stake, never withdrawals
+    pub bump: u8,
+    pub reward_mint_allowlist: bool, // create_incentive accepts only mints with a RewardMint entry
+}
@@ -1,5 +1,20 @@
 use crate::decimals::{Seconds, TokenAmount};
+use crate::math::{
+    calculate_accrued_part, calculate_accrued_reward, calculate_final_reward,
+    calculate_reward_for_seconds,
+};
 use anchor_lang::prelude::*;
+use invariant::strict_assert;
+
+pub const MAX_EXTRA_REWARDS: usize = 2;
+
+#[zero_copy]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug)]
+pub struct ExtraReward {
+    pub token_account: Pubkey,
+    pub total_reward_unclaimed: TokenAmount,
+}
 
 #[account(zero_copy)]
 #[repr(packed)]
@@ -15,4 +30,172 @@ pub struct Incentive {
     pub num_of_stakes: u64,
     pub pool: Pubkey,
     pub nonce: u8,
+    // rewards in other mints distributed over the same period, each at its own rate
+    pub extra_rewards: [ExtraReward; MAX_EXTRA_REWARDS],
+    pub extra_rewards_count: u8,
+    // part of `total_reward_unclaimed` earned before the last reward rate change, paid out at
+    // the rate it was earned at for that many unclaimed seconds
+    pub accrued_reward: TokenAmount,
+    pub accrued_seconds: Seconds,
+}
+
+impl Incentive {
+    pub fn extra_rewards(&self) -> &[ExtraReward] {
+        &self.extra_rewards[..self.extra_rewards_count as usize]
+    }
+
+    // Moves the main reward earned so far at the current emission rate to the accrued reward,
+    // the rest of `total_reward_unclaimed` is paid over the rest of the incentive
+    pub fn checkpoint_reward(&mut self, current_time: Seconds) {
+        let (seconds, reward) = calculate_accrued_reward(
+            self.total_reward_unclaimed - self.accrued_reward,
+            self.total_seconds_claimed + self.accrued_seconds,
+            self.start_time,
+            self.end_time,
+            current_time,
+        );
+        self.accrued_reward = self.accrued_reward + reward;
+        self.accrued_seconds = self.accrued_seconds + seconds;
+    }
+
+    // Settles `seconds_inside` of a stake, returning the main and the extra rewards. Claims made
+    // after the incentive ended are settled against what is left, so the stake can always be closed.
+    pub fn settle(
+        &mut self,
+        seconds_inside: Seconds,
+        current_time: Seconds,
+    ) -> (TokenAmount, [TokenAmount; MAX_EXTRA_REWARDS]) {
+        let (start_time, end_time) = (self.start_time, self.end_time);
+        let ended = current_time > end_time;
+        let is_last_stake = self.num_of_stakes == 1;
+        let settle =
+            |reward_unclaimed: TokenAmount, seconds_claimed: Seconds, seconds: Seconds| match ended
+            {
+                true => calculate_final_reward(
+                    reward_unclaimed,
+                    seconds_claimed,
+                    start_time,
+                    end_time,
+                    seconds,
+                    current_time,
+                    is_last_stake,
+                ),
+                false => calculate_reward_for_seconds(
+                    reward_unclaimed,
+                    seconds_claimed,
+                    start_time,
+                    end_time,
+                    seconds,
+                    current_time,
+                ),
+            };
+
+        let (accrued_seconds, accrued_reward) =
+            calculate_accrued_part(self.accrued_reward, self.accrued_seconds, seconds_inside);
+        let reward = accrued_reward
+            + settle(
+                self.total_reward_unclaimed - self.accrued_reward,
+                self.total_seconds_claimed + self.accrued_seconds,
+                seconds_inside - accrued_seconds,
+            );
+        strict_assert!(
+            reward <= { self.total_reward_unclaimed },
+            "reward exceeds unclaimed incentive reward"
+        );
+        let mut extra_rewards = [TokenAmount::new(0); MAX_EXTRA_REWARDS];
+        for (i, extra_reward) in self.extra_rewards().iter().enumerate() {
+            let reward = settle(
+                extra_reward.total_reward_unclaimed,
+                self.total_seconds_claimed,
+                seconds_inside,
+            );
+            strict_assert!(
+                reward <= { extra_reward.total_reward_unclaimed },
+                "reward exceeds unclaimed extra reward"
+            );
+            extra_rewards[i] = reward;
+        }
+
+        self.total_seconds_claimed = Seconds::new(
+            self.total_seconds_claimed
+                .get()
+                .saturating_add(seconds_inside.get()),
+        );
+        self.accrued_seconds = self.accrued_seconds - accrued_seconds;
+        self.accrued_reward = self.accrued_reward - accrued_reward;
+        self.total_reward_unclaimed = self.total_reward_unclaimed - reward;
+        for (i, reward) in extra_rewards.iter().enumerate() {
+            let extra_reward = &mut self.extra_rewards[i];
+            extra_reward.total_reward_unclaimed = extra_reward.total_reward_unclaimed - *reward;
+        }
+        (reward, extra_rewards)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn incentive() -> Incentive {
+        Incentive {
+            total_reward_unclaimed: TokenAmount::new(1000),
+            start_time: Seconds::new(100),
+            end_time: Seconds::new(200),
+            num_of_stakes: 2,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_settle() {
+        let mut incentive = incentive();
+        let (reward, _) = incentive.settle(Seconds::new(20), Seconds::new(150));
+        assert_eq!(reward, TokenAmount::new(200));
+        assert_eq!({ incentive.total_reward_unclaimed }, TokenAmount::new(800));
+        assert_eq!({ incentive.total_seconds_claimed }, Seconds::new(20));
+    }
+
+    #[test]
+    fn test_withdraw_after_rate_change() {
+        // two stakes splitting the range equally earned 250 each by the half of the incentive
+        let mut incentive = incentive();
+        incentive.checkpoint_reward(Seconds::new(150));
+        assert_eq!({ incentive.accrued_reward }, TokenAmount::new(500));
+        assert_eq!({ incentive.accrued_seconds }, Seconds::new(50));
+
+        // rate lowered to 100 over the remaining 50 seconds
+        incentive.total_reward_unclaimed = incentive.accrued_reward + TokenAmount::new(100);
+
+        // what was earned before the change is paid in full
+        let (reward, _) = incentive.settle(Seconds::new(25), Seconds::new(150));
+        assert_eq!(reward, TokenAmount::new(250));
+
+        // 25 seconds before the change and 5 after it
+        let (reward, _) = incentive.settle(Seconds::new(30), Seconds::new(160));
+        assert_eq!(reward, TokenAmount::new(260));
+        assert_eq!({ incentive.accrued_reward }, TokenAmount::new(0));
+        assert_eq!({ incentive.accrued_seconds }, Seconds::new(0));
+        assert_eq!({ incentive.total_reward_unclaimed }, TokenAmount::new(90));
+
+        // the rest is paid at the new rate
+        let (reward, _) = incentive.settle(Seconds::new(20), Seconds::new(200));
+        assert_eq!(reward, TokenAmount::new(40));
+        assert_eq!({ incentive.total_reward_unclaimed }, TokenAmount::new(50));
+    }
+
+    #[test]
+    fn test_checkpoint_reward_twice() {
+        let mut incentive = incentive();
+        incentive.checkpoint_reward(Seconds::new(150));
+        incentive.total_reward_unclaimed = incentive.accrued_reward + TokenAmount::new(100);
+        // 10 more seconds at 2 per second
+        incentive.checkpoint_reward(Seconds::new(160));
+        assert_eq!({ incentive.accrued_reward }, TokenAmount::new(520));
+        assert_eq!({ incentive.accrued_seconds }, Seconds::new(60));
+
+        // a raised rate does not change what was earned before
+        incentive.total_reward_unclaimed = incentive.accrued_reward + TokenAmount::new(4000);
+        let (reward, _) = incentive.settle(Seconds::new(60), Seconds::new(160));
+        assert_eq!(reward, TokenAmount::new(520));
+    }
 }
@@ -1,5 +1,22 @@
+pub mod config;
 pub mod incentive;
+pub mod reward_mint;
 pub mod user_stake;
 
+pub use config::*;
 pub use incentive::*;
+pub use reward_mint::*;
 pub use user_stake::*;
+
+// Mirrored in invariant-types, which asserts the same sizes
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::mem::size_of;
+
+    #[test]
+    fn test_mirrored_layout_sizes() {
+        assert_eq!(size_of::<Incentive>(), 242);
+        assert_eq!(size_of::<UserStake>(), 107);
+    }
+}
@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+// Allowlist entry, only allowed mints have one. UIs check a mint by deriving the address
+// from [b"rewardmintv1", mint] and filter incentives the same way.
+#[account(zero_copy)]
+#[repr(packed)]
+#[derive(PartialEq, Default, Debug)]
+pub struct RewardMint {
+    pub mint: Pubkey,
+    pub bump: u8,
+}
@@ -1,5 +1,12 @@
-use crate::decimals::{Liquidity, SecondsPerLiquidity};
+use crate::decimals::{Liquidity, Seconds, SecondsPerLiquidity};
 use anchor_lang::prelude::*;
+
+pub const BOOST_DENOMINATOR: u16 = 10_000;
+const DAY: u64 = 86_400;
+// Lock periods accepted by stake with the reward weight they give, stakes without a lock
+// have the weight of BOOST_DENOMINATOR
+pub const LOCK_BOOSTS: [(u64, u16); 2] = [(30 * DAY, 15_000), (90 * DAY, 20_000)];
+
 #[account(zero_copy)]
 #[repr(packed)]
 #[derive(PartialEq, Default, Debug)]
@@ -9,4 +16,30 @@ pub struct UserStake {
     pub seconds_per_liquidity_initial: SecondsPerLiquidity,
     pub liquidity: Liquidity,
     pub bump: u8,
+    pub lock_end: Seconds, // rewards can't be withdrawn before
+    pub boost: u16,        // weight of the stake seconds, in BOOST_DENOMINATOR units
+}
+
+pub fn lock_boost(lock_period: Option<u64>) -> Option<u16> {
+    match lock_period {
+        None => Some(BOOST_DENOMINATOR),
+        Some(lock_period) => LOCK_BOOSTS
+            .iter()
+            .find(|(period, _)| *period == lock_period)
+            .map(|(_, boost)| *boost),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lock_boost() {
+        assert_eq!(lock_boost(None), Some(BOOST_DENOMINATOR));
+        assert_eq!(lock_boost(Some(30 * DAY)), Some(15_000));
+        assert_eq!(lock_boost(Some(90 * DAY)), Some(20_000));
+        assert_eq!(lock_boost(Some(0)), None);
+        assert_eq!(lock_boost(Some(60 * DAY)), None);
+    }
 }
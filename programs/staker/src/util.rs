@@ -3,8 +3,19 @@ use anchor_lang::__private::CLOSED_ACCOUNT_DISCRIMINATOR;
 use std::io::Write;
 
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_lang::solana_program::system_instruction;
+use anchor_lang::{AccountsExit, Discriminator};
+
+use crate::structs::UserStake;
 
 pub const STAKER_SEED: &str = "staker";
+pub const REWARD_MINT_SEED: &str = "rewardmintv1";
+
+// the clock is shared with the invariant program, so tests of both pin the time the same way
+pub use invariant::get_current_timestamp;
+#[cfg(test)]
+pub use invariant::set_mocked_timestamp;
 
 pub fn get_current_slot() -> u64 {
     Clock::get().unwrap().slot
@@ -14,6 +25,9 @@ pub fn close<'info>(
     info: AccountInfo<'info>,
     sol_destination: AccountInfo<'info>,
 ) -> ProgramResult {
+    // closing an account into itself would burn its lamports together with the data
+    require!(info.key != sol_destination.key, InvalidCloseDestination);
+
     // Transfer tokens from the account to the sol_destination.
     let dest_starting_lamports = sol_destination.lamports();
     **sol_destination.lamports.borrow_mut() =
@@ -29,3 +43,73 @@ pub fn close<'info>(
         .map_err(|_| ErrorCode::AccountDidNotSerialize)?;
     Ok(())
 }
+
+// Checks an account of the program still has the layout it was created with, accounts like
+// that can't be loaded until `realloc_account` grows them to the current one
+pub fn check_legacy_account<T: Discriminator>(
+    info: &AccountInfo,
+    program_id: &Pubkey,
+    legacy_size: usize,
+) -> ProgramResult {
+    if info.owner != program_id {
+        return Err(ErrorCode::AccountNotProgramOwned.into());
+    }
+    let data = info.try_borrow_data()?;
+    require!(data.len() == 8 + legacy_size, AccountAlreadyMigrated);
+    if data[..8] != T::discriminator() {
+        return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+    }
+    Ok(())
+}
+
+// Grows an account appended to since it was created, the payer tops it up to the new rent
+// exempt minimum. Added bytes are zeroed, so new fields have to be valid as zeros.
+pub fn realloc_account<'info>(
+    info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    len: usize,
+) -> ProgramResult {
+    let top_up = Rent::get()?
+        .minimum_balance(len)
+        .saturating_sub(info.lamports());
+    if top_up > 0 {
+        invoke(
+            &system_instruction::transfer(payer.key, info.key, top_up),
+            &[payer.clone(), info.clone(), system_program.clone()],
+        )?;
+    }
+    info.realloc(len, true)
+}
+
+// Creates the stake at its canonical address, derived from `seeds` of the incentive and
+// the position, so a position can be staked only once in an incentive
+pub fn create_user_stake<'info>(
+    program_id: &Pubkey,
+    user_stake: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    rent: &Rent,
+    seeds: &[&[u8]],
+    stake: UserStake,
+) -> ProgramResult {
+    require!(user_stake.data_is_empty(), StakeAlreadyExists);
+
+    let space = 8 + std::mem::size_of::<UserStake>();
+    invoke_signed(
+        &system_instruction::create_account(
+            payer.key,
+            user_stake.key,
+            rent.minimum_balance(space),
+            space as u64,
+            program_id,
+        ),
+        &[payer.clone(), user_stake.clone(), system_program.clone()],
+        &[seeds],
+    )?;
+
+    let loader = AccountLoader::<'info, UserStake>::try_from_unchecked(program_id, user_stake)?;
+    *loader.load_init()? = stake;
+    // writes the discriminator
+    loader.exit(program_id)
+}
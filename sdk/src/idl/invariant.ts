@@ -31,18 +31,13 @@ export type Invariant = {
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "nonce",
-          "type": "u8"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "createFeeTier",
+      "name": "createAdminLog",
       "accounts": [
         {
-          "name": "feeTier",
+          "name": "adminLog",
           "isMut": true,
           "isSigner": false
         },
@@ -67,74 +62,66 @@ export type Invariant = {
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "fee",
-          "type": "u128"
-        },
-        {
-          "name": "tickSpacing",
-          "type": "u16"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "createPool",
+      "name": "createGlobalStats",
       "accounts": [
         {
-          "name": "state",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "pool",
+          "name": "globalStats",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "feeTier",
+          "name": "state",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tickmap",
+          "name": "adminLog",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenX",
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenY",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createFeeTierRegistry",
+      "accounts": [
         {
-          "name": "tokenXReserve",
+          "name": "feeTierRegistry",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "tokenYReserve",
-          "isMut": true,
-          "isSigner": true
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "payer",
+          "name": "adminLog",
           "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "authority",
-          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
         },
         {
           "name": "rent",
@@ -147,144 +134,114 @@ export type Invariant = {
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "initTick",
-          "type": "i32"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "swap",
+      "name": "createFeeTier",
       "accounts": [
         {
-          "name": "state",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tickmap",
+          "name": "feeTier",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "accountX",
+          "name": "feeTierRegistry",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "accountY",
-          "isMut": true,
+          "name": "state",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "reserveX",
+          "name": "adminLog",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "reserveY",
+          "name": "admin",
           "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "owner",
-          "isMut": false,
           "isSigner": true
         },
         {
-          "name": "programAuthority",
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "xToY",
-          "type": "bool"
-        },
-        {
-          "name": "amount",
-          "type": "u64"
-        },
-        {
-          "name": "byAmountIn",
-          "type": "bool"
-        },
-        {
-          "name": "sqrtPriceLimit",
-          "type": "u128"
+          "name": "args",
+          "type": {
+            "defined": "CreateFeeTierArgs"
+          }
         }
       ]
     },
     {
-      "name": "initializeOracle",
+      "name": "listFeeTiers",
       "accounts": [
         {
-          "name": "pool",
-          "isMut": true,
+          "name": "feeTierRegistry",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createPool",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "oracle",
+          "name": "pool",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenX",
+          "name": "feeTier",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenY",
-          "isMut": false,
+          "name": "globalStats",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "payer",
-          "isMut": false,
-          "isSigner": true
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "tokenX",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "tokenY",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "createTick",
-      "accounts": [
-        {
-          "name": "tick",
-          "isMut": true,
-          "isSigner": false
         },
         {
-          "name": "pool",
-          "isMut": false,
-          "isSigner": false
+          "name": "tokenXReserve",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "tickmap",
+          "name": "tokenYReserve",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
           "name": "payer",
@@ -292,12 +249,12 @@ export type Invariant = {
           "isSigner": true
         },
         {
-          "name": "tokenX",
+          "name": "authority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenY",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -314,87 +271,58 @@ export type Invariant = {
       ],
       "args": [
         {
-          "name": "index",
-          "type": "i32"
+          "name": "args",
+          "type": {
+            "defined": "CreatePoolArgs"
+          }
         }
       ]
     },
     {
-      "name": "createPositionList",
+      "name": "initPoolAndPosition",
       "accounts": [
         {
-          "name": "positionList",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "owner",
+          "name": "state",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "signer",
+          "name": "pool",
           "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "rent",
-          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "createPosition",
-      "accounts": [
-        {
-          "name": "state",
+          "name": "feeTier",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "position",
+          "name": "globalStats",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "pool",
+          "name": "tickmap",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "positionList",
+          "name": "lowerTick",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "lowerTick",
+          "name": "upperTick",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "upperTick",
+          "name": "position",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tickmap",
+          "name": "positionList",
           "isMut": true,
           "isSigner": false
         },
@@ -408,6 +336,16 @@ export type Invariant = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "tokenXReserve",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenYReserve",
+          "isMut": true,
+          "isSigner": true
+        },
         {
           "name": "accountX",
           "isMut": true,
@@ -419,17 +357,27 @@ export type Invariant = {
           "isSigner": false
         },
         {
-          "name": "reserveX",
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "positionNftMint",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "reserveY",
+          "name": "positionNftAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "programAuthority",
+          "name": "authority",
           "isMut": false,
           "isSigner": false
         },
@@ -439,47 +387,32 @@ export type Invariant = {
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "associatedTokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "lowerTickIndex",
-          "type": "i32"
-        },
-        {
-          "name": "upperTickIndex",
-          "type": "i32"
-        },
-        {
-          "name": "liquidityDelta",
-          "type": {
-            "defined": "Liquidity"
-          }
-        },
-        {
-          "name": "slippageLimitLower",
-          "type": {
-            "defined": "Price"
-          }
-        },
-        {
-          "name": "slippageLimitUpper",
+          "name": "args",
           "type": {
-            "defined": "Price"
+            "defined": "InitPoolAndPositionArgs"
           }
         }
       ]
     },
     {
-      "name": "removePosition",
+      "name": "createPoolsBatch",
       "accounts": [
         {
           "name": "state",
@@ -487,53 +420,61 @@ export type Invariant = {
           "isSigner": false
         },
         {
-          "name": "removedPosition",
+          "name": "globalStats",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "positionList",
+          "name": "payer",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "lastPosition",
-          "isMut": true,
+          "name": "authority",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "pool",
-          "isMut": true,
+          "name": "tokenProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tickmap",
-          "isMut": true,
+          "name": "rent",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "lowerTick",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "upperTick",
-          "isMut": true,
+          "name": "initTicks",
+          "type": {
+            "vec": "i32"
+          }
+        }
+      ]
+    },
+    {
+      "name": "swap",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "owner",
+          "name": "pool",
           "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "tokenX",
-          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenY",
-          "isMut": false,
+          "name": "tickmap",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -556,6 +497,11 @@ export type Invariant = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
         {
           "name": "programAuthority",
           "isMut": false,
@@ -569,77 +515,78 @@ export type Invariant = {
       ],
       "args": [
         {
-          "name": "index",
-          "type": "u32"
-        },
-        {
-          "name": "lowerTickIndex",
-          "type": "i32"
-        },
-        {
-          "name": "upperTickIndex",
-          "type": "i32"
+          "name": "args",
+          "type": {
+            "defined": "SwapArgs"
+          }
         }
       ]
     },
     {
-      "name": "transferPositionOwnership",
+      "name": "swapBounded",
       "accounts": [
         {
-          "name": "ownerList",
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "recipientList",
+          "name": "tickmap",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "newPosition",
+          "name": "accountX",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "removedPosition",
+          "name": "accountY",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "lastPosition",
+          "name": "reserveX",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "owner",
+          "name": "reserveY",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "recipient",
+          "name": "owner",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "rent",
+          "name": "programAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "index",
-          "type": "u32"
+          "name": "args",
+          "type": {
+            "defined": "SwapBoundedArgs"
+          }
         }
       ]
     },
     {
-      "name": "claimFee",
+      "name": "simulateSwap",
       "accounts": [
         {
           "name": "state",
@@ -652,17 +599,27 @@ export type Invariant = {
           "isSigner": false
         },
         {
-          "name": "position",
+          "name": "tickmap",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "lowerTick",
+          "name": "accountX",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "upperTick",
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
           "isMut": true,
           "isSigner": false
         },
@@ -672,34 +629,47 @@ export type Invariant = {
           "isSigner": true
         },
         {
-          "name": "tokenX",
+          "name": "programAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenY",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "accountX",
-          "isMut": true,
+          "name": "args",
+          "type": {
+            "defined": "SwapArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "swapRoute",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "accountY",
+          "name": "accountIn",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "reserveX",
+          "name": "accountOut",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "reserveY",
-          "isMut": true,
-          "isSigner": false
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
         },
         {
           "name": "programAuthority",
@@ -714,21 +684,15 @@ export type Invariant = {
       ],
       "args": [
         {
-          "name": "index",
-          "type": "u32"
-        },
-        {
-          "name": "lowerTickIndex",
-          "type": "i32"
-        },
-        {
-          "name": "upperTickIndex",
-          "type": "i32"
+          "name": "args",
+          "type": {
+            "defined": "SwapRouteArgs"
+          }
         }
       ]
     },
     {
-      "name": "updateSecondsPerLiquidity",
+      "name": "initializeOracle",
       "accounts": [
         {
           "name": "pool",
@@ -736,37 +700,53 @@ export type Invariant = {
           "isSigner": false
         },
         {
-          "name": "lowerTick",
-          "isMut": false,
+          "name": "oracle",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "upperTick",
+          "name": "tokenX",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "position",
-          "isMut": true,
+          "name": "tokenY",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenX",
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenY",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createPriceFeed",
+      "accounts": [
+        {
+          "name": "priceFeed",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "owner",
+          "name": "pool",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "signer",
+          "name": "payer",
           "isMut": true,
           "isSigner": true
         },
@@ -783,74 +763,108 @@ export type Invariant = {
       ],
       "args": [
         {
-          "name": "lowerTickIndex",
-          "type": "i32"
-        },
-        {
-          "name": "upperTickIndex",
-          "type": "i32"
-        },
-        {
-          "name": "index",
-          "type": "i32"
+          "name": "window",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "withdrawProtocolFee",
+      "name": "updatePriceFeed",
       "accounts": [
         {
-          "name": "state",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "pool",
+          "name": "priceFeed",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenX",
+          "name": "pool",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenY",
+          "name": "oracle",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createTick",
+      "accounts": [
         {
-          "name": "accountX",
+          "name": "tick",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "accountY",
-          "isMut": true,
+          "name": "pool",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "reserveX",
+          "name": "tickmap",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "reserveY",
+          "name": "payer",
           "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "authority",
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "i32"
+        }
+      ]
+    },
+    {
+      "name": "createPositionList",
+      "accounts": [
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
           "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "signer",
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "programAuthority",
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
@@ -858,18 +872,53 @@ export type Invariant = {
       "args": []
     },
     {
-      "name": "changeProtocolFee",
+      "name": "createPosition",
       "accounts": [
         {
           "name": "state",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "pool",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "tokenX",
           "isMut": false,
@@ -881,38 +930,138 @@ export type Invariant = {
           "isSigner": false
         },
         {
-          "name": "admin",
-          "isMut": false,
-          "isSigner": true
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": true,
+          "isSigner": false
         },
         {
           "name": "programAuthority",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "protocolFee",
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "liquidityDelta",
           "type": {
-            "defined": "FixedPoint"
+            "defined": "Liquidity"
+          }
+        },
+        {
+          "name": "slippageLimitLower",
+          "type": {
+            "defined": "Price"
+          }
+        },
+        {
+          "name": "slippageLimitUpper",
+          "type": {
+            "defined": "Price"
           }
         }
       ]
     },
     {
-      "name": "changeFeeReceiver",
+      "name": "simulateCreatePosition",
       "accounts": [
         {
           "name": "state",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "pool",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "tokenX",
           "isMut": false,
@@ -924,38 +1073,9897 @@ export type Invariant = {
           "isSigner": false
         },
         {
-          "name": "admin",
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "feeReceiver",
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": []
-    }
-  ],
-  "accounts": [
-    {
-      "name": "feeTier",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "fee",
-            "type": {
-              "defined": "FixedPoint"
-            }
-          },
+      "args": [
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "liquidityDelta",
+          "type": {
+            "defined": "Liquidity"
+          }
+        },
+        {
+          "name": "slippageLimitLower",
+          "type": {
+            "defined": "Price"
+          }
+        },
+        {
+          "name": "slippageLimitUpper",
+          "type": {
+            "defined": "Price"
+          }
+        }
+      ]
+    },
+    {
+      "name": "migrateFromExternal",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "amountX",
+          "type": "u64"
+        },
+        {
+          "name": "amountY",
+          "type": "u64"
+        },
+        {
+          "name": "slippageLimitLower",
+          "type": {
+            "defined": "Price"
+          }
+        },
+        {
+          "name": "slippageLimitUpper",
+          "type": {
+            "defined": "Price"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createLimitOrder",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "limitOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "liquidityDelta",
+          "type": {
+            "defined": "Liquidity"
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimLimitOrder",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "limitOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "exitTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "entryTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelLimitOrder",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "limitOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "removePosition",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "removedPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lastPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "holder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "positionNftMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        },
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        }
+      ]
+    },
+    {
+      "name": "decreaseLiquidity",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "holder",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        },
+        {
+          "name": "liquidityDelta",
+          "type": {
+            "defined": "Liquidity"
+          }
+        }
+      ]
+    },
+    {
+      "name": "increaseLiquidity",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "holder",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        },
+        {
+          "name": "liquidityDelta",
+          "type": {
+            "defined": "Liquidity"
+          }
+        },
+        {
+          "name": "slippageLimitLower",
+          "type": {
+            "defined": "Price"
+          }
+        },
+        {
+          "name": "slippageLimitUpper",
+          "type": {
+            "defined": "Price"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferPositionOwnership",
+      "accounts": [
+        {
+          "name": "ownerList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipientList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "removedPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lastPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "mintPositionNft",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "createPositionNftMetadata",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "holder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        },
+        {
+          "name": "baseUri",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "claimFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "holder",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        },
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        }
+      ]
+    },
+    {
+      "name": "claimFeeSide",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "holder",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        },
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "claimX",
+          "type": "bool"
+        },
+        {
+          "name": "claimY",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "claimFeeBatch",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "holder",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "positionsCount",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createSessionAuthority",
+      "accounts": [
+        {
+          "name": "sessionAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sessionKey",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "permissions",
+          "type": "u8"
+        },
+        {
+          "name": "expiry",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "revokeSessionAuthority",
+      "accounts": [
+        {
+          "name": "sessionAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createReferralEarnings",
+      "accounts": [
+        {
+          "name": "referralEarnings",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "referrer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimReferralFees",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "referralEarnings",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateSecondsPerLiquidity",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "index",
+          "type": "i32"
+        }
+      ]
+    },
+    {
+      "name": "withdrawProtocolFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "convertProtocolFees",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "targetIsX",
+          "type": "bool"
+        },
+        {
+          "name": "sqrtPriceLimit",
+          "type": "u128"
+        }
+      ]
+    },
+    {
+      "name": "changeProtocolFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "protocolFee",
+          "type": {
+            "defined": "FixedPoint"
+          }
+        }
+      ]
+    },
+    {
+      "name": "changeFeeReceiver",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "feeReceiver",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createWithdrawAcl",
+      "accounts": [
+        {
+          "name": "withdrawAcl",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setWithdrawer",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "withdrawAcl",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "withdrawer",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "allowed",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "createLaunchConfig",
+      "accounts": [
+        {
+          "name": "launchConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "durationSlots",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setLaunchWhitelisted",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "launchConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "user",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "whitelisted",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "createRebateSchedule",
+      "accounts": [
+        {
+          "name": "rebateSchedule",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setRebateSchedule",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rebateSchedule",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetRebateScheduleArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "proposePoolFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "fee",
+          "type": {
+            "defined": "FixedPoint"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updatePoolFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "proposeAdmin",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAdmin",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "acceptAdmin",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAdmin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateState",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migratePool",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXReserve",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYReserve",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migratePosition",
+      "accounts": [
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateOracle",
+      "accounts": [
+        {
+          "name": "oracle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateTick",
+      "accounts": [
+        {
+          "name": "tick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "forceCloseOrphanPosition",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "removedPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lastPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "holder",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        },
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        }
+      ]
+    },
+    {
+      "name": "changeTickEmaHalfLife",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "halfLife",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "setPoolHook",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "hookProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setMaxPriceChange",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "maxPriceChangeBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setWithdrawalOnly",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "withdrawalOnly",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "pause",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "unpause",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setReferralFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "referralFeeBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "skimExcess",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setFlashLoanFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "flashLoanFeeBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setLargeSwapThreshold",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "thresholdX",
+          "type": "u64"
+        },
+        {
+          "name": "thresholdY",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "flashLoan",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "borrower",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amountX",
+          "type": "u64"
+        },
+        {
+          "name": "amountY",
+          "type": "u64"
+        },
+        {
+          "name": "data",
+          "type": "bytes"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "adminLog",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "head",
+            "type": "u64"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "array": [
+                {
+                  "defined": "AdminLogEntry"
+                },
+                16
+              ]
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeTierRegistry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "count",
+            "type": "u16"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "array": [
+                {
+                  "defined": "FeeTierEntry"
+                },
+                32
+              ]
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "globalStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "totalPools",
+            "type": "u64"
+          },
+          {
+            "name": "totalSwaps",
+            "type": "u64"
+          },
+          {
+            "name": "protocolFees",
+            "type": {
+              "array": [
+                {
+                  "defined": "MintProtocolFee"
+                },
+                16
+              ]
+            }
+          },
+          {
+            "name": "protocolFeesCount",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "launchConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "whitelist",
+            "type": {
+              "array": [
+                "publicKey",
+                16
+              ]
+            }
+          },
+          {
+            "name": "whitelistCount",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "state",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "publicKey"
+          },
+          {
+            "name": "nonce",
+            "type": "u8"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "pendingAdmin",
+            "type": "publicKey"
+          },
+          {
+            "name": "isPaused",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeTier",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "fee",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "tickSpacing",
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pool",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenX",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenY",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenXReserve",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenYReserve",
+            "type": "publicKey"
+          },
+          {
+            "name": "positionIterator",
+            "type": "u128"
+          },
+          {
+            "name": "tickSpacing",
+            "type": "u16"
+          },
+          {
+            "name": "fee",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "protocolFee",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "liquidity",
+            "type": {
+              "defined": "Liquidity"
+            }
+          },
+          {
+            "name": "sqrtPrice",
+            "type": {
+              "defined": "Price"
+            }
+          },
+          {
+            "name": "currentTickIndex",
+            "type": "i32"
+          },
+          {
+            "name": "tickmap",
+            "type": "publicKey"
+          },
+          {
+            "name": "feeGrowthGlobalX",
+            "type": {
+              "defined": "FeeGrowth"
+            }
+          },
+          {
+            "name": "feeGrowthGlobalY",
+            "type": {
+              "defined": "FeeGrowth"
+            }
+          },
+          {
+            "name": "feeProtocolTokenX",
+            "type": "u64"
+          },
+          {
+            "name": "feeProtocolTokenY",
+            "type": "u64"
+          },
+          {
+            "name": "secondsPerLiquidityGlobal",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "startTimestamp",
+            "type": "u64"
+          },
+          {
+            "name": "lastTimestamp",
+            "type": "u64"
+          },
+          {
+            "name": "feeReceiver",
+            "type": "publicKey"
+          },
+          {
+            "name": "oracleAddress",
+            "type": "publicKey"
+          },
+          {
+            "name": "oracleInitialized",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "feeTierFee",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "pendingFee",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "pendingFeeTimestamp",
+            "type": "u64"
+          },
+          {
+            "name": "tickEma",
+            "type": "i64"
+          },
+          {
+            "name": "tickEmaHalfLife",
+            "type": "u32"
+          },
+          {
+            "name": "tickEmaLastTimestamp",
+            "type": "u64"
+          },
+          {
+            "name": "hookProgram",
+            "type": "publicKey"
+          },
+          {
+            "name": "maxPriceChangeBpsPerTx",
+            "type": "u16"
+          },
+          {
+            "name": "withdrawalOnly",
+            "type": "bool"
+          },
+          {
+            "name": "launchEndSlot",
+            "type": "u64"
+          },
+          {
+            "name": "referralFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "reserveX",
+            "type": "u64"
+          },
+          {
+            "name": "reserveY",
+            "type": "u64"
+          },
+          {
+            "name": "flashLoanFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "flashLoansEnabled",
+            "type": "bool"
+          },
+          {
+            "name": "largeSwapThresholdX",
+            "type": "u64"
+          },
+          {
+            "name": "largeSwapThresholdY",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "position",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "id",
+            "type": "u128"
+          },
+          {
+            "name": "liquidity",
+            "type": {
+              "defined": "Liquidity"
+            }
+          },
+          {
+            "name": "lowerTickIndex",
+            "type": "i32"
+          },
+          {
+            "name": "upperTickIndex",
+            "type": "i32"
+          },
+          {
+            "name": "feeGrowthInsideX",
+            "type": {
+              "defined": "FeeGrowth"
+            }
+          },
+          {
+            "name": "feeGrowthInsideY",
+            "type": {
+              "defined": "FeeGrowth"
+            }
+          },
+          {
+            "name": "secondsPerLiquidityInside",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "lastSlot",
+            "type": "u64"
+          },
+          {
+            "name": "tokensOwedX",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "tokensOwedY",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "makerFeesX",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "makerFeesY",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "totalClaimedX",
+            "type": "u64"
+          },
+          {
+            "name": "totalClaimedY",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "positionList",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "head",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "tick",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "index",
+            "type": "i32"
+          },
+          {
+            "name": "sign",
+            "type": "bool"
+          },
+          {
+            "name": "liquidityChange",
+            "type": {
+              "defined": "Liquidity"
+            }
+          },
+          {
+            "name": "liquidityGross",
+            "type": {
+              "defined": "Liquidity"
+            }
+          },
+          {
+            "name": "sqrtPrice",
+            "type": {
+              "defined": "Price"
+            }
+          },
+          {
+            "name": "feeGrowthOutsideX",
+            "type": {
+              "defined": "FeeGrowth"
+            }
+          },
+          {
+            "name": "feeGrowthOutsideY",
+            "type": {
+              "defined": "FeeGrowth"
+            }
+          },
+          {
+            "name": "secondsPerLiquidityOutside",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "secondsOutside",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "limitOrderLiquidityBelow",
+            "type": {
+              "defined": "Liquidity"
+            }
+          },
+          {
+            "name": "limitOrderLiquidityAbove",
+            "type": {
+              "defined": "Liquidity"
+            }
+          },
+          {
+            "name": "limitOrderFillsBelow",
+            "type": "u64"
+          },
+          {
+            "name": "limitOrderFillsAbove",
+            "type": "u64"
+          },
+          {
+            "name": "limitOrders",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "tickmap",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bitmap",
+            "type": {
+              "array": [
+                "u8",
+                11091
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "oracle",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "data",
+            "type": {
+              "array": [
+                {
+                  "defined": "Record"
+                },
+                256
+              ]
+            }
+          },
+          {
+            "name": "head",
+            "type": "u16"
+          },
+          {
+            "name": "amount",
+            "type": "u16"
+          },
+          {
+            "name": "size",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "priceFeed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenX",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenY",
+            "type": "publicKey"
+          },
+          {
+            "name": "window",
+            "type": "u64"
+          },
+          {
+            "name": "sqrtPrice",
+            "type": {
+              "defined": "Price"
+            }
+          },
+          {
+            "name": "price",
+            "type": {
+              "defined": "Price"
+            }
+          },
+          {
+            "name": "liquidity",
+            "type": {
+              "defined": "Liquidity"
+            }
+          },
+          {
+            "name": "publishTime",
+            "type": "u64"
+          },
+          {
+            "name": "publishSlot",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "limitOrder",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "id",
+            "type": "u128"
+          },
+          {
+            "name": "liquidity",
+            "type": {
+              "defined": "Liquidity"
+            }
+          },
+          {
+            "name": "lowerTickIndex",
+            "type": "i32"
+          },
+          {
+            "name": "upperTickIndex",
+            "type": "i32"
+          },
+          {
+            "name": "xToY",
+            "type": "bool"
+          },
+          {
+            "name": "fills",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rebateSchedule",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tiers",
+            "type": {
+              "array": [
+                {
+                  "defined": "RebateTier"
+                },
+                8
+              ]
+            }
+          },
+          {
+            "name": "tiersCount",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "referralEarnings",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "referrer",
+            "type": "publicKey"
+          },
+          {
+            "name": "amountX",
+            "type": "u64"
+          },
+          {
+            "name": "amountY",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sessionAuthority",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "sessionKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "permissions",
+            "type": "u8"
+          },
+          {
+            "name": "expiry",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "withdrawAcl",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "withdrawers",
+            "type": {
+              "array": [
+                "publicKey",
+                8
+              ]
+            }
+          },
+          {
+            "name": "withdrawersCount",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "AdminLogEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "actor",
+            "type": "publicKey"
+          },
+          {
+            "name": "action",
+            "type": "u8"
+          },
+          {
+            "name": "target",
+            "type": "publicKey"
+          },
+          {
+            "name": "oldValue",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "newValue",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreateFeeTierArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "fee",
+            "type": "u128"
+          },
+          {
+            "name": "tickSpacing",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreatePoolArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "initTick",
+            "type": "i32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitPoolAndPositionArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "initTick",
+            "type": "i32"
+          },
+          {
+            "name": "lowerTickIndex",
+            "type": "i32"
+          },
+          {
+            "name": "upperTickIndex",
+            "type": "i32"
+          },
+          {
+            "name": "liquidityDelta",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "direction",
+            "type": {
+              "defined": "Direction"
+            }
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "amountSpec",
+            "type": {
+              "defined": "AmountSpec"
+            }
+          },
+          {
+            "name": "sqrtPriceLimit",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapBoundedArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "direction",
+            "type": {
+              "defined": "Direction"
+            }
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "amountSpec",
+            "type": {
+              "defined": "AmountSpec"
+            }
+          },
+          {
+            "name": "sqrtPriceLimit",
+            "type": "u128"
+          },
+          {
+            "name": "tickLimit",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RouteHop",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "direction",
+            "type": {
+              "defined": "Direction"
+            }
+          },
+          {
+            "name": "sqrtPriceLimit",
+            "type": "u128"
+          },
+          {
+            "name": "tickAccounts",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapRouteArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amountIn",
+            "type": "u64"
+          },
+          {
+            "name": "minAmountOut",
+            "type": "u64"
+          },
+          {
+            "name": "hops",
+            "type": {
+              "vec": {
+                "defined": "RouteHop"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RebateTierArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minMakerFees",
+            "type": "u64"
+          },
+          {
+            "name": "rebate",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetRebateScheduleArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tiers",
+            "type": {
+              "vec": {
+                "defined": "RebateTierArgs"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapReturnData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lpFee",
+            "type": "u64"
+          },
+          {
+            "name": "protocolFee",
+            "type": "u64"
+          },
+          {
+            "name": "referralFee",
+            "type": "u64"
+          },
+          {
+            "name": "priceBefore",
+            "type": "u128"
+          },
+          {
+            "name": "priceAfter",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeTierReturnData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "fee",
+            "type": "u128"
+          },
+          {
+            "name": "tickSpacing",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ListFeeTiersReturnData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeTiers",
+            "type": {
+              "vec": {
+                "defined": "FeeTierReturnData"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "Price",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "v",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Liquidity",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "v",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeGrowth",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "v",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FixedPoint",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "v",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeTierEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "fee",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "tickSpacing",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FlashLoanCallback",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "amountX",
+            "type": "u64"
+          },
+          {
+            "name": "amountY",
+            "type": "u64"
+          },
+          {
+            "name": "feeX",
+            "type": "u64"
+          },
+          {
+            "name": "feeY",
+            "type": "u64"
+          },
+          {
+            "name": "data",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MintProtocolFee",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Record",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "timestamp",
+            "type": "u64"
+          },
+          {
+            "name": "sqrtPrice",
+            "type": {
+              "defined": "Price"
+            }
+          },
+          {
+            "name": "liquidity",
+            "type": {
+              "defined": "Liquidity"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RebateTier",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minMakerFees",
+            "type": "u64"
+          },
+          {
+            "name": "rebate",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "TickCrossed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "tick",
+            "type": "i32"
+          },
+          {
+            "name": "xToY",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AdminAction",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "CreateFeeTier"
+          },
+          {
+            "name": "ChangeFeeReceiver"
+          },
+          {
+            "name": "CreateWithdrawAcl"
+          },
+          {
+            "name": "SetWithdrawer"
+          },
+          {
+            "name": "ProposePoolFee"
+          },
+          {
+            "name": "UpdatePoolFee"
+          },
+          {
+            "name": "ChangeTickEmaHalfLife"
+          },
+          {
+            "name": "ForceCloseOrphanPosition"
+          },
+          {
+            "name": "SetPoolHook"
+          },
+          {
+            "name": "SetMaxPriceChange"
+          },
+          {
+            "name": "SetWithdrawalOnly"
+          },
+          {
+            "name": "CreateRebateSchedule"
+          },
+          {
+            "name": "SetRebateSchedule"
+          },
+          {
+            "name": "CreateLaunchConfig"
+          },
+          {
+            "name": "SetLaunchWhitelisted"
+          },
+          {
+            "name": "SetReferralFee"
+          },
+          {
+            "name": "SkimExcess"
+          },
+          {
+            "name": "SetFlashLoanFee"
+          },
+          {
+            "name": "ProposeAdmin"
+          },
+          {
+            "name": "AcceptAdmin"
+          },
+          {
+            "name": "SetPaused"
+          },
+          {
+            "name": "SetLargeSwapThreshold"
+          },
+          {
+            "name": "ChangeProtocolFee"
+          },
+          {
+            "name": "CreateGlobalStats"
+          },
+          {
+            "name": "CreateFeeTierRegistry"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Direction",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "YtoX"
+          },
+          {
+            "name": "XtoY"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AmountSpec",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Out"
+          },
+          {
+            "name": "In"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "ChangeFeeReceiverEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "oldFeeReceiver",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newFeeReceiver",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChangeProtocolFeeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "oldProtocolFee",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "newProtocolFee",
+          "type": "u128",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CreatePoolEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenX",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenY",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "fee",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "tickSpacing",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "initTick",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "sqrtPrice",
+          "type": "u128",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ClaimFeeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "position",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "liquidity",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "feeGrowthInsideXBefore",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "feeGrowthInsideYBefore",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "feeGrowthInsideXAfter",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "feeGrowthInsideYAfter",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "feeGrowthGlobalX",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "feeGrowthGlobalY",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "protocolFee",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "feeX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeY",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "rebateX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "rebateY",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CreatePositionEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "position",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "id",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "lowerTickIndex",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "liquidity",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "feeGrowthInsideX",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "feeGrowthInsideY",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "secondsPerLiquidityInside",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "lastSlot",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "sqrtPrice",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "currentTickIndex",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RemovePositionEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "position",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "liquidity",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "feeGrowthInsideXBefore",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "feeGrowthInsideYBefore",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "feeGrowthInsideXAfter",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "feeGrowthInsideYAfter",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "feeGrowthGlobalX",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "feeGrowthGlobalY",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "protocolFee",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeY",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "DecreaseLiquidityEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "position",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "liquidityDelta",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "liquidityAfter",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "IncreaseLiquidityEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "position",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "liquidityDelta",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "liquidityAfter",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ForceCloseOrphanPositionEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "position",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "liquidity",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "lowerTickMissing",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "upperTickMissing",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeY",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransferPositionOwnershipEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "id",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "oldOwner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newOwner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "liquidity",
+          "type": "u128",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SwapEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "xToY",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "amountIn",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountOut",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "fee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "protocolFee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "referralFee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "priceBefore",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "priceAfter",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "liquidityBefore",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "liquidityAfter",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "currentTickIndex",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "ticksCrossed",
+          "type": "u16",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CrossTickEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "index",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "xToY",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "sign",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "liquidityChange",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "liquidityAfter",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "sqrtPrice",
+          "type": "u128",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "FillLimitOrdersEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "index",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "xToY",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "liquidity",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "fills",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CreateLimitOrderEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "limitOrder",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lowerTickIndex",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "xToY",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "liquidity",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CloseLimitOrderEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "limitOrder",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "filled",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SwapBoundedEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "xToY",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "amountIn",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountOut",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "ticksCrossed",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "tickLimit",
+          "type": "u16",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LaunchWindowSetEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "endSlot",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LaunchWindowEndedEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "endSlot",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SkimExcessEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ClaimReferralFeesEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "referrer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "FlashLoanEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "borrower",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeY",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ProposeAdminEvent",
+      "fields": [
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pendingAdmin",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChangeAdminEvent",
+      "fields": [
+        {
+          "name": "oldAdmin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newAdmin",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "WithdrawProtocolFeeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LargeSwapEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "xToY",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "amountIn",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountOut",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "ZeroAmount",
+      "msg": "Amount is zero"
+    },
+    {
+      "code": 6001,
+      "name": "ZeroOutput",
+      "msg": "Output would be zero"
+    },
+    {
+      "code": 6002,
+      "name": "WrongTick",
+      "msg": "Not the expected tick"
+    },
+    {
+      "code": 6003,
+      "name": "WrongLimit",
+      "msg": "Price limit is on the wrong side of price"
+    },
+    {
+      "code": 6004,
+      "name": "InvalidTickIndex",
+      "msg": "Tick index not divisible by spacing or over limit"
+    },
+    {
+      "code": 6005,
+      "name": "InvalidTickInterval",
+      "msg": "Invalid tick_lower or tick_upper"
+    },
+    {
+      "code": 6006,
+      "name": "NoMoreTicks",
+      "msg": "There is no more tick in that direction"
+    },
+    {
+      "code": 6007,
+      "name": "TickNotFound",
+      "msg": "Correct tick not found in context"
+    },
+    {
+      "code": 6008,
+      "name": "PriceLimitReached",
+      "msg": "Price would cross swap limit"
+    },
+    {
+      "code": 6009,
+      "name": "InvalidTickLiquidity",
+      "msg": "Invalid tick liquidity"
+    },
+    {
+      "code": 6010,
+      "name": "EmptyPositionPokes",
+      "msg": "Disable empty position pokes"
+    },
+    {
+      "code": 6011,
+      "name": "InvalidPositionLiquidity",
+      "msg": "Invalid tick liquidity"
+    },
+    {
+      "code": 6012,
+      "name": "InvalidPoolLiquidity",
+      "msg": "Invalid pool liquidity"
+    },
+    {
+      "code": 6013,
+      "name": "InvalidPositionIndex",
+      "msg": "Invalid position index"
+    },
+    {
+      "code": 6014,
+      "name": "PositionWithoutLiquidity",
+      "msg": "Position liquidity would be zero"
+    },
+    {
+      "code": 6015,
+      "name": "Unauthorized",
+      "msg": "You are not admin"
+    },
+    {
+      "code": 6016,
+      "name": "InvalidPoolTokenAddresses",
+      "msg": "Invalid pool token addresses"
+    },
+    {
+      "code": 6017,
+      "name": "NegativeTime",
+      "msg": "Time cannot be negative"
+    },
+    {
+      "code": 6018,
+      "name": "OracleAlreadyInitialized",
+      "msg": "Oracle is already initialized"
+    },
+    {
+      "code": 6019,
+      "name": "LimitReached",
+      "msg": "Absolute price limit was reached"
+    },
+    {
+      "code": 6020,
+      "name": "InvalidProtocolFee",
+      "msg": "Invalid protocol fee"
+    },
+    {
+      "code": 6021,
+      "name": "NoGainSwap",
+      "msg": "Swap amount out is 0"
+    },
+    {
+      "code": 6022,
+      "name": "InvalidTokenAccount",
+      "msg": "Provided token account is different than expected"
+    },
+    {
+      "code": 6023,
+      "name": "InvalidAdmin",
+      "msg": "Admin address is different than expected"
+    },
+    {
+      "code": 6024,
+      "name": "InvalidAuthority",
+      "msg": "Provided authority is different than expected"
+    },
+    {
+      "code": 6025,
+      "name": "InvalidOwner",
+      "msg": "Provided token owner is different than expected"
+    },
+    {
+      "code": 6026,
+      "name": "InvalidMint",
+      "msg": "Provided token account mint is different than expected mint token"
+    },
+    {
+      "code": 6027,
+      "name": "InvalidTickmap",
+      "msg": "Provided tickmap is different than expected"
+    },
+    {
+      "code": 6028,
+      "name": "InvalidTickmapOwner",
+      "msg": "Provided tickmap owner is different than program ID"
+    },
+    {
+      "code": 6029,
+      "name": "InvalidListOwner",
+      "msg": "Recipient list address and owner list address should be different"
+    },
+    {
+      "code": 6030,
+      "name": "InvalidTickSpacing",
+      "msg": "Invalid tick spacing"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidBatchSize",
+      "msg": "Invalid number of pools in batch"
+    },
+    {
+      "code": 6032,
+      "name": "InvalidPoolAddress",
+      "msg": "Provided pool address is different than expected"
+    },
+    {
+      "code": 6033,
+      "name": "InvalidFeeTier",
+      "msg": "Provided fee tier is different than expected"
+    },
+    {
+      "code": 6034,
+      "name": "InvalidPoolFee",
+      "msg": "Pool fee is out of bounds"
+    },
+    {
+      "code": 6035,
+      "name": "NoPendingPoolFee",
+      "msg": "There is no pending pool fee"
+    },
+    {
+      "code": 6036,
+      "name": "PoolFeeTimelocked",
+      "msg": "Pending pool fee is still timelocked"
+    },
+    {
+      "code": 6037,
+      "name": "AccountNotRentExempt",
+      "msg": "Account would not be rent exempt"
+    },
+    {
+      "code": 6038,
+      "name": "InvalidCloseDestination",
+      "msg": "Account can not be closed into itself"
+    },
+    {
+      "code": 6039,
+      "name": "WithdrawAclFull",
+      "msg": "Withdraw access list is full"
+    },
+    {
+      "code": 6040,
+      "name": "InvalidWithdrawer",
+      "msg": "Withdrawer is not on the access list"
+    },
+    {
+      "code": 6041,
+      "name": "InvalidReferrer",
+      "msg": "Referrer is not on the whitelist"
+    },
+    {
+      "code": 6042,
+      "name": "InsufficientDelegation",
+      "msg": "Delegated amount is not enough to cover the swap"
+    },
+    {
+      "code": 6043,
+      "name": "InvalidSessionAuthority",
+      "msg": "Session authority is invalid, expired or out of scope"
+    },
+    {
+      "code": 6044,
+      "name": "UnsupportedTokenDecimals",
+      "msg": "Token decimals are not supported"
+    },
+    {
+      "code": 6045,
+      "name": "PositionNotOrphaned",
+      "msg": "Both ticks of the position exist"
+    },
+    {
+      "code": 6046,
+      "name": "InvalidHookProgram",
+      "msg": "Hook program is not executable"
+    },
+    {
+      "code": 6047,
+      "name": "HookProgramNotFound",
+      "msg": "Hook program of the pool not found in remaining accounts"
+    },
+    {
+      "code": 6048,
+      "name": "TooManyHookCalls",
+      "msg": "Swap crosses too many ticks for the pool hook"
+    },
+    {
+      "code": 6049,
+      "name": "PriceChangeTooLarge",
+      "msg": "Swap moves the price more than allowed in a single transaction"
+    },
+    {
+      "code": 6050,
+      "name": "PoolWithdrawalOnly",
+      "msg": "Pool accepts only withdrawals"
+    },
+    {
+      "code": 6051,
+      "name": "InvalidRoute",
+      "msg": "Route accounts do not match its hops"
+    },
+    {
+      "code": 6052,
+      "name": "RouteAmountOutTooLow",
+      "msg": "Route output is below the minimum"
+    },
+    {
+      "code": 6053,
+      "name": "OracleNotFound",
+      "msg": "Oracle of the pool not found in remaining accounts"
+    },
+    {
+      "code": 6054,
+      "name": "NoSideSelected",
+      "msg": "Neither token of the position selected to claim"
+    },
+    {
+      "code": 6055,
+      "name": "InvalidRebateSchedule",
+      "msg": "Rebate tiers have to be ascending with rebates up to 100%"
+    },
+    {
+      "code": 6056,
+      "name": "LaunchWindowActive",
+      "msg": "Pool is in its launch window and the user is not whitelisted"
+    },
+    {
+      "code": 6057,
+      "name": "LaunchWhitelistFull",
+      "msg": "Launch whitelist is full"
+    },
+    {
+      "code": 6058,
+      "name": "InvalidLimitOrder",
+      "msg": "Limit order has to be one tick wide and outside of the current price"
+    },
+    {
+      "code": 6059,
+      "name": "LimitOrderNotFilled",
+      "msg": "Price has not crossed the whole range of the limit order"
+    },
+    {
+      "code": 6060,
+      "name": "DevnetOnly",
+      "msg": "Instruction is available only in devnet builds"
+    },
+    {
+      "code": 6061,
+      "name": "SimulationOnly",
+      "msg": "Simulation completed, all changes reverted"
+    },
+    {
+      "code": 6062,
+      "name": "InvalidTwapWindow",
+      "msg": "TWAP window is empty or not covered by the oracle"
+    },
+    {
+      "code": 6063,
+      "name": "InvalidReferralFee",
+      "msg": "Referral fee over 100%"
+    },
+    {
+      "code": 6064,
+      "name": "FlashLoansDisabled",
+      "msg": "Flash loans are disabled on the pool"
+    },
+    {
+      "code": 6065,
+      "name": "FlashLoanNotRepaid",
+      "msg": "Flash loan was not repaid with the fee"
+    },
+    {
+      "code": 6066,
+      "name": "InvalidFlashLoanFee",
+      "msg": "Flash loan fee over 100%"
+    },
+    {
+      "code": 6067,
+      "name": "InvalidPendingAdmin",
+      "msg": "Signer is not the proposed admin"
+    },
+    {
+      "code": 6068,
+      "name": "FeeTierRegistryFull",
+      "msg": "Fee tier registry is full"
+    },
+    {
+      "code": 6069,
+      "name": "ProtocolPaused",
+      "msg": "Protocol is paused"
+    },
+    {
+      "code": 6070,
+      "name": "RouteHopNotFilled",
+      "msg": "Intermediate hop of the route did not swap its whole input"
+    },
+    {
+      "code": 6071,
+      "name": "AccountAlreadyMigrated",
+      "msg": "Account already has the current layout"
+    },
+    {
+      "code": 6072,
+      "name": "LimitOrderFilled",
+      "msg": "Limit order is filled, it can only be claimed"
+    },
+    {
+      "code": 6073,
+      "name": "InvalidPositionNftUri",
+      "msg": "Base uri of the position NFT is too long or has a query"
+    },
+    {
+      "code": 6074,
+      "name": "InvalidPositionNftMetadata",
+      "msg": "Metadata account is not the one of the position NFT"
+    }
+  ]
+};
+
+export const IDL: Invariant = {
+  "version": "0.1.0",
+  "name": "invariant",
+  "instructions": [
+    {
+      "name": "createState",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createAdminLog",
+      "accounts": [
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createGlobalStats",
+      "accounts": [
+        {
+          "name": "globalStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createFeeTierRegistry",
+      "accounts": [
+        {
+          "name": "feeTierRegistry",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createFeeTier",
+      "accounts": [
+        {
+          "name": "feeTier",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeTierRegistry",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CreateFeeTierArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "listFeeTiers",
+      "accounts": [
+        {
+          "name": "feeTierRegistry",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createPool",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeTier",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXReserve",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenYReserve",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CreatePoolArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initPoolAndPosition",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeTier",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXReserve",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenYReserve",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "positionNftMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "InitPoolAndPositionArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createPoolsBatch",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "initTicks",
+          "type": {
+            "vec": "i32"
+          }
+        }
+      ]
+    },
+    {
+      "name": "swap",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SwapArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "swapBounded",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SwapBoundedArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "simulateSwap",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SwapArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "swapRoute",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountIn",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountOut",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SwapRouteArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeOracle",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oracle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createPriceFeed",
+      "accounts": [
+        {
+          "name": "priceFeed",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "window",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updatePriceFeed",
+      "accounts": [
+        {
+          "name": "priceFeed",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "oracle",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createTick",
+      "accounts": [
+        {
+          "name": "tick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "i32"
+        }
+      ]
+    },
+    {
+      "name": "createPositionList",
+      "accounts": [
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createPosition",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "liquidityDelta",
+          "type": {
+            "defined": "Liquidity"
+          }
+        },
+        {
+          "name": "slippageLimitLower",
+          "type": {
+            "defined": "Price"
+          }
+        },
+        {
+          "name": "slippageLimitUpper",
+          "type": {
+            "defined": "Price"
+          }
+        }
+      ]
+    },
+    {
+      "name": "simulateCreatePosition",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "liquidityDelta",
+          "type": {
+            "defined": "Liquidity"
+          }
+        },
+        {
+          "name": "slippageLimitLower",
+          "type": {
+            "defined": "Price"
+          }
+        },
+        {
+          "name": "slippageLimitUpper",
+          "type": {
+            "defined": "Price"
+          }
+        }
+      ]
+    },
+    {
+      "name": "migrateFromExternal",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "amountX",
+          "type": "u64"
+        },
+        {
+          "name": "amountY",
+          "type": "u64"
+        },
+        {
+          "name": "slippageLimitLower",
+          "type": {
+            "defined": "Price"
+          }
+        },
+        {
+          "name": "slippageLimitUpper",
+          "type": {
+            "defined": "Price"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createLimitOrder",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "limitOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "liquidityDelta",
+          "type": {
+            "defined": "Liquidity"
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimLimitOrder",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "limitOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "exitTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "entryTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelLimitOrder",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "limitOrder",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "removePosition",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "removedPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lastPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "holder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "positionNftMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        },
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        }
+      ]
+    },
+    {
+      "name": "decreaseLiquidity",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "holder",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        },
+        {
+          "name": "liquidityDelta",
+          "type": {
+            "defined": "Liquidity"
+          }
+        }
+      ]
+    },
+    {
+      "name": "increaseLiquidity",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "holder",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        },
+        {
+          "name": "liquidityDelta",
+          "type": {
+            "defined": "Liquidity"
+          }
+        },
+        {
+          "name": "slippageLimitLower",
+          "type": {
+            "defined": "Price"
+          }
+        },
+        {
+          "name": "slippageLimitUpper",
+          "type": {
+            "defined": "Price"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferPositionOwnership",
+      "accounts": [
+        {
+          "name": "ownerList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipientList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "removedPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lastPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "mintPositionNft",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "createPositionNftMetadata",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "holder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        },
+        {
+          "name": "baseUri",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "claimFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "holder",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        },
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        }
+      ]
+    },
+    {
+      "name": "claimFeeSide",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "holder",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        },
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "claimX",
+          "type": "bool"
+        },
+        {
+          "name": "claimY",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "claimFeeBatch",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "holder",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "positionsCount",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createSessionAuthority",
+      "accounts": [
+        {
+          "name": "sessionAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sessionKey",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "permissions",
+          "type": "u8"
+        },
+        {
+          "name": "expiry",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "revokeSessionAuthority",
+      "accounts": [
+        {
+          "name": "sessionAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createReferralEarnings",
+      "accounts": [
+        {
+          "name": "referralEarnings",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "referrer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimReferralFees",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "referralEarnings",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateSecondsPerLiquidity",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "index",
+          "type": "i32"
+        }
+      ]
+    },
+    {
+      "name": "withdrawProtocolFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "convertProtocolFees",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "targetIsX",
+          "type": "bool"
+        },
+        {
+          "name": "sqrtPriceLimit",
+          "type": "u128"
+        }
+      ]
+    },
+    {
+      "name": "changeProtocolFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "protocolFee",
+          "type": {
+            "defined": "FixedPoint"
+          }
+        }
+      ]
+    },
+    {
+      "name": "changeFeeReceiver",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "feeReceiver",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createWithdrawAcl",
+      "accounts": [
+        {
+          "name": "withdrawAcl",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setWithdrawer",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "withdrawAcl",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "withdrawer",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "allowed",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "createLaunchConfig",
+      "accounts": [
+        {
+          "name": "launchConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "durationSlots",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setLaunchWhitelisted",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "launchConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "user",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "whitelisted",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "createRebateSchedule",
+      "accounts": [
+        {
+          "name": "rebateSchedule",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setRebateSchedule",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rebateSchedule",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetRebateScheduleArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "proposePoolFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "fee",
+          "type": {
+            "defined": "FixedPoint"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updatePoolFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "proposeAdmin",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAdmin",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "acceptAdmin",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAdmin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateState",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migratePool",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenXReserve",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenYReserve",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migratePosition",
+      "accounts": [
+        {
+          "name": "position",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateOracle",
+      "accounts": [
+        {
+          "name": "oracle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateTick",
+      "accounts": [
+        {
+          "name": "tick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "forceCloseOrphanPosition",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "removedPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lastPosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tickmap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lowerTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upperTick",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "holder",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        },
+        {
+          "name": "lowerTickIndex",
+          "type": "i32"
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32"
+        }
+      ]
+    },
+    {
+      "name": "changeTickEmaHalfLife",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "halfLife",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "setPoolHook",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "hookProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setMaxPriceChange",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "maxPriceChangeBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setWithdrawalOnly",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "withdrawalOnly",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "pause",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "unpause",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setReferralFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "referralFeeBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "skimExcess",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setFlashLoanFee",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "flashLoanFeeBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setLargeSwapThreshold",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "adminLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenX",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenY",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "thresholdX",
+          "type": "u64"
+        },
+        {
+          "name": "thresholdY",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "flashLoan",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "accountY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveX",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveY",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "borrower",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amountX",
+          "type": "u64"
+        },
+        {
+          "name": "amountY",
+          "type": "u64"
+        },
+        {
+          "name": "data",
+          "type": "bytes"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "adminLog",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "head",
+            "type": "u64"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "array": [
+                {
+                  "defined": "AdminLogEntry"
+                },
+                16
+              ]
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeTierRegistry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "count",
+            "type": "u16"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "array": [
+                {
+                  "defined": "FeeTierEntry"
+                },
+                32
+              ]
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "globalStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "totalPools",
+            "type": "u64"
+          },
+          {
+            "name": "totalSwaps",
+            "type": "u64"
+          },
+          {
+            "name": "protocolFees",
+            "type": {
+              "array": [
+                {
+                  "defined": "MintProtocolFee"
+                },
+                16
+              ]
+            }
+          },
+          {
+            "name": "protocolFeesCount",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "launchConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "whitelist",
+            "type": {
+              "array": [
+                "publicKey",
+                16
+              ]
+            }
+          },
+          {
+            "name": "whitelistCount",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "state",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "publicKey"
+          },
+          {
+            "name": "nonce",
+            "type": "u8"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "pendingAdmin",
+            "type": "publicKey"
+          },
+          {
+            "name": "isPaused",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeTier",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "fee",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "tickSpacing",
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pool",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenX",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenY",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenXReserve",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenYReserve",
+            "type": "publicKey"
+          },
+          {
+            "name": "positionIterator",
+            "type": "u128"
+          },
+          {
+            "name": "tickSpacing",
+            "type": "u16"
+          },
+          {
+            "name": "fee",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "protocolFee",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "liquidity",
+            "type": {
+              "defined": "Liquidity"
+            }
+          },
+          {
+            "name": "sqrtPrice",
+            "type": {
+              "defined": "Price"
+            }
+          },
+          {
+            "name": "currentTickIndex",
+            "type": "i32"
+          },
+          {
+            "name": "tickmap",
+            "type": "publicKey"
+          },
+          {
+            "name": "feeGrowthGlobalX",
+            "type": {
+              "defined": "FeeGrowth"
+            }
+          },
+          {
+            "name": "feeGrowthGlobalY",
+            "type": {
+              "defined": "FeeGrowth"
+            }
+          },
+          {
+            "name": "feeProtocolTokenX",
+            "type": "u64"
+          },
+          {
+            "name": "feeProtocolTokenY",
+            "type": "u64"
+          },
+          {
+            "name": "secondsPerLiquidityGlobal",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "startTimestamp",
+            "type": "u64"
+          },
+          {
+            "name": "lastTimestamp",
+            "type": "u64"
+          },
+          {
+            "name": "feeReceiver",
+            "type": "publicKey"
+          },
+          {
+            "name": "oracleAddress",
+            "type": "publicKey"
+          },
+          {
+            "name": "oracleInitialized",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "feeTierFee",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "pendingFee",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "pendingFeeTimestamp",
+            "type": "u64"
+          },
+          {
+            "name": "tickEma",
+            "type": "i64"
+          },
+          {
+            "name": "tickEmaHalfLife",
+            "type": "u32"
+          },
+          {
+            "name": "tickEmaLastTimestamp",
+            "type": "u64"
+          },
+          {
+            "name": "hookProgram",
+            "type": "publicKey"
+          },
+          {
+            "name": "maxPriceChangeBpsPerTx",
+            "type": "u16"
+          },
+          {
+            "name": "withdrawalOnly",
+            "type": "bool"
+          },
+          {
+            "name": "launchEndSlot",
+            "type": "u64"
+          },
+          {
+            "name": "referralFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "reserveX",
+            "type": "u64"
+          },
+          {
+            "name": "reserveY",
+            "type": "u64"
+          },
+          {
+            "name": "flashLoanFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "flashLoansEnabled",
+            "type": "bool"
+          },
+          {
+            "name": "largeSwapThresholdX",
+            "type": "u64"
+          },
+          {
+            "name": "largeSwapThresholdY",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "position",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "id",
+            "type": "u128"
+          },
+          {
+            "name": "liquidity",
+            "type": {
+              "defined": "Liquidity"
+            }
+          },
+          {
+            "name": "lowerTickIndex",
+            "type": "i32"
+          },
+          {
+            "name": "upperTickIndex",
+            "type": "i32"
+          },
+          {
+            "name": "feeGrowthInsideX",
+            "type": {
+              "defined": "FeeGrowth"
+            }
+          },
+          {
+            "name": "feeGrowthInsideY",
+            "type": {
+              "defined": "FeeGrowth"
+            }
+          },
+          {
+            "name": "secondsPerLiquidityInside",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
           {
-            "name": "tickSpacing",
-            "type": "u16"
+            "name": "lastSlot",
+            "type": "u64"
+          },
+          {
+            "name": "tokensOwedX",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "tokensOwedY",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "makerFeesX",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "makerFeesY",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "totalClaimedX",
+            "type": "u64"
+          },
+          {
+            "name": "totalClaimedY",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "positionList",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "head",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "tick",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "index",
+            "type": "i32"
+          },
+          {
+            "name": "sign",
+            "type": "bool"
+          },
+          {
+            "name": "liquidityChange",
+            "type": {
+              "defined": "Liquidity"
+            }
+          },
+          {
+            "name": "liquidityGross",
+            "type": {
+              "defined": "Liquidity"
+            }
+          },
+          {
+            "name": "sqrtPrice",
+            "type": {
+              "defined": "Price"
+            }
+          },
+          {
+            "name": "feeGrowthOutsideX",
+            "type": {
+              "defined": "FeeGrowth"
+            }
+          },
+          {
+            "name": "feeGrowthOutsideY",
+            "type": {
+              "defined": "FeeGrowth"
+            }
+          },
+          {
+            "name": "secondsPerLiquidityOutside",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          },
+          {
+            "name": "secondsOutside",
+            "type": "u64"
           },
           {
             "name": "bump",
             "type": "u8"
+          },
+          {
+            "name": "limitOrderLiquidityBelow",
+            "type": {
+              "defined": "Liquidity"
+            }
+          },
+          {
+            "name": "limitOrderLiquidityAbove",
+            "type": {
+              "defined": "Liquidity"
+            }
+          },
+          {
+            "name": "limitOrderFillsBelow",
+            "type": "u64"
+          },
+          {
+            "name": "limitOrderFillsAbove",
+            "type": "u64"
+          },
+          {
+            "name": "limitOrders",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "tickmap",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bitmap",
+            "type": {
+              "array": [
+                "u8",
+                11091
+              ]
+            }
           }
         ]
       }
@@ -992,46 +11000,76 @@ export type Invariant = {
       }
     },
     {
-      "name": "pool",
+      "name": "priceFeed",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "tokenX",
-            "type": "publicKey"
-          },
-          {
-            "name": "tokenY",
+            "name": "pool",
             "type": "publicKey"
           },
           {
-            "name": "tokenXReserve",
+            "name": "tokenX",
             "type": "publicKey"
           },
           {
-            "name": "tokenYReserve",
+            "name": "tokenY",
             "type": "publicKey"
           },
           {
-            "name": "positionIterator",
-            "type": "u128"
+            "name": "window",
+            "type": "u64"
           },
           {
-            "name": "tickSpacing",
-            "type": "u16"
+            "name": "sqrtPrice",
+            "type": {
+              "defined": "Price"
+            }
           },
           {
-            "name": "fee",
+            "name": "price",
             "type": {
-              "defined": "FixedPoint"
+              "defined": "Price"
             }
           },
           {
-            "name": "protocolFee",
+            "name": "liquidity",
             "type": {
-              "defined": "FixedPoint"
+              "defined": "Liquidity"
             }
           },
+          {
+            "name": "publishTime",
+            "type": "u64"
+          },
+          {
+            "name": "publishSlot",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "limitOrder",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "id",
+            "type": "u128"
+          },
           {
             "name": "liquidity",
             "type": {
@@ -1039,64 +11077,132 @@ export type Invariant = {
             }
           },
           {
-            "name": "sqrtPrice",
+            "name": "lowerTickIndex",
+            "type": "i32"
+          },
+          {
+            "name": "upperTickIndex",
+            "type": "i32"
+          },
+          {
+            "name": "xToY",
+            "type": "bool"
+          },
+          {
+            "name": "fills",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rebateSchedule",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tiers",
             "type": {
-              "defined": "Price"
+              "array": [
+                {
+                  "defined": "RebateTier"
+                },
+                8
+              ]
             }
           },
           {
-            "name": "currentTickIndex",
-            "type": "i32"
+            "name": "tiersCount",
+            "type": "u8"
           },
           {
-            "name": "tickmap",
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "referralEarnings",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
             "type": "publicKey"
           },
           {
-            "name": "feeGrowthGlobalX",
-            "type": {
-              "defined": "FeeGrowth"
-            }
+            "name": "referrer",
+            "type": "publicKey"
           },
           {
-            "name": "feeGrowthGlobalY",
-            "type": {
-              "defined": "FeeGrowth"
-            }
+            "name": "amountX",
+            "type": "u64"
           },
           {
-            "name": "feeProtocolTokenX",
+            "name": "amountY",
             "type": "u64"
           },
           {
-            "name": "feeProtocolTokenY",
-            "type": "u64"
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sessionAuthority",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "publicKey"
           },
           {
-            "name": "secondsPerLiquidityGlobal",
-            "type": {
-              "defined": "FixedPoint"
-            }
+            "name": "sessionKey",
+            "type": "publicKey"
           },
           {
-            "name": "startTimestamp",
-            "type": "u64"
+            "name": "permissions",
+            "type": "u8"
           },
           {
-            "name": "lastTimestamp",
+            "name": "expiry",
             "type": "u64"
           },
           {
-            "name": "feeReceiver",
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "withdrawAcl",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
             "type": "publicKey"
           },
           {
-            "name": "oracleAddress",
-            "type": "publicKey"
+            "name": "withdrawers",
+            "type": {
+              "array": [
+                "publicKey",
+                8
+              ]
+            }
           },
           {
-            "name": "oracleInitialized",
-            "type": "bool"
+            "name": "withdrawersCount",
+            "type": "u8"
           },
           {
             "name": "bump",
@@ -1104,30 +11210,88 @@ export type Invariant = {
           }
         ]
       }
-    },
+    }
+  ],
+  "types": [
     {
-      "name": "position",
+      "name": "AdminLogEntry",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "owner",
+            "name": "actor",
             "type": "publicKey"
           },
           {
-            "name": "pool",
+            "name": "action",
+            "type": "u8"
+          },
+          {
+            "name": "target",
             "type": "publicKey"
           },
           {
-            "name": "id",
-            "type": "u128"
+            "name": "oldValue",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
           {
-            "name": "liquidity",
+            "name": "newValue",
             "type": {
-              "defined": "Liquidity"
+              "array": [
+                "u8",
+                32
+              ]
             }
           },
+          {
+            "name": "slot",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreateFeeTierArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "fee",
+            "type": "u128"
+          },
+          {
+            "name": "tickSpacing",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreatePoolArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "initTick",
+            "type": "i32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitPoolAndPositionArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "initTick",
+            "type": "i32"
+          },
           {
             "name": "lowerTickIndex",
             "type": "i32"
@@ -1137,169 +11301,210 @@ export type Invariant = {
             "type": "i32"
           },
           {
-            "name": "feeGrowthInsideX",
+            "name": "liquidityDelta",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "direction",
             "type": {
-              "defined": "FeeGrowth"
+              "defined": "Direction"
             }
           },
           {
-            "name": "feeGrowthInsideY",
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "amountSpec",
             "type": {
-              "defined": "FeeGrowth"
+              "defined": "AmountSpec"
             }
           },
           {
-            "name": "secondsPerLiquidityInside",
+            "name": "sqrtPriceLimit",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapBoundedArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "direction",
             "type": {
-              "defined": "FixedPoint"
+              "defined": "Direction"
             }
           },
           {
-            "name": "lastSlot",
+            "name": "amount",
             "type": "u64"
           },
           {
-            "name": "tokensOwedX",
+            "name": "amountSpec",
             "type": {
-              "defined": "FixedPoint"
+              "defined": "AmountSpec"
             }
           },
           {
-            "name": "tokensOwedY",
-            "type": {
-              "defined": "FixedPoint"
-            }
+            "name": "sqrtPriceLimit",
+            "type": "u128"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "tickLimit",
+            "type": "u16"
           }
         ]
       }
     },
     {
-      "name": "positionList",
+      "name": "RouteHop",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "head",
-            "type": "u32"
+            "name": "direction",
+            "type": {
+              "defined": "Direction"
+            }
           },
           {
-            "name": "bump",
+            "name": "sqrtPriceLimit",
+            "type": "u128"
+          },
+          {
+            "name": "tickAccounts",
             "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "state",
+      "name": "SwapRouteArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "admin",
-            "type": "publicKey"
-          },
-          {
-            "name": "nonce",
-            "type": "u8"
+            "name": "amountIn",
+            "type": "u64"
           },
           {
-            "name": "authority",
-            "type": "publicKey"
+            "name": "minAmountOut",
+            "type": "u64"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "hops",
+            "type": {
+              "vec": {
+                "defined": "RouteHop"
+              }
+            }
           }
         ]
       }
     },
     {
-      "name": "tick",
+      "name": "RebateTierArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "pool",
-            "type": "publicKey"
-          },
-          {
-            "name": "index",
-            "type": "i32"
+            "name": "minMakerFees",
+            "type": "u64"
           },
           {
-            "name": "sign",
-            "type": "bool"
-          },
+            "name": "rebate",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetRebateScheduleArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "liquidityChange",
+            "name": "tiers",
             "type": {
-              "defined": "Liquidity"
+              "vec": {
+                "defined": "RebateTierArgs"
+              }
             }
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapReturnData",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "liquidityGross",
-            "type": {
-              "defined": "Liquidity"
-            }
+            "name": "lpFee",
+            "type": "u64"
           },
           {
-            "name": "sqrtPrice",
-            "type": {
-              "defined": "Price"
-            }
+            "name": "protocolFee",
+            "type": "u64"
           },
           {
-            "name": "feeGrowthOutsideX",
-            "type": {
-              "defined": "FeeGrowth"
-            }
+            "name": "referralFee",
+            "type": "u64"
           },
           {
-            "name": "feeGrowthOutsideY",
-            "type": {
-              "defined": "FeeGrowth"
-            }
+            "name": "priceBefore",
+            "type": "u128"
           },
           {
-            "name": "secondsPerLiquidityOutside",
-            "type": {
-              "defined": "FixedPoint"
-            }
-          },
+            "name": "priceAfter",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeTierReturnData",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "secondsOutside",
-            "type": "u64"
+            "name": "fee",
+            "type": "u128"
           },
-          {
-            "name": "bump",
-            "type": "u8"
+          {
+            "name": "tickSpacing",
+            "type": "u16"
           }
         ]
       }
     },
     {
-      "name": "tickmap",
+      "name": "ListFeeTiersReturnData",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "bitmap",
+            "name": "feeTiers",
             "type": {
-              "array": [
-                "u8",
-                11091
-              ]
+              "vec": {
+                "defined": "FeeTierReturnData"
+              }
             }
           }
         ]
       }
-    }
-  ],
-  "types": [
+    },
     {
       "name": "Price",
       "type": {
@@ -1349,1550 +11554,1185 @@ export type Invariant = {
       }
     },
     {
-      "name": "Record",
+      "name": "FeeTierEntry",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "timestamp",
-            "type": "u64"
-          },
-          {
-            "name": "price",
+            "name": "fee",
             "type": {
-              "defined": "Price"
+              "defined": "FixedPoint"
             }
+          },
+          {
+            "name": "tickSpacing",
+            "type": "u16"
           }
         ]
       }
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "ZeroAmount",
-      "msg": "Amount is zero"
-    },
-    {
-      "code": 6001,
-      "name": "ZeroOutput",
-      "msg": "Output would be zero"
-    },
-    {
-      "code": 6002,
-      "name": "WrongTick",
-      "msg": "Not the expected tick"
-    },
-    {
-      "code": 6003,
-      "name": "WrongLimit",
-      "msg": "Price limit is on the wrong side of price"
-    },
-    {
-      "code": 6004,
-      "name": "InvalidTickIndex",
-      "msg": "Tick index not divisible by spacing or over limit"
-    },
-    {
-      "code": 6005,
-      "name": "InvalidTickInterval",
-      "msg": "Invalid tick_lower or tick_upper"
-    },
-    {
-      "code": 6006,
-      "name": "NoMoreTicks",
-      "msg": "There is no more tick in that direction"
-    },
-    {
-      "code": 6007,
-      "name": "TickNotFound",
-      "msg": "Correct tick not found in context"
-    },
-    {
-      "code": 6008,
-      "name": "PriceLimitReached",
-      "msg": "Price would cross swap limit"
-    },
-    {
-      "code": 6009,
-      "name": "InvalidTickLiquidity",
-      "msg": "Invalid tick liquidity"
-    },
-    {
-      "code": 6010,
-      "name": "EmptyPositionPokes",
-      "msg": "Disable empty position pokes"
-    },
-    {
-      "code": 6011,
-      "name": "InvalidPositionLiquidity",
-      "msg": "Invalid tick liquidity"
-    },
-    {
-      "code": 6012,
-      "name": "InvalidPoolLiquidity",
-      "msg": "Invalid pool liquidity"
-    },
-    {
-      "code": 6013,
-      "name": "InvalidPositionIndex",
-      "msg": "Invalid position index"
-    },
-    {
-      "code": 6014,
-      "name": "PositionWithoutLiquidity",
-      "msg": "Position liquidity would be zero"
-    },
-    {
-      "code": 6015,
-      "name": "Unauthorized",
-      "msg": "You are not admin"
-    },
-    {
-      "code": 6016,
-      "name": "InvalidPoolTokenAddresses",
-      "msg": "Invalid pool token addresses"
-    },
-    {
-      "code": 6017,
-      "name": "NegativeTime",
-      "msg": "Time cannot be negative"
-    },
-    {
-      "code": 6018,
-      "name": "OracleAlreadyInitialized",
-      "msg": "Oracle is already initialized"
-    },
-    {
-      "code": 6019,
-      "name": "LimitReached",
-      "msg": "Absolute price limit was reached"
     },
     {
-      "code": 6020,
-      "name": "InvalidProtocolFee",
-      "msg": "Invalid protocol fee"
-    },
-    {
-      "code": 6021,
-      "name": "NoGainSwap",
-      "msg": "Swap amount out is 0"
-    },
-    {
-      "code": 6022,
-      "name": "InvalidTokenAccount",
-      "msg": "Provided token account is different than expected"
-    },
-    {
-      "code": 6023,
-      "name": "InvalidAdmin",
-      "msg": "Admin address is different than expected"
-    },
-    {
-      "code": 6024,
-      "name": "InvalidAuthority",
-      "msg": "Provided authority is different than expected"
-    },
-    {
-      "code": 6025,
-      "name": "InvalidOwner",
-      "msg": "Provided token owner is different than expected"
-    },
-    {
-      "code": 6026,
-      "name": "InvalidMint",
-      "msg": "Provided token account mint is different than expected mint token"
-    },
-    {
-      "code": 6027,
-      "name": "InvalidTickmap",
-      "msg": "Provided tickmap is different than expected"
-    },
-    {
-      "code": 6028,
-      "name": "InvalidTickmapOwner",
-      "msg": "Provided tickmap owner is different than program ID"
-    },
-    {
-      "code": 6029,
-      "name": "InvalidListOwner",
-      "msg": "Recipient list address and owner list address should be different"
+      "name": "FlashLoanCallback",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "amountX",
+            "type": "u64"
+          },
+          {
+            "name": "amountY",
+            "type": "u64"
+          },
+          {
+            "name": "feeX",
+            "type": "u64"
+          },
+          {
+            "name": "feeY",
+            "type": "u64"
+          },
+          {
+            "name": "data",
+            "type": "bytes"
+          }
+        ]
+      }
     },
     {
-      "code": 6030,
-      "name": "InvalidTickSpacing",
-      "msg": "Invalid tick spacing"
-    }
-  ]
-};
-
-export const IDL: Invariant = {
-  "version": "0.1.0",
-  "name": "invariant",
-  "instructions": [
-    {
-      "name": "createState",
-      "accounts": [
-        {
-          "name": "state",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "admin",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "programAuthority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "nonce",
-          "type": "u8"
-        }
-      ]
+      "name": "MintProtocolFee",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "type": "u128"
+          }
+        ]
+      }
     },
     {
-      "name": "createFeeTier",
-      "accounts": [
-        {
-          "name": "feeTier",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "state",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "admin",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "fee",
-          "type": "u128"
-        },
-        {
-          "name": "tickSpacing",
-          "type": "u16"
-        }
-      ]
+      "name": "Record",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "timestamp",
+            "type": "u64"
+          },
+          {
+            "name": "sqrtPrice",
+            "type": {
+              "defined": "Price"
+            }
+          },
+          {
+            "name": "liquidity",
+            "type": {
+              "defined": "Liquidity"
+            }
+          }
+        ]
+      }
     },
     {
-      "name": "createPool",
-      "accounts": [
-        {
-          "name": "state",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "feeTier",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tickmap",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenX",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenY",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenXReserve",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "tokenYReserve",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "initTick",
-          "type": "i32"
-        }
-      ]
+      "name": "RebateTier",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minMakerFees",
+            "type": "u64"
+          },
+          {
+            "name": "rebate",
+            "type": {
+              "defined": "FixedPoint"
+            }
+          }
+        ]
+      }
     },
     {
-      "name": "swap",
-      "accounts": [
-        {
-          "name": "state",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tickmap",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "accountX",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "accountY",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "reserveX",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "reserveY",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "programAuthority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "xToY",
-          "type": "bool"
-        },
-        {
-          "name": "amount",
-          "type": "u64"
-        },
-        {
-          "name": "byAmountIn",
-          "type": "bool"
-        },
-        {
-          "name": "sqrtPriceLimit",
-          "type": "u128"
-        }
-      ]
+      "name": "TickCrossed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "tick",
+            "type": "i32"
+          },
+          {
+            "name": "xToY",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AdminAction",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "CreateFeeTier"
+          },
+          {
+            "name": "ChangeFeeReceiver"
+          },
+          {
+            "name": "CreateWithdrawAcl"
+          },
+          {
+            "name": "SetWithdrawer"
+          },
+          {
+            "name": "ProposePoolFee"
+          },
+          {
+            "name": "UpdatePoolFee"
+          },
+          {
+            "name": "ChangeTickEmaHalfLife"
+          },
+          {
+            "name": "ForceCloseOrphanPosition"
+          },
+          {
+            "name": "SetPoolHook"
+          },
+          {
+            "name": "SetMaxPriceChange"
+          },
+          {
+            "name": "SetWithdrawalOnly"
+          },
+          {
+            "name": "CreateRebateSchedule"
+          },
+          {
+            "name": "SetRebateSchedule"
+          },
+          {
+            "name": "CreateLaunchConfig"
+          },
+          {
+            "name": "SetLaunchWhitelisted"
+          },
+          {
+            "name": "SetReferralFee"
+          },
+          {
+            "name": "SkimExcess"
+          },
+          {
+            "name": "SetFlashLoanFee"
+          },
+          {
+            "name": "ProposeAdmin"
+          },
+          {
+            "name": "AcceptAdmin"
+          },
+          {
+            "name": "SetPaused"
+          },
+          {
+            "name": "SetLargeSwapThreshold"
+          },
+          {
+            "name": "ChangeProtocolFee"
+          },
+          {
+            "name": "CreateGlobalStats"
+          },
+          {
+            "name": "CreateFeeTierRegistry"
+          }
+        ]
+      }
     },
     {
-      "name": "initializeOracle",
-      "accounts": [
-        {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "oracle",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenX",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenY",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "payer",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
+      "name": "Direction",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "YtoX"
+          },
+          {
+            "name": "XtoY"
+          }
+        ]
+      }
     },
     {
-      "name": "createTick",
-      "accounts": [
-        {
-          "name": "tick",
-          "isMut": true,
-          "isSigner": false
-        },
+      "name": "AmountSpec",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Out"
+          },
+          {
+            "name": "In"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "ChangeFeeReceiverEvent",
+      "fields": [
         {
           "name": "pool",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tickmap",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "tokenX",
-          "isMut": false,
-          "isSigner": false
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "tokenY",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
+          "name": "oldFeeReceiver",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "index",
-          "type": "i32"
+          "name": "newFeeReceiver",
+          "type": "publicKey",
+          "index": false
         }
       ]
     },
     {
-      "name": "createPositionList",
-      "accounts": [
-        {
-          "name": "positionList",
-          "isMut": true,
-          "isSigner": false
-        },
+      "name": "ChangeProtocolFeeEvent",
+      "fields": [
         {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "signer",
-          "isMut": true,
-          "isSigner": true
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
+          "name": "oldProtocolFee",
+          "type": "u128",
+          "index": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "newProtocolFee",
+          "type": "u128",
+          "index": false
         }
-      ],
-      "args": []
+      ]
     },
     {
-      "name": "createPosition",
-      "accounts": [
-        {
-          "name": "state",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "position",
-          "isMut": true,
-          "isSigner": false
-        },
+      "name": "CreatePoolEvent",
+      "fields": [
         {
           "name": "pool",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "positionList",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "lowerTick",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "upperTick",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tickmap",
-          "isMut": true,
-          "isSigner": false
+          "type": "publicKey",
+          "index": false
         },
         {
           "name": "tokenX",
-          "isMut": false,
-          "isSigner": false
+          "type": "publicKey",
+          "index": false
         },
         {
           "name": "tokenY",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "accountX",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "accountY",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "reserveX",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "reserveY",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "programAuthority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "lowerTickIndex",
-          "type": "i32"
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "upperTickIndex",
-          "type": "i32"
+          "name": "fee",
+          "type": "u128",
+          "index": false
         },
         {
-          "name": "liquidityDelta",
-          "type": {
-            "defined": "Liquidity"
-          }
+          "name": "tickSpacing",
+          "type": "u16",
+          "index": false
         },
         {
-          "name": "slippageLimitLower",
-          "type": {
-            "defined": "Price"
-          }
+          "name": "initTick",
+          "type": "i32",
+          "index": false
         },
         {
-          "name": "slippageLimitUpper",
-          "type": {
-            "defined": "Price"
-          }
+          "name": "sqrtPrice",
+          "type": "u128",
+          "index": false
         }
       ]
     },
     {
-      "name": "removePosition",
-      "accounts": [
+      "name": "ClaimFeeEvent",
+      "fields": [
         {
-          "name": "state",
-          "isMut": false,
-          "isSigner": false
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "removedPosition",
-          "isMut": true,
-          "isSigner": false
+          "name": "position",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "positionList",
-          "isMut": true,
-          "isSigner": false
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "lastPosition",
-          "isMut": true,
-          "isSigner": false
+          "name": "liquidity",
+          "type": "u128",
+          "index": false
         },
         {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false
+          "name": "feeGrowthInsideXBefore",
+          "type": "u128",
+          "index": false
         },
         {
-          "name": "tickmap",
-          "isMut": true,
-          "isSigner": false
+          "name": "feeGrowthInsideYBefore",
+          "type": "u128",
+          "index": false
         },
         {
-          "name": "lowerTick",
-          "isMut": true,
-          "isSigner": false
+          "name": "feeGrowthInsideXAfter",
+          "type": "u128",
+          "index": false
         },
         {
-          "name": "upperTick",
-          "isMut": true,
-          "isSigner": false
+          "name": "feeGrowthInsideYAfter",
+          "type": "u128",
+          "index": false
         },
         {
-          "name": "owner",
-          "isMut": true,
-          "isSigner": true
+          "name": "feeGrowthGlobalX",
+          "type": "u128",
+          "index": false
         },
         {
-          "name": "tokenX",
-          "isMut": false,
-          "isSigner": false
+          "name": "feeGrowthGlobalY",
+          "type": "u128",
+          "index": false
         },
         {
-          "name": "tokenY",
-          "isMut": false,
-          "isSigner": false
+          "name": "protocolFee",
+          "type": "u128",
+          "index": false
         },
         {
-          "name": "accountX",
-          "isMut": true,
-          "isSigner": false
+          "name": "feeX",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "accountY",
-          "isMut": true,
-          "isSigner": false
+          "name": "feeY",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "reserveX",
-          "isMut": true,
-          "isSigner": false
+          "name": "rebateX",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "reserveY",
-          "isMut": true,
-          "isSigner": false
+          "name": "rebateY",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CreatePositionEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "programAuthority",
-          "isMut": false,
-          "isSigner": false
+          "name": "position",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
         {
-          "name": "index",
-          "type": "u32"
+          "name": "id",
+          "type": "u128",
+          "index": false
         },
         {
           "name": "lowerTickIndex",
-          "type": "i32"
+          "type": "i32",
+          "index": false
         },
         {
           "name": "upperTickIndex",
-          "type": "i32"
-        }
-      ]
-    },
-    {
-      "name": "transferPositionOwnership",
-      "accounts": [
-        {
-          "name": "ownerList",
-          "isMut": true,
-          "isSigner": false
+          "type": "i32",
+          "index": false
         },
         {
-          "name": "recipientList",
-          "isMut": true,
-          "isSigner": false
+          "name": "liquidity",
+          "type": "u128",
+          "index": false
         },
         {
-          "name": "newPosition",
-          "isMut": true,
-          "isSigner": false
+          "name": "feeGrowthInsideX",
+          "type": "u128",
+          "index": false
         },
         {
-          "name": "removedPosition",
-          "isMut": true,
-          "isSigner": false
+          "name": "feeGrowthInsideY",
+          "type": "u128",
+          "index": false
         },
         {
-          "name": "lastPosition",
-          "isMut": true,
-          "isSigner": false
+          "name": "secondsPerLiquidityInside",
+          "type": "u128",
+          "index": false
         },
         {
-          "name": "owner",
-          "isMut": true,
-          "isSigner": true
+          "name": "lastSlot",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "recipient",
-          "isMut": false,
-          "isSigner": false
+          "name": "sqrtPrice",
+          "type": "u128",
+          "index": false
         },
         {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
+          "name": "currentTickIndex",
+          "type": "i32",
+          "index": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
         {
-          "name": "index",
-          "type": "u32"
+          "name": "amountY",
+          "type": "u64",
+          "index": false
         }
       ]
     },
     {
-      "name": "claimFee",
-      "accounts": [
-        {
-          "name": "state",
-          "isMut": false,
-          "isSigner": false
-        },
+      "name": "RemovePositionEvent",
+      "fields": [
         {
           "name": "pool",
-          "isMut": true,
-          "isSigner": false
+          "type": "publicKey",
+          "index": false
         },
         {
           "name": "position",
-          "isMut": true,
-          "isSigner": false
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "lowerTick",
-          "isMut": true,
-          "isSigner": false
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "upperTick",
-          "isMut": true,
-          "isSigner": false
+          "name": "liquidity",
+          "type": "u128",
+          "index": false
         },
         {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": true
+          "name": "feeGrowthInsideXBefore",
+          "type": "u128",
+          "index": false
         },
         {
-          "name": "tokenX",
-          "isMut": false,
-          "isSigner": false
+          "name": "feeGrowthInsideYBefore",
+          "type": "u128",
+          "index": false
         },
         {
-          "name": "tokenY",
-          "isMut": false,
-          "isSigner": false
+          "name": "feeGrowthInsideXAfter",
+          "type": "u128",
+          "index": false
         },
         {
-          "name": "accountX",
-          "isMut": true,
-          "isSigner": false
+          "name": "feeGrowthInsideYAfter",
+          "type": "u128",
+          "index": false
         },
         {
-          "name": "accountY",
-          "isMut": true,
-          "isSigner": false
+          "name": "feeGrowthGlobalX",
+          "type": "u128",
+          "index": false
         },
         {
-          "name": "reserveX",
-          "isMut": true,
-          "isSigner": false
+          "name": "feeGrowthGlobalY",
+          "type": "u128",
+          "index": false
         },
         {
-          "name": "reserveY",
-          "isMut": true,
-          "isSigner": false
+          "name": "protocolFee",
+          "type": "u128",
+          "index": false
         },
         {
-          "name": "programAuthority",
-          "isMut": false,
-          "isSigner": false
+          "name": "amountX",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "index",
-          "type": "u32"
+          "name": "amountY",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "lowerTickIndex",
-          "type": "i32"
+          "name": "feeX",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "upperTickIndex",
-          "type": "i32"
+          "name": "feeY",
+          "type": "u64",
+          "index": false
         }
       ]
     },
     {
-      "name": "updateSecondsPerLiquidity",
-      "accounts": [
+      "name": "DecreaseLiquidityEvent",
+      "fields": [
         {
           "name": "pool",
-          "isMut": true,
-          "isSigner": false
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "lowerTick",
-          "isMut": false,
-          "isSigner": false
+          "name": "position",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "upperTick",
-          "isMut": false,
-          "isSigner": false
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "position",
-          "isMut": true,
-          "isSigner": false
+          "name": "liquidityDelta",
+          "type": "u128",
+          "index": false
         },
         {
-          "name": "tokenX",
-          "isMut": false,
-          "isSigner": false
+          "name": "liquidityAfter",
+          "type": "u128",
+          "index": false
         },
         {
-          "name": "tokenY",
-          "isMut": false,
-          "isSigner": false
+          "name": "amountX",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": false
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "IncreaseLiquidityEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "signer",
-          "isMut": true,
-          "isSigner": true
+          "name": "position",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
+          "name": "liquidityDelta",
+          "type": "u128",
+          "index": false
+        },
         {
-          "name": "lowerTickIndex",
-          "type": "i32"
+          "name": "liquidityAfter",
+          "type": "u128",
+          "index": false
         },
         {
-          "name": "upperTickIndex",
-          "type": "i32"
+          "name": "amountX",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "index",
-          "type": "i32"
+          "name": "amountY",
+          "type": "u64",
+          "index": false
         }
       ]
     },
     {
-      "name": "withdrawProtocolFee",
-      "accounts": [
-        {
-          "name": "state",
-          "isMut": false,
-          "isSigner": false
-        },
+      "name": "ForceCloseOrphanPositionEvent",
+      "fields": [
         {
           "name": "pool",
-          "isMut": true,
-          "isSigner": false
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "tokenX",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenY",
-          "isMut": false,
-          "isSigner": false
+          "name": "position",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "accountX",
-          "isMut": true,
-          "isSigner": false
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "accountY",
-          "isMut": true,
-          "isSigner": false
+          "name": "liquidity",
+          "type": "u128",
+          "index": false
         },
         {
-          "name": "reserveX",
-          "isMut": true,
-          "isSigner": false
+          "name": "lowerTickMissing",
+          "type": "bool",
+          "index": false
         },
         {
-          "name": "reserveY",
-          "isMut": true,
-          "isSigner": false
+          "name": "upperTickMissing",
+          "type": "bool",
+          "index": false
         },
         {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true
+          "name": "amountX",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "programAuthority",
-          "isMut": false,
-          "isSigner": false
+          "name": "amountY",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "feeX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeY",
+          "type": "u64",
+          "index": false
         }
-      ],
-      "args": []
+      ]
     },
     {
-      "name": "changeProtocolFee",
-      "accounts": [
-        {
-          "name": "state",
-          "isMut": false,
-          "isSigner": false
-        },
+      "name": "TransferPositionOwnershipEvent",
+      "fields": [
         {
           "name": "pool",
-          "isMut": true,
-          "isSigner": false
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "tokenX",
-          "isMut": false,
-          "isSigner": false
+          "name": "id",
+          "type": "u128",
+          "index": false
         },
         {
-          "name": "tokenY",
-          "isMut": false,
-          "isSigner": false
+          "name": "oldOwner",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "admin",
-          "isMut": false,
-          "isSigner": true
+          "name": "newOwner",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "programAuthority",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "protocolFee",
-          "type": {
-            "defined": "FixedPoint"
-          }
+          "name": "liquidity",
+          "type": "u128",
+          "index": false
         }
       ]
     },
     {
-      "name": "changeFeeReceiver",
-      "accounts": [
+      "name": "SwapEvent",
+      "fields": [
         {
-          "name": "state",
-          "isMut": false,
-          "isSigner": false
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "tokenX",
-          "isMut": false,
-          "isSigner": false
+          "name": "xToY",
+          "type": "bool",
+          "index": false
         },
         {
-          "name": "tokenY",
-          "isMut": false,
-          "isSigner": false
+          "name": "amountIn",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "admin",
-          "isMut": false,
-          "isSigner": true
+          "name": "amountOut",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "feeReceiver",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
-    }
-  ],
-  "accounts": [
-    {
-      "name": "feeTier",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "fee",
-            "type": {
-              "defined": "FixedPoint"
-            }
-          },
-          {
-            "name": "tickSpacing",
-            "type": "u16"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          }
-        ]
-      }
-    },
-    {
-      "name": "oracle",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "data",
-            "type": {
-              "array": [
-                {
-                  "defined": "Record"
-                },
-                256
-              ]
-            }
-          },
-          {
-            "name": "head",
-            "type": "u16"
-          },
-          {
-            "name": "amount",
-            "type": "u16"
-          },
-          {
-            "name": "size",
-            "type": "u16"
-          }
-        ]
-      }
-    },
-    {
-      "name": "pool",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "tokenX",
-            "type": "publicKey"
-          },
-          {
-            "name": "tokenY",
-            "type": "publicKey"
-          },
-          {
-            "name": "tokenXReserve",
-            "type": "publicKey"
-          },
-          {
-            "name": "tokenYReserve",
-            "type": "publicKey"
-          },
-          {
-            "name": "positionIterator",
-            "type": "u128"
-          },
-          {
-            "name": "tickSpacing",
-            "type": "u16"
-          },
-          {
-            "name": "fee",
-            "type": {
-              "defined": "FixedPoint"
-            }
-          },
-          {
-            "name": "protocolFee",
-            "type": {
-              "defined": "FixedPoint"
-            }
-          },
-          {
-            "name": "liquidity",
-            "type": {
-              "defined": "Liquidity"
-            }
-          },
-          {
-            "name": "sqrtPrice",
-            "type": {
-              "defined": "Price"
-            }
-          },
-          {
-            "name": "currentTickIndex",
-            "type": "i32"
-          },
-          {
-            "name": "tickmap",
-            "type": "publicKey"
-          },
-          {
-            "name": "feeGrowthGlobalX",
-            "type": {
-              "defined": "FeeGrowth"
-            }
-          },
-          {
-            "name": "feeGrowthGlobalY",
-            "type": {
-              "defined": "FeeGrowth"
-            }
-          },
-          {
-            "name": "feeProtocolTokenX",
-            "type": "u64"
-          },
-          {
-            "name": "feeProtocolTokenY",
-            "type": "u64"
-          },
-          {
-            "name": "secondsPerLiquidityGlobal",
-            "type": {
-              "defined": "FixedPoint"
-            }
-          },
-          {
-            "name": "startTimestamp",
-            "type": "u64"
-          },
-          {
-            "name": "lastTimestamp",
-            "type": "u64"
-          },
-          {
-            "name": "feeReceiver",
-            "type": "publicKey"
-          },
-          {
-            "name": "oracleAddress",
-            "type": "publicKey"
-          },
-          {
-            "name": "oracleInitialized",
-            "type": "bool"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          }
-        ]
-      }
+          "name": "fee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "protocolFee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "referralFee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "priceBefore",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "priceAfter",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "liquidityBefore",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "liquidityAfter",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "currentTickIndex",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "ticksCrossed",
+          "type": "u16",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "position",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "owner",
-            "type": "publicKey"
-          },
-          {
-            "name": "pool",
-            "type": "publicKey"
-          },
-          {
-            "name": "id",
-            "type": "u128"
-          },
-          {
-            "name": "liquidity",
-            "type": {
-              "defined": "Liquidity"
-            }
-          },
-          {
-            "name": "lowerTickIndex",
-            "type": "i32"
-          },
-          {
-            "name": "upperTickIndex",
-            "type": "i32"
-          },
-          {
-            "name": "feeGrowthInsideX",
-            "type": {
-              "defined": "FeeGrowth"
-            }
-          },
-          {
-            "name": "feeGrowthInsideY",
-            "type": {
-              "defined": "FeeGrowth"
-            }
-          },
-          {
-            "name": "secondsPerLiquidityInside",
-            "type": {
-              "defined": "FixedPoint"
-            }
-          },
-          {
-            "name": "lastSlot",
-            "type": "u64"
-          },
-          {
-            "name": "tokensOwedX",
-            "type": {
-              "defined": "FixedPoint"
-            }
-          },
-          {
-            "name": "tokensOwedY",
-            "type": {
-              "defined": "FixedPoint"
-            }
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          }
-        ]
-      }
+      "name": "CrossTickEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "index",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "xToY",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "sign",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "liquidityChange",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "liquidityAfter",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "sqrtPrice",
+          "type": "u128",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "positionList",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "head",
-            "type": "u32"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          }
-        ]
-      }
+      "name": "FillLimitOrdersEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "index",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "xToY",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "liquidity",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "fills",
+          "type": "u64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "state",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "admin",
-            "type": "publicKey"
-          },
-          {
-            "name": "nonce",
-            "type": "u8"
-          },
-          {
-            "name": "authority",
-            "type": "publicKey"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          }
-        ]
-      }
+      "name": "CreateLimitOrderEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "limitOrder",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lowerTickIndex",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "upperTickIndex",
+          "type": "i32",
+          "index": false
+        },
+        {
+          "name": "xToY",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "liquidity",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "tick",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "pool",
-            "type": "publicKey"
-          },
-          {
-            "name": "index",
-            "type": "i32"
-          },
-          {
-            "name": "sign",
-            "type": "bool"
-          },
-          {
-            "name": "liquidityChange",
-            "type": {
-              "defined": "Liquidity"
-            }
-          },
-          {
-            "name": "liquidityGross",
-            "type": {
-              "defined": "Liquidity"
-            }
-          },
-          {
-            "name": "sqrtPrice",
-            "type": {
-              "defined": "Price"
-            }
-          },
-          {
-            "name": "feeGrowthOutsideX",
-            "type": {
-              "defined": "FeeGrowth"
-            }
-          },
-          {
-            "name": "feeGrowthOutsideY",
-            "type": {
-              "defined": "FeeGrowth"
-            }
-          },
-          {
-            "name": "secondsPerLiquidityOutside",
-            "type": {
-              "defined": "FixedPoint"
-            }
-          },
-          {
-            "name": "secondsOutside",
-            "type": "u64"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          }
-        ]
-      }
+      "name": "CloseLimitOrderEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "limitOrder",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "filled",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SwapBoundedEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "xToY",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "amountIn",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountOut",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "ticksCrossed",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "tickLimit",
+          "type": "u16",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "tickmap",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bitmap",
-            "type": {
-              "array": [
-                "u8",
-                11091
-              ]
-            }
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
+      "name": "LaunchWindowSetEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "endSlot",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
     {
-      "name": "Price",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "v",
-            "type": "u128"
-          }
-        ]
-      }
+      "name": "LaunchWindowEndedEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "endSlot",
+          "type": "u64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "Liquidity",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "v",
-            "type": "u128"
-          }
-        ]
-      }
+      "name": "SkimExcessEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "FeeGrowth",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "v",
-            "type": "u128"
-          }
-        ]
-      }
+      "name": "ClaimReferralFeesEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "referrer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "FixedPoint",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "v",
-            "type": "u128"
-          }
-        ]
-      }
+      "name": "FlashLoanEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "borrower",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeY",
+          "type": "u64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "Record",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "timestamp",
-            "type": "u64"
-          },
-          {
-            "name": "price",
-            "type": {
-              "defined": "Price"
-            }
-          }
-        ]
-      }
+      "name": "ProposeAdminEvent",
+      "fields": [
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pendingAdmin",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChangeAdminEvent",
+      "fields": [
+        {
+          "name": "oldAdmin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newAdmin",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "WithdrawProtocolFeeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amountX",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountY",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LargeSwapEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "xToY",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "amountIn",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountOut",
+          "type": "u64",
+          "index": false
+        }
+      ]
     }
   ],
   "errors": [
@@ -3050,6 +12890,226 @@ export const IDL: Invariant = {
       "code": 6030,
       "name": "InvalidTickSpacing",
       "msg": "Invalid tick spacing"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidBatchSize",
+      "msg": "Invalid number of pools in batch"
+    },
+    {
+      "code": 6032,
+      "name": "InvalidPoolAddress",
+      "msg": "Provided pool address is different than expected"
+    },
+    {
+      "code": 6033,
+      "name": "InvalidFeeTier",
+      "msg": "Provided fee tier is different than expected"
+    },
+    {
+      "code": 6034,
+      "name": "InvalidPoolFee",
+      "msg": "Pool fee is out of bounds"
+    },
+    {
+      "code": 6035,
+      "name": "NoPendingPoolFee",
+      "msg": "There is no pending pool fee"
+    },
+    {
+      "code": 6036,
+      "name": "PoolFeeTimelocked",
+      "msg": "Pending pool fee is still timelocked"
+    },
+    {
+      "code": 6037,
+      "name": "AccountNotRentExempt",
+      "msg": "Account would not be rent exempt"
+    },
+    {
+      "code": 6038,
+      "name": "InvalidCloseDestination",
+      "msg": "Account can not be closed into itself"
+    },
+    {
+      "code": 6039,
+      "name": "WithdrawAclFull",
+      "msg": "Withdraw access list is full"
+    },
+    {
+      "code": 6040,
+      "name": "InvalidWithdrawer",
+      "msg": "Withdrawer is not on the access list"
+    },
+    {
+      "code": 6041,
+      "name": "InvalidReferrer",
+      "msg": "Referrer is not on the whitelist"
+    },
+    {
+      "code": 6042,
+      "name": "InsufficientDelegation",
+      "msg": "Delegated amount is not enough to cover the swap"
+    },
+    {
+      "code": 6043,
+      "name": "InvalidSessionAuthority",
+      "msg": "Session authority is invalid, expired or out of scope"
+    },
+    {
+      "code": 6044,
+      "name": "UnsupportedTokenDecimals",
+      "msg": "Token decimals are not supported"
+    },
+    {
+      "code": 6045,
+      "name": "PositionNotOrphaned",
+      "msg": "Both ticks of the position exist"
+    },
+    {
+      "code": 6046,
+      "name": "InvalidHookProgram",
+      "msg": "Hook program is not executable"
+    },
+    {
+      "code": 6047,
+      "name": "HookProgramNotFound",
+      "msg": "Hook program of the pool not found in remaining accounts"
+    },
+    {
+      "code": 6048,
+      "name": "TooManyHookCalls",
+      "msg": "Swap crosses too many ticks for the pool hook"
+    },
+    {
+      "code": 6049,
+      "name": "PriceChangeTooLarge",
+      "msg": "Swap moves the price more than allowed in a single transaction"
+    },
+    {
+      "code": 6050,
+      "name": "PoolWithdrawalOnly",
+      "msg": "Pool accepts only withdrawals"
+    },
+    {
+      "code": 6051,
+      "name": "InvalidRoute",
+      "msg": "Route accounts do not match its hops"
+    },
+    {
+      "code": 6052,
+      "name": "RouteAmountOutTooLow",
+      "msg": "Route output is below the minimum"
+    },
+    {
+      "code": 6053,
+      "name": "OracleNotFound",
+      "msg": "Oracle of the pool not found in remaining accounts"
+    },
+    {
+      "code": 6054,
+      "name": "NoSideSelected",
+      "msg": "Neither token of the position selected to claim"
+    },
+    {
+      "code": 6055,
+      "name": "InvalidRebateSchedule",
+      "msg": "Rebate tiers have to be ascending with rebates up to 100%"
+    },
+    {
+      "code": 6056,
+      "name": "LaunchWindowActive",
+      "msg": "Pool is in its launch window and the user is not whitelisted"
+    },
+    {
+      "code": 6057,
+      "name": "LaunchWhitelistFull",
+      "msg": "Launch whitelist is full"
+    },
+    {
+      "code": 6058,
+      "name": "InvalidLimitOrder",
+      "msg": "Limit order has to be one tick wide and outside of the current price"
+    },
+    {
+      "code": 6059,
+      "name": "LimitOrderNotFilled",
+      "msg": "Price has not crossed the whole range of the limit order"
+    },
+    {
+      "code": 6060,
+      "name": "DevnetOnly",
+      "msg": "Instruction is available only in devnet builds"
+    },
+    {
+      "code": 6061,
+      "name": "SimulationOnly",
+      "msg": "Simulation completed, all changes reverted"
+    },
+    {
+      "code": 6062,
+      "name": "InvalidTwapWindow",
+      "msg": "TWAP window is empty or not covered by the oracle"
+    },
+    {
+      "code": 6063,
+      "name": "InvalidReferralFee",
+      "msg": "Referral fee over 100%"
+    },
+    {
+      "code": 6064,
+      "name": "FlashLoansDisabled",
+      "msg": "Flash loans are disabled on the pool"
+    },
+    {
+      "code": 6065,
+      "name": "FlashLoanNotRepaid",
+      "msg": "Flash loan was not repaid with the fee"
+    },
+    {
+      "code": 6066,
+      "name": "InvalidFlashLoanFee",
+      "msg": "Flash loan fee over 100%"
+    },
+    {
+      "code": 6067,
+      "name": "InvalidPendingAdmin",
+      "msg": "Signer is not the proposed admin"
+    },
+    {
+      "code": 6068,
+      "name": "FeeTierRegistryFull",
+      "msg": "Fee tier registry is full"
+    },
+    {
+      "code": 6069,
+      "name": "ProtocolPaused",
+      "msg": "Protocol is paused"
+    },
+    {
+      "code": 6070,
+      "name": "RouteHopNotFilled",
+      "msg": "Intermediate hop of the route did not swap its whole input"
+    },
+    {
+      "code": 6071,
+      "name": "AccountAlreadyMigrated",
+      "msg": "Account already has the current layout"
+    },
+    {
+      "code": 6072,
+      "name": "LimitOrderFilled",
+      "msg": "Limit order is filled, it can only be claimed"
+    },
+    {
+      "code": 6073,
+      "name": "InvalidPositionNftUri",
+      "msg": "Base uri of the position NFT is too long or has a query"
+    },
+    {
+      "code": 6074,
+      "name": "InvalidPositionNftMetadata",
+      "msg": "Metadata account is not the one of the position NFT"
     }
   ]
 };
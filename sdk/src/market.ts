@@ -1,5 +1,5 @@
 import { BN, Program, utils, Provider } from '@project-serum/anchor'
-import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { ASSOCIATED_TOKEN_PROGRAM_ID, Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
 import {
   ComputeBudgetProgram,
   Connection,
@@ -33,6 +33,8 @@ import {
   PositionClaimData,
   SEED,
   SimulateClaim,
+  toAmountSpec,
+  toDirection,
   TokenData
 } from './utils'
 import { Invariant, IDL } from './idl/invariant'
@@ -44,6 +46,14 @@ const POSITION_SEED = 'positionv1'
 const TICK_SEED = 'tickv1'
 const POSITION_LIST_SEED = 'positionlistv1'
 const STATE_SEED = 'statev1'
+const ADMIN_LOG_SEED = 'adminlogv1'
+const GLOBAL_STATS_SEED = 'globalstatsv1'
+const FEE_TIER_REGISTRY_SEED = 'feetierregistryv1'
+const LIMIT_ORDER_SEED = 'limitorderv1'
+const POSITION_NFT_SEED = 'positionnftv1'
+export const TOKEN_METADATA_PROGRAM_ID = new PublicKey(
+  'metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s'
+)
 export const TICK_CROSSES_PER_IX = 19
 export const FEE_TIER = 'feetierv1'
 export const DEFAULT_PUBLIC_KEY = new PublicKey(0)
@@ -114,24 +124,29 @@ export class Market {
 
     const [poolAddress] = await pair.getAddressAndBump(this.program.programId)
     const { address: feeTierAddress } = await this.getFeeTierAddress(pair.feeTier)
+    const { address: globalStatsAddress } = await this.getGlobalStatsAddress()
 
-    const createIx = this.program.instruction.createPool(tick, {
-      accounts: {
-        state: stateAddress,
-        pool: poolAddress,
-        feeTier: feeTierAddress,
-        tickmap: bitmapKeypair.publicKey,
-        tokenX: pair.tokenX,
-        tokenY: pair.tokenY,
-        tokenXReserve: tokenXReserve.publicKey,
-        tokenYReserve: tokenYReserve.publicKey,
-        authority: this.programAuthority,
-        tokenProgram: TOKEN_PROGRAM_ID,
-        payer: payerPubkey,
-        rent: SYSVAR_RENT_PUBKEY,
-        systemProgram: SystemProgram.programId
+    const createIx = this.program.instruction.createPool(
+      { initTick: tick },
+      {
+        accounts: {
+          state: stateAddress,
+          pool: poolAddress,
+          feeTier: feeTierAddress,
+          globalStats: globalStatsAddress,
+          tickmap: bitmapKeypair.publicKey,
+          tokenX: pair.tokenX,
+          tokenY: pair.tokenY,
+          tokenXReserve: tokenXReserve.publicKey,
+          tokenYReserve: tokenYReserve.publicKey,
+          authority: this.programAuthority,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          payer: payerPubkey,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId
+        }
       }
-    })
+    )
 
     const transaction = new Transaction({
       feePayer: payerPubkey
@@ -522,17 +537,24 @@ export class Market {
     admin = admin ?? this.wallet.publicKey
     const { fee, tickSpacing } = feeTier
     const { address } = await this.getFeeTierAddress(feeTier)
+    const { address: feeTierRegistry } = await this.getFeeTierRegistryAddress()
+    const { address: adminLog } = await this.getAdminLogAddress()
     const ts = tickSpacing ?? feeToTickSpacing(fee)
 
-    return this.program.instruction.createFeeTier(fee, ts, {
-      accounts: {
-        state: this.stateAddress,
-        feeTier: address,
-        admin,
-        rent: SYSVAR_RENT_PUBKEY,
-        systemProgram: SystemProgram.programId
+    return this.program.instruction.createFeeTier(
+      { fee, tickSpacing: ts },
+      {
+        accounts: {
+          state: this.stateAddress,
+          feeTier: address,
+          feeTierRegistry,
+          adminLog,
+          admin,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId
+        }
       }
-    })
+    )
   }
 
   async createFeeTierTransaction(createFeeTier: CreateFeeTier) {
@@ -549,10 +571,10 @@ export class Market {
 
   async createStateInstruction(admin?: PublicKey) {
     admin = admin ?? this.wallet.publicKey
-    const { programAuthority, nonce } = await this.getProgramAuthority()
+    const { programAuthority } = await this.getProgramAuthority()
     const { address } = await this.getStateAddress()
 
-    return this.program.instruction.createState(nonce, {
+    return this.program.instruction.createState({
       accounts: {
         state: address,
         admin,
@@ -563,9 +585,63 @@ export class Market {
     })
   }
 
+  async createAdminLogInstruction(admin?: PublicKey) {
+    admin = admin ?? this.wallet.publicKey
+    const { address } = await this.getAdminLogAddress()
+
+    return this.program.instruction.createAdminLog({
+      accounts: {
+        adminLog: address,
+        state: this.stateAddress,
+        admin,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId
+      }
+    })
+  }
+
+  async createGlobalStatsInstruction(admin?: PublicKey) {
+    admin = admin ?? this.wallet.publicKey
+    const { address } = await this.getGlobalStatsAddress()
+    const { address: adminLog } = await this.getAdminLogAddress()
+
+    return this.program.instruction.createGlobalStats({
+      accounts: {
+        globalStats: address,
+        state: this.stateAddress,
+        adminLog,
+        admin,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId
+      }
+    })
+  }
+
+  async createFeeTierRegistryInstruction(admin?: PublicKey) {
+    admin = admin ?? this.wallet.publicKey
+    const { address } = await this.getFeeTierRegistryAddress()
+    const { address: adminLog } = await this.getAdminLogAddress()
+
+    return this.program.instruction.createFeeTierRegistry({
+      accounts: {
+        feeTierRegistry: address,
+        state: this.stateAddress,
+        adminLog,
+        admin,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId
+      }
+    })
+  }
+
+  // State together with the other program wide accounts, admin instructions log to the admin
+  // log, pools are counted in global stats and fee tiers are listed in the registry
   async createStateTransaction(admin?: PublicKey) {
-    const ix = await this.createStateInstruction(admin)
-    return new Transaction().add(ix)
+    return new Transaction()
+      .add(await this.createStateInstruction(admin))
+      .add(await this.createAdminLogInstruction(admin))
+      .add(await this.createGlobalStatsInstruction(admin))
+      .add(await this.createFeeTierRegistryInstruction(admin))
   }
 
   async createState(admin: PublicKey, signer: Keypair) {
@@ -591,6 +667,204 @@ export class Market {
     return (await this.program.account.state.fetch(address)) as State
   }
 
+  async getAdminLogAddress() {
+    const [address, bump] = await PublicKey.findProgramAddress(
+      [Buffer.from(utils.bytes.utf8.encode(ADMIN_LOG_SEED))],
+      this.program.programId
+    )
+
+    return {
+      address,
+      bump
+    }
+  }
+
+  async getGlobalStatsAddress() {
+    const [address, bump] = await PublicKey.findProgramAddress(
+      [Buffer.from(utils.bytes.utf8.encode(GLOBAL_STATS_SEED))],
+      this.program.programId
+    )
+
+    return {
+      address,
+      bump
+    }
+  }
+
+  async getFeeTierRegistryAddress() {
+    const [address, bump] = await PublicKey.findProgramAddress(
+      [Buffer.from(utils.bytes.utf8.encode(FEE_TIER_REGISTRY_SEED))],
+      this.program.programId
+    )
+
+    return {
+      address,
+      bump
+    }
+  }
+
+  async getFeeTierRegistry() {
+    const { address } = await this.getFeeTierRegistryAddress()
+    return (await this.program.account.feeTierRegistry.fetch(address)) as FeeTierRegistry
+  }
+
+  async getLimitOrderAddress(pool: PublicKey, id: BN) {
+    const [address, bump] = await PublicKey.findProgramAddress(
+      [
+        Buffer.from(utils.bytes.utf8.encode(LIMIT_ORDER_SEED)),
+        pool.toBuffer(),
+        id.toArrayLike(Buffer, 'le', 16)
+      ],
+      this.program.programId
+    )
+
+    return {
+      address,
+      bump
+    }
+  }
+  async getLimitOrder(pool: PublicKey, id: BN) {
+    const { address } = await this.getLimitOrderAddress(pool, id)
+    return (await this.program.account.limitOrder.fetch(address)) as LimitOrder
+  }
+
+  async getPositionNftMintAddress(pool: PublicKey, id: BN) {
+    const [address, bump] = await PublicKey.findProgramAddress(
+      [
+        Buffer.from(utils.bytes.utf8.encode(POSITION_NFT_SEED)),
+        pool.toBuffer(),
+        id.toArrayLike(Buffer, 'le', 16)
+      ],
+      this.program.programId
+    )
+
+    return {
+      address,
+      bump
+    }
+  }
+
+  // associated account of the holder with the position NFT
+  async getPositionNftAccount(pool: PublicKey, id: BN, holder: PublicKey) {
+    const { address: mint } = await this.getPositionNftMintAddress(pool, id)
+    return await Token.getAssociatedTokenAddress(
+      ASSOCIATED_TOKEN_PROGRAM_ID,
+      TOKEN_PROGRAM_ID,
+      mint,
+      holder
+    )
+  }
+
+  // positions created before position NFTs have to mint theirs before they are managed
+  async mintPositionNftInstruction({ owner, payer, index }: MintPositionNft) {
+    owner = owner ?? this.wallet.publicKey
+    payer = payer ?? owner
+    const { positionAddress } = await this.getPositionAddress(owner, index)
+    const position = await this.getPosition(owner, index)
+    const { address: positionNftMint } = await this.getPositionNftMintAddress(
+      position.pool,
+      position.id
+    )
+    const positionNftAccount = await this.getPositionNftAccount(position.pool, position.id, owner)
+
+    return this.program.instruction.mintPositionNft(index, {
+      accounts: {
+        state: this.stateAddress,
+        position: positionAddress,
+        positionNftMint,
+        positionNftAccount,
+        payer,
+        owner,
+        programAuthority: this.programAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId
+      }
+    })
+  }
+
+  async mintPositionNftTransaction(mintPositionNft: MintPositionNft) {
+    const ix = await this.mintPositionNftInstruction(mintPositionNft)
+    return new Transaction().add(ix)
+  }
+
+  async mintPositionNft(mintPositionNft: MintPositionNft, signer: Keypair) {
+    const tx = await this.mintPositionNftTransaction(mintPositionNft)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
+  // Metaplex metadata of the position NFT, passed in the remaining accounts of increase and
+  // decrease liquidity together with TOKEN_METADATA_PROGRAM_ID to keep its attributes up to date
+  async getPositionNftMetadataAddress(pool: PublicKey, id: BN) {
+    const { address: mint } = await this.getPositionNftMintAddress(pool, id)
+    const [address, bump] = await PublicKey.findProgramAddress(
+      [
+        Buffer.from(utils.bytes.utf8.encode('metadata')),
+        TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+        mint.toBuffer()
+      ],
+      TOKEN_METADATA_PROGRAM_ID
+    )
+
+    return {
+      address,
+      bump
+    }
+  }
+
+  async createPositionNftMetadataInstruction({
+    owner,
+    holder,
+    index,
+    baseUri
+  }: CreatePositionNftMetadata) {
+    owner = owner ?? this.wallet.publicKey
+    holder = holder ?? owner
+    const { positionAddress } = await this.getPositionAddress(owner, index)
+    const position = await this.getPosition(owner, index)
+    const { address: positionNftMint } = await this.getPositionNftMintAddress(
+      position.pool,
+      position.id
+    )
+    const { address: metadata } = await this.getPositionNftMetadataAddress(
+      position.pool,
+      position.id
+    )
+    const positionNftAccount = await this.getPositionNftAccount(position.pool, position.id, holder)
+
+    return this.program.instruction.createPositionNftMetadata(index, baseUri, {
+      accounts: {
+        state: this.stateAddress,
+        position: positionAddress,
+        positionNftMint,
+        metadata,
+        owner,
+        holder,
+        positionNftAccount,
+        programAuthority: this.programAuthority,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId
+      }
+    })
+  }
+
+  async createPositionNftMetadataTransaction(createPositionNftMetadata: CreatePositionNftMetadata) {
+    const ix = await this.createPositionNftMetadataInstruction(createPositionNftMetadata)
+    return new Transaction().add(ix)
+  }
+
+  async createPositionNftMetadata(
+    createPositionNftMetadata: CreatePositionNftMetadata,
+    signer: Keypair
+  ) {
+    const tx = await this.createPositionNftMetadataTransaction(createPositionNftMetadata)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
   async createTickInstruction({ pair, index, payer }: CreateTick) {
     payer = payer ?? this.wallet.publicKey
     const state = await this.getPool(pair)
@@ -679,6 +953,15 @@ export class Market {
     )
     const { positionListAddress } = await this.getPositionListAddress(owner)
     const poolAddress = await pair.getAddress(this.program.programId)
+    const { address: positionNftMint } = await this.getPositionNftMintAddress(
+      poolAddress,
+      state.positionIterator
+    )
+    const positionNftAccount = await this.getPositionNftAccount(
+      poolAddress,
+      state.positionIterator,
+      owner
+    )
 
     return this.program.instruction.createPosition(
       lowerTickIndex,
@@ -703,8 +986,11 @@ export class Market {
           accountY: userTokenY,
           reserveX: state.tokenXReserve,
           reserveY: state.tokenYReserve,
+          positionNftMint,
+          positionNftAccount,
           programAuthority: this.programAuthority,
           tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           rent: SYSVAR_RENT_PUBKEY,
           systemProgram: SystemProgram.programId
         }
@@ -799,6 +1085,7 @@ export class Market {
 
     const [poolAddress] = await pair.getAddressAndBump(this.program.programId)
     const { address: feeTierAddress } = await this.getFeeTierAddress(pair.feeTier)
+    const { address: globalStatsAddress } = await this.getGlobalStatsAddress()
 
     const { positionListAddress } = await this.getPositionListAddress(payerPubkey)
     const { tickAddress } = await this.getTickAddress(pair, lowerTick)
@@ -808,6 +1095,9 @@ export class Market {
     const head = listExists ? (await this.getPositionList(payerPubkey)).head : 0
 
     const { positionAddress } = await this.getPositionAddress(payerPubkey, head)
+    // first position of the pool
+    const { address: positionNftMint } = await this.getPositionNftMintAddress(poolAddress, new BN(0))
+    const positionNftAccount = await this.getPositionNftAccount(poolAddress, new BN(0), payerPubkey)
 
     const transaction = new Transaction({
       feePayer: payerPubkey
@@ -827,23 +1117,27 @@ export class Market {
         })
       )
       .add(
-        this.program.instruction.createPool(tick, {
-          accounts: {
-            state: stateAddress,
-            pool: poolAddress,
-            feeTier: feeTierAddress,
-            tickmap: bitmapKeypair.publicKey,
-            tokenX: pair.tokenX,
-            tokenY: pair.tokenY,
-            tokenXReserve: tokenXReserve.publicKey,
-            tokenYReserve: tokenYReserve.publicKey,
-            authority: this.programAuthority,
-            tokenProgram: TOKEN_PROGRAM_ID,
-            payer: payerPubkey,
-            rent: SYSVAR_RENT_PUBKEY,
-            systemProgram: SystemProgram.programId
+        this.program.instruction.createPool(
+          { initTick: tick },
+          {
+            accounts: {
+              state: stateAddress,
+              pool: poolAddress,
+              feeTier: feeTierAddress,
+              globalStats: globalStatsAddress,
+              tickmap: bitmapKeypair.publicKey,
+              tokenX: pair.tokenX,
+              tokenY: pair.tokenY,
+              tokenXReserve: tokenXReserve.publicKey,
+              tokenYReserve: tokenYReserve.publicKey,
+              authority: this.programAuthority,
+              tokenProgram: TOKEN_PROGRAM_ID,
+              payer: payerPubkey,
+              rent: SYSVAR_RENT_PUBKEY,
+              systemProgram: SystemProgram.programId
+            }
           }
-        })
+        )
       )
       .add(
         this.program.instruction.createTick(lowerTick, {
@@ -902,8 +1196,11 @@ export class Market {
             accountY: userTokenY,
             reserveX: tokenXReserve.publicKey,
             reserveY: tokenYReserve.publicKey,
+            positionNftMint,
+            positionNftAccount,
             programAuthority: this.programAuthority,
             tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             rent: SYSVAR_RENT_PUBKEY,
             systemProgram: SystemProgram.programId
           }
@@ -973,6 +1270,10 @@ export class Market {
     if (referralAccount) {
       remainingAccounts.unshift(referralAccount)
     }
+    // swaps record an observation in the oracle, so it has to be passed once initialized
+    if (pool.oracleInitialized) {
+      remainingAccounts.push(pool.oracleAddress)
+    }
 
     // trunk-ignore(eslint)
     const ra: Array<{ pubkey: PublicKey; isWritable: boolean; isSigner: boolean }> =
@@ -982,21 +1283,29 @@ export class Market {
 
     const tx: Transaction = new Transaction()
 
-    const swapIx = this.program.instruction.swap(xToY, amount, byAmountIn, priceLimit, {
-      remainingAccounts: ra,
-      accounts: {
-        state: this.stateAddress,
-        pool: poolAddress,
-        tickmap: pool.tickmap,
-        reserveX: pool.tokenXReserve,
-        reserveY: pool.tokenYReserve,
-        owner,
-        accountX,
-        accountY,
-        programAuthority: this.programAuthority,
-        tokenProgram: TOKEN_PROGRAM_ID
+    const swapIx = this.program.instruction.swap(
+      {
+        direction: toDirection(xToY),
+        amount,
+        amountSpec: toAmountSpec(byAmountIn),
+        sqrtPriceLimit: priceLimit
+      },
+      {
+        remainingAccounts: ra,
+        accounts: {
+          state: this.stateAddress,
+          pool: poolAddress,
+          tickmap: pool.tickmap,
+          reserveX: pool.tokenXReserve,
+          reserveY: pool.tokenYReserve,
+          owner,
+          accountX,
+          accountY,
+          programAuthority: this.programAuthority,
+          tokenProgram: TOKEN_PROGRAM_ID
+        }
       }
-    })
+    )
     tx.add(swapIx)
     return tx
   }
@@ -1027,10 +1336,12 @@ export class Market {
   async claimFeeInstruction(claimFee: ClaimFee) {
     const { pair, userTokenX, userTokenY, index } = claimFee
     const owner = claimFee.owner ?? this.wallet.publicKey
+    const holder = claimFee.holder ?? owner
 
     const state = await this.getPool(pair)
     const { positionAddress } = await this.getPositionAddress(owner, index)
     const position = await this.getPosition(owner, index)
+    const positionNftAccount = await this.getPositionNftAccount(position.pool, position.id, holder)
     const { tickAddress: lowerTickAddress } = await this.getTickAddress(
       pair,
       position.lowerTickIndex
@@ -1052,6 +1363,8 @@ export class Market {
           lowerTick: lowerTickAddress,
           upperTick: upperTickAddress,
           owner,
+          holder,
+          positionNftAccount,
           tokenX: pair.tokenX,
           tokenY: pair.tokenY,
           accountX: userTokenX,
@@ -1081,11 +1394,13 @@ export class Market {
     const admin = withdrawProtocolFee.admin ?? this.wallet.publicKey
 
     const pool = await this.getPool(pair)
+    const { address: globalStats } = await this.getGlobalStatsAddress()
 
     return this.program.instruction.withdrawProtocolFee({
       accounts: {
         state: this.stateAddress,
         pool: await pair.getAddress(this.program.programId),
+        globalStats,
         tokenX: pool.tokenX,
         tokenY: pool.tokenY,
         reserveX: pool.tokenXReserve,
@@ -1114,6 +1429,7 @@ export class Market {
   async removePositionInstruction(removePosition: RemovePosition): Promise<TransactionInstruction> {
     const { pair, index, userTokenX, userTokenY } = removePosition
     const owner = removePosition.owner ?? this.wallet.publicKey
+    const holder = removePosition.holder ?? owner
 
     const positionList = await this.getPositionList(owner)
     const { positionListAddress } = await this.getPositionListAddress(owner)
@@ -1125,6 +1441,12 @@ export class Market {
 
     const state = await this.getPool(pair)
     const position = await this.getPosition(owner, index)
+    const poolAddress = await pair.getAddress(this.program.programId)
+    const { address: positionNftMint } = await this.getPositionNftMintAddress(
+      poolAddress,
+      position.id
+    )
+    const positionNftAccount = await this.getPositionNftAccount(poolAddress, position.id, holder)
 
     const { tickAddress: lowerTickAddress } = await this.getTickAddress(
       pair,
@@ -1143,10 +1465,13 @@ export class Market {
         accounts: {
           state: this.stateAddress,
           owner: owner,
+          holder,
+          positionNftMint,
+          positionNftAccount,
           removedPosition: removedPositionAddress,
           positionList: positionListAddress,
           lastPosition: lastPositionAddress,
-          pool: await pair.getAddress(this.program.programId),
+          pool: poolAddress,
           tickmap: state.tickmap,
           lowerTick: lowerTickAddress,
           upperTick: upperTickAddress,
@@ -1174,6 +1499,152 @@ export class Market {
     await signAndSend(tx, [signer], this.connection)
   }
 
+  // Order on the range of one tick spacing starting at `lowerTickIndex`, which has to be
+  // entirely above the price when selling x and below it when selling y
+  async createLimitOrderInstruction(createLimitOrder: CreateLimitOrder) {
+    const { pair, lowerTickIndex, liquidity, userTokenX, userTokenY } = createLimitOrder
+    const owner = createLimitOrder.owner ?? this.wallet.publicKey
+    const payer = createLimitOrder.payer ?? owner
+    const upperTickIndex = lowerTickIndex + pair.tickSpacing
+
+    const state = await this.getPool(pair)
+    const poolAddress = await pair.getAddress(this.program.programId)
+    const { address: limitOrderAddress } = await this.getLimitOrderAddress(
+      poolAddress,
+      state.positionIterator
+    )
+    const { tickAddress: lowerTickAddress } = await this.getTickAddress(pair, lowerTickIndex)
+    const { tickAddress: upperTickAddress } = await this.getTickAddress(pair, upperTickIndex)
+
+    return this.program.instruction.createLimitOrder(lowerTickIndex, upperTickIndex, liquidity, {
+      accounts: {
+        state: this.stateAddress,
+        limitOrder: limitOrderAddress,
+        pool: poolAddress,
+        tickmap: state.tickmap,
+        lowerTick: lowerTickAddress,
+        upperTick: upperTickAddress,
+        payer,
+        owner,
+        tokenX: pair.tokenX,
+        tokenY: pair.tokenY,
+        accountX: userTokenX,
+        accountY: userTokenY,
+        reserveX: state.tokenXReserve,
+        reserveY: state.tokenYReserve,
+        programAuthority: this.programAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId
+      }
+    })
+  }
+
+  async createLimitOrderTransaction(createLimitOrder: CreateLimitOrder) {
+    const ix = await this.createLimitOrderInstruction(createLimitOrder)
+    return new Transaction().add(ix)
+  }
+
+  async createLimitOrder(createLimitOrder: CreateLimitOrder, signer: Keypair) {
+    const tx = await this.createLimitOrderTransaction(createLimitOrder)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
+  // Anyone can claim a filled order, the tokens go to the token accounts of its owner
+  async claimLimitOrderInstruction(closeLimitOrder: CloseLimitOrder) {
+    const { pair, id, userTokenX, userTokenY } = closeLimitOrder
+
+    const state = await this.getPool(pair)
+    const poolAddress = await pair.getAddress(this.program.programId)
+    const { address: limitOrderAddress } = await this.getLimitOrderAddress(poolAddress, id)
+    const limitOrder = await this.getLimitOrder(poolAddress, id)
+    const [exitTickIndex, entryTickIndex] = limitOrder.xToY
+      ? [limitOrder.upperTickIndex, limitOrder.lowerTickIndex]
+      : [limitOrder.lowerTickIndex, limitOrder.upperTickIndex]
+    const { tickAddress: exitTickAddress } = await this.getTickAddress(pair, exitTickIndex)
+    const { tickAddress: entryTickAddress } = await this.getTickAddress(pair, entryTickIndex)
+
+    return this.program.instruction.claimLimitOrder({
+      accounts: {
+        state: this.stateAddress,
+        limitOrder: limitOrderAddress,
+        pool: poolAddress,
+        tickmap: state.tickmap,
+        exitTick: exitTickAddress,
+        entryTick: entryTickAddress,
+        owner: limitOrder.owner,
+        tokenX: pair.tokenX,
+        tokenY: pair.tokenY,
+        accountX: userTokenX,
+        accountY: userTokenY,
+        reserveX: state.tokenXReserve,
+        reserveY: state.tokenYReserve,
+        programAuthority: this.programAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID
+      }
+    })
+  }
+
+  async claimLimitOrderTransaction(closeLimitOrder: CloseLimitOrder) {
+    const ix = await this.claimLimitOrderInstruction(closeLimitOrder)
+    return new Transaction().add(ix)
+  }
+
+  async claimLimitOrder(closeLimitOrder: CloseLimitOrder, signer: Keypair) {
+    const tx = await this.claimLimitOrderTransaction(closeLimitOrder)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
+  async cancelLimitOrderInstruction(closeLimitOrder: CloseLimitOrder) {
+    const { pair, id, userTokenX, userTokenY } = closeLimitOrder
+
+    const state = await this.getPool(pair)
+    const poolAddress = await pair.getAddress(this.program.programId)
+    const { address: limitOrderAddress } = await this.getLimitOrderAddress(poolAddress, id)
+    const limitOrder = await this.getLimitOrder(poolAddress, id)
+    const { tickAddress: lowerTickAddress } = await this.getTickAddress(
+      pair,
+      limitOrder.lowerTickIndex
+    )
+    const { tickAddress: upperTickAddress } = await this.getTickAddress(
+      pair,
+      limitOrder.upperTickIndex
+    )
+
+    return this.program.instruction.cancelLimitOrder({
+      accounts: {
+        state: this.stateAddress,
+        limitOrder: limitOrderAddress,
+        pool: poolAddress,
+        tickmap: state.tickmap,
+        lowerTick: lowerTickAddress,
+        upperTick: upperTickAddress,
+        owner: limitOrder.owner,
+        tokenX: pair.tokenX,
+        tokenY: pair.tokenY,
+        accountX: userTokenX,
+        accountY: userTokenY,
+        reserveX: state.tokenXReserve,
+        reserveY: state.tokenYReserve,
+        programAuthority: this.programAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID
+      }
+    })
+  }
+
+  async cancelLimitOrderTransaction(closeLimitOrder: CloseLimitOrder) {
+    const ix = await this.cancelLimitOrderInstruction(closeLimitOrder)
+    return new Transaction().add(ix)
+  }
+
+  async cancelLimitOrder(closeLimitOrder: CloseLimitOrder, signer: Keypair) {
+    const tx = await this.cancelLimitOrderTransaction(closeLimitOrder)
+
+    await signAndSend(tx, [signer], this.connection)
+  }
+
   async transferPositionOwnershipInstruction(
     transferPositionOwnership: TransferPositionOwnership
   ): Promise<TransactionInstruction> {
@@ -1294,11 +1765,13 @@ export class Market {
     admin = admin ?? this.wallet.publicKey
 
     const { address: stateAddress } = await this.getStateAddress()
+    const { address: adminLog } = await this.getAdminLogAddress()
     const poolAddress = await pair.getAddress(this.program.programId)
 
     return this.program.instruction.changeProtocolFee(protocolFee, {
       accounts: {
         state: stateAddress,
+        adminLog,
         pool: poolAddress,
         tokenX: pair.tokenX,
         tokenY: pair.tokenY,
@@ -1323,11 +1796,13 @@ export class Market {
     const { pair, feeReceiver } = changeFeeReceiver
     const adminPubkey = changeFeeReceiver.admin ?? this.wallet.publicKey
     const { address: stateAddress } = await this.getStateAddress()
+    const { address: adminLog } = await this.getAdminLogAddress()
     const poolAddress = await pair.getAddress(this.program.programId)
 
     return this.program.instruction.changeFeeReceiver({
       accounts: {
         state: stateAddress,
+        adminLog,
         pool: poolAddress,
         tokenX: pair.tokenX,
         tokenY: pair.tokenY,
@@ -1453,6 +1928,8 @@ export interface State {
   nonce: number
   authority: PublicKey
   bump: number
+  pendingAdmin: PublicKey
+  isPaused: boolean
 }
 
 export interface FeeTierStructure {
@@ -1461,6 +1938,17 @@ export interface FeeTierStructure {
   bump: number
 }
 
+export interface FeeTierEntry {
+  fee: Decimal
+  tickSpacing: number
+}
+
+export interface FeeTierRegistry {
+  count: number
+  entries: FeeTierEntry[]
+  bump: number
+}
+
 export interface PoolStructure {
   tokenX: PublicKey
   tokenY: PublicKey
@@ -1485,6 +1973,23 @@ export interface PoolStructure {
   oracleAddress: PublicKey
   oracleInitialized: boolean
   bump: number
+  feeTierFee: Decimal
+  pendingFee: Decimal
+  pendingFeeTimestamp: BN
+  tickEma: BN
+  tickEmaHalfLife: number
+  tickEmaLastTimestamp: BN
+  hookProgram: PublicKey
+  maxPriceChangeBpsPerTx: number
+  withdrawalOnly: boolean
+  launchEndSlot: BN
+  referralFeeBps: number
+  reserveX: BN
+  reserveY: BN
+  flashLoanFeeBps: number
+  flashLoansEnabled: boolean
+  largeSwapThresholdX: BN
+  largeSwapThresholdY: BN
 }
 
 export interface PoolData {
@@ -1516,6 +2021,22 @@ export interface Tick {
   feeGrowthOutsideY: Decimal
   secondsPerLiquidityOutside: Decimal
   bump: number
+  limitOrderLiquidityBelow: Decimal
+  limitOrderLiquidityAbove: Decimal
+  limitOrderFillsBelow: BN
+  limitOrderFillsAbove: BN
+  limitOrders: number
+}
+export interface LimitOrder {
+  owner: PublicKey
+  pool: PublicKey
+  id: BN
+  liquidity: Decimal
+  lowerTickIndex: number
+  upperTickIndex: number
+  xToY: boolean
+  fills: BN
+  bump: number
 }
 
 export interface Position {
@@ -1532,6 +2053,10 @@ export interface Position {
   tokensOwedX: Decimal
   tokensOwedY: Decimal
   bump: number
+  makerFeesX: Decimal
+  makerFeesY: Decimal
+  totalClaimedX: BN
+  totalClaimedY: BN
 }
 
 export interface PositionStructure {
@@ -1602,6 +2127,7 @@ export interface CreatePool extends CreatePoolTx {
 export interface ClaimFee {
   pair: Pair
   owner?: PublicKey
+  holder?: PublicKey
   userTokenX: PublicKey
   userTokenY: PublicKey
   index: number
@@ -1647,13 +2173,41 @@ export interface WithdrawProtocolFee {
   accountY: PublicKey
   admin?: PublicKey
 }
+export interface MintPositionNft {
+  owner?: PublicKey
+  payer?: PublicKey
+  index: number
+}
+export interface CreatePositionNftMetadata {
+  owner?: PublicKey
+  holder?: PublicKey
+  index: number
+  // pool, range and liquidity of the position are appended as the query
+  baseUri: string
+}
 export interface RemovePosition {
   pair: Pair
   owner?: PublicKey
+  holder?: PublicKey
   index: number
   userTokenX: PublicKey
   userTokenY: PublicKey
 }
+export interface CreateLimitOrder {
+  pair: Pair
+  owner?: PublicKey
+  payer?: PublicKey
+  lowerTickIndex: number
+  liquidity: Decimal
+  userTokenX: PublicKey
+  userTokenY: PublicKey
+}
+export interface CloseLimitOrder {
+  pair: Pair
+  id: BN
+  userTokenX: PublicKey
+  userTokenY: PublicKey
+}
 export interface TransferPositionOwnership {
   owner?: PublicKey
   recipient?: PublicKey
@@ -96,7 +96,51 @@ export enum INVARIANT_ERRORS {
   INVALID_TICKMAP = '0x178b',
   INVALID_TICKMAP_OWNER = '0x178c',
   INVALID_LIST_OWNER = '0x178d',
-  INVALID_TICK_SPACING = '0x178e'
+  INVALID_TICK_SPACING = '0x178e',
+  INVALID_BATCH_SIZE = '0x178f',
+  INVALID_POOL_ADDRESS = '0x1790',
+  INVALID_FEE_TIER = '0x1791',
+  INVALID_POOL_FEE = '0x1792',
+  NO_PENDING_POOL_FEE = '0x1793',
+  POOL_FEE_TIMELOCKED = '0x1794',
+  ACCOUNT_NOT_RENT_EXEMPT = '0x1795',
+  INVALID_CLOSE_DESTINATION = '0x1796',
+  WITHDRAW_ACL_FULL = '0x1797',
+  INVALID_WITHDRAWER = '0x1798',
+  INVALID_REFERRER = '0x1799',
+  INSUFFICIENT_DELEGATION = '0x179a',
+  INVALID_SESSION_AUTHORITY = '0x179b',
+  UNSUPPORTED_TOKEN_DECIMALS = '0x179c',
+  POSITION_NOT_ORPHANED = '0x179d',
+  INVALID_HOOK_PROGRAM = '0x179e',
+  HOOK_PROGRAM_NOT_FOUND = '0x179f',
+  TOO_MANY_HOOK_CALLS = '0x17a0',
+  PRICE_CHANGE_TOO_LARGE = '0x17a1',
+  POOL_WITHDRAWAL_ONLY = '0x17a2',
+  INVALID_ROUTE = '0x17a3',
+  ROUTE_AMOUNT_OUT_TOO_LOW = '0x17a4',
+  ORACLE_NOT_FOUND = '0x17a5',
+  NO_SIDE_SELECTED = '0x17a6',
+  INVALID_REBATE_SCHEDULE = '0x17a7',
+  LAUNCH_WINDOW_ACTIVE = '0x17a8',
+  LAUNCH_WHITELIST_FULL = '0x17a9',
+  INVALID_LIMIT_ORDER = '0x17aa',
+  LIMIT_ORDER_NOT_FILLED = '0x17ab',
+  DEVNET_ONLY = '0x17ac',
+  SIMULATION_ONLY = '0x17ad',
+  INVALID_TWAP_WINDOW = '0x17ae',
+  INVALID_REFERRAL_FEE = '0x17af',
+  FLASH_LOANS_DISABLED = '0x17b0',
+  FLASH_LOAN_NOT_REPAID = '0x17b1',
+  INVALID_FLASH_LOAN_FEE = '0x17b2',
+  INVALID_PENDING_ADMIN = '0x17b3',
+  FEE_TIER_REGISTRY_FULL = '0x17b4',
+  PROTOCOL_PAUSED = '0x17b5',
+  ROUTE_HOP_NOT_FILLED = '0x17b6',
+  ACCOUNT_ALREADY_MIGRATED = '0x17b7',
+  LIMIT_ORDER_FILLED = '0x17b8',
+  INVALID_POSITION_NFT_URI = '0x17b9',
+  INVALID_POSITION_NFT_METADATA = '0x17ba'
 }
 
 export interface SimulateSwapPrice {
@@ -332,6 +376,11 @@ export const getFeeTierAddress = async ({ fee, tickSpacing }: FeeTier, programId
   }
 }
 
+// Swap arguments as anchor encodes the `Direction` and `AmountSpec` enums of the program
+export const toDirection = (xToY: boolean) => (xToY ? { xtoY: {} } : { ytoX: {} })
+
+export const toAmountSpec = (byAmountIn: boolean) => (byAmountIn ? { in: {} } : { out: {} })
+
 export const toDecimal = (x: number, decimals: number = 0): Decimal => {
   return { v: DENOMINATOR.muln(x).div(new BN(10).pow(new BN(decimals))) }
 }
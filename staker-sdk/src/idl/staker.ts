@@ -2,9 +2,149 @@ export type Staker = {
   "version": "0.1.0",
   "name": "staker",
   "instructions": [
+    {
+      "name": "createConfig",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "pauseAll",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setRewardMintAllowlist",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "addRewardMint",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "removeRewardMint",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
     {
       "name": "createIncentive",
       "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "incentive",
           "isMut": true,
@@ -62,10 +202,6 @@ export type Staker = {
         }
       ],
       "args": [
-        {
-          "name": "nonce",
-          "type": "u8"
-        },
         {
           "name": "reward",
           "type": {
@@ -83,6 +219,14 @@ export type Staker = {
           "type": {
             "defined": "Seconds"
           }
+        },
+        {
+          "name": "extraRewards",
+          "type": {
+            "vec": {
+              "defined": "TokenAmount"
+            }
+          }
         }
       ]
     },
@@ -104,6 +248,16 @@ export type Staker = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "invariantState",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "owner",
           "isMut": false,
@@ -134,6 +288,12 @@ export type Staker = {
         {
           "name": "index",
           "type": "i32"
+        },
+        {
+          "name": "lockPeriod",
+          "type": {
+            "option": "u64"
+          }
         }
       ]
     },
@@ -160,6 +320,11 @@ export type Staker = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "ownerTokenAccount",
           "isMut": true,
@@ -185,16 +350,17 @@ export type Staker = {
         {
           "name": "index",
           "type": "i32"
-        },
-        {
-          "name": "nonce",
-          "type": "u8"
         }
       ]
     },
     {
-      "name": "endIncentive",
+      "name": "withdrawNative",
       "accounts": [
+        {
+          "name": "userStake",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "incentive",
           "isMut": true,
@@ -206,40 +372,65 @@ export type Staker = {
           "isSigner": false
         },
         {
-          "name": "founderTokenAccount",
-          "isMut": true,
+          "name": "position",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "incentiveToken",
+          "name": "positionNftAccount",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "stakerAuthority",
+          "name": "unwrapAccount",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "nativeMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "founder",
+          "name": "stakerAuthority",
           "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "holder",
+          "isMut": true,
           "isSigner": true
         },
         {
           "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "nonce",
-          "type": "u8"
+          "name": "index",
+          "type": "i32"
         }
       ]
     },
     {
-      "name": "removeStake",
+      "name": "endIncentive",
       "accounts": [
         {
           "name": "incentive",
@@ -247,54 +438,288 @@ export type Staker = {
           "isSigner": false
         },
         {
-          "name": "userStake",
+          "name": "incentiveTokenAccount",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "founderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "incentiveToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakerAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "founder",
           "isMut": false,
           "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": []
     },
     {
-      "name": "closeStakeByOwner",
+      "name": "extendClaimWindow",
       "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "incentive",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userStake",
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newEndClaimTime",
+          "type": {
+            "defined": "Seconds"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateRewardRate",
+      "accounts": [
+        {
+          "name": "incentive",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "position",
+          "name": "incentiveTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "founderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stakerAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "owner",
+          "name": "founder",
           "isMut": false,
           "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "index",
-          "type": "i32"
+          "name": "futureReward",
+          "type": {
+            "defined": "TokenAmount"
+          }
         }
       ]
-    }
-  ],
-  "accounts": [
+    },
     {
-      "name": "incentive",
-      "type": {
+      "name": "removeStake",
+      "accounts": [
+        {
+          "name": "incentive",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userStake",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "founder",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeStakeByOwner",
+      "accounts": [
+        {
+          "name": "incentive",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userStake",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "holder",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "i32"
+        }
+      ]
+    },
+    {
+      "name": "adoptStake",
+      "accounts": [
+        {
+          "name": "legacyStake",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userStake",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "incentive",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "migrateIncentive",
+      "accounts": [
+        {
+          "name": "incentive",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateUserStake",
+      "accounts": [
+        {
+          "name": "userStake",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "publicKey"
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "rewardMintAllowlist",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "incentive",
+      "type": {
         "kind": "struct",
         "fields": [
           {
@@ -346,6 +771,49 @@ export type Staker = {
           {
             "name": "nonce",
             "type": "u8"
+          },
+          {
+            "name": "extraRewards",
+            "type": {
+              "array": [
+                {
+                  "defined": "ExtraReward"
+                },
+                2
+              ]
+            }
+          },
+          {
+            "name": "extraRewardsCount",
+            "type": "u8"
+          },
+          {
+            "name": "accruedReward",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "accruedSeconds",
+            "type": {
+              "defined": "Seconds"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "rewardMint",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
           }
         ]
       }
@@ -378,6 +846,16 @@ export type Staker = {
           {
             "name": "bump",
             "type": "u8"
+          },
+          {
+            "name": "lockEnd",
+            "type": {
+              "defined": "Seconds"
+            }
+          },
+          {
+            "name": "boost",
+            "type": "u16"
           }
         ]
       }
@@ -431,6 +909,24 @@ export type Staker = {
           }
         ]
       }
+    },
+    {
+      "name": "ExtraReward",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "totalRewardUnclaimed",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          }
+        ]
+      }
     }
   ],
   "errors": [
@@ -548,6 +1044,56 @@ export type Staker = {
       "code": 6022,
       "name": "DifferentIncentivePool",
       "msg": "Incentive pool is different from stake pool"
+    },
+    {
+      "code": 6023,
+      "name": "Paused",
+      "msg": "Staker is paused"
+    },
+    {
+      "code": 6024,
+      "name": "InvalidAdmin",
+      "msg": "Admin address is different than expected"
+    },
+    {
+      "code": 6025,
+      "name": "InvalidClaimTime",
+      "msg": "New claim time has to be later than the current one"
+    },
+    {
+      "code": 6026,
+      "name": "RewardMintNotAllowed",
+      "msg": "Reward mint is not on the allowlist"
+    },
+    {
+      "code": 6027,
+      "name": "TooManyRewards",
+      "msg": "Too many extra rewards"
+    },
+    {
+      "code": 6028,
+      "name": "InvalidLockPeriod",
+      "msg": "Lock period is not one of the supported ones"
+    },
+    {
+      "code": 6029,
+      "name": "StakeLocked",
+      "msg": "Stake is locked"
+    },
+    {
+      "code": 6030,
+      "name": "StakeAlreadyExists",
+      "msg": "Position is already staked in the incentive"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidCloseDestination",
+      "msg": "Account can not be closed into itself"
+    },
+    {
+      "code": 6032,
+      "name": "AccountAlreadyMigrated",
+      "msg": "Account already has the current layout"
     }
   ]
 };
@@ -557,17 +1103,444 @@ export const IDL: Staker = {
   "name": "staker",
   "instructions": [
     {
-      "name": "createIncentive",
+      "name": "createConfig",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "pauseAll",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setRewardMintAllowlist",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "addRewardMint",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "removeRewardMint",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createIncentive",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "incentive",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "incentiveTokenAccount",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "founderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "founder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "stakerAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "incentiveToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "invariant",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "reward",
+          "type": {
+            "defined": "TokenAmount"
+          }
+        },
+        {
+          "name": "startTime",
+          "type": {
+            "defined": "Seconds"
+          }
+        },
+        {
+          "name": "endTime",
+          "type": {
+            "defined": "Seconds"
+          }
+        },
+        {
+          "name": "extraRewards",
+          "type": {
+            "vec": {
+              "defined": "TokenAmount"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "stake",
+      "accounts": [
+        {
+          "name": "userStake",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "incentive",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "invariantState",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "invariant",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "i32"
+        },
+        {
+          "name": "lockPeriod",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "withdraw",
+      "accounts": [
+        {
+          "name": "userStake",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "incentive",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "incentiveTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stakerAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "i32"
+        }
+      ]
+    },
+    {
+      "name": "withdrawNative",
+      "accounts": [
+        {
+          "name": "userStake",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "incentive",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "incentiveTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "position",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "positionNftAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "unwrapAccount",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "nativeMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakerAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "holder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "i32"
+        }
+      ]
+    },
+    {
+      "name": "endIncentive",
       "accounts": [
         {
           "name": "incentive",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
           "name": "incentiveTokenAccount",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
           "name": "founderTokenAccount",
@@ -575,65 +1548,50 @@ export const IDL: Staker = {
           "isSigner": false
         },
         {
-          "name": "pool",
+          "name": "incentiveToken",
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "founder",
-          "isMut": true,
-          "isSigner": true
-        },
         {
           "name": "stakerAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "incentiveToken",
+          "name": "founder",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
           "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "extendClaimWindow",
+      "accounts": [
         {
-          "name": "invariant",
+          "name": "config",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "incentive",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "admin",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         }
       ],
       "args": [
         {
-          "name": "nonce",
-          "type": "u8"
-        },
-        {
-          "name": "reward",
-          "type": {
-            "defined": "TokenAmount"
-          }
-        },
-        {
-          "name": "startTime",
-          "type": {
-            "defined": "Seconds"
-          }
-        },
-        {
-          "name": "endTime",
+          "name": "newEndClaimTime",
           "type": {
             "defined": "Seconds"
           }
@@ -641,86 +1599,84 @@ export const IDL: Staker = {
       ]
     },
     {
-      "name": "stake",
+      "name": "updateRewardRate",
       "accounts": [
-        {
-          "name": "userStake",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "position",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "incentive",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "owner",
-          "isMut": false,
+          "name": "incentiveTokenAccount",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "signer",
+          "name": "founderTokenAccount",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "invariant",
+          "name": "stakerAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "founder",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "rent",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "index",
-          "type": "i32"
+          "name": "futureReward",
+          "type": {
+            "defined": "TokenAmount"
+          }
         }
       ]
     },
     {
-      "name": "withdraw",
+      "name": "removeStake",
       "accounts": [
-        {
-          "name": "userStake",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "incentive",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "incentiveTokenAccount",
+          "name": "userStake",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "position",
+          "name": "founder",
           "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeStakeByOwner",
+      "accounts": [
+        {
+          "name": "incentive",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "ownerTokenAccount",
+          "name": "userStake",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "stakerAuthority",
+          "name": "position",
           "isMut": false,
           "isSigner": false
         },
@@ -730,70 +1686,71 @@ export const IDL: Staker = {
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "positionNftAccount",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "holder",
+          "isMut": false,
+          "isSigner": true
         }
       ],
       "args": [
         {
           "name": "index",
           "type": "i32"
-        },
-        {
-          "name": "nonce",
-          "type": "u8"
         }
       ]
     },
     {
-      "name": "endIncentive",
+      "name": "adoptStake",
       "accounts": [
         {
-          "name": "incentive",
+          "name": "legacyStake",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "incentiveTokenAccount",
+          "name": "userStake",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "founderTokenAccount",
-          "isMut": true,
+          "name": "position",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "incentiveToken",
-          "isMut": false,
+          "name": "incentive",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "stakerAuthority",
-          "isMut": false,
-          "isSigner": false
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "founder",
+          "name": "systemProgram",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "nonce",
-          "type": "u8"
+          "name": "index",
+          "type": "u32"
         }
       ]
     },
     {
-      "name": "removeStake",
+      "name": "migrateIncentive",
       "accounts": [
         {
           "name": "incentive",
@@ -801,51 +1758,65 @@ export const IDL: Staker = {
           "isSigner": false
         },
         {
-          "name": "userStake",
+          "name": "payer",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "founder",
+          "name": "systemProgram",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         }
       ],
       "args": []
     },
     {
-      "name": "closeStakeByOwner",
+      "name": "migrateUserStake",
       "accounts": [
         {
-          "name": "incentive",
+          "name": "userStake",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userStake",
+          "name": "payer",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "position",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
-        },
-        {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": true
         }
       ],
-      "args": [
-        {
-          "name": "index",
-          "type": "i32"
-        }
-      ]
+      "args": []
     }
   ],
   "accounts": [
+    {
+      "name": "config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "publicKey"
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "rewardMintAllowlist",
+            "type": "bool"
+          }
+        ]
+      }
+    },
     {
       "name": "incentive",
       "type": {
@@ -900,6 +1871,49 @@ export const IDL: Staker = {
           {
             "name": "nonce",
             "type": "u8"
+          },
+          {
+            "name": "extraRewards",
+            "type": {
+              "array": [
+                {
+                  "defined": "ExtraReward"
+                },
+                2
+              ]
+            }
+          },
+          {
+            "name": "extraRewardsCount",
+            "type": "u8"
+          },
+          {
+            "name": "accruedReward",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          },
+          {
+            "name": "accruedSeconds",
+            "type": {
+              "defined": "Seconds"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "rewardMint",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
           }
         ]
       }
@@ -932,6 +1946,16 @@ export const IDL: Staker = {
           {
             "name": "bump",
             "type": "u8"
+          },
+          {
+            "name": "lockEnd",
+            "type": {
+              "defined": "Seconds"
+            }
+          },
+          {
+            "name": "boost",
+            "type": "u16"
           }
         ]
       }
@@ -985,6 +2009,24 @@ export const IDL: Staker = {
           }
         ]
       }
+    },
+    {
+      "name": "ExtraReward",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "totalRewardUnclaimed",
+            "type": {
+              "defined": "TokenAmount"
+            }
+          }
+        ]
+      }
     }
   ],
   "errors": [
@@ -1102,6 +2144,56 @@ export const IDL: Staker = {
       "code": 6022,
       "name": "DifferentIncentivePool",
       "msg": "Incentive pool is different from stake pool"
+    },
+    {
+      "code": 6023,
+      "name": "Paused",
+      "msg": "Staker is paused"
+    },
+    {
+      "code": 6024,
+      "name": "InvalidAdmin",
+      "msg": "Admin address is different than expected"
+    },
+    {
+      "code": 6025,
+      "name": "InvalidClaimTime",
+      "msg": "New claim time has to be later than the current one"
+    },
+    {
+      "code": 6026,
+      "name": "RewardMintNotAllowed",
+      "msg": "Reward mint is not on the allowlist"
+    },
+    {
+      "code": 6027,
+      "name": "TooManyRewards",
+      "msg": "Too many extra rewards"
+    },
+    {
+      "code": 6028,
+      "name": "InvalidLockPeriod",
+      "msg": "Lock period is not one of the supported ones"
+    },
+    {
+      "code": 6029,
+      "name": "StakeLocked",
+      "msg": "Stake is locked"
+    },
+    {
+      "code": 6030,
+      "name": "StakeAlreadyExists",
+      "msg": "Position is already staked in the incentive"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidCloseDestination",
+      "msg": "Account can not be closed into itself"
+    },
+    {
+      "code": 6032,
+      "name": "AccountAlreadyMigrated",
+      "msg": "Account already has the current layout"
     }
   ]
 };
@@ -14,7 +14,12 @@ import {
   Keypair,
   sendAndConfirmRawTransaction
 } from '@solana/web3.js'
-import { STAKER_SEED } from './utils'
+import {
+  BPF_LOADER_UPGRADEABLE_ID,
+  CONFIG_SEED,
+  INVARIANT_STATE_SEED,
+  STAKER_SEED
+} from './utils'
 import { bs58 } from '@project-serum/anchor/dist/cjs/utils/bytes'
 
 export class Staker {
@@ -55,6 +60,14 @@ export class Staker {
   }
 
   // frontend methods
+  public async createConfig() {
+    const createConfigIx = await this.createConfigIx()
+    const tx = new Transaction().add(createConfigIx)
+    const stringTx = await this.signAndSend(tx)
+
+    return stringTx
+  }
+
   public async createIncentive(createIncentive: CreateIncentive) {
     const incentiveAccount = Keypair.generate()
     const incentiveTokenAccount = Keypair.generate()
@@ -117,7 +130,7 @@ export class Staker {
   }
 
   public async closeStakeByOwner(closeStake: CloseStake) {
-    const { pool, id, incentive, position, owner, index } = closeStake
+    const { pool, id, incentive, position, owner, index, positionNftAccount, holder } = closeStake
     const [userStakeAddress] = await this.getUserStakeAddressAndBump(incentive, pool, id)
 
     const closeIx = await this.closeStakeByOwnerIx(
@@ -125,7 +138,9 @@ export class Staker {
       incentive,
       position,
       owner,
-      index
+      index,
+      positionNftAccount,
+      holder
     )
     const tx = new Transaction().add(closeIx)
     const stringTx = await this.signAndSend(tx)
@@ -154,6 +169,26 @@ export class Staker {
 
   // instructions
 
+  // only the upgrade authority of the program can create the config
+  public async createConfigIx(admin?: PublicKey) {
+    const [config] = await this.getConfigAddressAndBump()
+    const [programData] = await PublicKey.findProgramAddress(
+      [this.programId.toBuffer()],
+      BPF_LOADER_UPGRADEABLE_ID
+    )
+
+    return this.program.instruction.createConfig({
+      accounts: {
+        config,
+        admin: admin ?? this.wallet.publicKey,
+        program: this.programId,
+        programData,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId
+      }
+    })
+  }
+
   public async createIncentiveIx(
     {
       reward,
@@ -168,13 +203,16 @@ export class Staker {
     incentive: PublicKey,
     incentiveTokenAccount: PublicKey
   ) {
+    const [config] = await this.getConfigAddressAndBump()
+
     return this.program.instruction.createIncentive(
-      this.programAuthority.nonce,
       reward,
       startTime,
       endTime,
+      [],
       {
         accounts: {
+          config,
           incentive: incentive,
           pool,
           incentiveTokenAccount,
@@ -199,15 +237,23 @@ export class Staker {
     owner,
     signer,
     index,
+    lockPeriod,
     invariant
   }: CreateStake) {
     const [userStakeAddress] = await this.getUserStakeAddressAndBump(incentive, pool, id)
+    const [config] = await this.getConfigAddressAndBump()
+    const [invariantState] = await PublicKey.findProgramAddress(
+      [Buffer.from(INVARIANT_STATE_SEED)],
+      invariant
+    )
 
-    return this.program.instruction.stake(index, {
+    return this.program.instruction.stake(index, lockPeriod ?? null, {
       accounts: {
         userStake: userStakeAddress,
         position,
         incentive,
+        config,
+        invariantState,
         owner,
         signer: signer ?? owner,
         systemProgram: SystemProgram.programId,
@@ -224,18 +270,20 @@ export class Staker {
     incentiveTokenAccount,
     ownerTokenAcc,
     position,
+    positionNftAccount,
     owner,
     index
   }: Withdraw) {
     const [userStakeAddress] = await this.getUserStakeAddressAndBump(incentive, pool, id)
 
-    return this.program.instruction.withdraw(index, this.programAuthority.nonce, {
+    return this.program.instruction.withdraw(index, {
       accounts: {
         userStake: userStakeAddress,
         incentive,
         incentiveTokenAccount: incentiveTokenAccount,
         ownerTokenAccount: ownerTokenAcc,
         position,
+        positionNftAccount,
         stakerAuthority: this.programAuthority.authority,
         owner,
         tokenProgram: TOKEN_PROGRAM_ID
@@ -250,7 +298,7 @@ export class Staker {
     founderTokenAccount,
     founder
   }: EndIncentive) {
-    return this.program.instruction.endIncentive(this.programAuthority.nonce, {
+    return this.program.instruction.endIncentive({
       accounts: {
         incentive,
         incentiveToken,
@@ -278,14 +326,18 @@ export class Staker {
     incentive: PublicKey,
     position: PublicKey,
     owner: PublicKey,
-    index: number
+    index: number,
+    positionNftAccount: PublicKey,
+    holder: PublicKey = owner
   ) {
     return this.program.instruction.closeStakeByOwner(index, {
       accounts: {
         incentive,
         userStake,
         position,
-        owner
+        owner,
+        positionNftAccount,
+        holder
       }
     })
   }
@@ -303,6 +355,15 @@ export class Staker {
     }
   }
 
+  public async getConfigAddressAndBump() {
+    return await PublicKey.findProgramAddress([Buffer.from(CONFIG_SEED)], this.programId)
+  }
+
+  public async getConfig() {
+    const [config] = await this.getConfigAddressAndBump()
+    return (await this.program.account.config.fetch(config)) as Config
+  }
+
   public async getIncentive(incentivePubKey: PublicKey) {
     return (await this.program.account.incentive.fetch(incentivePubKey)) as IncentiveStructure
   }
@@ -402,6 +463,7 @@ export interface CreateStake {
   owner: PublicKey
   signer?: PublicKey
   index: number
+  lockPeriod?: BN
   invariant: PublicKey
 }
 export interface Stake {
@@ -410,6 +472,8 @@ export interface Stake {
   secondsPerLiquidityInitial: Decimal
   liquidity: Decimal
   bump: number
+  lockEnd: Decimal
+  boost: number
 }
 export interface Withdraw {
   incentive: PublicKey
@@ -418,6 +482,8 @@ export interface Withdraw {
   incentiveTokenAccount: PublicKey
   ownerTokenAcc: PublicKey
   position: PublicKey
+  // account of the holder with the position NFT, rewards go to the holder
+  positionNftAccount: PublicKey
   owner: PublicKey
   index: number
 }
@@ -437,6 +503,8 @@ export interface CloseStake {
   position: PublicKey
   owner: PublicKey
   index: number
+  positionNftAccount: PublicKey
+  holder?: PublicKey
 }
 
 export interface IncentiveStructure {
@@ -450,6 +518,22 @@ export interface IncentiveStructure {
   numOfStakes: BN
   pool: PublicKey
   nonce: number
+  extraRewards: ExtraReward[]
+  extraRewardsCount: number
+  accruedReward: Decimal
+  accruedSeconds: Decimal
+}
+
+export interface ExtraReward {
+  tokenAccount: PublicKey
+  totalRewardUnclaimed: Decimal
+}
+
+export interface Config {
+  admin: PublicKey
+  paused: boolean
+  bump: number
+  rewardMintAllowlist: boolean
 }
 
 export interface Incentive extends IncentiveStructure {
@@ -2,6 +2,7 @@ import { FeeTier, PoolStructure, Tick, Tickmap } from '@invariant-labs/sdk/src/m
 import { isInitialized, findClosestTicks, MAX_TICK, MIN_TICK } from '@invariant-labs/sdk/src/math'
 import { FEE_DENOMINATOR, parseLiquidityOnTicks } from '@invariant-labs/sdk/src/utils'
 import { BN } from '@project-serum/anchor'
+import { PublicKey } from '@solana/web3.js'
 import { count } from 'console'
 
 export const DECIMAL = 12
@@ -14,7 +15,17 @@ export enum STAKER_ERRORS {
   START_IN_PAST = '0x1775',
   TO_LONG_DURATION = '0x1774',
   ENDED = '0x1776',
-  DIFFERENT_INCENTIVE_POOL = '0x1786'
+  DIFFERENT_INCENTIVE_POOL = '0x1786',
+  PAUSED = '0x1787',
+  INVALID_ADMIN = '0x1788',
+  INVALID_CLAIM_TIME = '0x1789',
+  REWARD_MINT_NOT_ALLOWED = '0x178a',
+  TOO_MANY_REWARDS = '0x178b',
+  INVALID_LOCK_PERIOD = '0x178c',
+  STAKE_LOCKED = '0x178d',
+  STAKE_ALREADY_EXISTS = '0x178e',
+  INVALID_CLOSE_DESTINATION = '0x178f',
+  ACCOUNT_ALREADY_MIGRATED = '0x1790'
 }
 
 export interface Decimal {
@@ -26,6 +37,11 @@ export const toDecimal = (x: number, decimals: number = 0): Decimal => {
 }
 
 export const STAKER_SEED = 'staker'
+export const CONFIG_SEED = 'configv1'
+export const INVARIANT_STATE_SEED = 'statev1'
+export const BPF_LOADER_UPGRADEABLE_ID = new PublicKey(
+  'BPFLoaderUpgradeab1e11111111111111111111111'
+)
 
 export const fromInteger = (integer: number): { v: BN } => {
   return { v: new BN(integer).mul(DENOMINATOR) }
@@ -0,0 +1,764 @@
+// Harness of the integration tests. Both programs run natively in solana-program-test, so the
+// tests go through the same instructions, account constraints and CPIs as the TypeScript suites,
+// with the clock under their control.
+use anchor_lang::{AnchorDeserialize, Id, InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::{get_associated_token_address, AssociatedToken};
+use anchor_spl::token::spl_token;
+use bytemuck::Pod;
+use invariant::args::*;
+use invariant::decimals::*;
+use invariant::structs::{Pool, Position, PositionList, Tickmap};
+use invariant_types::errors::InvariantErrorCode;
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::account::Account;
+use solana_sdk::clock::Clock;
+use solana_sdk::hash::hash;
+use solana_sdk::instruction::{AccountMeta, Instruction, InstructionError};
+use solana_sdk::program_pack::Pack;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::rent::Rent;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::{Transaction, TransactionError};
+use solana_sdk::{system_instruction, system_program, sysvar};
+use std::mem::size_of;
+
+pub const FEE_TIER_FEE: u128 = 6_000_000_000; // 0.6% as raw FixedPoint
+pub const TICK_SPACING: u16 = 10;
+pub const TOKEN_DECIMALS: u8 = 6;
+
+pub fn state_address() -> Pubkey {
+    Pubkey::find_program_address(&[b"statev1"], &invariant::ID).0
+}
+
+pub fn program_authority() -> Pubkey {
+    Pubkey::find_program_address(&[b"Invariant"], &invariant::ID).0
+}
+
+pub fn admin_log_address() -> Pubkey {
+    Pubkey::find_program_address(&[b"adminlogv1"], &invariant::ID).0
+}
+
+pub fn global_stats_address() -> Pubkey {
+    Pubkey::find_program_address(&[b"globalstatsv1"], &invariant::ID).0
+}
+
+pub fn fee_tier_registry_address() -> Pubkey {
+    Pubkey::find_program_address(&[b"feetierregistryv1"], &invariant::ID).0
+}
+
+pub fn fee_tier_address(fee: u128, tick_spacing: u16) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"feetierv1",
+            invariant::ID.as_ref(),
+            &fee.to_le_bytes(),
+            &tick_spacing.to_le_bytes(),
+        ],
+        &invariant::ID,
+    )
+    .0
+}
+
+pub fn pool_address(token_x: &Pubkey, token_y: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"poolv1",
+            token_x.as_ref(),
+            token_y.as_ref(),
+            &FEE_TIER_FEE.to_le_bytes(),
+            &TICK_SPACING.to_le_bytes(),
+        ],
+        &invariant::ID,
+    )
+    .0
+}
+
+pub fn tick_address(pool: &Pubkey, index: i32) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"tickv1", pool.as_ref(), &index.to_le_bytes()],
+        &invariant::ID,
+    )
+    .0
+}
+
+pub fn position_list_address(owner: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"positionlistv1", owner.as_ref()], &invariant::ID).0
+}
+
+pub fn position_address(owner: &Pubkey, index: u32) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"positionv1", owner.as_ref(), &index.to_le_bytes()],
+        &invariant::ID,
+    )
+    .0
+}
+
+pub fn position_nft_account(pool: &Pubkey, id: u128, holder: &Pubkey) -> Pubkey {
+    let mint = invariant::structs::position_nft_mint(pool, id);
+    get_associated_token_address(holder, &mint)
+}
+
+// Anchor marks only `Signer` accounts as signers, so keypairs of `init` accounts and signers
+// checked by the handler have to be marked by hand
+pub fn with_signers(mut accounts: Vec<AccountMeta>, signers: &[Pubkey]) -> Vec<AccountMeta> {
+    for account in accounts.iter_mut() {
+        account.is_signer |= signers.contains(&account.pubkey);
+    }
+    accounts
+}
+
+// Custom error a transaction failed with, programs report theirs as ERROR_CODE_OFFSET + code
+pub fn custom_error(result: Result<(), BanksClientError>) -> Option<u32> {
+    match result.err()?.unwrap() {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => Some(code),
+        _ => None,
+    }
+}
+
+pub fn assert_invariant_error(result: Result<(), BanksClientError>, error: InvariantErrorCode) {
+    assert_eq!(custom_error(result), Some(u32::from(error)));
+}
+
+// Invariant and the staker, with the staker config injected as create_config can only be
+// called by the upgrade authority of a deployed program
+pub fn program_test() -> ProgramTest {
+    let mut program_test =
+        ProgramTest::new("invariant", invariant::ID, processor!(invariant::entry));
+    program_test.add_program("staker", staker::ID, processor!(staker::entry));
+
+    let (config, bump) = Pubkey::find_program_address(&[b"configv1"], &staker::ID);
+    let mut data = hash(b"account:Config").to_bytes()[..8].to_vec();
+    data.extend_from_slice(Pubkey::new_unique().as_ref()); // admin
+    data.extend_from_slice(&[0, bump, 0]); // paused, bump, reward_mint_allowlist
+    program_test.add_account(
+        config,
+        Account {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner: staker::ID,
+            ..Account::default()
+        },
+    );
+    program_test
+}
+
+pub struct TestPool {
+    pub address: Pubkey,
+    pub token_x: Pubkey,
+    pub token_y: Pubkey,
+    pub reserve_x: Pubkey,
+    pub reserve_y: Pubkey,
+    pub tickmap: Pubkey,
+}
+
+pub struct TestPosition {
+    pub owner: Pubkey,
+    pub index: u32,
+    pub id: u128,
+    pub lower_tick_index: i32,
+    pub upper_tick_index: i32,
+}
+
+pub struct TestEnv {
+    pub context: ProgramTestContext,
+}
+
+impl TestEnv {
+    // Starts the programs with the protocol set up by the payer as its admin, along with the
+    // 0.6% fee tier the pools of the tests are created in
+    pub async fn start(program_test: ProgramTest) -> Self {
+        let mut env = TestEnv {
+            context: program_test.start_with_context().await,
+        };
+        let admin = env.payer();
+
+        env.process(
+            &[
+                Instruction {
+                    program_id: invariant::ID,
+                    accounts: invariant::accounts::CreateState {
+                        state: state_address(),
+                        admin,
+                        program_authority: program_authority(),
+                        rent: sysvar::rent::ID,
+                        system_program: system_program::ID,
+                    }
+                    .to_account_metas(None),
+                    data: invariant::instruction::CreateState {}.data(),
+                },
+                Instruction {
+                    program_id: invariant::ID,
+                    accounts: invariant::accounts::CreateAdminLog {
+                        admin_log: admin_log_address(),
+                        state: state_address(),
+                        admin,
+                        rent: sysvar::rent::ID,
+                        system_program: system_program::ID,
+                    }
+                    .to_account_metas(None),
+                    data: invariant::instruction::CreateAdminLog {}.data(),
+                },
+                Instruction {
+                    program_id: invariant::ID,
+                    accounts: invariant::accounts::CreateGlobalStats {
+                        global_stats: global_stats_address(),
+                        state: state_address(),
+                        admin_log: admin_log_address(),
+                        admin,
+                        rent: sysvar::rent::ID,
+                        system_program: system_program::ID,
+                    }
+                    .to_account_metas(None),
+                    data: invariant::instruction::CreateGlobalStats {}.data(),
+                },
+                Instruction {
+                    program_id: invariant::ID,
+                    accounts: invariant::accounts::CreateFeeTierRegistry {
+                        fee_tier_registry: fee_tier_registry_address(),
+                        state: state_address(),
+                        admin_log: admin_log_address(),
+                        admin,
+                        rent: sysvar::rent::ID,
+                        system_program: system_program::ID,
+                    }
+                    .to_account_metas(None),
+                    data: invariant::instruction::CreateFeeTierRegistry {}.data(),
+                },
+                Instruction {
+                    program_id: invariant::ID,
+                    accounts: invariant::accounts::CreateFeeTier {
+                        fee_tier: fee_tier_address(FEE_TIER_FEE, TICK_SPACING),
+                        fee_tier_registry: fee_tier_registry_address(),
+                        state: state_address(),
+                        admin_log: admin_log_address(),
+                        admin,
+                        rent: sysvar::rent::ID,
+                        system_program: system_program::ID,
+                    }
+                    .to_account_metas(None),
+                    data: invariant::instruction::CreateFeeTier {
+                        args: CreateFeeTierArgs {
+                            fee: FEE_TIER_FEE,
+                            tick_spacing: TICK_SPACING,
+                        },
+                    }
+                    .data(),
+                },
+            ],
+            &[],
+        )
+        .await
+        .unwrap();
+        env
+    }
+
+    pub fn payer(&self) -> Pubkey {
+        self.context.payer.pubkey()
+    }
+
+    async fn transaction(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Transaction {
+        let blockhash = self
+            .context
+            .banks_client
+            .get_latest_blockhash()
+            .await
+            .unwrap();
+        let mut all_signers = vec![&self.context.payer];
+        all_signers.extend_from_slice(signers);
+        Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.context.payer.pubkey()),
+            &all_signers,
+            blockhash,
+        )
+    }
+
+    // Signed by the payer on top of the given signers
+    pub async fn process(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<(), BanksClientError> {
+        let transaction = self.transaction(instructions, signers).await;
+        self.context
+            .banks_client
+            .process_transaction(transaction)
+            .await
+    }
+
+    // Return data set by the last instruction of the transaction
+    pub async fn process_with_return_data(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<Vec<u8>, BanksClientError> {
+        let transaction = self.transaction(instructions, signers).await;
+        let result = self
+            .context
+            .banks_client
+            .process_transaction_with_metadata(transaction)
+            .await?;
+        result.result.map_err(BanksClientError::TransactionError)?;
+        Ok(result
+            .metadata
+            .and_then(|metadata| metadata.return_data)
+            .map(|return_data| return_data.data)
+            .unwrap_or_default())
+    }
+
+    pub async fn account(&mut self, address: &Pubkey) -> Option<Account> {
+        self.context
+            .banks_client
+            .get_account(*address)
+            .await
+            .unwrap()
+    }
+
+    // Zero copy accounts of the programs, read past their discriminator
+    pub async fn load<T: Pod>(&mut self, address: &Pubkey) -> T {
+        let account = self.account(address).await.unwrap();
+        *bytemuck::from_bytes(&account.data[8..8 + size_of::<T>()])
+    }
+
+    pub async fn balance(&mut self, token_account: &Pubkey) -> u64 {
+        let account = self.account(token_account).await.unwrap();
+        spl_token::state::Account::unpack(&account.data)
+            .unwrap()
+            .amount
+    }
+
+    pub async fn now(&mut self) -> u64 {
+        let clock: Clock = self.context.banks_client.get_sysvar().await.unwrap();
+        clock.unix_timestamp as u64
+    }
+
+    // Moves to the next slot, with the clock the given number of seconds later
+    pub async fn advance(&mut self, seconds: u64) {
+        let before: Clock = self.context.banks_client.get_sysvar().await.unwrap();
+        self.context.warp_to_slot(before.slot + 1).unwrap();
+        let after: Clock = self.context.banks_client.get_sysvar().await.unwrap();
+        self.context.set_sysvar(&Clock {
+            unix_timestamp: before.unix_timestamp + seconds as i64,
+            ..after
+        });
+    }
+
+    pub async fn create_mint(&mut self) -> Pubkey {
+        let mint = Keypair::new();
+        let rent = self.context.banks_client.get_rent().await.unwrap();
+        let payer = self.payer();
+        self.process(
+            &[
+                system_instruction::create_account(
+                    &payer,
+                    &mint.pubkey(),
+                    rent.minimum_balance(spl_token::state::Mint::LEN),
+                    spl_token::state::Mint::LEN as u64,
+                    &spl_token::ID,
+                ),
+                spl_token::instruction::initialize_mint(
+                    &spl_token::ID,
+                    &mint.pubkey(),
+                    &payer,
+                    None,
+                    TOKEN_DECIMALS,
+                )
+                .unwrap(),
+            ],
+            &[&mint],
+        )
+        .await
+        .unwrap();
+        mint.pubkey()
+    }
+
+    // Mints ordered the way pools require them, by their base58 representation
+    pub async fn create_pair(&mut self) -> (Pubkey, Pubkey) {
+        let (a, b) = (self.create_mint().await, self.create_mint().await);
+        match a.to_string() < b.to_string() {
+            true => (a, b),
+            false => (b, a),
+        }
+    }
+
+    pub async fn create_token_account(&mut self, mint: &Pubkey, owner: &Pubkey) -> Pubkey {
+        let account = Keypair::new();
+        let rent = self.context.banks_client.get_rent().await.unwrap();
+        let payer = self.payer();
+        self.process(
+            &[
+                system_instruction::create_account(
+                    &payer,
+                    &account.pubkey(),
+                    rent.minimum_balance(spl_token::state::Account::LEN),
+                    spl_token::state::Account::LEN as u64,
+                    &spl_token::ID,
+                ),
+                spl_token::instruction::initialize_account(
+                    &spl_token::ID,
+                    &account.pubkey(),
+                    mint,
+                    owner,
+                )
+                .unwrap(),
+            ],
+            &[&account],
+        )
+        .await
+        .unwrap();
+        account.pubkey()
+    }
+
+    pub async fn mint_to(&mut self, mint: &Pubkey, account: &Pubkey, amount: u64) {
+        let payer = self.payer();
+        self.process(
+            &[
+                spl_token::instruction::mint_to(&spl_token::ID, mint, account, &payer, &[], amount)
+                    .unwrap(),
+            ],
+            &[],
+        )
+        .await
+        .unwrap();
+    }
+
+    // Token accounts of the owner for both tokens of the pool, funded with the given amount
+    pub async fn create_funded_accounts(
+        &mut self,
+        pool: &TestPool,
+        owner: &Pubkey,
+        amount: u64,
+    ) -> (Pubkey, Pubkey) {
+        let account_x = self.create_token_account(&pool.token_x, owner).await;
+        let account_y = self.create_token_account(&pool.token_y, owner).await;
+        self.mint_to(&pool.token_x, &account_x, amount).await;
+        self.mint_to(&pool.token_y, &account_y, amount).await;
+        (account_x, account_y)
+    }
+
+    // Pool of the 0.6% fee tier, the tickmap is created in the same transaction as `zero`
+    // accounts have to be
+    pub async fn create_pool(
+        &mut self,
+        token_x: Pubkey,
+        token_y: Pubkey,
+        init_tick: i32,
+    ) -> TestPool {
+        let (tickmap, reserve_x, reserve_y) = (Keypair::new(), Keypair::new(), Keypair::new());
+        let tickmap_size = 8 + size_of::<Tickmap>();
+        let rent = self.context.banks_client.get_rent().await.unwrap();
+        let payer = self.payer();
+        let pool = TestPool {
+            address: pool_address(&token_x, &token_y),
+            token_x,
+            token_y,
+            reserve_x: reserve_x.pubkey(),
+            reserve_y: reserve_y.pubkey(),
+            tickmap: tickmap.pubkey(),
+        };
+
+        self.process(
+            &[
+                system_instruction::create_account(
+                    &payer,
+                    &pool.tickmap,
+                    rent.minimum_balance(tickmap_size),
+                    tickmap_size as u64,
+                    &invariant::ID,
+                ),
+                Instruction {
+                    program_id: invariant::ID,
+                    accounts: with_signers(
+                        invariant::accounts::CreatePool {
+                            state: state_address(),
+                            pool: pool.address,
+                            fee_tier: fee_tier_address(FEE_TIER_FEE, TICK_SPACING),
+                            global_stats: global_stats_address(),
+                            tickmap: pool.tickmap,
+                            token_x,
+                            token_y,
+                            token_x_reserve: pool.reserve_x,
+                            token_y_reserve: pool.reserve_y,
+                            payer,
+                            authority: program_authority(),
+                            token_program: spl_token::ID,
+                            rent: sysvar::rent::ID,
+                            system_program: system_program::ID,
+                        }
+                        .to_account_metas(None),
+                        &[pool.reserve_x, pool.reserve_y],
+                    ),
+                    data: invariant::instruction::CreatePool {
+                        args: CreatePoolArgs { init_tick },
+                    }
+                    .data(),
+                },
+            ],
+            &[&tickmap, &reserve_x, &reserve_y],
+        )
+        .await
+        .unwrap();
+        pool
+    }
+
+    pub async fn create_tick(&mut self, pool: &TestPool, index: i32) {
+        let payer = self.payer();
+        self.process(
+            &[Instruction {
+                program_id: invariant::ID,
+                accounts: invariant::accounts::CreateTick {
+                    tick: tick_address(&pool.address, index),
+                    pool: pool.address,
+                    tickmap: pool.tickmap,
+                    payer,
+                    token_x: pool.token_x,
+                    token_y: pool.token_y,
+                    rent: sysvar::rent::ID,
+                    system_program: system_program::ID,
+                }
+                .to_account_metas(None),
+                data: invariant::instruction::CreateTick { index }.data(),
+            }],
+            &[],
+        )
+        .await
+        .unwrap();
+    }
+
+    // Position of the owner funded from the given accounts, the list of the owner is created
+    // along with the first one. Slippage is not limited.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_position(
+        &mut self,
+        pool: &TestPool,
+        owner: &Keypair,
+        account_x: &Pubkey,
+        account_y: &Pubkey,
+        lower_tick_index: i32,
+        upper_tick_index: i32,
+        liquidity: Liquidity,
+    ) -> TestPosition {
+        let payer = self.payer();
+        let position_list = position_list_address(&owner.pubkey());
+        let mut instructions = vec![];
+        let index = match self.account(&position_list).await {
+            Some(_) => self.load::<PositionList>(&position_list).await.head,
+            None => {
+                instructions.push(Instruction {
+                    program_id: invariant::ID,
+                    accounts: invariant::accounts::CreatePositionList {
+                        position_list,
+                        owner: owner.pubkey(),
+                        signer: payer,
+                        rent: sysvar::rent::ID,
+                        system_program: system_program::ID,
+                    }
+                    .to_account_metas(None),
+                    data: invariant::instruction::CreatePositionList {}.data(),
+                });
+                0
+            }
+        };
+        let id = self.load::<Pool>(&pool.address).await.position_iterator;
+        let position_nft_mint = invariant::structs::position_nft_mint(&pool.address, id);
+
+        instructions.push(Instruction {
+            program_id: invariant::ID,
+            accounts: invariant::accounts::CreatePosition {
+                state: state_address(),
+                position: position_address(&owner.pubkey(), index),
+                pool: pool.address,
+                position_list,
+                payer,
+                owner: owner.pubkey(),
+                lower_tick: tick_address(&pool.address, lower_tick_index),
+                upper_tick: tick_address(&pool.address, upper_tick_index),
+                tickmap: pool.tickmap,
+                token_x: pool.token_x,
+                token_y: pool.token_y,
+                account_x: *account_x,
+                account_y: *account_y,
+                reserve_x: pool.reserve_x,
+                reserve_y: pool.reserve_y,
+                position_nft_mint,
+                position_nft_account: get_associated_token_address(
+                    &owner.pubkey(),
+                    &position_nft_mint,
+                ),
+                program_authority: program_authority(),
+                token_program: spl_token::ID,
+                associated_token_program: AssociatedToken::id(),
+                rent: sysvar::rent::ID,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: invariant::instruction::CreatePosition {
+                _lower_tick_index: lower_tick_index,
+                _upper_tick_index: upper_tick_index,
+                liquidity_delta: liquidity,
+                slippage_limit_lower: Price::new(invariant_types::MIN_SQRT_PRICE),
+                slippage_limit_upper: Price::new(invariant_types::MAX_SQRT_PRICE),
+            }
+            .data(),
+        });
+        self.process(&instructions, &[owner]).await.unwrap();
+
+        TestPosition {
+            owner: owner.pubkey(),
+            index,
+            id,
+            lower_tick_index,
+            upper_tick_index,
+        }
+    }
+
+    pub async fn position(&mut self, position: &TestPosition) -> Position {
+        self.load(&position_address(&position.owner, position.index))
+            .await
+    }
+
+    // Exact input swap, ticks it crosses have to be passed
+    #[allow(clippy::too_many_arguments)]
+    pub async fn swap(
+        &mut self,
+        pool: &TestPool,
+        owner: &Keypair,
+        account_x: &Pubkey,
+        account_y: &Pubkey,
+        direction: Direction,
+        amount: u64,
+        remaining_accounts: &[AccountMeta],
+    ) -> Result<SwapReturnData, BanksClientError> {
+        let sqrt_price_limit = match direction {
+            Direction::XtoY => invariant_types::MIN_SQRT_PRICE,
+            Direction::YtoX => invariant_types::MAX_SQRT_PRICE,
+        };
+        let mut accounts = invariant::accounts::Swap {
+            state: state_address(),
+            pool: pool.address,
+            tickmap: pool.tickmap,
+            account_x: *account_x,
+            account_y: *account_y,
+            reserve_x: pool.reserve_x,
+            reserve_y: pool.reserve_y,
+            owner: owner.pubkey(),
+            program_authority: program_authority(),
+            token_program: spl_token::ID,
+        }
+        .to_account_metas(None);
+        accounts.extend_from_slice(remaining_accounts);
+
+        let data = self
+            .process_with_return_data(
+                &[Instruction {
+                    program_id: invariant::ID,
+                    accounts,
+                    data: invariant::instruction::Swap {
+                        args: SwapArgs {
+                            direction,
+                            amount,
+                            amount_spec: AmountSpec::In,
+                            sqrt_price_limit,
+                        },
+                    }
+                    .data(),
+                }],
+                &[owner],
+            )
+            .await?;
+        Ok(SwapReturnData::try_from_slice(&data).unwrap())
+    }
+
+    // Fees of the position go to the accounts of the holder of its NFT
+    pub async fn claim_fee(
+        &mut self,
+        pool: &TestPool,
+        position: &TestPosition,
+        holder: &Keypair,
+        account_x: &Pubkey,
+        account_y: &Pubkey,
+    ) -> Result<(), BanksClientError> {
+        self.process(
+            &[Instruction {
+                program_id: invariant::ID,
+                accounts: with_signers(
+                    invariant::accounts::ClaimFee {
+                        state: state_address(),
+                        pool: pool.address,
+                        position: position_address(&position.owner, position.index),
+                        lower_tick: tick_address(&pool.address, position.lower_tick_index),
+                        upper_tick: tick_address(&pool.address, position.upper_tick_index),
+                        owner: position.owner,
+                        holder: holder.pubkey(),
+                        position_nft_account: position_nft_account(
+                            &pool.address,
+                            position.id,
+                            &holder.pubkey(),
+                        ),
+                        token_x: pool.token_x,
+                        token_y: pool.token_y,
+                        account_x: *account_x,
+                        account_y: *account_y,
+                        reserve_x: pool.reserve_x,
+                        reserve_y: pool.reserve_y,
+                        program_authority: program_authority(),
+                        token_program: spl_token::ID,
+                    }
+                    .to_account_metas(None),
+                    &[holder.pubkey()],
+                ),
+                data: invariant::instruction::ClaimFee {
+                    _index: position.index,
+                    _lower_tick_index: position.lower_tick_index,
+                    _upper_tick_index: position.upper_tick_index,
+                }
+                .data(),
+            }],
+            &[holder],
+        )
+        .await
+    }
+
+    // Brings seconds per liquidity of the position to the current slot, the staker requires it
+    // in the same transaction as stake and withdraw
+    pub fn update_seconds_per_liquidity(
+        &self,
+        pool: &TestPool,
+        position: &TestPosition,
+    ) -> Instruction {
+        Instruction {
+            program_id: invariant::ID,
+            accounts: invariant::accounts::UpdateSecondsPerLiquidity {
+                pool: pool.address,
+                lower_tick: tick_address(&pool.address, position.lower_tick_index),
+                upper_tick: tick_address(&pool.address, position.upper_tick_index),
+                position: position_address(&position.owner, position.index),
+                token_x: pool.token_x,
+                token_y: pool.token_y,
+                owner: position.owner,
+                signer: self.payer(),
+                rent: sysvar::rent::ID,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: invariant::instruction::UpdateSecondsPerLiquidity {
+                _lower_tick_index: position.lower_tick_index,
+                _upper_tick_index: position.upper_tick_index,
+                _index: position.index as i32,
+            }
+            .data(),
+        }
+    }
+}
+
+// Tick accounts passed to a swap, in the order it crosses them
+pub fn tick_accounts(pool: &TestPool, indexes: &[i32]) -> Vec<AccountMeta> {
+    indexes
+        .iter()
+        .map(|index| AccountMeta::new(tick_address(&pool.address, *index), false))
+        .collect()
+}
@@ -0,0 +1,203 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token;
+use integration_tests::*;
+use invariant::decimals::{Factories, Liquidity};
+use invariant::structs::Pool;
+use invariant_types::errors::InvariantErrorCode;
+use solana_program_test::processor;
+use solana_sdk::account_info::AccountInfo;
+use solana_sdk::entrypoint::ProgramResult;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::program::invoke;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use std::convert::TryInto;
+
+const FLASH_LOAN_FEE_BPS: u16 = 30;
+const LOAN: u64 = 100_000;
+const REPAY: u8 = 1;
+
+// Borrower called back by flash_loan with its token account, the reserve, itself as the signer
+// and the token program. Repays the loan with the fee if the data passed through asks it to.
+fn process_borrower(_program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    // discriminator, pool, amount_x, amount_y, fee_x, fee_y, then the borsh encoded data
+    let amount_x = u64::from_le_bytes(data[40..48].try_into().unwrap());
+    let fee_x = u64::from_le_bytes(data[56..64].try_into().unwrap());
+    if data[76] != REPAY {
+        return Ok(());
+    }
+    invoke(
+        &spl_token::instruction::transfer(
+            &spl_token::ID,
+            accounts[0].key,
+            accounts[1].key,
+            accounts[2].key,
+            &[],
+            amount_x + fee_x,
+        )?,
+        accounts,
+    )
+}
+
+fn flash_loan(
+    pool: &TestPool,
+    borrower_program: &Pubkey,
+    borrower: &Keypair,
+    account_x: &Pubkey,
+    account_y: &Pubkey,
+    amount_x: u64,
+    action: u8,
+) -> Instruction {
+    let mut accounts = invariant::accounts::FlashLoan {
+        state: state_address(),
+        pool: pool.address,
+        account_x: *account_x,
+        account_y: *account_y,
+        reserve_x: pool.reserve_x,
+        reserve_y: pool.reserve_y,
+        borrower: borrower.pubkey(),
+        program_authority: program_authority(),
+        token_program: spl_token::ID,
+    }
+    .to_account_metas(None);
+    accounts.extend([
+        AccountMeta::new_readonly(*borrower_program, false),
+        AccountMeta::new(*account_x, false),
+        AccountMeta::new(pool.reserve_x, false),
+        AccountMeta::new_readonly(borrower.pubkey(), true),
+        AccountMeta::new_readonly(spl_token::ID, false),
+    ]);
+
+    Instruction {
+        program_id: invariant::ID,
+        accounts,
+        data: invariant::instruction::FlashLoan {
+            amount_x,
+            amount_y: 0,
+            data: vec![action],
+        }
+        .data(),
+    }
+}
+
+// Loans are only given once the admin sets their fee, have to be repaid within the transaction
+// and the fee they pay is shared between the liquidity providers and the protocol like a swap fee
+#[tokio::test]
+async fn test_flash_loan() {
+    let borrower_program = Pubkey::new_unique();
+    let mut program_test = program_test();
+    program_test.add_program("borrower", borrower_program, processor!(process_borrower));
+    let mut env = TestEnv::start(program_test).await;
+
+    let (token_x, token_y) = env.create_pair().await;
+    let pool = env.create_pool(token_x, token_y, 0).await;
+    env.create_tick(&pool, -20).await;
+    env.create_tick(&pool, 20).await;
+    let lp = Keypair::new();
+    let (lp_x, lp_y) = env
+        .create_funded_accounts(&pool, &lp.pubkey(), 10u64.pow(12))
+        .await;
+    let position = env
+        .create_position(
+            &pool,
+            &lp,
+            &lp_x,
+            &lp_y,
+            -20,
+            20,
+            Liquidity::from_integer(1_000_000_000),
+        )
+        .await;
+
+    let borrower = Keypair::new();
+    let (account_x, account_y) = env
+        .create_funded_accounts(&pool, &borrower.pubkey(), 1_000)
+        .await;
+
+    let result = env
+        .process(
+            &[flash_loan(
+                &pool,
+                &borrower_program,
+                &borrower,
+                &account_x,
+                &account_y,
+                1,
+                REPAY,
+            )],
+            &[&borrower],
+        )
+        .await;
+    assert_invariant_error(result, InvariantErrorCode::FlashLoansDisabled);
+
+    let admin = env.payer();
+    env.process(
+        &[Instruction {
+            program_id: invariant::ID,
+            accounts: invariant::accounts::SetFlashLoanFee {
+                state: state_address(),
+                admin_log: admin_log_address(),
+                pool: pool.address,
+                token_x,
+                token_y,
+                admin,
+            }
+            .to_account_metas(None),
+            data: invariant::instruction::SetFlashLoanFee {
+                flash_loan_fee_bps: FLASH_LOAN_FEE_BPS,
+            }
+            .data(),
+        }],
+        &[],
+    )
+    .await
+    .unwrap();
+
+    let result = env
+        .process(
+            &[flash_loan(
+                &pool,
+                &borrower_program,
+                &borrower,
+                &account_x,
+                &account_y,
+                LOAN,
+                0,
+            )],
+            &[&borrower],
+        )
+        .await;
+    assert_invariant_error(result, InvariantErrorCode::FlashLoanNotRepaid);
+
+    let pool_before = env.load::<Pool>(&pool.address).await;
+    let reserve_before = env.balance(&pool.reserve_x).await;
+    env.process(
+        &[flash_loan(
+            &pool,
+            &borrower_program,
+            &borrower,
+            &account_x,
+            &account_y,
+            LOAN,
+            REPAY,
+        )],
+        &[&borrower],
+    )
+    .await
+    .unwrap();
+
+    // 0.3% of the loan
+    let fee = 300;
+    assert_eq!(env.balance(&account_x).await, 1_000 - fee);
+    assert_eq!(env.balance(&pool.reserve_x).await, reserve_before + fee);
+    let pool_after = env.load::<Pool>(&pool.address).await;
+    assert_eq!({ pool_after.reserve_x }, { pool_before.reserve_x } + fee);
+
+    let protocol_fee = pool_after.fee_protocol_token_x - pool_before.fee_protocol_token_x;
+    let before = env.balance(&lp_x).await;
+    env.claim_fee(&pool, &position, &lp, &lp_x, &lp_y)
+        .await
+        .unwrap();
+    let lp_fee = env.balance(&lp_x).await - before;
+    assert!(lp_fee > 0 && lp_fee + protocol_fee <= fee && lp_fee + protocol_fee + 1 >= fee);
+}
@@ -0,0 +1,258 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token;
+use integration_tests::*;
+use invariant::args::Direction;
+use invariant::decimals::{Factories, Liquidity};
+use invariant::structs::Pool;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::{system_program, sysvar};
+use staker::decimals::{Decimal, Seconds, TokenAmount};
+
+const REWARD: u64 = 1_000_000;
+const DURATION: u64 = 1_000;
+
+fn staker_authority() -> Pubkey {
+    Pubkey::find_program_address(&[b"staker"], &staker::ID).0
+}
+
+fn config_address() -> Pubkey {
+    Pubkey::find_program_address(&[b"configv1"], &staker::ID).0
+}
+
+fn user_stake_address(incentive: &Pubkey, pool: &TestPool, position: &TestPosition) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"staker",
+            incentive.as_ref(),
+            pool.address.as_ref(),
+            &position.id.to_le_bytes(),
+        ],
+        &staker::ID,
+    )
+    .0
+}
+
+struct TestIncentive {
+    address: Pubkey,
+    token_account: Pubkey,
+    mint: Pubkey,
+}
+
+// Incentive of the payer starting now, funded with the whole reward
+async fn create_incentive(env: &mut TestEnv, pool: &TestPool) -> TestIncentive {
+    let founder = env.payer();
+    let mint = env.create_mint().await;
+    let founder_token_account = env.create_token_account(&mint, &founder).await;
+    env.mint_to(&mint, &founder_token_account, REWARD).await;
+
+    let (incentive, token_account) = (Keypair::new(), Keypair::new());
+    let now = env.now().await;
+    env.process(
+        &[Instruction {
+            program_id: staker::ID,
+            accounts: with_signers(
+                staker::accounts::CreateIncentive {
+                    config: config_address(),
+                    incentive: incentive.pubkey(),
+                    incentive_token_account: token_account.pubkey(),
+                    founder_token_account,
+                    pool: pool.address,
+                    founder,
+                    staker_authority: staker_authority(),
+                    incentive_token: mint,
+                    token_program: spl_token::ID,
+                    invariant: invariant::ID,
+                    system_program: system_program::ID,
+                    rent: sysvar::rent::ID,
+                }
+                .to_account_metas(None),
+                &[incentive.pubkey(), token_account.pubkey()],
+            ),
+            data: staker::instruction::CreateIncentive {
+                reward: TokenAmount::new(REWARD),
+                start_time: Seconds::new(now),
+                end_time: Seconds::new(now + DURATION),
+                extra_rewards: vec![],
+            }
+            .data(),
+        }],
+        &[&incentive, &token_account],
+    )
+    .await
+    .unwrap();
+
+    TestIncentive {
+        address: incentive.pubkey(),
+        token_account: token_account.pubkey(),
+        mint,
+    }
+}
+
+// Staking needs the position updated in the same slot, so both go in one transaction
+fn stake(
+    env: &TestEnv,
+    pool: &TestPool,
+    position: &TestPosition,
+    incentive: &TestIncentive,
+) -> [Instruction; 2] {
+    [
+        env.update_seconds_per_liquidity(pool, position),
+        Instruction {
+            program_id: staker::ID,
+            accounts: staker::accounts::CreateUserStake {
+                user_stake: user_stake_address(&incentive.address, pool, position),
+                position: position_address(&position.owner, position.index),
+                incentive: incentive.address,
+                config: config_address(),
+                invariant_state: state_address(),
+                owner: position.owner,
+                signer: env.payer(),
+                invariant: invariant::ID,
+                system_program: system_program::ID,
+                rent: sysvar::rent::ID,
+            }
+            .to_account_metas(None),
+            data: staker::instruction::Stake {
+                _index: position.index as i32,
+                lock_period: None,
+            }
+            .data(),
+        },
+    ]
+}
+
+fn withdraw(
+    env: &TestEnv,
+    pool: &TestPool,
+    position: &TestPosition,
+    incentive: &TestIncentive,
+    owner_token_account: &Pubkey,
+) -> [Instruction; 2] {
+    [
+        env.update_seconds_per_liquidity(pool, position),
+        Instruction {
+            program_id: staker::ID,
+            accounts: staker::accounts::Withdraw {
+                user_stake: user_stake_address(&incentive.address, pool, position),
+                incentive: incentive.address,
+                incentive_token_account: incentive.token_account,
+                position: position_address(&position.owner, position.index),
+                position_nft_account: position_nft_account(
+                    &pool.address,
+                    position.id,
+                    &position.owner,
+                ),
+                owner_token_account: *owner_token_account,
+                staker_authority: staker_authority(),
+                owner: position.owner,
+                token_program: spl_token::ID,
+            }
+            .to_account_metas(None),
+            data: staker::instruction::Withdraw {
+                _index: position.index as i32,
+            }
+            .data(),
+        },
+    ]
+}
+
+// Two positions around the current price are staked for the whole incentive, halfway through
+// a swap leaves only the wider one in range. Rewards follow the seconds each was in range with
+// its share of the liquidity, fees follow the liquidity each provided to the swap.
+#[tokio::test]
+async fn test_stake_swap_and_withdraw() {
+    let mut env = TestEnv::start(program_test()).await;
+    let (token_x, token_y) = env.create_pair().await;
+    let pool = env.create_pool(token_x, token_y, 0).await;
+    for index in [-100, -20, 20, 100] {
+        env.create_tick(&pool, index).await;
+    }
+
+    let (lp_a, lp_b, swapper) = (Keypair::new(), Keypair::new(), Keypair::new());
+    let (lp_a_x, lp_a_y) = env
+        .create_funded_accounts(&pool, &lp_a.pubkey(), 10u64.pow(12))
+        .await;
+    let (lp_b_x, lp_b_y) = env
+        .create_funded_accounts(&pool, &lp_b.pubkey(), 10u64.pow(12))
+        .await;
+    let liquidity = Liquidity::from_integer(1_000_000_000);
+    let position_a = env
+        .create_position(&pool, &lp_a, &lp_a_x, &lp_a_y, -100, 100, liquidity)
+        .await;
+    let position_b = env
+        .create_position(&pool, &lp_b, &lp_b_x, &lp_b_y, -20, 20, liquidity)
+        .await;
+
+    let incentive = create_incentive(&mut env, &pool).await;
+    let mut instructions = stake(&env, &pool, &position_a, &incentive).to_vec();
+    instructions.extend(stake(&env, &pool, &position_b, &incentive));
+    env.process(&instructions, &[]).await.unwrap();
+
+    // for 100 seconds both positions hold half of the liquidity in range
+    env.advance(100).await;
+    let (swapper_x, swapper_y) = env
+        .create_funded_accounts(&pool, &swapper.pubkey(), 10u64.pow(12))
+        .await;
+    let swap = env
+        .swap(
+            &pool,
+            &swapper,
+            &swapper_x,
+            &swapper_y,
+            Direction::XtoY,
+            3_000_000,
+            &tick_accounts(&pool, &[-20, -100]),
+        )
+        .await
+        .unwrap();
+    let pool_state = env.load::<Pool>(&pool.address).await;
+    let current_tick_index = pool_state.current_tick_index;
+    assert!(current_tick_index < -20 && current_tick_index >= -100);
+
+    // the fee is charged on the input net of it
+    let total_fee = swap.lp_fee + swap.protocol_fee;
+    assert!(total_fee >= 17_892 && total_fee <= 18_000);
+    assert_eq!({ pool_state.fee_protocol_token_x }, swap.protocol_fee);
+
+    // for the next 100 seconds the wider position is alone in range
+    env.advance(100).await;
+    let reward_a = env
+        .create_token_account(&incentive.mint, &lp_a.pubkey())
+        .await;
+    let reward_b = env
+        .create_token_account(&incentive.mint, &lp_b.pubkey())
+        .await;
+    env.process(
+        &withdraw(&env, &pool, &position_a, &incentive, &reward_a),
+        &[],
+    )
+    .await
+    .unwrap();
+    env.process(
+        &withdraw(&env, &pool, &position_b, &incentive, &reward_b),
+        &[],
+    )
+    .await
+    .unwrap();
+
+    // 150 and 50 of the 1000 seconds of the incentive
+    assert_eq!(env.balance(&reward_a).await, 150_000);
+    assert_eq!(env.balance(&reward_b).await, 50_000);
+    assert_eq!(env.balance(&incentive.token_account).await, 800_000);
+
+    let (before_a, before_b) = (env.balance(&lp_a_x).await, env.balance(&lp_b_x).await);
+    env.claim_fee(&pool, &position_a, &lp_a, &lp_a_x, &lp_a_y)
+        .await
+        .unwrap();
+    env.claim_fee(&pool, &position_b, &lp_b, &lp_b_x, &lp_b_y)
+        .await
+        .unwrap();
+    let fee_a = env.balance(&lp_a_x).await - before_a;
+    let fee_b = env.balance(&lp_b_x).await - before_b;
+
+    // fee growth is rounded down, each position can miss a token
+    assert!(fee_a > fee_b);
+    assert!(fee_a + fee_b <= swap.lp_fee && fee_a + fee_b + 2 >= swap.lp_fee);
+}
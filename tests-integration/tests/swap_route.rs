@@ -0,0 +1,177 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token;
+use integration_tests::*;
+use invariant::args::{Direction, RouteHop, SwapRouteArgs};
+use invariant::decimals::{Factories, Liquidity};
+use invariant_types::errors::InvariantErrorCode;
+use invariant_types::MIN_SQRT_PRICE;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+const AMOUNT_IN: u64 = 100_000;
+
+// Pools of tokens a/b and b/c with the same liquidity around the price of 1. Mints are ordered,
+// so both hops of a route from a to c swap x to y.
+async fn create_route(env: &mut TestEnv) -> (TestPool, TestPool) {
+    let mut mints = vec![
+        env.create_mint().await,
+        env.create_mint().await,
+        env.create_mint().await,
+    ];
+    mints.sort_by_key(|mint| mint.to_string());
+    let lp = Keypair::new();
+
+    let mut pools = vec![];
+    for (token_x, token_y) in [(mints[0], mints[1]), (mints[1], mints[2])] {
+        let pool = env.create_pool(token_x, token_y, 0).await;
+        env.create_tick(&pool, -20).await;
+        env.create_tick(&pool, 20).await;
+        let (account_x, account_y) = env
+            .create_funded_accounts(&pool, &lp.pubkey(), 10u64.pow(12))
+            .await;
+        env.create_position(
+            &pool,
+            &lp,
+            &account_x,
+            &account_y,
+            -20,
+            20,
+            Liquidity::from_integer(1_000_000_000),
+        )
+        .await;
+        pools.push(pool);
+    }
+    let second = pools.pop().unwrap();
+    (pools.pop().unwrap(), second)
+}
+
+fn swap_route(
+    owner: &Keypair,
+    account_in: &Pubkey,
+    account_out: &Pubkey,
+    pools: &[&TestPool],
+    min_amount_out: u64,
+) -> Instruction {
+    let mut accounts = invariant::accounts::SwapRoute {
+        state: state_address(),
+        account_in: *account_in,
+        account_out: *account_out,
+        owner: owner.pubkey(),
+        program_authority: program_authority(),
+        token_program: spl_token::ID,
+    }
+    .to_account_metas(None);
+    for pool in pools {
+        accounts.extend([
+            AccountMeta::new(pool.address, false),
+            AccountMeta::new_readonly(pool.tickmap, false),
+            AccountMeta::new(pool.reserve_x, false),
+            AccountMeta::new(pool.reserve_y, false),
+        ]);
+    }
+
+    Instruction {
+        program_id: invariant::ID,
+        accounts,
+        data: invariant::instruction::SwapRoute {
+            args: SwapRouteArgs {
+                amount_in: AMOUNT_IN,
+                min_amount_out,
+                hops: pools
+                    .iter()
+                    .map(|_| RouteHop {
+                        direction: Direction::XtoY,
+                        sqrt_price_limit: MIN_SQRT_PRICE,
+                        tick_accounts: 0,
+                    })
+                    .collect(),
+            },
+        }
+        .data(),
+    }
+}
+
+// A route pays out what the same swaps made one after another would, the intermediate token
+// goes from reserve to reserve
+#[tokio::test]
+async fn test_swap_route_matches_single_swaps() {
+    let mut env = TestEnv::start(program_test()).await;
+    let trader = Keypair::new();
+
+    // reference: two single swaps on a route of its own
+    let (first, second) = create_route(&mut env).await;
+    let account_a = env
+        .create_token_account(&first.token_x, &trader.pubkey())
+        .await;
+    let account_b = env
+        .create_token_account(&first.token_y, &trader.pubkey())
+        .await;
+    let account_c = env
+        .create_token_account(&second.token_y, &trader.pubkey())
+        .await;
+    env.mint_to(&first.token_x, &account_a, AMOUNT_IN).await;
+    env.swap(
+        &first,
+        &trader,
+        &account_a,
+        &account_b,
+        Direction::XtoY,
+        AMOUNT_IN,
+        &[],
+    )
+    .await
+    .unwrap();
+    let amount_b = env.balance(&account_b).await;
+    env.swap(
+        &second,
+        &trader,
+        &account_b,
+        &account_c,
+        Direction::XtoY,
+        amount_b,
+        &[],
+    )
+    .await
+    .unwrap();
+    let expected_out = env.balance(&account_c).await;
+    assert!(expected_out > 0);
+
+    let (first, second) = create_route(&mut env).await;
+    let account_in = env
+        .create_token_account(&first.token_x, &trader.pubkey())
+        .await;
+    let account_out = env
+        .create_token_account(&second.token_y, &trader.pubkey())
+        .await;
+    env.mint_to(&first.token_x, &account_in, AMOUNT_IN).await;
+
+    let result = env
+        .process(
+            &[swap_route(
+                &trader,
+                &account_in,
+                &account_out,
+                &[&first, &second],
+                expected_out + 1,
+            )],
+            &[&trader],
+        )
+        .await;
+    assert_invariant_error(result, InvariantErrorCode::RouteAmountOutTooLow);
+
+    env.process(
+        &[swap_route(
+            &trader,
+            &account_in,
+            &account_out,
+            &[&first, &second],
+            expected_out,
+        )],
+        &[&trader],
+    )
+    .await
+    .unwrap();
+    assert_eq!(env.balance(&account_in).await, 0);
+    assert_eq!(env.balance(&account_out).await, expected_out);
+}
@@ -0,0 +1,144 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use integration_tests::*;
+use invariant::args::Direction;
+use invariant::decimals::{Factories, Liquidity};
+use invariant::structs::Pool;
+use invariant_types::errors::InvariantErrorCode;
+use solana_program_test::processor;
+use solana_sdk::account_info::AccountInfo;
+use solana_sdk::entrypoint::ProgramResult;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::program_error::ProgramError;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::system_program;
+use std::convert::TryInto;
+
+const BLOCKED_TICK: i32 = -20;
+const HOOK_ERROR: u32 = 42;
+
+// Hook refusing swaps that cross BLOCKED_TICK
+fn process_hook(_program_id: &Pubkey, _accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    // discriminator, pool, tick, x_to_y
+    let tick = i32::from_le_bytes(data[40..44].try_into().unwrap());
+    match tick == BLOCKED_TICK {
+        true => Err(ProgramError::Custom(HOOK_ERROR)),
+        false => Ok(()),
+    }
+}
+
+fn set_pool_hook(env: &TestEnv, pool: &TestPool, hook_program: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: invariant::ID,
+        accounts: invariant::accounts::SetPoolHook {
+            state: state_address(),
+            admin_log: admin_log_address(),
+            pool: pool.address,
+            token_x: pool.token_x,
+            token_y: pool.token_y,
+            hook_program: *hook_program,
+            admin: env.payer(),
+        }
+        .to_account_metas(None),
+        data: invariant::instruction::SetPoolHook {}.data(),
+    }
+}
+
+// Once set, the hook has to be passed to every swap of the pool and is called on every tick
+// crossed, so it can stop swaps from crossing
+#[tokio::test]
+async fn test_tick_hook() {
+    let hook_program = Pubkey::new_unique();
+    let mut program_test = program_test();
+    program_test.add_program("hook", hook_program, processor!(process_hook));
+    let mut env = TestEnv::start(program_test).await;
+
+    let (token_x, token_y) = env.create_pair().await;
+    let pool = env.create_pool(token_x, token_y, 0).await;
+    for index in [-40, -20, 20, 40] {
+        env.create_tick(&pool, index).await;
+    }
+    let lp = Keypair::new();
+    let (lp_x, lp_y) = env
+        .create_funded_accounts(&pool, &lp.pubkey(), 10u64.pow(12))
+        .await;
+    let liquidity = Liquidity::from_integer(1_000_000_000);
+    env.create_position(&pool, &lp, &lp_x, &lp_y, -40, 40, liquidity)
+        .await;
+    env.create_position(&pool, &lp, &lp_x, &lp_y, -20, 20, liquidity)
+        .await;
+    env.process(&[set_pool_hook(&env, &pool, &hook_program)], &[])
+        .await
+        .unwrap();
+
+    let trader = Keypair::new();
+    let (account_x, account_y) = env
+        .create_funded_accounts(&pool, &trader.pubkey(), 10u64.pow(12))
+        .await;
+    let hook = AccountMeta::new_readonly(hook_program, false);
+    let mut crossing = tick_accounts(&pool, &[-20, -40]);
+    crossing.push(hook.clone());
+
+    let result = env
+        .swap(
+            &pool,
+            &trader,
+            &account_x,
+            &account_y,
+            Direction::XtoY,
+            1_000,
+            &[],
+        )
+        .await
+        .map(|_| ());
+    assert_invariant_error(result, InvariantErrorCode::HookProgramNotFound);
+
+    // swaps within the ticks don't call it
+    env.swap(
+        &pool,
+        &trader,
+        &account_x,
+        &account_y,
+        Direction::YtoX,
+        1_000,
+        &[hook],
+    )
+    .await
+    .unwrap();
+
+    let result = env
+        .swap(
+            &pool,
+            &trader,
+            &account_x,
+            &account_y,
+            Direction::XtoY,
+            2_500_000,
+            &crossing,
+        )
+        .await
+        .map(|_| ());
+    assert_eq!(custom_error(result), Some(HOOK_ERROR));
+    assert_eq!(
+        { env.load::<Pool>(&pool.address).await.current_tick_index },
+        0
+    );
+
+    // the system program turns the hook off
+    env.process(&[set_pool_hook(&env, &pool, &system_program::ID)], &[])
+        .await
+        .unwrap();
+    env.swap(
+        &pool,
+        &trader,
+        &account_x,
+        &account_y,
+        Direction::XtoY,
+        2_500_000,
+        &tick_accounts(&pool, &[-20, -40]),
+    )
+    .await
+    .unwrap();
+    let current_tick_index = env.load::<Pool>(&pool.address).await.current_tick_index;
+    assert!(current_tick_index < BLOCKED_TICK);
+}
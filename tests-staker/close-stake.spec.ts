@@ -5,7 +5,7 @@ import { Network } from '../staker-sdk/src'
 import { Keypair, PublicKey, Transaction } from '@solana/web3.js'
 import { assert } from 'chai'
 import { CloseStake, CreateIncentive, CreateStake, Decimal, Staker } from '../staker-sdk/src/staker'
-import { eqDecimal, createToken, signAndSend } from './testUtils'
+import { eqDecimal, createToken, signAndSend, createConfig } from './testUtils'
 import { createToken as createTkn } from '../tests/testUtils'
 import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
 import { fromFee } from '@invariant-labs/sdk/lib/utils'
@@ -45,6 +45,7 @@ describe('Remove Stake tests', () => {
   before(async () => {
     // create staker
     staker = await Staker.build(Network.LOCAL, provider.wallet, connection)
+    await createConfig(staker)
 
     await Promise.all([
       await connection.requestAirdrop(mintAuthority.publicKey, 1e9),
@@ -229,7 +230,8 @@ describe('Remove Stake tests', () => {
       incentiveAccount.publicKey,
       position,
       positionOwner.publicKey,
-      index
+      index,
+      await market.getPositionNftAccount(pool, positionId, positionOwner.publicKey)
     )
     const closeTx = new Transaction().add(closeStakeIx)
     await signAndSend(closeTx, [positionOwner], staker.connection)
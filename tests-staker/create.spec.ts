@@ -4,7 +4,14 @@ import { Market, Pair } from '@invariant-labs/sdk'
 import { Keypair, PublicKey, Transaction } from '@solana/web3.js'
 import { assert } from 'chai'
 import { CreateIncentive, Decimal, Staker } from '../staker-sdk/src/staker'
-import { eqDecimal, createToken, assertThrowsAsync, ERRORS_STAKER, signAndSend } from './testUtils'
+import {
+  eqDecimal,
+  createToken,
+  assertThrowsAsync,
+  ERRORS_STAKER,
+  signAndSend,
+  createConfig
+} from './testUtils'
 import { createToken as createTkn, initMarket } from '../tests/testUtils'
 import { fromFee } from '@invariant-labs/sdk/lib/utils'
 import { FeeTier } from '@invariant-labs/sdk/lib/market'
@@ -35,6 +42,8 @@ describe('Create incentive tests', () => {
 
     staker = await Staker.build(Network.LOCAL, provider.wallet, connection)
 
+    await createConfig(staker)
+
     // create token
     incentiveToken = await createToken(connection, wallet, wallet)
     // add SOL to founder acc
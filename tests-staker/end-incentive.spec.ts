@@ -5,7 +5,7 @@ import { Network } from '../staker-sdk/src'
 import { Keypair, PublicKey, Transaction } from '@solana/web3.js'
 import { assert } from 'chai'
 import { CreateIncentive, Decimal, EndIncentive, Staker } from '../staker-sdk/src/staker'
-import { assertThrowsAsync, createToken, signAndSend } from './testUtils'
+import { assertThrowsAsync, createToken, signAndSend, createConfig } from './testUtils'
 import { createToken as createTkn, initMarket } from '../tests/testUtils'
 import { fromFee } from '@invariant-labs/sdk/lib/utils'
 import { FeeTier } from '@invariant-labs/sdk/lib/market'
@@ -35,6 +35,8 @@ describe('End incentive tests', () => {
 
     staker = await Staker.build(Network.LOCAL, provider.wallet, connection)
 
+    await createConfig(staker)
+
     // create token
     incentiveToken = await createToken(connection, wallet, wallet)
     // add SOL to founder acc
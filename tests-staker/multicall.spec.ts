@@ -3,7 +3,7 @@ import { Provider, BN } from '@project-serum/anchor'
 import { Market, Pair, sleep, PRICE_DENOMINATOR } from '@invariant-labs/sdk'
 import { Network } from '../staker-sdk/src'
 import { Keypair, PublicKey, Transaction } from '@solana/web3.js'
-import { createToken, getTime, signAndSend, almostEqual } from './testUtils'
+import { createToken, getTime, signAndSend, almostEqual, createConfig } from './testUtils'
 import { createToken as createTkn, initMarket } from '../tests/testUtils'
 import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
 import { fromInteger, toDecimal } from '../staker-sdk/lib/utils'
@@ -54,6 +54,8 @@ describe('Multicall test', () => {
 
     staker = await Staker.build(Network.LOCAL, provider.wallet, connection)
 
+    await createConfig(staker)
+
     await Promise.all([
       connection.requestAirdrop(mintAuthority.publicKey, 1e9),
       connection.requestAirdrop(firstPositionOwner.publicKey, 1e9),
@@ -367,6 +369,11 @@ describe('Multicall test', () => {
       pool,
       id: firstPositionId,
       position: firstPosition,
+      positionNftAccount: await market.getPositionNftAccount(
+        pool,
+        firstPositionId,
+        firstPositionOwner.publicKey
+      ),
       owner: firstPositionOwner.publicKey,
       incentiveTokenAccount: firstIncentiveTokenAccount.publicKey,
       ownerTokenAcc: firstOwnerTokenAccount,
@@ -386,6 +393,11 @@ describe('Multicall test', () => {
       pool,
       id: secondPositionId,
       position: secondPosition,
+      positionNftAccount: await market.getPositionNftAccount(
+        pool,
+        secondPositionId,
+        secondPositionOwner.publicKey
+      ),
       owner: secondPositionOwner.publicKey,
       incentiveTokenAccount: firstIncentiveTokenAccount.publicKey,
       ownerTokenAcc: secondOwnerTokenAccount,
@@ -404,6 +416,11 @@ describe('Multicall test', () => {
       pool,
       id: firstPositionId,
       position: firstPosition,
+      positionNftAccount: await market.getPositionNftAccount(
+        pool,
+        firstPositionId,
+        firstPositionOwner.publicKey
+      ),
       owner: firstPositionOwner.publicKey,
       incentiveTokenAccount: secondIncentiveTokenAccount.publicKey,
       ownerTokenAcc: firstOwnerTokenAccount,
@@ -422,6 +439,11 @@ describe('Multicall test', () => {
       pool,
       id: secondPositionId,
       position: secondPosition,
+      positionNftAccount: await market.getPositionNftAccount(
+        pool,
+        secondPositionId,
+        secondPositionOwner.publicKey
+      ),
       owner: secondPositionOwner.publicKey,
       incentiveTokenAccount: secondIncentiveTokenAccount.publicKey,
       ownerTokenAcc: secondOwnerTokenAccount,
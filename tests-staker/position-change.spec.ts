@@ -3,7 +3,7 @@ import { Provider, BN } from '@project-serum/anchor'
 import { Market, Pair, sleep, PRICE_DENOMINATOR, LIQUIDITY_DENOMINATOR } from '@invariant-labs/sdk'
 import { Network } from '../staker-sdk/src'
 import { Keypair, PublicKey, Transaction } from '@solana/web3.js'
-import { createToken, getTime, signAndSend, almostEqual } from './testUtils'
+import { createToken, getTime, signAndSend, almostEqual, createConfig } from './testUtils'
 import { createToken as createTkn, initMarket } from '../tests/testUtils'
 import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
 import { toDecimal } from '../staker-sdk/lib/utils'
@@ -45,6 +45,8 @@ describe('Withdraw with transfer position ownership', () => {
 
     staker = await Staker.build(Network.LOCAL, provider.wallet, connection)
 
+    await createConfig(staker)
+
     await Promise.all([
       connection.requestAirdrop(mintAuthority.publicKey, 1e9),
       connection.requestAirdrop(positionOwner.publicKey, 1e9),
@@ -211,6 +213,23 @@ describe('Withdraw with transfer position ownership', () => {
     const recipientPosition = await market.getPosition(positionRecipient.publicKey, 0)
     const recipientPositionId = recipientPosition.id
 
+    // rewards follow the position NFT
+    const { address: positionNftMint } = await market.getPositionNftMintAddress(
+      poolAddress,
+      recipientPositionId
+    )
+    const positionNft = new Token(connection, positionNftMint, TOKEN_PROGRAM_ID, wallet)
+    const recipientNftAccount = await positionNft.createAssociatedTokenAccount(
+      positionRecipient.publicKey
+    )
+    await positionNft.transfer(
+      await market.getPositionNftAccount(poolAddress, recipientPositionId, positionOwner.publicKey),
+      recipientNftAccount,
+      positionOwner,
+      [],
+      1
+    )
+
     const { positionAddress: recipientPositionAddress } = await market.getPositionAddress(
       positionRecipient.publicKey,
       index
@@ -259,6 +278,11 @@ describe('Withdraw with transfer position ownership', () => {
       pool: poolAddress,
       id: recipientPositionId,
       position: recipientPositionAddress,
+      positionNftAccount: await market.getPositionNftAccount(
+        poolAddress,
+        recipientPositionId,
+        positionRecipient.publicKey
+      ),
       owner: positionRecipient.publicKey,
       incentiveTokenAccount: incentiveTokenAccount.publicKey,
       ownerTokenAcc: positionRecipientTokenAccount,
@@ -9,7 +9,8 @@ import {
   createToken,
   createSomePositionsAndStakes,
   signAndSend,
-  createToken as createTkn
+  createToken as createTkn,
+  createConfig
 } from './testUtils'
 
 import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
@@ -44,6 +45,7 @@ describe('Remove all takes', () => {
   before(async () => {
     // create staker
     staker = await Staker.build(Network.LOCAL, provider.wallet, connection)
+    await createConfig(staker)
 
     await Promise.all([
       await connection.requestAirdrop(mintAuthority.publicKey, 1e9),
@@ -11,7 +11,7 @@ import { Network } from '../staker-sdk/src'
 import { Keypair, PublicKey, Transaction } from '@solana/web3.js'
 import { assert } from 'chai'
 import { Decimal, Staker, CreateStake, CreateIncentive } from '../staker-sdk/src/staker'
-import { createToken, signAndSend, eqDecimal, assertThrowsAsync } from './testUtils'
+import { createToken, signAndSend, eqDecimal, assertThrowsAsync, createConfig } from './testUtils'
 import { createToken as createTkn, initMarket } from '../tests/testUtils'
 import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
 import { fromFee, FEE_TIERS } from '@invariant-labs/sdk/lib/utils'
@@ -51,6 +51,8 @@ describe('Stake tests', () => {
 
     staker = await Staker.build(Network.LOCAL, provider.wallet, connection)
 
+    await createConfig(staker)
+
     await Promise.all([
       connection.requestAirdrop(mintAuthority.publicKey, 1e9),
       connection.requestAirdrop(positionOwner.publicKey, 1e9),
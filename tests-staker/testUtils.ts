@@ -95,6 +95,14 @@ export const createToken = async (
   return token
 }
 
+// the config is created once per validator by the upgrade authority, which is the provider wallet
+export const createConfig = async (staker: Staker) => {
+  const [config] = await staker.getConfigAddressAndBump()
+  if ((await staker.connection.getAccountInfo(config)) === null) {
+    await staker.createConfig()
+  }
+}
+
 export const almostEqual = (num1: BN, num2: BN, epsilon: BN = new BN(50)) => {
   return num1.sub(num2).abs().lt(epsilon)
 }
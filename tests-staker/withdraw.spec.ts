@@ -3,7 +3,7 @@ import { Provider, BN } from '@project-serum/anchor'
 import { Market, Pair, DENOMINATOR, sleep, PRICE_DENOMINATOR } from '@invariant-labs/sdk'
 import { Network } from '../staker-sdk/src'
 import { Keypair, PublicKey, Transaction } from '@solana/web3.js'
-import { createToken, getTime, signAndSend, almostEqual } from './testUtils'
+import { createToken, getTime, signAndSend, almostEqual, createConfig } from './testUtils'
 import { createToken as createTkn, initMarket } from '../tests/testUtils'
 import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
 import { STAKER_ERRORS, toDecimal } from '../staker-sdk/lib/utils'
@@ -49,6 +49,8 @@ describe('Withdraw tests', () => {
 
     staker = await Staker.build(Network.LOCAL, provider.wallet, connection)
 
+    await createConfig(staker)
+
     await Promise.all([
       connection.requestAirdrop(mintAuthority.publicKey, 1e9),
       connection.requestAirdrop(positionOwner.publicKey, 1e9),
@@ -238,6 +240,11 @@ describe('Withdraw tests', () => {
       pool: poolAddress,
       id: positionId,
       position,
+      positionNftAccount: await market.getPositionNftAccount(
+        poolAddress,
+        positionId,
+        positionOwner.publicKey
+      ),
       owner: positionOwner.publicKey,
       incentiveTokenAccount: incentiveTokenAccount.publicKey,
       ownerTokenAcc: ownerTokenAcc,
@@ -410,6 +417,11 @@ describe('Withdraw tests', () => {
       pool: poolAddress,
       id: positionId,
       position,
+      positionNftAccount: await market.getPositionNftAccount(
+        poolAddress,
+        positionId,
+        positionOwner.publicKey
+      ),
       owner: positionOwner.publicKey,
       incentiveTokenAccount: incentiveTokenAccount.publicKey,
       ownerTokenAcc: ownerTokenAcc,
@@ -610,6 +622,11 @@ describe('Withdraw tests', () => {
       pool: poolAddress,
       id: secondPosition.id,
       position: secondPositionAddress,
+      positionNftAccount: await market.getPositionNftAccount(
+        poolAddress,
+        secondPosition.id,
+        positionOwner.publicKey
+      ),
       owner: positionOwner.publicKey,
       incentiveTokenAccount: incentiveTokenAccount.publicKey,
       ownerTokenAcc: ownerTokenAcc,
@@ -817,6 +834,11 @@ describe('Withdraw tests', () => {
       pool: poolAddress,
       id: secondPosition.id,
       position: secondPositionAddress,
+      positionNftAccount: await market.getPositionNftAccount(
+        poolAddress,
+        secondPosition.id,
+        positionOwner.publicKey
+      ),
       owner: positionOwner.publicKey,
       incentiveTokenAccount: incentiveTokenAccount.publicKey,
       ownerTokenAcc: ownerTokenAcc,
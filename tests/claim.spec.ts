@@ -167,6 +167,7 @@ describe('claim', () => {
       incorrectUpperTickIndex
     )
     const { positionAddress } = await market.getPositionAddress(positionOwner.publicKey, 0)
+    const position = await market.getPosition(positionOwner.publicKey, 0)
 
     const incorrectClaimFeeIx = await market.program.instruction.claimFee(
       0,
@@ -180,6 +181,12 @@ describe('claim', () => {
           lowerTick: incorrectLowerTickAddress,
           upperTick: incorrectUpperTickAddress,
           owner: positionOwner.publicKey,
+          holder: positionOwner.publicKey,
+          positionNftAccount: await market.getPositionNftAccount(
+            position.pool,
+            position.id,
+            positionOwner.publicKey
+          ),
           tokenX: pair.tokenX,
           tokenY: pair.tokenY,
           accountX: userTokenXAccount,
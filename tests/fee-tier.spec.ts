@@ -5,6 +5,7 @@ import { INVARIANT_ERRORS, fromFee } from '@invariant-labs/sdk/src/utils'
 import * as anchor from '@project-serum/anchor'
 import { BN, Provider } from '@project-serum/anchor'
 import { Keypair } from '@solana/web3.js'
+import { assert } from 'chai'
 
 describe('fee-tier', () => {
   const provider = Provider.local()
@@ -47,6 +48,11 @@ describe('fee-tier', () => {
       admin: admin.publicKey
     }
     await market.createFeeTier(createFeeTierVars, admin)
+
+    const registry = await market.getFeeTierRegistry()
+    assert.equal(registry.count, 1)
+    assert.ok(registry.entries[0].fee.v.eq(feeTierAdmin.fee))
+    assert.equal(registry.entries[0].tickSpacing, feeTierAdmin.tickSpacing)
   })
   it('#createFeeTier() with 0 tick spacing should failed', async () => {
     const feeTier: FeeTier = {
@@ -0,0 +1,226 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { assert } from 'chai'
+import { assertThrowsAsync, createToken, initMarket } from './testUtils'
+import { Market, Pair, LIQUIDITY_DENOMINATOR, Network, INVARIANT_ERRORS } from '@invariant-labs/sdk'
+import { FeeTier } from '@invariant-labs/sdk/lib/market'
+import { fromFee } from '@invariant-labs/sdk/lib/utils'
+import { toDecimal, tou64 } from '@invariant-labs/sdk/src/utils'
+import { calculatePriceSqrt, getDeltaX, getDeltaY } from '@invariant-labs/sdk/src/math'
+import { CreateTick, InitPosition, Swap } from '@invariant-labs/sdk/src/market'
+import { PRICE_DENOMINATOR } from '@invariant-labs/sdk'
+
+describe('limit order', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Keypair
+  const mintAuthority = Keypair.generate()
+  const admin = Keypair.generate()
+  const orderOwner = Keypair.generate()
+  const swapper = Keypair.generate()
+  const feeTier: FeeTier = {
+    fee: fromFee(new BN(600)), // 0.6%
+    tickSpacing: 10
+  }
+  const liquidityDelta = { v: new BN(1000000).mul(LIQUIDITY_DENOMINATOR) }
+  const orderLiquidity = { v: new BN(100000).mul(LIQUIDITY_DENOMINATOR) }
+  let market: Market
+  let pair: Pair
+  let poolAddress: PublicKey
+  let tokenX: Token
+  let tokenY: Token
+  let orderAccountX: PublicKey
+  let orderAccountY: PublicKey
+  let swapperAccountX: PublicKey
+  let swapperAccountY: PublicKey
+
+  const swap = async (xToY: boolean, limitTick: number) => {
+    const swapVars: Swap = {
+      pair,
+      owner: swapper.publicKey,
+      xToY,
+      amount: new BN(3000),
+      estimatedPriceAfterSwap: calculatePriceSqrt(limitTick),
+      slippage: toDecimal(0),
+      accountX: swapperAccountX,
+      accountY: swapperAccountY,
+      byAmountIn: true
+    }
+    await market.swap(swapVars, swapper)
+  }
+
+  before(async () => {
+    market = await Market.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e9),
+      connection.requestAirdrop(admin.publicKey, 1e9),
+      connection.requestAirdrop(orderOwner.publicKey, 1e9),
+      connection.requestAirdrop(swapper.publicKey, 1e9)
+    ])
+    const tokens = await Promise.all([
+      createToken(connection, wallet, mintAuthority),
+      createToken(connection, wallet, mintAuthority)
+    ])
+
+    pair = new Pair(tokens[0].publicKey, tokens[1].publicKey, feeTier)
+    tokenX = new Token(connection, pair.tokenX, TOKEN_PROGRAM_ID, wallet)
+    tokenY = new Token(connection, pair.tokenY, TOKEN_PROGRAM_ID, wallet)
+
+    const mintAmount = tou64(new BN(10).pow(new BN(10)))
+    orderAccountX = await tokenX.createAccount(orderOwner.publicKey)
+    orderAccountY = await tokenY.createAccount(orderOwner.publicKey)
+    swapperAccountX = await tokenX.createAccount(swapper.publicKey)
+    swapperAccountY = await tokenY.createAccount(swapper.publicKey)
+    for (const account of [orderAccountX, swapperAccountX]) {
+      await tokenX.mintTo(account, mintAuthority.publicKey, [mintAuthority], mintAmount)
+    }
+    for (const account of [orderAccountY, swapperAccountY]) {
+      await tokenY.mintTo(account, mintAuthority.publicKey, [mintAuthority], mintAmount)
+    }
+  })
+
+  it('#init()', async () => {
+    await initMarket(market, [pair], admin)
+    poolAddress = await pair.getAddress(market.program.programId)
+
+    for (const index of [-100, -30, -20, 20, 30, 100]) {
+      const createTickVars: CreateTick = {
+        pair,
+        index,
+        payer: admin.publicKey
+      }
+      await market.createTick(createTickVars, admin)
+    }
+
+    const positionOwner = Keypair.generate()
+    await connection.requestAirdrop(positionOwner.publicKey, 1e9)
+    const userTokenX = await tokenX.createAccount(positionOwner.publicKey)
+    const userTokenY = await tokenY.createAccount(positionOwner.publicKey)
+    const mintAmount = tou64(new BN(10).pow(new BN(10)))
+    await tokenX.mintTo(userTokenX, mintAuthority.publicKey, [mintAuthority], mintAmount)
+    await tokenY.mintTo(userTokenY, mintAuthority.publicKey, [mintAuthority], mintAmount)
+
+    await market.createPositionList(positionOwner.publicKey, positionOwner)
+    const initPositionVars: InitPosition = {
+      pair,
+      owner: positionOwner.publicKey,
+      userTokenX,
+      userTokenY,
+      lowerTick: -100,
+      upperTick: 100,
+      liquidityDelta,
+      knownPrice: { v: PRICE_DENOMINATOR },
+      slippage: { v: new BN(0) }
+    }
+    await market.initPosition(initPositionVars, positionOwner)
+  })
+
+  it('#claimLimitOrder() after the price came back', async () => {
+    const { positionIterator: id } = await market.getPool(pair)
+    const xBefore = (await tokenX.getAccountInfo(orderAccountX)).amount
+
+    // sells x above the price
+    await market.createLimitOrder(
+      {
+        pair,
+        owner: orderOwner.publicKey,
+        lowerTickIndex: 20,
+        liquidity: orderLiquidity,
+        userTokenX: orderAccountX,
+        userTokenY: orderAccountY
+      },
+      orderOwner
+    )
+    const order = await market.getLimitOrder(poolAddress, id)
+    assert.ok(order.xToY)
+    assert.equal(order.lowerTickIndex, 20)
+    assert.equal(order.upperTickIndex, 30)
+    const sold = xBefore.sub((await tokenX.getAccountInfo(orderAccountX)).amount)
+    assert.ok(
+      sold.eq(getDeltaX(calculatePriceSqrt(20), calculatePriceSqrt(30), orderLiquidity, true) as BN)
+    )
+
+    // cannot be claimed before the fill
+    await assertThrowsAsync(
+      market.claimLimitOrder(
+        { pair, id, userTokenX: orderAccountX, userTokenY: orderAccountY },
+        swapper
+      ),
+      INVARIANT_ERRORS.LIMIT_ORDER_NOT_FILLED
+    )
+
+    // price goes over the range and comes back below it
+    await swap(false, 100)
+    assert.ok((await market.getPool(pair)).currentTickIndex >= 30)
+    const exitTick = await market.getTick(pair, 30)
+    assert.ok(exitTick.limitOrderLiquidityBelow.v.eqn(0))
+    assert.ok(exitTick.limitOrderFillsBelow.eqn(1))
+    assert.equal(exitTick.limitOrders, 1)
+    assert.ok(exitTick.liquidityGross.v.eqn(0))
+
+    await swap(true, -100)
+    const pool = await market.getPool(pair)
+    assert.ok(pool.currentTickIndex < 20)
+    // the filled order is not in the pool anymore
+    assert.ok(pool.liquidity.v.eq(liquidityDelta.v))
+
+    // anyone can crank the claim, the bought token goes to the owner
+    const yBefore = (await tokenY.getAccountInfo(orderAccountY)).amount
+    await market.claimLimitOrder(
+      { pair, id, userTokenX: orderAccountX, userTokenY: orderAccountY },
+      swapper
+    )
+    const bought = (await tokenY.getAccountInfo(orderAccountY)).amount.sub(yBefore)
+    assert.ok(
+      bought.eq(getDeltaY(calculatePriceSqrt(20), calculatePriceSqrt(30), orderLiquidity, false) as BN)
+    )
+
+    // order and its emptied ticks are closed
+    const { address } = await market.getLimitOrderAddress(poolAddress, id)
+    assert.isNull(await connection.getAccountInfo(address))
+    assert.isFalse(await market.isInitialized(pair, 20))
+    assert.isFalse(await market.isInitialized(pair, 30))
+  })
+
+  it('#cancelLimitOrder() before the fill', async () => {
+    const { positionIterator: id } = await market.getPool(pair)
+    const yBefore = (await tokenY.getAccountInfo(orderAccountY)).amount
+
+    // sells y below the price
+    await market.createLimitOrder(
+      {
+        pair,
+        owner: orderOwner.publicKey,
+        lowerTickIndex: -30,
+        liquidity: orderLiquidity,
+        userTokenX: orderAccountX,
+        userTokenY: orderAccountY
+      },
+      orderOwner
+    )
+    const order = await market.getLimitOrder(poolAddress, id)
+    assert.isFalse(order.xToY)
+
+    await market.cancelLimitOrder(
+      { pair, id, userTokenX: orderAccountX, userTokenY: orderAccountY },
+      orderOwner
+    )
+
+    // deposit is rounded up and the withdrawal down
+    const yAfter = (await tokenY.getAccountInfo(orderAccountY)).amount
+    assert.ok(yBefore.sub(yAfter).lten(1))
+    const { address } = await market.getLimitOrderAddress(poolAddress, id)
+    assert.isNull(await connection.getAccountInfo(address))
+    assert.isFalse(await market.isInitialized(pair, -30))
+    assert.isFalse(await market.isInitialized(pair, -20))
+  })
+})
@@ -0,0 +1,191 @@
+import * as anchor from '@project-serum/anchor'
+import { Provider, BN } from '@project-serum/anchor'
+import { Token, TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { assert } from 'chai'
+import { assertThrowsAsync, createToken, initMarket } from './testUtils'
+import { Market, Pair, LIQUIDITY_DENOMINATOR, Network, INVARIANT_ERRORS } from '@invariant-labs/sdk'
+import { FeeTier } from '@invariant-labs/sdk/lib/market'
+import { fromFee } from '@invariant-labs/sdk/lib/utils'
+import { tou64 } from '@invariant-labs/sdk/src/utils'
+import { CreateTick, InitPosition } from '@invariant-labs/sdk/src/market'
+import { PRICE_DENOMINATOR } from '@invariant-labs/sdk'
+
+describe('position nft', () => {
+  const provider = Provider.local()
+  const connection = provider.connection
+  // @ts-expect-error
+  const wallet = provider.wallet.payer as Keypair
+  const mintAuthority = Keypair.generate()
+  const admin = Keypair.generate()
+  const positionOwner = Keypair.generate()
+  const buyer = Keypair.generate()
+  const feeTier: FeeTier = {
+    fee: fromFee(new BN(600)), // 0.6%
+    tickSpacing: 10
+  }
+  let market: Market
+  let pair: Pair
+  let poolAddress: PublicKey
+  let positionNft: Token
+  let tokenX: Token
+  let tokenY: Token
+  let ownerAccountX: PublicKey
+  let ownerAccountY: PublicKey
+  let buyerAccountX: PublicKey
+  let buyerAccountY: PublicKey
+
+  before(async () => {
+    market = await Market.build(
+      Network.LOCAL,
+      provider.wallet,
+      connection,
+      anchor.workspace.Invariant.programId
+    )
+
+    await Promise.all([
+      connection.requestAirdrop(mintAuthority.publicKey, 1e9),
+      connection.requestAirdrop(admin.publicKey, 1e9),
+      connection.requestAirdrop(positionOwner.publicKey, 1e9),
+      connection.requestAirdrop(buyer.publicKey, 1e9)
+    ])
+    const tokens = await Promise.all([
+      createToken(connection, wallet, mintAuthority),
+      createToken(connection, wallet, mintAuthority)
+    ])
+
+    pair = new Pair(tokens[0].publicKey, tokens[1].publicKey, feeTier)
+    tokenX = new Token(connection, pair.tokenX, TOKEN_PROGRAM_ID, wallet)
+    tokenY = new Token(connection, pair.tokenY, TOKEN_PROGRAM_ID, wallet)
+
+    ownerAccountX = await tokenX.createAccount(positionOwner.publicKey)
+    ownerAccountY = await tokenY.createAccount(positionOwner.publicKey)
+    buyerAccountX = await tokenX.createAccount(buyer.publicKey)
+    buyerAccountY = await tokenY.createAccount(buyer.publicKey)
+    const mintAmount = tou64(new BN(10).pow(new BN(10)))
+    await tokenX.mintTo(ownerAccountX, mintAuthority.publicKey, [mintAuthority], mintAmount)
+    await tokenY.mintTo(ownerAccountY, mintAuthority.publicKey, [mintAuthority], mintAmount)
+  })
+
+  it('#init()', async () => {
+    await initMarket(market, [pair], admin)
+    poolAddress = await pair.getAddress(market.program.programId)
+
+    for (const index of [-20, 20]) {
+      const createTickVars: CreateTick = {
+        pair,
+        index,
+        payer: admin.publicKey
+      }
+      await market.createTick(createTickVars, admin)
+    }
+  })
+
+  it('#createPosition() mints the nft', async () => {
+    await market.createPositionList(positionOwner.publicKey, positionOwner)
+    const initPositionVars: InitPosition = {
+      pair,
+      owner: positionOwner.publicKey,
+      userTokenX: ownerAccountX,
+      userTokenY: ownerAccountY,
+      lowerTick: -20,
+      upperTick: 20,
+      liquidityDelta: { v: new BN(1000000).mul(LIQUIDITY_DENOMINATOR) },
+      knownPrice: { v: PRICE_DENOMINATOR },
+      slippage: { v: new BN(0) }
+    }
+    await market.initPosition(initPositionVars, positionOwner)
+
+    const position = await market.getPosition(positionOwner.publicKey, 0)
+    const { address: mint } = await market.getPositionNftMintAddress(poolAddress, position.id)
+    positionNft = new Token(connection, mint, TOKEN_PROGRAM_ID, wallet)
+
+    const mintInfo = await positionNft.getMintInfo()
+    assert.equal(mintInfo.decimals, 0)
+    assert.ok(mintInfo.supply.eqn(1))
+    // kept by the program to sign the metadata, it never mints another token
+    assert.ok(mintInfo.mintAuthority?.equals(market.programAuthority))
+
+    const ownerNftAccount = await market.getPositionNftAccount(
+      poolAddress,
+      position.id,
+      positionOwner.publicKey
+    )
+    assert.ok((await positionNft.getAccountInfo(ownerNftAccount)).amount.eqn(1))
+  })
+
+  it('#createPositionNftMetadata() checks the base uri', async () => {
+    // the attributes of the position are appended as the query
+    await assertThrowsAsync(
+      market.createPositionNftMetadata(
+        { owner: positionOwner.publicKey, index: 0, baseUri: 'https://invariant.app/?position' },
+        positionOwner
+      ),
+      INVARIANT_ERRORS.INVALID_POSITION_NFT_URI
+    )
+    await assertThrowsAsync(
+      market.createPositionNftMetadata(
+        { owner: positionOwner.publicKey, index: 0, baseUri: 'a'.repeat(65) },
+        positionOwner
+      ),
+      INVARIANT_ERRORS.INVALID_POSITION_NFT_URI
+    )
+  })
+
+  it('#claimFee() and #removePosition() follow the nft', async () => {
+    const position = await market.getPosition(positionOwner.publicKey, 0)
+    const ownerNftAccount = await market.getPositionNftAccount(
+      poolAddress,
+      position.id,
+      positionOwner.publicKey
+    )
+    const buyerNftAccount = await positionNft.createAssociatedTokenAccount(buyer.publicKey)
+    await positionNft.transfer(ownerNftAccount, buyerNftAccount, positionOwner, [], 1)
+
+    // the position stays in the list of its owner, who can't manage it anymore
+    await assertThrowsAsync(
+      market.claimFee(
+        {
+          pair,
+          owner: positionOwner.publicKey,
+          userTokenX: ownerAccountX,
+          userTokenY: ownerAccountY,
+          index: 0
+        },
+        positionOwner
+      ),
+      INVARIANT_ERRORS.INVALID_OWNER
+    )
+
+    await market.claimFee(
+      {
+        pair,
+        owner: positionOwner.publicKey,
+        holder: buyer.publicKey,
+        userTokenX: buyerAccountX,
+        userTokenY: buyerAccountY,
+        index: 0
+      },
+      buyer
+    )
+
+    const xBefore = (await tokenX.getAccountInfo(buyerAccountX)).amount
+    await market.removePosition(
+      {
+        pair,
+        owner: positionOwner.publicKey,
+        holder: buyer.publicKey,
+        index: 0,
+        userTokenX: buyerAccountX,
+        userTokenY: buyerAccountY
+      },
+      buyer
+    )
+    assert.ok((await tokenX.getAccountInfo(buyerAccountX)).amount.gt(xBefore))
+    assert.equal((await market.getPositionList(positionOwner.publicKey)).head, 0)
+
+    // burned with the position, the emptied account is closed
+    assert.ok((await positionNft.getMintInfo()).supply.eqn(0))
+    assert.isNull(await connection.getAccountInfo(buyerNftAccount))
+  })
+})
@@ -161,6 +161,12 @@ describe('withdraw', () => {
       incorrectUpperTickIndex
     )
 
+    const poolAddress = await pair.getAddress(market.program.programId)
+    const { address: positionNftMint } = await market.getPositionNftMintAddress(
+      poolAddress,
+      position.id
+    )
+
     // remove position ix with incorrect ticks
     const removePositionIx = market.program.instruction.removePosition(
       removedPositionIndex,
@@ -170,6 +176,13 @@ describe('withdraw', () => {
         accounts: {
           state: (await market.getStateAddress()).address,
           owner: positionOwner.publicKey,
+          holder: positionOwner.publicKey,
+          positionNftMint,
+          positionNftAccount: await market.getPositionNftAccount(
+            poolAddress,
+            position.id,
+            positionOwner.publicKey
+          ),
           removedPosition: removedPositionAddress,
           positionList: positionListAddress,
           lastPosition: lastPositionAddress,